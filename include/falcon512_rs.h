@@ -0,0 +1,89 @@
+/*
+ * C API of falcon512_rs (libfalcon512_rs.so / .dylib / falcon512_rs.dll, built with
+ * `cargo rustc --release --lib --crate-type cdylib --features capi,std`).
+ *
+ * PQClean-style entry points for Falcon-512, with PQClean's key, signature and signed-message
+ * formats. The library has no randomness source: key generation and signing take a seed, which
+ * must come from a cryptographically secure RNG (48 bytes or more) and never be reused for
+ * signing. `FALCON512_RS_crypto_sign_keypair_seeded` is only exported when the library is built
+ * with `--features std`.
+ *
+ * Every function returns FALCON512_RS_OK (0) or a negative FALCON512_RS_ERR_* code.
+ *
+ * Kept in sync with src/capi.rs (checked by the test suite).
+ */
+
+#ifndef FALCON512_RS_H
+#define FALCON512_RS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define FALCON512_RS_CRYPTO_SECRETKEYBYTES 1281
+#define FALCON512_RS_CRYPTO_PUBLICKEYBYTES 897
+/* maximum signature size: header, 40-byte nonce, compressed s2 */
+#define FALCON512_RS_CRYPTO_BYTES 752
+#define FALCON512_RS_CRYPTO_ALGNAME "Falcon-512"
+/* coefficients of a prepared (NTT format) public key */
+#define FALCON512_RS_PREPARED_PUBLICKEY_COEFFS 512
+
+#define FALCON512_RS_OK 0
+#define FALCON512_RS_ERR_BAD_HEADER (-1)
+#define FALCON512_RS_ERR_COEFFICIENT_OUT_OF_RANGE (-2)
+#define FALCON512_RS_ERR_TRAILING_BITS (-3)
+#define FALCON512_RS_ERR_BAD_LENGTH (-4)
+#define FALCON512_RS_ERR_DECODE_FAILED (-5)
+#define FALCON512_RS_ERR_NORM_TOO_LARGE (-6)
+#define FALCON512_RS_ERR_MALFORMED_DER (-7)
+#define FALCON512_RS_ERR_MALFORMED_PEM (-8)
+#define FALCON512_RS_ERR_UNKNOWN_ALGORITHM (-9)
+#define FALCON512_RS_ERR_INVALID_SECRET_KEY (-10)
+#define FALCON512_RS_ERR_KEY_MISMATCH (-11)
+#define FALCON512_RS_ERR_ISSUER_MISMATCH (-12)
+#define FALCON512_RS_ERR_NULL_POINTER (-100)
+
+/* Describes an error code (static string). */
+const char *FALCON512_RS_error_message(int32_t code);
+
+/* Generates a key pair from a seed (built with the `std` feature only). */
+int32_t FALCON512_RS_crypto_sign_keypair_seeded(uint8_t *pk, uint8_t *sk, const uint8_t *seed,
+                                                size_t seedlen);
+
+/* Signs m into sig (at most CRYPTO_BYTES bytes: header || nonce || compressed s2). */
+int32_t FALCON512_RS_crypto_sign_signature_seeded(uint8_t *sig, size_t *siglen, const uint8_t *m,
+                                                  size_t mlen, const uint8_t *sk,
+                                                  const uint8_t *seed, size_t seedlen);
+
+/* Verifies sig (compressed, padded or constant-time format) on m. */
+int32_t FALCON512_RS_crypto_sign_verify(const uint8_t *sig, size_t siglen, const uint8_t *m,
+                                        size_t mlen, const uint8_t *pk);
+
+/* Converts pk to NTT format, for repeated FALCON512_RS_crypto_sign_verify_prepared calls. */
+int32_t FALCON512_RS_prepare_public_key(uint16_t pk_ntt[FALCON512_RS_PREPARED_PUBLICKEY_COEFFS],
+                                        const uint8_t *pk);
+
+/* FALCON512_RS_crypto_sign_verify with a prepared public key. */
+int32_t FALCON512_RS_crypto_sign_verify_prepared(
+    const uint8_t *sig, size_t siglen, const uint8_t *m, size_t mlen,
+    const uint16_t pk_ntt[FALCON512_RS_PREPARED_PUBLICKEY_COEFFS]);
+
+/*
+ * Signs m into the signed message sm (at most mlen + CRYPTO_BYTES + 2 bytes): 2-byte big-endian
+ * length of the signature part, nonce, message, 0x29 || compressed s2. m may overlap sm.
+ */
+int32_t FALCON512_RS_crypto_sign_seeded(uint8_t *sm, size_t *smlen, const uint8_t *m, size_t mlen,
+                                        const uint8_t *sk, const uint8_t *seed, size_t seedlen);
+
+/* Verifies a signed message and copies the message to m (room for smlen bytes). */
+int32_t FALCON512_RS_crypto_sign_open(uint8_t *m, size_t *mlen, const uint8_t *sm, size_t smlen,
+                                      const uint8_t *pk);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FALCON512_RS_H */
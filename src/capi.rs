@@ -0,0 +1,346 @@
+//! C API (`capi` feature), declared in `include/falcon512_rs.h`. The shared library is built with
+//! `cargo rustc --release --lib --crate-type cdylib --features capi,std`.
+//!
+//! The entry points follow the PQClean naming and conventions (`crypto_sign_keypair`,
+//! `crypto_sign_signature`, `crypto_sign_verify`, `crypto_sign`, `crypto_sign_open`) under the
+//! `FALCON512_RS_` prefix, with the same key, signature and signed-message formats as PQClean's
+//! Falcon-512. The crate has no randomness source of its own, so key generation and signing
+//! take a caller-provided seed (`_seeded`, fed to [`Shake256Prng`]); key generation is only
+//! exported when the library is built with the `std` feature.
+//!
+//! Every function returns [`FALCON512_RS_OK`] (0) or a negative error code, one per
+//! [`Error`] variant, plus [`FALCON512_RS_ERR_NULL_POINTER`]; `FALCON512_RS_error_message`
+//! describes a code. Nothing panics on malformed input and nothing allocates.
+
+// the exported names follow the C header
+#![allow(non_snake_case)]
+
+use core::{ffi::c_char, ptr, slice};
+
+#[cfg(feature = "std")]
+use crate::falcon512::{keygen, sk_encode};
+use crate::{
+    constants::{
+        FALCON_PK_SIZE, FALCON_SK_SIZE, LOGN, N, NONCE_LEN, SIG_COMP_MAXSIZE, SIG_HEADER_COMP,
+    },
+    error::Error,
+    falcon512::{pk_to_ntt_fmt, sign, sk_decode, verify_sig, PreparedPublicKey},
+    shake256::Shake256Prng,
+};
+
+pub const FALCON512_RS_OK: i32 = 0;
+pub const FALCON512_RS_ERR_NULL_POINTER: i32 = -100;
+
+// header of the signature part of a signed message (NIST format: `0x20 | LOGN`, where detached
+// signatures use `SIG_HEADER_COMP | LOGN`)
+const SM_SIG_HEADER: u8 = 0x20 | LOGN;
+
+/// Error code of an [`Error`] (stable: new variants get new codes).
+pub(crate) fn error_code(err: Error) -> i32 {
+    match err {
+        Error::BadHeader => -1,
+        Error::CoefficientOutOfRange => -2,
+        Error::TrailingBits => -3,
+        Error::BadLength => -4,
+        Error::DecodeFailed => -5,
+        Error::NormTooLarge => -6,
+        Error::MalformedDer => -7,
+        Error::MalformedPem => -8,
+        Error::UnknownAlgorithm => -9,
+        Error::InvalidSecretKey => -10,
+        Error::KeyMismatch => -11,
+        Error::IssuerMismatch => -12,
+    }
+}
+
+fn status(result: Result<(), Error>) -> i32 {
+    match result {
+        Ok(()) => FALCON512_RS_OK,
+        Err(err) => error_code(err),
+    }
+}
+
+/// Borrows `len` bytes at `p` (`p` may be null when `len` is 0).
+unsafe fn input<'a, T>(p: *const T, len: usize) -> Result<&'a [T], i32> {
+    match (p.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(FALCON512_RS_ERR_NULL_POINTER),
+        (false, _) => Ok(slice::from_raw_parts(p, len)),
+    }
+}
+
+/// Borrows a fixed-size array at `p`.
+unsafe fn array<'a, T, const L: usize>(p: *const T) -> Result<&'a [T; L], i32> {
+    match p.is_null() {
+        true => Err(FALCON512_RS_ERR_NULL_POINTER),
+        false => Ok(&*p.cast::<[T; L]>()),
+    }
+}
+
+macro_rules! try_c {
+    ($e:expr) => {
+        match $e {
+            Ok(v) => v,
+            Err(code) => return code,
+        }
+    };
+}
+
+/// Describes an error code, as a static NUL-terminated string.
+#[no_mangle]
+pub extern "C" fn FALCON512_RS_error_message(code: i32) -> *const c_char {
+    let msg = match code {
+        0 => c"success",
+        -1 => c"invalid header byte",
+        -2 => c"public key coefficient out of range",
+        -3 => c"non-zero padding",
+        -4 => c"invalid length",
+        -5 => c"invalid signature encoding",
+        -6 => c"signature vector too long",
+        -7 => c"malformed DER",
+        -8 => c"malformed PEM",
+        -9 => c"unsupported algorithm identifier",
+        -10 => c"invalid private key",
+        -11 => c"public key does not match the private key",
+        -12 => c"certificate issuer does not match",
+        FALCON512_RS_ERR_NULL_POINTER => c"null pointer",
+        _ => c"unknown error",
+    };
+
+    msg.as_ptr()
+}
+
+/// Generates a key pair from a seed (`std` feature).
+///
+/// # Safety
+/// `pk` and `sk` must be valid for writes of `CRYPTO_PUBLICKEYBYTES` / `CRYPTO_SECRETKEYBYTES`
+/// bytes, `seed` for reads of `seedlen` bytes.
+#[cfg(feature = "std")]
+#[no_mangle]
+pub unsafe extern "C" fn FALCON512_RS_crypto_sign_keypair_seeded(
+    pk: *mut u8,
+    sk: *mut u8,
+    seed: *const u8,
+    seedlen: usize,
+) -> i32 {
+    let seed = try_c!(input(seed, seedlen));
+
+    if pk.is_null() || sk.is_null() {
+        return FALCON512_RS_ERR_NULL_POINTER;
+    }
+
+    let (secret, public) = keygen(&mut Shake256Prng::from_seed(seed));
+
+    ptr::copy_nonoverlapping(public.as_ptr(), pk, FALCON_PK_SIZE);
+    ptr::copy_nonoverlapping(sk_encode(&secret).as_ptr(), sk, FALCON_SK_SIZE);
+
+    FALCON512_RS_OK
+}
+
+/// Signs `m` into `sig` (`header ‖ nonce ‖ compressed s2`, at most `CRYPTO_BYTES` bytes) and
+/// stores the signature length in `siglen`.
+///
+/// # Safety
+/// `sig` must be valid for writes of `CRYPTO_BYTES` bytes, `siglen` for a write, `m` for reads of
+/// `mlen` bytes, `sk` of `CRYPTO_SECRETKEYBYTES` bytes and `seed` of `seedlen` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn FALCON512_RS_crypto_sign_signature_seeded(
+    sig: *mut u8,
+    siglen: *mut usize,
+    m: *const u8,
+    mlen: usize,
+    sk: *const u8,
+    seed: *const u8,
+    seedlen: usize,
+) -> i32 {
+    let m = try_c!(input(m, mlen));
+    let sk = try_c!(array::<u8, FALCON_SK_SIZE>(sk));
+    let seed = try_c!(input(seed, seedlen));
+    let sk = try_c!(sk_decode(sk).map_err(error_code));
+
+    if sig.is_null() || siglen.is_null() {
+        return FALCON512_RS_ERR_NULL_POINTER;
+    }
+
+    let (nonce, comp_s2, len) = sign(&sk, m, &mut Shake256Prng::from_seed(seed));
+    let nonce_len = NONCE_LEN as usize;
+
+    *sig = SIG_HEADER_COMP | LOGN;
+    ptr::copy_nonoverlapping(nonce.as_ptr(), sig.add(1), nonce_len);
+    ptr::copy_nonoverlapping(comp_s2.as_ptr(), sig.add(1 + nonce_len), len);
+    *siglen = 1 + nonce_len + len;
+
+    FALCON512_RS_OK
+}
+
+/// Verifies a signature (`header ‖ nonce ‖ s2`, any format) on `m`.
+///
+/// # Safety
+/// `sig` must be valid for reads of `siglen` bytes, `m` of `mlen` bytes and `pk` of
+/// `CRYPTO_PUBLICKEYBYTES` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn FALCON512_RS_crypto_sign_verify(
+    sig: *const u8,
+    siglen: usize,
+    m: *const u8,
+    mlen: usize,
+    pk: *const u8,
+) -> i32 {
+    let sig = try_c!(input(sig, siglen));
+    let m = try_c!(input(m, mlen));
+    let pk = try_c!(array::<u8, FALCON_PK_SIZE>(pk));
+
+    status(pk_to_ntt_fmt(pk).and_then(|h| verify_sig(m, sig, &h)))
+}
+
+/// Converts a public key to NTT format (`N` coefficients), for repeated
+/// `crypto_sign_verify_prepared` calls.
+///
+/// # Safety
+/// `pk_ntt` must be valid for writes of `N` aligned `uint16_t`, `pk` for reads of
+/// `CRYPTO_PUBLICKEYBYTES` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn FALCON512_RS_prepare_public_key(pk_ntt: *mut u16, pk: *const u8) -> i32 {
+    let pk = try_c!(array::<u8, FALCON_PK_SIZE>(pk));
+
+    if pk_ntt.is_null() {
+        return FALCON512_RS_ERR_NULL_POINTER;
+    }
+
+    match pk_to_ntt_fmt(pk) {
+        Ok(h) => {
+            ptr::copy_nonoverlapping(h.as_ptr(), pk_ntt, N);
+
+            FALCON512_RS_OK
+        }
+        Err(err) => error_code(err),
+    }
+}
+
+/// [`FALCON512_RS_crypto_sign_verify`] with a key from `prepare_public_key`.
+///
+/// # Safety
+/// `sig` must be valid for reads of `siglen` bytes, `m` of `mlen` bytes and `pk_ntt` of `N`
+/// aligned `uint16_t`.
+#[no_mangle]
+pub unsafe extern "C" fn FALCON512_RS_crypto_sign_verify_prepared(
+    sig: *const u8,
+    siglen: usize,
+    m: *const u8,
+    mlen: usize,
+    pk_ntt: *const u16,
+) -> i32 {
+    let sig = try_c!(input(sig, siglen));
+    let m = try_c!(input(m, mlen));
+    let pk_ntt = try_c!(array::<u16, N>(pk_ntt));
+
+    status(PreparedPublicKey::from_ntt_fmt(pk_ntt).and_then(|h| verify_sig(m, sig, h.as_ntt_fmt())))
+}
+
+/// Signs `m` into the signed message `sm` (PQClean / NIST format: 2-byte big-endian length of
+/// the signature part, nonce, message, then `0x29 ‖ compressed s2`), at most
+/// `mlen + CRYPTO_BYTES + 2` bytes, and stores its length in `smlen`. `m` may overlap `sm`.
+///
+/// # Safety
+/// `sm` must be valid for writes of `mlen + CRYPTO_BYTES + 2` bytes, `smlen` for a write, `m`
+/// for reads of `mlen` bytes, `sk` of `CRYPTO_SECRETKEYBYTES` bytes and `seed` of `seedlen`
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn FALCON512_RS_crypto_sign_seeded(
+    sm: *mut u8,
+    smlen: *mut usize,
+    m: *const u8,
+    mlen: usize,
+    sk: *const u8,
+    seed: *const u8,
+    seedlen: usize,
+) -> i32 {
+    let sk = try_c!(array::<u8, FALCON_SK_SIZE>(sk));
+    let seed = try_c!(input(seed, seedlen));
+    let sk = try_c!(sk_decode(sk).map_err(error_code));
+    let nonce_len = NONCE_LEN as usize;
+
+    if sm.is_null() || smlen.is_null() || (m.is_null() && mlen != 0) {
+        return FALCON512_RS_ERR_NULL_POINTER;
+    }
+
+    // the message goes after the length and the nonce; move it first, it may overlap `sm`
+    if mlen != 0 {
+        ptr::copy(m, sm.add(2 + nonce_len), mlen);
+    }
+
+    let msg = try_c!(input(sm.add(2 + nonce_len), mlen));
+    let (nonce, comp_s2, len) = sign(&sk, msg, &mut Shake256Prng::from_seed(seed));
+    let sig_len = 1 + len;
+
+    *sm = (sig_len >> 8) as u8;
+    *sm.add(1) = sig_len as u8;
+    ptr::copy_nonoverlapping(nonce.as_ptr(), sm.add(2), nonce_len);
+    *sm.add(2 + nonce_len + mlen) = SM_SIG_HEADER;
+    ptr::copy_nonoverlapping(comp_s2.as_ptr(), sm.add(3 + nonce_len + mlen), len);
+    *smlen = 2 + nonce_len + mlen + sig_len;
+
+    FALCON512_RS_OK
+}
+
+/// Verifies a signed message from `crypto_sign` and copies the message to `m` (at most `smlen`
+/// bytes), storing its length in `mlen`. `m` may overlap `sm`.
+///
+/// # Safety
+/// `m` must be valid for writes of `smlen` bytes, `mlen` for a write, `sm` for reads of `smlen`
+/// bytes and `pk` of `CRYPTO_PUBLICKEYBYTES` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn FALCON512_RS_crypto_sign_open(
+    m: *mut u8,
+    mlen: *mut usize,
+    sm: *const u8,
+    smlen: usize,
+    pk: *const u8,
+) -> i32 {
+    let signed = try_c!(input(sm, smlen));
+    let pk = try_c!(array::<u8, FALCON_PK_SIZE>(pk));
+    let nonce_len = NONCE_LEN as usize;
+
+    if m.is_null() || mlen.is_null() {
+        return FALCON512_RS_ERR_NULL_POINTER;
+    }
+
+    if smlen < 2 + nonce_len {
+        return error_code(Error::BadLength);
+    }
+
+    let sig_len = (signed[0] as usize) << 8 | signed[1] as usize;
+
+    if sig_len == 0 || sig_len > smlen - 2 - nonce_len {
+        return error_code(Error::BadLength);
+    }
+
+    let msg_len = smlen - 2 - nonce_len - sig_len;
+    let (nonce, msg_sig) = signed[2..].split_at(nonce_len);
+    let (msg, sig) = msg_sig.split_at(msg_len);
+
+    if sig[0] != SM_SIG_HEADER {
+        return error_code(Error::BadHeader);
+    }
+
+    if sig_len - 1 > SIG_COMP_MAXSIZE as usize {
+        return error_code(Error::BadLength);
+    }
+
+    // rebuild the detached signature `header ‖ nonce ‖ compressed s2`; the message may be empty
+    let mut detached = [0u8; 1 + NONCE_LEN as usize + SIG_COMP_MAXSIZE as usize];
+    let detached_len = nonce_len + sig_len;
+
+    detached[0] = SIG_HEADER_COMP | LOGN;
+    detached[1..1 + nonce_len].copy_from_slice(nonce);
+    detached[1 + nonce_len..detached_len].copy_from_slice(&sig[1..]);
+
+    let h = try_c!(pk_to_ntt_fmt(pk).map_err(error_code));
+
+    try_c!(verify_sig(msg, &detached[..detached_len], &h).map_err(error_code));
+
+    ptr::copy(sm.add(2 + nonce_len), m, msg_len);
+    *mlen = msg_len;
+
+    FALCON512_RS_OK
+}
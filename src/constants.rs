@@ -13,11 +13,33 @@ pub const SHAKE256_RATE: u8 = 136;
 
 pub const FALCON_PK_SIZE: usize = 897;
 
+// secret key: header, f and g at SK_FG_BITS, F at SK_BIG_F_BITS per coefficient (G is recomputed)
+pub const FALCON_SK_SIZE: usize = 1281;
+pub const SK_FG_BITS: u32 = 6;
+pub const SK_BIG_F_BITS: u32 = 8;
+
+// expanded secret key: header, then the FFT basis and the normalized Falcon tree, as binary64 (LE)
+pub const FFLDL_TREE_SIZE: usize = (LOGN as usize + 1) << LOGN;
+pub const FALCON_EXPANDED_SK_SIZE: usize = 1 + 8 * (4 * N + FFLDL_TREE_SIZE);
+
 pub const NONCE_LEN: u8 = 40;
 
 // max sig size (in bytes) when using the COMPRESSED format (- 1 - _NONCE_LEN)
 pub const SIG_COMP_MAXSIZE: u16 = 711;
 
+// signature header byte: format in the high nibble, LOGN in the low nibble (COMPRESSED and PADDED
+// share a header)
+pub const SIG_HEADER_COMP: u8 = 0x30;
+pub const SIG_HEADER_CT: u8 = 0x50;
+
+// total sig size (in bytes, header and nonce included) when using the PADDED format
+pub const SIG_PADDED_SIZE: usize = 666;
+
+// total sig size (in bytes, header and nonce included) when using the CT format, and bits per s2
+// coefficient
+pub const SIG_CT_SIZE: usize = 809;
+pub const SIG_CT_BITS: u32 = 12;
+
 // LOGN = 9 oversampling (hash_to_point_ct)
 pub const OVER_SAMPLING: u8 = 205;
 
@@ -41,7 +63,7 @@ pub const R2: u16 = 10952;
 // GMB[x] = g^rev(x) mod q monty
 // where g = 7 (it is a 2048-th primitive root of 1 modulo q)
 // and rev() is the bit-reversal function over 10 bits.
-pub const GMB: [u16; 512] = [
+pub const GMB: [u16; 1024] = [
     4091, 7888, 11060, 11208, 6960, 4342, 6275, 9759, 1591, 6399, 9477, 5266, 586, 5825, 7538,
     9710, 1134, 6407, 1711, 965, 7099, 7674, 3743, 6442, 10414, 8100, 1885, 1688, 1364, 10329,
     10164, 9180, 12210, 6240, 997, 117, 4783, 4407, 1549, 7072, 2829, 6458, 4431, 8877, 7144, 2564,
@@ -75,13 +97,46 @@ pub const GMB: [u16; 512] = [
     10213, 12184, 7827, 11903, 5600, 9779, 1012, 721, 2784, 6676, 6552, 5348, 4424, 6816, 8405,
     9959, 5150, 2356, 5552, 5267, 1333, 8801, 9661, 7308, 5788, 4910, 909, 11613, 4395, 8238, 6686,
     4302, 3044, 2285, 12249, 1963, 9216, 4296, 11918, 695, 4371, 9793, 4884, 2411, 10230, 2650,
-    841, 3890, 10231, 7248, 8505, 11196, 6688,
+    841, 3890, 10231, 7248, 8505, 11196, 6688, 4059, 6060, 3686, 4722, 11853, 5816, 7058, 6868,
+    11137, 7926, 4894, 12284, 4102, 3908, 3610, 6525, 7938, 7982, 11977, 6755, 537, 4562, 1623,
+    8227, 11453, 7544, 906, 11816, 9548, 10858, 9703, 2815, 11736, 6813, 6979, 819, 8903, 6271,
+    10843, 348, 7514, 8339, 6439, 694, 852, 5659, 2781, 3716, 11589, 3024, 1523, 8659, 4114, 10738,
+    3303, 5885, 2978, 7289, 11884, 9123, 9323, 11830, 98, 2526, 2116, 4131, 11407, 1844, 3645,
+    3916, 8133, 2224, 10871, 8092, 9651, 5989, 7140, 8480, 1670, 159, 10923, 4918, 128, 7312, 725,
+    9157, 5006, 6393, 3494, 6043, 10972, 6181, 11838, 3423, 10514, 7668, 3693, 6658, 6905, 11953,
+    10212, 11922, 9101, 8365, 5110, 45, 2400, 1921, 4377, 2720, 1695, 51, 2808, 650, 1896, 9997,
+    9971, 11980, 8098, 4833, 4135, 4257, 5838, 4765, 10985, 11532, 590, 12198, 482, 12173, 2006,
+    7064, 10018, 3912, 12016, 10519, 11362, 6954, 2210, 284, 5413, 6601, 3865, 10339, 11188, 6231,
+    517, 9564, 11281, 3863, 1210, 4604, 8160, 11447, 153, 7204, 5763, 5089, 9248, 12154, 11748,
+    1354, 6672, 179, 5532, 2646, 5941, 12185, 862, 3158, 477, 7279, 5678, 7914, 4254, 302, 2893,
+    10114, 6890, 9560, 9647, 11905, 4098, 9824, 10269, 1353, 10715, 5325, 6254, 3951, 1807, 6449,
+    5159, 1308, 8315, 3404, 1877, 1231, 112, 6398, 11724, 12272, 7286, 1459, 12274, 9896, 3456,
+    800, 1397, 10678, 103, 7420, 7976, 936, 764, 632, 7996, 8223, 8445, 7758, 10870, 9571, 2508,
+    1946, 6524, 10158, 1044, 4338, 2457, 3641, 1659, 4139, 4688, 9733, 11148, 3946, 2082, 5261,
+    2036, 11850, 7636, 12236, 5366, 2380, 1399, 7720, 2100, 3217, 10912, 8898, 7578, 11995, 2791,
+    1215, 3355, 2711, 2267, 2004, 8568, 10176, 3214, 2337, 1750, 4729, 4997, 7415, 6315, 12044,
+    4374, 7157, 4844, 211, 8003, 10159, 9290, 11481, 1735, 2336, 5793, 9875, 8192, 986, 7527, 1401,
+    870, 3615, 8465, 2756, 9770, 2034, 10168, 3264, 6132, 54, 2880, 4763, 11805, 3074, 8286, 9428,
+    4881, 6933, 1090, 10038, 2567, 708, 893, 6465, 4962, 10024, 2090, 5718, 10743, 780, 4733, 4623,
+    2134, 2087, 4802, 884, 5372, 5795, 5938, 4333, 6559, 7549, 5269, 10664, 4252, 3260, 5917,
+    10814, 5768, 9983, 8096, 7791, 6800, 7491, 6272, 1907, 10947, 6289, 11803, 6032, 11449, 1171,
+    9201, 7933, 2479, 7970, 11337, 7062, 8911, 6728, 6542, 8114, 8828, 6595, 3545, 4348, 4610,
+    2205, 6999, 8106, 5560, 10390, 9321, 2499, 2413, 7272, 6881, 10582, 9308, 9437, 3554, 3326,
+    5991, 11969, 3415, 12283, 9838, 12063, 4332, 7830, 11329, 6605, 12271, 2044, 11611, 7353,
+    11201, 11582, 3733, 8943, 9978, 1627, 7168, 3935, 5050, 2762, 7496, 10383, 755, 1654, 12053,
+    4952, 10134, 4394, 6592, 7898, 7497, 8904, 12029, 3581, 10748, 5674, 10358, 4901, 7414, 8771,
+    710, 6764, 8462, 7193, 5371, 7274, 11084, 290, 7864, 6827, 11822, 2509, 6578, 4026, 5807, 1458,
+    5721, 5762, 4178, 2105, 11621, 4852, 8897, 2856, 11510, 9264, 2520, 8776, 7011, 2647, 1898,
+    7039, 5950, 11163, 5488, 6277, 9182, 11456, 633, 10046, 11554, 5633, 9587, 2333, 7008, 7084,
+    5047, 7199, 9865, 8997, 569, 6390, 10845, 9679, 8268, 11472, 4203, 1997, 2, 9331, 162, 6182,
+    2000, 3649, 9792, 6363, 7557, 6187, 8510, 9935, 5536, 9019, 3706, 12009, 1452, 3067, 5494,
+    9692, 4865, 6019, 7106, 9610, 4588, 10165, 6261, 5887, 2652, 10172, 1580, 10379, 4638, 9949,
 ];
 
 // table for inverse NTT, binary case:
 // IGMB[x] = (1/g)^rev(x) mod q monty
 // since g = 7, 1/g = 8778 mod 12289.
-pub const IGMB: [u16; 512] = [
+pub const IGMB: [u16; 1024] = [
     4091, 4401, 1081, 1229, 2530, 6014, 7947, 5329, 2579, 4751, 6464, 11703, 7023, 2812, 5890,
     10698, 3109, 2125, 1960, 10925, 10601, 10404, 4189, 1875, 5847, 8546, 4615, 5190, 11324, 10578,
     5882, 11155, 8417, 12275, 10599, 7446, 5719, 3569, 5981, 10108, 4426, 8306, 10755, 4679, 11052,
@@ -115,7 +170,40 @@ pub const IGMB: [u16; 512] = [
     10857, 8069, 832, 1628, 3410, 4900, 10855, 5111, 9543, 6325, 7431, 4083, 3072, 8847, 9853,
     10122, 5259, 11413, 6556, 303, 1465, 3871, 4873, 5813, 10017, 6898, 3311, 5947, 8637, 5852,
     3856, 928, 4933, 8530, 1871, 2184, 5571, 5879, 3481, 11597, 9511, 8153, 35, 2609, 5963, 8064,
-    1080, 12039, 8444, 3052, 3813, 11065, 6736, 8454,
+    1080, 12039, 8444, 3052, 3813, 11065, 6736, 8454, 2340, 7651, 1910, 10709, 2117, 9637, 6402,
+    6028, 2124, 7701, 2679, 5183, 6270, 7424, 2597, 6795, 9222, 10837, 280, 8583, 3270, 6753, 2354,
+    3779, 6102, 4732, 5926, 2497, 8640, 10289, 6107, 12127, 2958, 12287, 10292, 8086, 817, 4021,
+    2610, 1444, 5899, 11720, 3292, 2424, 5090, 7242, 5205, 5281, 9956, 2702, 6656, 735, 2243,
+    11656, 833, 3107, 6012, 6801, 1126, 6339, 5250, 10391, 9642, 5278, 3513, 9769, 3025, 779, 9433,
+    3392, 7437, 668, 10184, 8111, 6527, 6568, 10831, 6482, 8263, 5711, 9780, 467, 5462, 4425,
+    11999, 1205, 5015, 6918, 5096, 3827, 5525, 11579, 3518, 4875, 7388, 1931, 6615, 1541, 8708,
+    260, 3385, 4792, 4391, 5697, 7895, 2155, 7337, 236, 10635, 11534, 1906, 4793, 9527, 7239, 8354,
+    5121, 10662, 2311, 3346, 8556, 707, 1088, 4936, 678, 10245, 18, 5684, 960, 4459, 7957, 226,
+    2451, 6, 8874, 320, 6298, 8963, 8735, 2852, 2981, 1707, 5408, 5017, 9876, 9790, 2968, 1899,
+    6729, 4183, 5290, 10084, 7679, 7941, 8744, 5694, 3461, 4175, 5747, 5561, 3378, 5227, 952, 4319,
+    9810, 4356, 3088, 11118, 840, 6257, 486, 6000, 1342, 10382, 6017, 4798, 5489, 4498, 4193, 2306,
+    6521, 1475, 6372, 9029, 8037, 1625, 7020, 4740, 5730, 7956, 6351, 6494, 6917, 11405, 7487,
+    10202, 10155, 7666, 7556, 11509, 1546, 6571, 10199, 2265, 7327, 5824, 11396, 11581, 9722, 2251,
+    11199, 5356, 7408, 2861, 4003, 9215, 484, 7526, 9409, 12235, 6157, 9025, 2121, 10255, 2519,
+    9533, 3824, 8674, 11419, 10888, 4762, 11303, 4097, 2414, 6496, 9953, 10554, 808, 2999, 2130,
+    4286, 12078, 7445, 5132, 7915, 245, 5974, 4874, 7292, 7560, 10539, 9952, 9075, 2113, 3721,
+    10285, 10022, 9578, 8934, 11074, 9498, 294, 4711, 3391, 1377, 9072, 10189, 4569, 10890, 9909,
+    6923, 53, 4653, 439, 10253, 7028, 10207, 8343, 1141, 2556, 7601, 8150, 10630, 8648, 9832, 7951,
+    11245, 2131, 5765, 10343, 9781, 2718, 1419, 4531, 3844, 4066, 4293, 11657, 11525, 11353, 4313,
+    4869, 12186, 1611, 10892, 11489, 8833, 2393, 15, 10830, 5003, 17, 565, 5891, 12177, 11058,
+    10412, 8885, 3974, 10981, 7130, 5840, 10482, 8338, 6035, 6964, 1574, 10936, 2020, 2465, 8191,
+    384, 2642, 2729, 5399, 2175, 9396, 11987, 8035, 4375, 6611, 5010, 11812, 9131, 11427, 104,
+    6348, 9643, 6757, 12110, 5617, 10935, 541, 135, 3041, 7200, 6526, 5085, 12136, 842, 4129, 7685,
+    11079, 8426, 1008, 2725, 11772, 6058, 1101, 1950, 8424, 5688, 6876, 12005, 10079, 5335, 927,
+    1770, 273, 8377, 2271, 5225, 10283, 116, 11807, 91, 11699, 757, 1304, 7524, 6451, 8032, 8154,
+    7456, 4191, 309, 2318, 2292, 10393, 11639, 9481, 12238, 10594, 9569, 7912, 10368, 9889, 12244,
+    7179, 3924, 3188, 367, 2077, 336, 5384, 5631, 8596, 4621, 1775, 8866, 451, 6108, 1317, 6246,
+    8795, 5896, 7283, 3132, 11564, 4977, 12161, 7371, 1366, 12130, 10619, 3809, 5149, 6300, 2638,
+    4197, 1418, 10065, 4156, 8373, 8644, 10445, 882, 8158, 10173, 9763, 12191, 459, 2966, 3166,
+    405, 5000, 9311, 6404, 8986, 1551, 8175, 3630, 10766, 9265, 700, 8573, 9508, 6630, 11437,
+    11595, 5850, 3950, 4775, 11941, 1446, 6018, 3386, 11470, 5310, 5476, 553, 9474, 2586, 1431,
+    2741, 473, 11383, 4745, 836, 4062, 10666, 7727, 11752, 5534, 312, 4307, 4351, 5764, 8679, 8381,
+    8187, 5, 7395, 4363, 1152, 5421, 5231, 6473, 436, 7567, 8603, 6229, 8230,
 ];
 
 // shake256 round pub constants
@@ -162,6 +250,1119 @@ pub const SHAKE_VARTIME_BLOCKS: usize = 9;
 pub const SHAKE256_RATE_WORDS: usize = 17;
 pub const SHAKE_VARTIME_WORDS: usize = SHAKE_VARTIME_BLOCKS * SHAKE256_RATE_WORDS; // 153
 
-pub mod errors {
-    pub const E_INVALID_PUBLIC_KEY: &str = "INVALID PK";
+// Discrete Gaussian sampler (keygen + signing).
+// The base sampler draws from a half-Gaussian of standard deviation sigma0 = 1.8205, with a
+// 72-bit reverse cumulative distribution table (3 x 24-bit limbs per entry, high limb first).
+pub const GAUSS0_RCDT: [u32; 54] = [
+    10745844, 3068844, 3741698, 5559083, 1580863, 8248194, 2260429, 13669192, 2736639, 708981,
+    4421575, 10046180, 169348, 7122675, 4136815, 30538, 13063405, 7650655, 4132, 14505003, 7826148,
+    417, 16768101, 11363290, 31, 8444042, 8086568, 1, 12844466, 265321, 0, 1232676, 13644283, 0,
+    38047, 9111839, 0, 870, 6138264, 0, 14, 12545723, 0, 0, 3104126, 0, 0, 28824, 0, 0, 198, 0, 0,
+    1,
+];
+
+// 1 / (2 * sigma0^2)
+pub const INV_2SQRSIGMA0: f64 = 0.150865048875372721532312163019;
+
+// Coefficients of the exp(-x) approximation used by the Bernoulli rejection step, scaled by 2^63
+// (FACCT, https://eprint.iacr.org/2018/1234).
+pub const EXPM_P63_COEFFS: [u64; 13] = [
+    0x00000004741183A3,
+    0x00000036548CFC06,
+    0x0000024FDCBF140A,
+    0x0000171D939DE045,
+    0x0000D00CF58F6F84,
+    0x000680681CF796E3,
+    0x002D82D8305B0FEA,
+    0x011111110E066FD0,
+    0x0555555555070F00,
+    0x155555555581FF00,
+    0x400000000002B400,
+    0x7FFFFFFFFFFF4800,
+    0x8000000000000000,
+];
+
+// Keygen: f and g coefficients follow D_{Z, sigma_fg} with sigma_fg = 1.17 * sqrt(q / (2 * N)),
+// obtained by summing 4096 / N samples of standard deviation FG_SIGMA_STAR.
+pub const FG_SIGMA_STAR: f64 = 1.43300980528773;
+pub const FG_SAMPLES: usize = 4096;
+
+// Keygen: maximal squared Gram-Schmidt norm of the NTRU basis, (1.17^2) * q.
+pub const GS_NORM_BOUND: f64 = 16822.4121;
+
+// Largest magnitude a secret key coefficient may take: f and g (SK_FG_BITS), F and G (8 bits).
+pub const SK_FG_COEFF_MAX: i32 = 31;
+pub const SK_COEFF_MAX: i32 = 127;
+
+// Signing: 1 / sigma (sigma = 165.7366171829776) and the smallest leaf standard deviation of the
+// Falcon tree, for LOGN = 9.
+pub const SIGN_INV_SIGMA: f64 = 0.006033669668157724;
+pub const SIGN_SIGMA_MIN: f64 = 1.2778336969128337;
+
+// Acceptance bound on the squared norm of a signature vector (s1, s2), for LOGN = 9.
+pub const SIG_L2_BOUND: u32 = 34034726;
+
+// Number of bytes drawn from the caller's randomness to seed the signing sampler.
+pub const SIGN_SEED_LEN: usize = 48;
+
+// Table for the floating-point FFT (keygen / signing), binary case:
+// FFT_GM[2x] + i * FFT_GM[2x + 1] = exp(i * pi * (2 * rev(x - 2^j) + 1) / 2^(j + 1)),
+// with 2^j <= x < 2^(j + 1) and rev() the bit-reversal function over j bits. Entry 0 is unused.
+// These are the roots of X^N + 1 in the order the FFT butterflies consume them (same layout as
+// the Falcon reference `fpr_gm_tab`), as exact f64 constants so that every platform transforms
+// identically.
+#[allow(clippy::approx_constant)]
+pub const FFT_GM: [f64; 1024] = [
+    0.0,
+    0.0,
+    0.0,
+    1.0,
+    0.7071067811865476,
+    0.7071067811865476,
+    -0.7071067811865476,
+    0.7071067811865476,
+    0.9238795325112867,
+    0.3826834323650898,
+    -0.3826834323650898,
+    0.9238795325112867,
+    0.3826834323650898,
+    0.9238795325112867,
+    -0.9238795325112867,
+    0.3826834323650898,
+    0.9807852804032304,
+    0.19509032201612828,
+    -0.19509032201612828,
+    0.9807852804032304,
+    0.5555702330196022,
+    0.8314696123025452,
+    -0.8314696123025452,
+    0.5555702330196022,
+    0.8314696123025452,
+    0.5555702330196022,
+    -0.5555702330196022,
+    0.8314696123025452,
+    0.19509032201612828,
+    0.9807852804032304,
+    -0.9807852804032304,
+    0.19509032201612828,
+    0.9951847266721969,
+    0.0980171403295606,
+    -0.0980171403295606,
+    0.9951847266721969,
+    0.6343932841636455,
+    0.773010453362737,
+    -0.773010453362737,
+    0.6343932841636455,
+    0.881921264348355,
+    0.47139673682599764,
+    -0.47139673682599764,
+    0.881921264348355,
+    0.2902846772544624,
+    0.9569403357322088,
+    -0.9569403357322088,
+    0.2902846772544624,
+    0.9569403357322088,
+    0.2902846772544624,
+    -0.2902846772544624,
+    0.9569403357322088,
+    0.47139673682599764,
+    0.881921264348355,
+    -0.881921264348355,
+    0.47139673682599764,
+    0.773010453362737,
+    0.6343932841636455,
+    -0.6343932841636455,
+    0.773010453362737,
+    0.0980171403295606,
+    0.9951847266721969,
+    -0.9951847266721969,
+    0.0980171403295606,
+    0.9987954562051724,
+    0.049067674327418015,
+    -0.049067674327418015,
+    0.9987954562051724,
+    0.6715589548470184,
+    0.7409511253549591,
+    -0.7409511253549591,
+    0.6715589548470184,
+    0.9039892931234433,
+    0.4275550934302821,
+    -0.4275550934302821,
+    0.9039892931234433,
+    0.33688985339222005,
+    0.9415440651830208,
+    -0.9415440651830208,
+    0.33688985339222005,
+    0.970031253194544,
+    0.2429801799032639,
+    -0.2429801799032639,
+    0.970031253194544,
+    0.5141027441932218,
+    0.8577286100002721,
+    -0.8577286100002721,
+    0.5141027441932218,
+    0.8032075314806449,
+    0.5956993044924334,
+    -0.5956993044924334,
+    0.8032075314806449,
+    0.14673047445536175,
+    0.989176509964781,
+    -0.989176509964781,
+    0.14673047445536175,
+    0.989176509964781,
+    0.14673047445536175,
+    -0.14673047445536175,
+    0.989176509964781,
+    0.5956993044924334,
+    0.8032075314806449,
+    -0.8032075314806449,
+    0.5956993044924334,
+    0.8577286100002721,
+    0.5141027441932218,
+    -0.5141027441932218,
+    0.8577286100002721,
+    0.2429801799032639,
+    0.970031253194544,
+    -0.970031253194544,
+    0.2429801799032639,
+    0.9415440651830208,
+    0.33688985339222005,
+    -0.33688985339222005,
+    0.9415440651830208,
+    0.4275550934302821,
+    0.9039892931234433,
+    -0.9039892931234433,
+    0.4275550934302821,
+    0.7409511253549591,
+    0.6715589548470184,
+    -0.6715589548470184,
+    0.7409511253549591,
+    0.049067674327418015,
+    0.9987954562051724,
+    -0.9987954562051724,
+    0.049067674327418015,
+    0.9996988186962042,
+    0.024541228522912288,
+    -0.024541228522912288,
+    0.9996988186962042,
+    0.6895405447370669,
+    0.7242470829514669,
+    -0.7242470829514669,
+    0.6895405447370669,
+    0.9142097557035307,
+    0.40524131400498986,
+    -0.40524131400498986,
+    0.9142097557035307,
+    0.35989503653498817,
+    0.9329927988347388,
+    -0.9329927988347388,
+    0.35989503653498817,
+    0.9757021300385286,
+    0.2191012401568698,
+    -0.2191012401568698,
+    0.9757021300385286,
+    0.5349976198870973,
+    0.8448535652497071,
+    -0.8448535652497071,
+    0.5349976198870973,
+    0.8175848131515837,
+    0.5758081914178453,
+    -0.5758081914178453,
+    0.8175848131515837,
+    0.17096188876030122,
+    0.9852776423889412,
+    -0.9852776423889412,
+    0.17096188876030122,
+    0.99247953459871,
+    0.1224106751992162,
+    -0.1224106751992162,
+    0.99247953459871,
+    0.6152315905806268,
+    0.7883464276266062,
+    -0.7883464276266062,
+    0.6152315905806268,
+    0.8700869911087115,
+    0.49289819222978404,
+    -0.49289819222978404,
+    0.8700869911087115,
+    0.26671275747489837,
+    0.9637760657954398,
+    -0.9637760657954398,
+    0.26671275747489837,
+    0.9495281805930367,
+    0.31368174039889146,
+    -0.31368174039889146,
+    0.9495281805930367,
+    0.4496113296546066,
+    0.8932243011955153,
+    -0.8932243011955153,
+    0.4496113296546066,
+    0.7572088465064846,
+    0.6531728429537768,
+    -0.6531728429537768,
+    0.7572088465064846,
+    0.07356456359966743,
+    0.9972904566786902,
+    -0.9972904566786902,
+    0.07356456359966743,
+    0.9972904566786902,
+    0.07356456359966743,
+    -0.07356456359966743,
+    0.9972904566786902,
+    0.6531728429537768,
+    0.7572088465064846,
+    -0.7572088465064846,
+    0.6531728429537768,
+    0.8932243011955153,
+    0.4496113296546066,
+    -0.4496113296546066,
+    0.8932243011955153,
+    0.31368174039889146,
+    0.9495281805930367,
+    -0.9495281805930367,
+    0.31368174039889146,
+    0.9637760657954398,
+    0.26671275747489837,
+    -0.26671275747489837,
+    0.9637760657954398,
+    0.49289819222978404,
+    0.8700869911087115,
+    -0.8700869911087115,
+    0.49289819222978404,
+    0.7883464276266062,
+    0.6152315905806268,
+    -0.6152315905806268,
+    0.7883464276266062,
+    0.1224106751992162,
+    0.99247953459871,
+    -0.99247953459871,
+    0.1224106751992162,
+    0.9852776423889412,
+    0.17096188876030122,
+    -0.17096188876030122,
+    0.9852776423889412,
+    0.5758081914178453,
+    0.8175848131515837,
+    -0.8175848131515837,
+    0.5758081914178453,
+    0.8448535652497071,
+    0.5349976198870973,
+    -0.5349976198870973,
+    0.8448535652497071,
+    0.2191012401568698,
+    0.9757021300385286,
+    -0.9757021300385286,
+    0.2191012401568698,
+    0.9329927988347388,
+    0.35989503653498817,
+    -0.35989503653498817,
+    0.9329927988347388,
+    0.40524131400498986,
+    0.9142097557035307,
+    -0.9142097557035307,
+    0.40524131400498986,
+    0.7242470829514669,
+    0.6895405447370669,
+    -0.6895405447370669,
+    0.7242470829514669,
+    0.024541228522912288,
+    0.9996988186962042,
+    -0.9996988186962042,
+    0.024541228522912288,
+    0.9999247018391445,
+    0.012271538285719925,
+    -0.012271538285719925,
+    0.9999247018391445,
+    0.6983762494089728,
+    0.7157308252838187,
+    -0.7157308252838187,
+    0.6983762494089728,
+    0.9191138516900578,
+    0.3939920400610481,
+    -0.3939920400610481,
+    0.9191138516900578,
+    0.37131719395183754,
+    0.9285060804732156,
+    -0.9285060804732156,
+    0.37131719395183754,
+    0.9783173707196277,
+    0.20711137619221856,
+    -0.20711137619221856,
+    0.9783173707196277,
+    0.5453249884220465,
+    0.8382247055548381,
+    -0.8382247055548381,
+    0.5453249884220465,
+    0.8245893027850253,
+    0.5657318107836132,
+    -0.5657318107836132,
+    0.8245893027850253,
+    0.18303988795514095,
+    0.9831054874312163,
+    -0.9831054874312163,
+    0.18303988795514095,
+    0.9939069700023561,
+    0.11022220729388306,
+    -0.11022220729388306,
+    0.9939069700023561,
+    0.6248594881423863,
+    0.7807372285720945,
+    -0.7807372285720945,
+    0.6248594881423863,
+    0.8760700941954066,
+    0.4821837720791228,
+    -0.4821837720791228,
+    0.8760700941954066,
+    0.2785196893850531,
+    0.9604305194155658,
+    -0.9604305194155658,
+    0.2785196893850531,
+    0.9533060403541939,
+    0.3020059493192281,
+    -0.3020059493192281,
+    0.9533060403541939,
+    0.46053871095824,
+    0.8876396204028539,
+    -0.8876396204028539,
+    0.46053871095824,
+    0.765167265622459,
+    0.6438315428897915,
+    -0.6438315428897915,
+    0.765167265622459,
+    0.0857973123444399,
+    0.996312612182778,
+    -0.996312612182778,
+    0.0857973123444399,
+    0.9981181129001492,
+    0.06132073630220858,
+    -0.06132073630220858,
+    0.9981181129001492,
+    0.6624157775901718,
+    0.7491363945234594,
+    -0.7491363945234594,
+    0.6624157775901718,
+    0.8986744656939538,
+    0.43861623853852766,
+    -0.43861623853852766,
+    0.8986744656939538,
+    0.3253102921622629,
+    0.9456073253805213,
+    -0.9456073253805213,
+    0.3253102921622629,
+    0.9669764710448521,
+    0.25486565960451457,
+    -0.25486565960451457,
+    0.9669764710448521,
+    0.5035383837257176,
+    0.8639728561215867,
+    -0.8639728561215867,
+    0.5035383837257176,
+    0.7958369046088836,
+    0.6055110414043255,
+    -0.6055110414043255,
+    0.7958369046088836,
+    0.1345807085071262,
+    0.99090263542778,
+    -0.99090263542778,
+    0.1345807085071262,
+    0.9873014181578584,
+    0.15885814333386145,
+    -0.15885814333386145,
+    0.9873014181578584,
+    0.5857978574564389,
+    0.8104571982525948,
+    -0.8104571982525948,
+    0.5857978574564389,
+    0.8513551931052652,
+    0.524589682678469,
+    -0.524589682678469,
+    0.8513551931052652,
+    0.2310581082806711,
+    0.9729399522055602,
+    -0.9729399522055602,
+    0.2310581082806711,
+    0.937339011912575,
+    0.34841868024943456,
+    -0.34841868024943456,
+    0.937339011912575,
+    0.4164295600976372,
+    0.9091679830905224,
+    -0.9091679830905224,
+    0.4164295600976372,
+    0.7326542716724128,
+    0.680600997795453,
+    -0.680600997795453,
+    0.7326542716724128,
+    0.03680722294135883,
+    0.9993223845883495,
+    -0.9993223845883495,
+    0.03680722294135883,
+    0.9993223845883495,
+    0.03680722294135883,
+    -0.03680722294135883,
+    0.9993223845883495,
+    0.680600997795453,
+    0.7326542716724128,
+    -0.7326542716724128,
+    0.680600997795453,
+    0.9091679830905224,
+    0.4164295600976372,
+    -0.4164295600976372,
+    0.9091679830905224,
+    0.34841868024943456,
+    0.937339011912575,
+    -0.937339011912575,
+    0.34841868024943456,
+    0.9729399522055602,
+    0.2310581082806711,
+    -0.2310581082806711,
+    0.9729399522055602,
+    0.524589682678469,
+    0.8513551931052652,
+    -0.8513551931052652,
+    0.524589682678469,
+    0.8104571982525948,
+    0.5857978574564389,
+    -0.5857978574564389,
+    0.8104571982525948,
+    0.15885814333386145,
+    0.9873014181578584,
+    -0.9873014181578584,
+    0.15885814333386145,
+    0.99090263542778,
+    0.1345807085071262,
+    -0.1345807085071262,
+    0.99090263542778,
+    0.6055110414043255,
+    0.7958369046088836,
+    -0.7958369046088836,
+    0.6055110414043255,
+    0.8639728561215867,
+    0.5035383837257176,
+    -0.5035383837257176,
+    0.8639728561215867,
+    0.25486565960451457,
+    0.9669764710448521,
+    -0.9669764710448521,
+    0.25486565960451457,
+    0.9456073253805213,
+    0.3253102921622629,
+    -0.3253102921622629,
+    0.9456073253805213,
+    0.43861623853852766,
+    0.8986744656939538,
+    -0.8986744656939538,
+    0.43861623853852766,
+    0.7491363945234594,
+    0.6624157775901718,
+    -0.6624157775901718,
+    0.7491363945234594,
+    0.06132073630220858,
+    0.9981181129001492,
+    -0.9981181129001492,
+    0.06132073630220858,
+    0.996312612182778,
+    0.0857973123444399,
+    -0.0857973123444399,
+    0.996312612182778,
+    0.6438315428897915,
+    0.765167265622459,
+    -0.765167265622459,
+    0.6438315428897915,
+    0.8876396204028539,
+    0.46053871095824,
+    -0.46053871095824,
+    0.8876396204028539,
+    0.3020059493192281,
+    0.9533060403541939,
+    -0.9533060403541939,
+    0.3020059493192281,
+    0.9604305194155658,
+    0.2785196893850531,
+    -0.2785196893850531,
+    0.9604305194155658,
+    0.4821837720791228,
+    0.8760700941954066,
+    -0.8760700941954066,
+    0.4821837720791228,
+    0.7807372285720945,
+    0.6248594881423863,
+    -0.6248594881423863,
+    0.7807372285720945,
+    0.11022220729388306,
+    0.9939069700023561,
+    -0.9939069700023561,
+    0.11022220729388306,
+    0.9831054874312163,
+    0.18303988795514095,
+    -0.18303988795514095,
+    0.9831054874312163,
+    0.5657318107836132,
+    0.8245893027850253,
+    -0.8245893027850253,
+    0.5657318107836132,
+    0.8382247055548381,
+    0.5453249884220465,
+    -0.5453249884220465,
+    0.8382247055548381,
+    0.20711137619221856,
+    0.9783173707196277,
+    -0.9783173707196277,
+    0.20711137619221856,
+    0.9285060804732156,
+    0.37131719395183754,
+    -0.37131719395183754,
+    0.9285060804732156,
+    0.3939920400610481,
+    0.9191138516900578,
+    -0.9191138516900578,
+    0.3939920400610481,
+    0.7157308252838187,
+    0.6983762494089728,
+    -0.6983762494089728,
+    0.7157308252838187,
+    0.012271538285719925,
+    0.9999247018391445,
+    -0.9999247018391445,
+    0.012271538285719925,
+    0.9999811752826011,
+    0.006135884649154475,
+    -0.006135884649154475,
+    0.9999811752826011,
+    0.7027547444572253,
+    0.7114321957452164,
+    -0.7114321957452164,
+    0.7027547444572253,
+    0.9215140393420419,
+    0.3883450466988263,
+    -0.3883450466988263,
+    0.9215140393420419,
+    0.37700741021641826,
+    0.9262102421383114,
+    -0.9262102421383114,
+    0.37700741021641826,
+    0.9795697656854405,
+    0.2011046348420919,
+    -0.2011046348420919,
+    0.9795697656854405,
+    0.5504579729366048,
+    0.83486287498638,
+    -0.83486287498638,
+    0.5504579729366048,
+    0.8280450452577558,
+    0.560661576197336,
+    -0.560661576197336,
+    0.8280450452577558,
+    0.18906866414980622,
+    0.9819638691095552,
+    -0.9819638691095552,
+    0.18906866414980622,
+    0.9945645707342554,
+    0.10412163387205457,
+    -0.10412163387205457,
+    0.9945645707342554,
+    0.629638238914927,
+    0.7768884656732324,
+    -0.7768884656732324,
+    0.629638238914927,
+    0.8790122264286335,
+    0.47679923006332214,
+    -0.47679923006332214,
+    0.8790122264286335,
+    0.2844075372112718,
+    0.9587034748958716,
+    -0.9587034748958716,
+    0.2844075372112718,
+    0.9551411683057707,
+    0.29615088824362384,
+    -0.29615088824362384,
+    0.9551411683057707,
+    0.4659764957679662,
+    0.8847970984309378,
+    -0.8847970984309378,
+    0.4659764957679662,
+    0.7691033376455796,
+    0.6391244448637757,
+    -0.6391244448637757,
+    0.7691033376455796,
+    0.09190895649713272,
+    0.9957674144676598,
+    -0.9957674144676598,
+    0.09190895649713272,
+    0.9984755805732948,
+    0.05519524434968994,
+    -0.05519524434968994,
+    0.9984755805732948,
+    0.6669999223036375,
+    0.745057785441466,
+    -0.745057785441466,
+    0.6669999223036375,
+    0.901348847046022,
+    0.43309381885315196,
+    -0.43309381885315196,
+    0.901348847046022,
+    0.33110630575987643,
+    0.9435934581619604,
+    -0.9435934581619604,
+    0.33110630575987643,
+    0.9685220942744173,
+    0.24892760574572018,
+    -0.24892760574572018,
+    0.9685220942744173,
+    0.508830142543107,
+    0.8608669386377673,
+    -0.8608669386377673,
+    0.508830142543107,
+    0.799537269107905,
+    0.600616479383869,
+    -0.600616479383869,
+    0.799537269107905,
+    0.14065823933284924,
+    0.9900582102622971,
+    -0.9900582102622971,
+    0.14065823933284924,
+    0.9882575677307495,
+    0.15279718525844344,
+    -0.15279718525844344,
+    0.9882575677307495,
+    0.5907597018588743,
+    0.8068475535437992,
+    -0.8068475535437992,
+    0.5907597018588743,
+    0.8545579883654005,
+    0.5193559901655896,
+    -0.5193559901655896,
+    0.8545579883654005,
+    0.2370236059943672,
+    0.9715038909862518,
+    -0.9715038909862518,
+    0.2370236059943672,
+    0.9394592236021899,
+    0.3426607173119944,
+    -0.3426607173119944,
+    0.9394592236021899,
+    0.4220002707997997,
+    0.9065957045149153,
+    -0.9065957045149153,
+    0.4220002707997997,
+    0.7368165688773699,
+    0.6760927035753159,
+    -0.6760927035753159,
+    0.7368165688773699,
+    0.04293825693494082,
+    0.9990777277526454,
+    -0.9990777277526454,
+    0.04293825693494082,
+    0.9995294175010931,
+    0.030674803176636626,
+    -0.030674803176636626,
+    0.9995294175010931,
+    0.6850836677727004,
+    0.7284643904482252,
+    -0.7284643904482252,
+    0.6850836677727004,
+    0.9117060320054299,
+    0.41084317105790397,
+    -0.41084317105790397,
+    0.9117060320054299,
+    0.3541635254204904,
+    0.9351835099389476,
+    -0.9351835099389476,
+    0.3541635254204904,
+    0.9743393827855759,
+    0.22508391135979283,
+    -0.22508391135979283,
+    0.9743393827855759,
+    0.5298036246862947,
+    0.8481203448032972,
+    -0.8481203448032972,
+    0.5298036246862947,
+    0.8140363297059484,
+    0.5808139580957645,
+    -0.5808139580957645,
+    0.8140363297059484,
+    0.16491312048996992,
+    0.9863080972445987,
+    -0.9863080972445987,
+    0.16491312048996992,
+    0.9917097536690995,
+    0.12849811079379317,
+    -0.12849811079379317,
+    0.9917097536690995,
+    0.6103828062763095,
+    0.7921065773002124,
+    -0.7921065773002124,
+    0.6103828062763095,
+    0.8670462455156926,
+    0.49822766697278187,
+    -0.49822766697278187,
+    0.8670462455156926,
+    0.2607941179152755,
+    0.9653944416976894,
+    -0.9653944416976894,
+    0.2607941179152755,
+    0.9475855910177411,
+    0.3195020308160157,
+    -0.3195020308160157,
+    0.9475855910177411,
+    0.44412214457042926,
+    0.8959662497561851,
+    -0.8959662497561851,
+    0.44412214457042926,
+    0.7531867990436125,
+    0.6578066932970786,
+    -0.6578066932970786,
+    0.7531867990436125,
+    0.06744391956366406,
+    0.9977230666441916,
+    -0.9977230666441916,
+    0.06744391956366406,
+    0.9968202992911657,
+    0.07968243797143013,
+    -0.07968243797143013,
+    0.9968202992911657,
+    0.6485144010221124,
+    0.7612023854842618,
+    -0.7612023854842618,
+    0.6485144010221124,
+    0.8904487232447579,
+    0.45508358712634384,
+    -0.45508358712634384,
+    0.8904487232447579,
+    0.30784964004153487,
+    0.9514350209690083,
+    -0.9514350209690083,
+    0.30784964004153487,
+    0.9621214042690416,
+    0.272621355449949,
+    -0.272621355449949,
+    0.9621214042690416,
+    0.48755016014843594,
+    0.8730949784182901,
+    -0.8730949784182901,
+    0.48755016014843594,
+    0.7845565971555752,
+    0.6200572117632892,
+    -0.6200572117632892,
+    0.7845565971555752,
+    0.11631863091190477,
+    0.9932119492347945,
+    -0.9932119492347945,
+    0.11631863091190477,
+    0.984210092386929,
+    0.17700422041214875,
+    -0.17700422041214875,
+    0.984210092386929,
+    0.5707807458869673,
+    0.8211025149911046,
+    -0.8211025149911046,
+    0.5707807458869673,
+    0.8415549774368984,
+    0.5401714727298929,
+    -0.5401714727298929,
+    0.8415549774368984,
+    0.21311031991609136,
+    0.9770281426577544,
+    -0.9770281426577544,
+    0.21311031991609136,
+    0.9307669610789837,
+    0.36561299780477385,
+    -0.36561299780477385,
+    0.9307669610789837,
+    0.39962419984564684,
+    0.9166790599210427,
+    -0.9166790599210427,
+    0.39962419984564684,
+    0.7200025079613817,
+    0.693971460889654,
+    -0.693971460889654,
+    0.7200025079613817,
+    0.01840672990580482,
+    0.9998305817958234,
+    -0.9998305817958234,
+    0.01840672990580482,
+    0.9998305817958234,
+    0.01840672990580482,
+    -0.01840672990580482,
+    0.9998305817958234,
+    0.693971460889654,
+    0.7200025079613817,
+    -0.7200025079613817,
+    0.693971460889654,
+    0.9166790599210427,
+    0.39962419984564684,
+    -0.39962419984564684,
+    0.9166790599210427,
+    0.36561299780477385,
+    0.9307669610789837,
+    -0.9307669610789837,
+    0.36561299780477385,
+    0.9770281426577544,
+    0.21311031991609136,
+    -0.21311031991609136,
+    0.9770281426577544,
+    0.5401714727298929,
+    0.8415549774368984,
+    -0.8415549774368984,
+    0.5401714727298929,
+    0.8211025149911046,
+    0.5707807458869673,
+    -0.5707807458869673,
+    0.8211025149911046,
+    0.17700422041214875,
+    0.984210092386929,
+    -0.984210092386929,
+    0.17700422041214875,
+    0.9932119492347945,
+    0.11631863091190477,
+    -0.11631863091190477,
+    0.9932119492347945,
+    0.6200572117632892,
+    0.7845565971555752,
+    -0.7845565971555752,
+    0.6200572117632892,
+    0.8730949784182901,
+    0.48755016014843594,
+    -0.48755016014843594,
+    0.8730949784182901,
+    0.272621355449949,
+    0.9621214042690416,
+    -0.9621214042690416,
+    0.272621355449949,
+    0.9514350209690083,
+    0.30784964004153487,
+    -0.30784964004153487,
+    0.9514350209690083,
+    0.45508358712634384,
+    0.8904487232447579,
+    -0.8904487232447579,
+    0.45508358712634384,
+    0.7612023854842618,
+    0.6485144010221124,
+    -0.6485144010221124,
+    0.7612023854842618,
+    0.07968243797143013,
+    0.9968202992911657,
+    -0.9968202992911657,
+    0.07968243797143013,
+    0.9977230666441916,
+    0.06744391956366406,
+    -0.06744391956366406,
+    0.9977230666441916,
+    0.6578066932970786,
+    0.7531867990436125,
+    -0.7531867990436125,
+    0.6578066932970786,
+    0.8959662497561851,
+    0.44412214457042926,
+    -0.44412214457042926,
+    0.8959662497561851,
+    0.3195020308160157,
+    0.9475855910177411,
+    -0.9475855910177411,
+    0.3195020308160157,
+    0.9653944416976894,
+    0.2607941179152755,
+    -0.2607941179152755,
+    0.9653944416976894,
+    0.49822766697278187,
+    0.8670462455156926,
+    -0.8670462455156926,
+    0.49822766697278187,
+    0.7921065773002124,
+    0.6103828062763095,
+    -0.6103828062763095,
+    0.7921065773002124,
+    0.12849811079379317,
+    0.9917097536690995,
+    -0.9917097536690995,
+    0.12849811079379317,
+    0.9863080972445987,
+    0.16491312048996992,
+    -0.16491312048996992,
+    0.9863080972445987,
+    0.5808139580957645,
+    0.8140363297059484,
+    -0.8140363297059484,
+    0.5808139580957645,
+    0.8481203448032972,
+    0.5298036246862947,
+    -0.5298036246862947,
+    0.8481203448032972,
+    0.22508391135979283,
+    0.9743393827855759,
+    -0.9743393827855759,
+    0.22508391135979283,
+    0.9351835099389476,
+    0.3541635254204904,
+    -0.3541635254204904,
+    0.9351835099389476,
+    0.41084317105790397,
+    0.9117060320054299,
+    -0.9117060320054299,
+    0.41084317105790397,
+    0.7284643904482252,
+    0.6850836677727004,
+    -0.6850836677727004,
+    0.7284643904482252,
+    0.030674803176636626,
+    0.9995294175010931,
+    -0.9995294175010931,
+    0.030674803176636626,
+    0.9990777277526454,
+    0.04293825693494082,
+    -0.04293825693494082,
+    0.9990777277526454,
+    0.6760927035753159,
+    0.7368165688773699,
+    -0.7368165688773699,
+    0.6760927035753159,
+    0.9065957045149153,
+    0.4220002707997997,
+    -0.4220002707997997,
+    0.9065957045149153,
+    0.3426607173119944,
+    0.9394592236021899,
+    -0.9394592236021899,
+    0.3426607173119944,
+    0.9715038909862518,
+    0.2370236059943672,
+    -0.2370236059943672,
+    0.9715038909862518,
+    0.5193559901655896,
+    0.8545579883654005,
+    -0.8545579883654005,
+    0.5193559901655896,
+    0.8068475535437992,
+    0.5907597018588743,
+    -0.5907597018588743,
+    0.8068475535437992,
+    0.15279718525844344,
+    0.9882575677307495,
+    -0.9882575677307495,
+    0.15279718525844344,
+    0.9900582102622971,
+    0.14065823933284924,
+    -0.14065823933284924,
+    0.9900582102622971,
+    0.600616479383869,
+    0.799537269107905,
+    -0.799537269107905,
+    0.600616479383869,
+    0.8608669386377673,
+    0.508830142543107,
+    -0.508830142543107,
+    0.8608669386377673,
+    0.24892760574572018,
+    0.9685220942744173,
+    -0.9685220942744173,
+    0.24892760574572018,
+    0.9435934581619604,
+    0.33110630575987643,
+    -0.33110630575987643,
+    0.9435934581619604,
+    0.43309381885315196,
+    0.901348847046022,
+    -0.901348847046022,
+    0.43309381885315196,
+    0.745057785441466,
+    0.6669999223036375,
+    -0.6669999223036375,
+    0.745057785441466,
+    0.05519524434968994,
+    0.9984755805732948,
+    -0.9984755805732948,
+    0.05519524434968994,
+    0.9957674144676598,
+    0.09190895649713272,
+    -0.09190895649713272,
+    0.9957674144676598,
+    0.6391244448637757,
+    0.7691033376455796,
+    -0.7691033376455796,
+    0.6391244448637757,
+    0.8847970984309378,
+    0.4659764957679662,
+    -0.4659764957679662,
+    0.8847970984309378,
+    0.29615088824362384,
+    0.9551411683057707,
+    -0.9551411683057707,
+    0.29615088824362384,
+    0.9587034748958716,
+    0.2844075372112718,
+    -0.2844075372112718,
+    0.9587034748958716,
+    0.47679923006332214,
+    0.8790122264286335,
+    -0.8790122264286335,
+    0.47679923006332214,
+    0.7768884656732324,
+    0.629638238914927,
+    -0.629638238914927,
+    0.7768884656732324,
+    0.10412163387205457,
+    0.9945645707342554,
+    -0.9945645707342554,
+    0.10412163387205457,
+    0.9819638691095552,
+    0.18906866414980622,
+    -0.18906866414980622,
+    0.9819638691095552,
+    0.560661576197336,
+    0.8280450452577558,
+    -0.8280450452577558,
+    0.560661576197336,
+    0.83486287498638,
+    0.5504579729366048,
+    -0.5504579729366048,
+    0.83486287498638,
+    0.2011046348420919,
+    0.9795697656854405,
+    -0.9795697656854405,
+    0.2011046348420919,
+    0.9262102421383114,
+    0.37700741021641826,
+    -0.37700741021641826,
+    0.9262102421383114,
+    0.3883450466988263,
+    0.9215140393420419,
+    -0.9215140393420419,
+    0.3883450466988263,
+    0.7114321957452164,
+    0.7027547444572253,
+    -0.7027547444572253,
+    0.7114321957452164,
+    0.006135884649154475,
+    0.9999811752826011,
+    -0.9999811752826011,
+    0.006135884649154475,
+];
+
+// Falcon-1024 (LOGN = 10). Q, the Montgomery constants and the GMB / IGMB tables are shared.
+pub mod falcon1024 {
+    use super::SHAKE256_RATE_WORDS;
+
+    pub const LOGN: u8 = 10;
+
+    pub const N: usize = 1024;
+
+    pub const FALCON_PK_SIZE: usize = 1793;
+
+    // max sig size (in bytes) when using the COMPRESSED format (- 1 - _NONCE_LEN)
+    pub const SIG_COMP_MAXSIZE: u16 = 1421;
+
+    // total sig sizes (in bytes) when using the PADDED and CT formats
+    pub const SIG_PADDED_SIZE: usize = 1280;
+    pub const SIG_CT_SIZE: usize = 1577;
+
+    // Acceptance bound on the squared norm of a signature vector (s1, s2), for LOGN = 10.
+    pub const SIG_L2_BOUND: u32 = 70265242;
+
+    // Variable-time squeeze sizing: 1024 accepted draws need 1024 / (1 - 4091/65536) ≈ 1092 draws
+    // on average. 18 blocks = 1224 draws ⇒ E[accepted] ≈ 1148, σ ≈ 8.5, so a short fill is a
+    // ~14σ event (never), at least the margin of the 9 blocks of Falcon-512.
+    pub const SHAKE_VARTIME_BLOCKS: usize = 18;
+    // 18 x 17 = 306 words
+    pub const SHAKE_VARTIME_WORDS: usize = SHAKE_VARTIME_BLOCKS * SHAKE256_RATE_WORDS;
 }
@@ -0,0 +1,310 @@
+//! Minimal DER reader and writer, and PEM (RFC 7468) armoring, for the key containers of
+//! [`crate::pkix`] and the certificates of [`crate::x509`].
+//!
+//! Only what these formats need: single-byte tags, definite lengths in minimal form (at most
+//! 4 length bytes) and the standard base64 alphabet. Nothing allocates: the writer fills a
+//! caller buffer, the reader borrows from its input.
+
+use crate::error::Error;
+
+pub(crate) const TAG_INTEGER: u8 = 0x02;
+pub(crate) const TAG_BIT_STRING: u8 = 0x03;
+pub(crate) const TAG_OCTET_STRING: u8 = 0x04;
+pub(crate) const TAG_NULL: u8 = 0x05;
+pub(crate) const TAG_OID: u8 = 0x06;
+pub(crate) const TAG_SEQUENCE: u8 = 0x30;
+
+/// Size of a TLV header for `len` content bytes.
+pub(crate) const fn header_len(len: usize) -> usize {
+    match len {
+        0..=0x7f => 2,
+        0x80..=0xff => 3,
+        0x100..=0xffff => 4,
+        0x1_0000..=0xff_ffff => 5,
+        _ => 6,
+    }
+}
+
+/// Size of a TLV with `len` content bytes.
+pub(crate) const fn tlv_len(len: usize) -> usize {
+    header_len(len) + len
+}
+
+/// A cursor over DER input.
+pub(crate) struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Tag of the next TLV, if any.
+    pub(crate) fn peek_tag(&self) -> Option<u8> {
+        self.input.first().copied()
+    }
+
+    /// Reads the next TLV, as `(tag, content)`.
+    pub(crate) fn read_any(&mut self) -> Result<(u8, &'a [u8]), Error> {
+        let (&tag, rest) = self.input.split_first().ok_or(Error::MalformedDer)?;
+        let (&first, mut rest) = rest.split_first().ok_or(Error::MalformedDer)?;
+
+        // high tag numbers (0x1f) are not used by the containers
+        if tag & 0x1f == 0x1f {
+            return Err(Error::MalformedDer);
+        }
+
+        let len = if first < 0x80 {
+            first as usize
+        } else {
+            let n = (first & 0x7f) as usize;
+
+            if n == 0 || n > 4 || rest.len() < n || rest[0] == 0 {
+                return Err(Error::MalformedDer);
+            }
+
+            let len = rest[..n]
+                .iter()
+                .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+
+            // minimal length encoding
+            if len < 0x80 {
+                return Err(Error::MalformedDer);
+            }
+
+            rest = &rest[n..];
+            len
+        };
+
+        if rest.len() < len {
+            return Err(Error::MalformedDer);
+        }
+
+        let (content, rest) = rest.split_at(len);
+
+        self.input = rest;
+
+        Ok((tag, content))
+    }
+
+    /// Reads the next TLV, which must have tag `tag`, and returns its content.
+    pub(crate) fn read(&mut self, tag: u8) -> Result<&'a [u8], Error> {
+        match self.read_any()? {
+            (t, content) if t == tag => Ok(content),
+            _ => Err(Error::MalformedDer),
+        }
+    }
+
+    /// Reads the next TLV, which must have tag `tag`, and returns it whole (header included).
+    pub(crate) fn read_raw(&mut self, tag: u8) -> Result<&'a [u8], Error> {
+        let start = self.input;
+
+        self.read(tag)?;
+
+        Ok(&start[..start.len() - self.input.len()])
+    }
+
+    /// Reads an AlgorithmIdentifier and returns the content octets of its OID. Parameters must
+    /// be absent or `NULL`.
+    pub(crate) fn read_algorithm(&mut self) -> Result<&'a [u8], Error> {
+        let mut alg = Reader::new(self.read(TAG_SEQUENCE)?);
+        let oid = alg.read(TAG_OID)?;
+
+        if let Some(params) = alg.read_optional(TAG_NULL)? {
+            if !params.is_empty() {
+                return Err(Error::MalformedDer);
+            }
+        }
+
+        alg.finish()?;
+
+        Ok(oid)
+    }
+
+    /// Reads a TLV with tag `tag` if it is the next one.
+    pub(crate) fn read_optional(&mut self, tag: u8) -> Result<Option<&'a [u8]>, Error> {
+        match self.peek_tag() {
+            Some(t) if t == tag => self.read(tag).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Checks that the whole input was consumed.
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::MalformedDer)
+        }
+    }
+}
+
+/// A cursor writing DER into a buffer sized by the caller (with [`tlv_len`]).
+pub(crate) struct Writer<'a> {
+    out: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> Writer<'a> {
+    pub(crate) fn new(out: &'a mut [u8]) -> Self {
+        Self { out, pos: 0 }
+    }
+
+    /// Writes a TLV header; the `len` content bytes must follow.
+    pub(crate) fn header(&mut self, tag: u8, len: usize) {
+        let n = header_len(len) - 2;
+
+        self.out[self.pos] = tag;
+
+        if n == 0 {
+            self.out[self.pos + 1] = len as u8;
+        } else {
+            self.out[self.pos + 1] = 0x80 | n as u8;
+
+            for i in 0..n {
+                self.out[self.pos + 2 + i] = (len >> (8 * (n - 1 - i))) as u8;
+            }
+        }
+
+        self.pos += n + 2;
+    }
+
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) {
+        self.out[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+    }
+
+    /// Writes a whole TLV.
+    pub(crate) fn tlv(&mut self, tag: u8, content: &[u8]) {
+        self.header(tag, content.len());
+        self.bytes(content);
+    }
+
+    /// Number of bytes written.
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// PEM line length (base64 characters per line).
+const PEM_LINE: usize = 64;
+
+/// Size of the PEM armoring of `der_len` bytes under a label of `label_len` characters, with
+/// `\n` line endings (boundaries included).
+pub(crate) const fn pem_len(label_len: usize, der_len: usize) -> usize {
+    let b64 = 4 * der_len.div_ceil(3);
+    let lines = b64.div_ceil(PEM_LINE);
+
+    // "-----BEGIN " label "-----\n" ... "-----END " label "-----\n"
+    (11 + label_len + 6) + b64 + lines + (9 + label_len + 6)
+}
+
+/// Writes the PEM armoring of `der` into `out` (at least [`pem_len`] bytes) and returns it.
+pub(crate) fn pem_encode<'o>(label: &str, der: &[u8], out: &'o mut [u8]) -> &'o str {
+    let mut pos = 0;
+    let mut put = |bytes: &[u8]| {
+        out[pos..pos + bytes.len()].copy_from_slice(bytes);
+        pos += bytes.len();
+    };
+
+    put(b"-----BEGIN ");
+    put(label.as_bytes());
+    put(b"-----\n");
+
+    for line in der.chunks(PEM_LINE / 4 * 3) {
+        for chunk in line.chunks(3) {
+            let b = [
+                chunk[0],
+                chunk.get(1).copied().unwrap_or(0),
+                chunk.get(2).copied().unwrap_or(0),
+            ];
+            let w = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+            let mut quad = [b'='; 4];
+
+            for (i, q) in quad.iter_mut().enumerate().take(chunk.len() + 1) {
+                *q = BASE64[(w >> (18 - 6 * i) & 0x3f) as usize];
+            }
+
+            put(&quad);
+        }
+
+        put(b"\n");
+    }
+
+    put(b"-----END ");
+    put(label.as_bytes());
+    put(b"-----\n");
+
+    // only ASCII was written
+    core::str::from_utf8(&out[..pos]).unwrap()
+}
+
+fn base64_value(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Decodes a PEM document with label `label` into `out` and returns the DER bytes.
+///
+/// Whitespace is accepted around the boundaries and inside the base64 body; anything else
+/// (explanatory text, headers, a second document) is rejected, as are non-canonical base64
+/// padding bits and documents that do not fit in `out`.
+pub(crate) fn pem_decode<'o>(label: &str, pem: &str, out: &'o mut [u8]) -> Result<&'o [u8], Error> {
+    let body = pem
+        .trim()
+        .strip_prefix("-----BEGIN ")
+        .and_then(|s| s.strip_prefix(label))
+        .and_then(|s| s.strip_prefix("-----"))
+        .and_then(|s| s.strip_suffix("-----"))
+        .and_then(|s| s.strip_suffix(label))
+        .and_then(|s| s.strip_suffix("-----END "))
+        .ok_or(Error::MalformedPem)?;
+
+    let mut len = 0;
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut pad = 0;
+
+    for c in body.bytes().filter(|c| !c.is_ascii_whitespace()) {
+        if c == b'=' {
+            pad += 1;
+            continue;
+        }
+
+        // data after padding
+        if pad > 0 {
+            return Err(Error::MalformedPem);
+        }
+
+        acc = (acc << 6) | base64_value(c).ok_or(Error::MalformedPem)? as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+
+            *out.get_mut(len).ok_or(Error::MalformedPem)? = (acc >> bits) as u8;
+            len += 1;
+            acc &= (1 << bits) - 1;
+        }
+    }
+
+    // padding completes the last quad, and the leftover bits are zero
+    if pad > 2 || (bits != 0 && bits / 2 != pad) || (bits == 0 && pad != 0) || acc != 0 {
+        return Err(Error::MalformedPem);
+    }
+
+    Ok(&out[..len])
+}
@@ -0,0 +1,59 @@
+//! Errors of public key decoding, signature decoding, verification, key containers and
+//! certificates.
+//!
+//! Malformed inputs are reported through `Result<_, Error>` rather than a panic (the release
+//! profile aborts on panic). The low-level codecs (`comp_decode`, `trim_i16_decode`, ...) keep
+//! their "0 bytes read" convention; the functions built on them map it to an [`Error`].
+
+use core::fmt;
+
+/// Why a public key, a signature, a key container or a certificate was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The header byte has the wrong format nibble, or its `logn` nibble does not match.
+    BadHeader,
+    /// A public key coefficient is `>= q`.
+    CoefficientOutOfRange,
+    /// Padding bits or padding bytes are not zero.
+    TrailingBits,
+    /// The input does not have the size of its format.
+    BadLength,
+    /// The signature vector `s2` is not a valid encoding (compressed or fixed-width).
+    DecodeFailed,
+    /// The signature is well-formed, but `(s1, s2)` is above the acceptance bound.
+    NormTooLarge,
+    /// A key container is not valid DER, or does not have the expected structure.
+    MalformedDer,
+    /// PEM armoring is missing, has the wrong label, or its base64 body is invalid.
+    MalformedPem,
+    /// A key container holds a key of another algorithm (unknown OID).
+    UnknownAlgorithm,
+    /// A private key encoding is invalid (see `sk_decode`).
+    InvalidSecretKey,
+    /// The public key stored next to a private key is not the one of that private key.
+    KeyMismatch,
+    /// A certificate names an issuer other than the subject of the certificate checked against.
+    IssuerMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BadHeader => "invalid header byte",
+            Error::CoefficientOutOfRange => "public key coefficient out of range",
+            Error::TrailingBits => "non-zero padding",
+            Error::BadLength => "invalid length",
+            Error::DecodeFailed => "invalid signature encoding",
+            Error::NormTooLarge => "signature vector too long",
+            Error::MalformedDer => "malformed DER",
+            Error::MalformedPem => "malformed PEM",
+            Error::UnknownAlgorithm => "unsupported algorithm identifier",
+            Error::InvalidSecretKey => "invalid private key",
+            Error::KeyMismatch => "public key does not match the private key",
+            Error::IssuerMismatch => "certificate issuer does not match",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
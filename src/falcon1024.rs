@@ -0,0 +1,155 @@
+//! Falcon-1024 verification.
+//!
+//! The same pipeline as [`crate::falcon512`] at degree 1024 (`LOGN = 10`): public key decoding
+//! (1793 bytes), NTT with one more stage, variable-time hash-to-point on a longer squeeze, and the
+//! norm check against the Falcon-1024 bound. These are the [`Falcon1024`] instances of the
+//! parameter-generic functions of [`crate::falcon512`].
+
+use crate::{
+    constants::falcon1024::{FALCON_PK_SIZE, N, SHAKE_VARTIME_WORDS},
+    error::Error,
+    falcon512::{
+        comp_decode_with, comp_encode_with, hash_to_point_vartime_with, is_short_with,
+        mq_decode_with, mq_encode_with, mq_intt_with, mq_ntt_with, pk_from_ntt_fmt_with,
+        pk_to_ntt_fmt_with, sig_decode_with, verify_detailed_with, verify_raw_with,
+        verify_sig_detailed_with, verify_sig_with, verify_with, DecodedSig, VerifyReport,
+    },
+    params::Falcon1024,
+    shake256::shake_extract_blocks,
+};
+
+/// Variable-time hash-to-point for verification: collects the first `N` accepted draws of the
+/// squeezed stream (see [`crate::falcon512::hash_to_point_vartime`]).
+///
+/// # Parameters
+/// - `extracted`: The squeezed SHAKE256 stream ([`shake_extract_vartime`] output).
+/// - `x`: Output polynomial coefficients, as `[u16; N]`.
+#[inline(always)]
+pub fn hash_to_point_vartime(extracted: &[u64], x: &mut [u16; N]) {
+    hash_to_point_vartime_with::<Falcon1024, N>(extracted, x);
+}
+
+/// Squeezes the [`SHAKE_VARTIME_WORDS`] (18 rate blocks) that [`hash_to_point_vartime`] needs from
+/// a flipped SHAKE256 context.
+pub fn shake_extract_vartime(shake_ctx: &mut [u64; 26]) -> [u64; SHAKE_VARTIME_WORDS] {
+    shake_extract_blocks(shake_ctx)
+}
+
+/// Computes the NTT of a polynomial in-place (10 stages: the three radix-8 passes of Falcon-512,
+/// then a radix-2 pass).
+#[inline(always)]
+pub fn mq_ntt(p: &mut [u16; N]) {
+    mq_ntt_with::<Falcon1024, N>(p);
+}
+
+/// Computes the inverse NTT of a polynomial in-place, including the 1/N scaling.
+#[inline(always)]
+pub fn mq_intt(p: &mut [u16; N]) {
+    mq_intt_with::<Falcon1024, N>(p);
+}
+
+/// Decodes a 14-bit packed polynomial (the public key format).
+///
+/// # Returns
+/// The number of bytes read (1792); see [`crate::falcon512::mq_decode`] for the errors.
+pub fn mq_decode(
+    x: &mut [u16; N],
+    input: &[u8; FALCON_PK_SIZE],
+    offset: usize,
+) -> Result<usize, Error> {
+    mq_decode_with::<Falcon1024, N>(x, input, offset)
+}
+
+/// Encodes a polynomial (coefficients `< q`) in the 14-bit packed public key format.
+///
+/// # Returns
+/// The number of bytes written (1792), or 0 if a coefficient is out of range.
+pub fn mq_encode(x: &[u16; N], out: &mut [u8; FALCON_PK_SIZE], offset: usize) -> usize {
+    mq_encode_with::<Falcon1024, N>(x, out, offset)
+}
+
+/// Decodes a compressed vector.
+///
+/// # Returns
+/// The decoded vector and the number of bytes read, or 0 on an invalid encoding.
+pub fn comp_decode(input: &[u8]) -> ([u16; N], usize) {
+    comp_decode_with::<Falcon1024, N>(input)
+}
+
+/// Encodes a vector in the compressed signature format (at most 1421 bytes).
+///
+/// # Returns
+/// The number of bytes written, or 0 if a coefficient is outside `[-2047, 2047]` or the encoding
+/// does not fit.
+pub fn comp_encode(x: &[u16; N], out: &mut [u8]) -> usize {
+    comp_encode_with::<Falcon1024, N>(x, out)
+}
+
+/// Returns true if `(s1, s2)` is within the Falcon-1024 acceptance bound.
+pub fn is_short(s1: &[u16; N], s2: &[u16; N]) -> bool {
+    is_short_with::<Falcon1024, N>(s1, s2)
+}
+
+/// Internal signature verification routine; see [`crate::falcon512::verify_raw`].
+pub fn verify_raw(c0: &mut [u16; N], s2: &[u16; N], h: &[u16; N], s1: &mut [u16; N]) -> bool {
+    verify_raw_with::<Falcon1024, N>(c0, s2, h, s1)
+}
+
+/// Converts a serialized Falcon-1024 public key (header `0x0a`) to NTT format.
+///
+/// # Returns
+/// The key in NTT format, or [`Error::BadHeader`] if the header is not `0x0a`, otherwise the
+/// [`mq_decode`] error.
+pub fn pk_to_ntt_fmt(pk: &[u8; FALCON_PK_SIZE]) -> Result<[u16; N], Error> {
+    pk_to_ntt_fmt_with::<Falcon1024, N>(pk)
+}
+
+/// Converts a public key in NTT format back to the serialized 1793-byte key.
+///
+/// # Returns
+/// The serialized key, or [`Error::CoefficientOutOfRange`] if a coefficient is not in `[0, q)`.
+pub fn pk_from_ntt_fmt(pk_ntt_fmt: &[u16; N]) -> Result<[u8; FALCON_PK_SIZE], Error> {
+    let mut pk = [0u8; FALCON_PK_SIZE];
+
+    pk_from_ntt_fmt_with::<Falcon1024, N>(pk_ntt_fmt, &mut pk)?;
+
+    Ok(pk)
+}
+
+/// Verifies a Falcon-1024 signature.
+///
+/// # Parameters
+/// - `nonce_msg`: Message (and nonce) bytes.
+/// - `sig`: Compressed signature bytes (without header and nonce).
+/// - `pk_ntt_fmt`: Public key in NTT format, as `[u16; N]`.
+///
+/// # Returns
+/// `true` if the signature is valid, otherwise `false`.
+pub fn verify(nonce_msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> bool {
+    verify_with::<Falcon1024, N>(nonce_msg, sig, pk_ntt_fmt)
+}
+
+/// Decodes a complete Falcon-1024 signature (header `0x3a` compressed or padded, `0x5a`
+/// constant-time); see [`crate::falcon512::sig_decode`].
+pub fn sig_decode(sig: &[u8]) -> Result<DecodedSig<'_, N>, Error> {
+    sig_decode_with::<Falcon1024, N>(sig)
+}
+
+/// Verifies a complete Falcon-1024 signature (`header ‖ nonce ‖ s2`) on `msg`.
+///
+/// # Returns
+/// `Ok(())` if the signature is valid; see [`crate::falcon512::verify_sig`] for the errors.
+pub fn verify_sig(msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> Result<(), Error> {
+    verify_sig_with::<Falcon1024, N>(msg, sig, pk_ntt_fmt)
+}
+
+/// [`verify`] with the rejecting stage and the squared norm (bound 70265242); see
+/// [`crate::falcon512::verify_detailed`].
+pub fn verify_detailed(nonce_msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> VerifyReport {
+    verify_detailed_with::<Falcon1024, N>(nonce_msg, sig, pk_ntt_fmt)
+}
+
+/// [`verify_sig`] with the squared norm; see [`crate::falcon512::verify_sig_detailed`].
+pub fn verify_sig_detailed(msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> VerifyReport {
+    verify_sig_detailed_with::<Falcon1024, N>(msg, sig, pk_ntt_fmt)
+}
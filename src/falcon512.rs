@@ -1,12 +1,47 @@
 use crate::{
     constants::{
-        errors::E_INVALID_PUBLIC_KEY, FALCON_PK_SIZE, GMB, IGMB, LOGN, M, N, NONCE_LEN,
-        OVER_SAMPLING, Q, R2, SIG_COMP_MAXSIZE,
+        FALCON_EXPANDED_SK_SIZE, FALCON_PK_SIZE, FALCON_SK_SIZE,
+        FFLDL_TREE_SIZE, LOGN, M, N, NONCE_LEN, OVER_SAMPLING, Q, R2, SHAKE256_RATE,
+        SIGN_INV_SIGMA, SIGN_SEED_LEN, SIGN_SIGMA_MIN, SIG_COMP_MAXSIZE, SIG_CT_SIZE,
+        SIG_HEADER_COMP, SIG_HEADER_CT, SIG_L2_BOUND, SK_BIG_F_BITS, SK_COEFF_MAX, SK_FG_BITS,
     },
-    shake256::{shake_extract_vartime, shake_flip, shake_inject},
-    utils::{mq_montymul, mq_sub, revert, sign_extend_u16_to_u32, swap_byte_pairs},
+    error::Error,
+    ffsampling::{
+        ff_sampling_dyn, ff_sampling_tree, ffldl_binary_normalize, ffldl_fft, ffldl_treesize,
+    },
+    fft::{fft, ifft, poly_add, poly_mul_fft, poly_muladj_fft, poly_mulconst, poly_mulselfadj_fft},
+    fpr::{Fpr, SignFpr},
+    params::{Falcon512, FalconParams, SqueezeBuffer},
+    shake256::{shake_extract, shake_extract_vartime, shake_flip, shake_inject, Shake256Prng},
+    utils::{mq_montymul, mq_sub, sign_extend_u16_to_u32, swap_byte_pairs},
 };
 
+#[cfg(feature = "std")]
+use crate::ntru::ntru_gen;
+
+/// A Falcon-512 private key: the NTRU basis `(f, g, F, G)`, with `f * G - g * F = q mod (X^N + 1)`.
+///
+/// All coefficients are small (`|x| <= 127`), hence stored as `i8`.
+#[derive(Clone)]
+pub struct SecretKey {
+    pub f: [i8; N],
+    pub g: [i8; N],
+    pub big_f: [i8; N],
+    pub big_g: [i8; N],
+}
+
+/// A Falcon-512 private key expanded for signing: the basis `B = [[g, -f], [G, -F]]` and the
+/// normalized Falcon tree (LDL* decomposition of `B * B*`), both in FFT representation.
+///
+/// Expanding costs about as much as one signature; [`sign_tree`] then skips the basis and tree
+/// computations, which pays off when the same key signs many messages. The values depend on the
+/// floating-point backend `F` only through rounding, which all backends perform identically.
+#[derive(Clone)]
+pub struct ExpandedSecretKey<F: Fpr = SignFpr> {
+    basis: [[F; N]; 4],
+    tree: [F; FFLDL_TREE_SIZE],
+}
+
 /// Handles a pair of bytes as a `u64` and converts it to a field element.
 ///
 /// # Parameters
@@ -52,6 +87,16 @@ const REJECT_THRESHOLD: u16 = 5 * Q;
 /// before `N` acceptances would index out of bounds.
 #[inline(always)]
 pub fn hash_to_point_vartime(extracted: &[u64], x: &mut [u16; N]) {
+    hash_to_point_vartime_with::<Falcon512, N>(extracted, x);
+}
+
+/// [`hash_to_point_vartime`] for any parameter set: collects the first `N` accepted draws (the
+/// squeeze is [`FalconParams::Squeeze`]).
+#[inline(always)]
+pub fn hash_to_point_vartime_with<P: FalconParams<N>, const N: usize>(
+    extracted: &[u64],
+    x: &mut [u16; N],
+) {
     let x_ptr = x.as_mut_ptr();
 
     unsafe {
@@ -335,14 +380,14 @@ fn r8_fwd(
     )
 }
 
-/// One radix-8 forward pass over the array, merging stages `k, k+1, k+2` (`k ∈ {0, 3, 6}`). Each
-/// group of 8 coefficients is loaded once, transformed in registers, and stored once - so the whole
-/// 9-stage forward NTT is **3 passes** over memory instead of 9, cutting load/store traffic ~3×.
-/// Twiddles are read from `GMB` at the same offsets the radix-2 transform uses for those stages, so
-/// the output ordering is identical (public-key NTT format unchanged).
+/// One radix-8 forward pass over the `n` coefficients, merging stages `k, k+1, k+2`
+/// (`k ∈ {0, 3, 6}`). Each group of 8 coefficients is loaded once, transformed in registers, and
+/// stored once - so the whole 9-stage forward NTT is **3 passes** over memory instead of 9, cutting
+/// load/store traffic ~3×. Twiddles are read from `GMB` at the same offsets the radix-2 transform
+/// uses for those stages, so the output ordering is identical (public-key NTT format unchanged).
 #[inline(always)]
-unsafe fn radix8_pass_fwd(ptr: *mut u16, k: usize) {
-    let tk = N >> k; // span of a stage-k block
+unsafe fn radix8_pass_fwd(ptr: *mut u16, n: usize, k: usize, gmb: &[u16]) {
+    let tk = n >> k; // span of a stage-k block
     let g = tk >> 3; // stride between the 8 butterfly inputs
     let base0 = 1usize << k;
     let base1 = 1usize << (k + 1);
@@ -351,13 +396,13 @@ unsafe fn radix8_pass_fwd(ptr: *mut u16, k: usize) {
 
     let mut b = 0usize;
     while b != nblocks {
-        let s0 = GMB[base0 + b];
-        let sa = GMB[base1 + 2 * b];
-        let sb = GMB[base1 + 2 * b + 1];
-        let t0 = GMB[base2 + 4 * b];
-        let t1 = GMB[base2 + 4 * b + 1];
-        let t2 = GMB[base2 + 4 * b + 2];
-        let t3 = GMB[base2 + 4 * b + 3];
+        let s0 = gmb[base0 + b];
+        let sa = gmb[base1 + 2 * b];
+        let sb = gmb[base1 + 2 * b + 1];
+        let t0 = gmb[base2 + 4 * b];
+        let t1 = gmb[base2 + 4 * b + 1];
+        let t2 = gmb[base2 + 4 * b + 2];
+        let t3 = gmb[base2 + 4 * b + 3];
         let bs = b * tk;
 
         let mut jo = 0usize;
@@ -395,18 +440,61 @@ unsafe fn radix8_pass_fwd(ptr: *mut u16, k: usize) {
 /// * `p` - A mutable slice representing the polynomial coefficients.
 #[inline(always)]
 pub fn mq_ntt(p: &mut [u16; N]) {
+    mq_ntt_with::<Falcon512, N>(p);
+}
+
+/// [`mq_ntt`] for any parameter set. The `LOGN / 3` radix-8 passes come first (three for
+/// Falcon-512, none below degree 8); the remaining one or two stages (Falcon-1024, and the toy
+/// degrees that are not a power of 8) are plain radix-2 passes with GMB-ordered twiddles, each
+/// after a `% q` reduction. A radix-2 pass leaves its output `< 2q`.
+#[inline(always)]
+pub fn mq_ntt_with<P: FalconParams<N>, const N: usize>(p: &mut [u16; N]) {
+    let logn = P::LOGN as usize;
+    let gmb = P::GMB;
+
     unsafe {
         let ptr = p.as_mut_ptr();
 
-        radix8_pass_fwd(ptr, 0);
-        for i in 0..N {
-            *ptr.add(i) %= Q;
+        if logn >= 3 {
+            radix8_pass_fwd(ptr, N, 0, gmb);
         }
-        radix8_pass_fwd(ptr, 3);
-        for i in 0..N {
-            *ptr.add(i) %= Q;
+        if logn >= 6 {
+            for i in 0..N {
+                *ptr.add(i) %= Q;
+            }
+            radix8_pass_fwd(ptr, N, 3, gmb);
+        }
+        if logn >= 9 {
+            for i in 0..N {
+                *ptr.add(i) %= Q;
+            }
+            radix8_pass_fwd(ptr, N, 6, gmb);
+        }
+
+        // stage k: blocks of t = N >> k, pairs (j, j + t / 2) with GMB[2^k + block]
+        for k in (logn / 3) * 3..logn {
+            if k != 0 {
+                for i in 0..N {
+                    *ptr.add(i) %= Q;
+                }
+            }
+
+            let m = 1usize << k;
+            let ht = (N >> k) >> 1;
+
+            for i in 0..m {
+                let s = gmb[m + i];
+                let j1 = 2 * i * ht;
+
+                for j in j1..j1 + ht {
+                    let u = *ptr.add(j);
+                    let v = mq_montymul(*ptr.add(j + ht), s);
+
+                    *ptr.add(j) = u + v;
+                    *ptr.add(j + ht) = u + Q - v;
+                }
+            }
         }
-        radix8_pass_fwd(ptr, 6);
     }
 }
 
@@ -452,29 +540,30 @@ fn r8_intt(
     )
 }
 
-/// One radix-8 inverse pass over the array, merging inverse stages `k, k+1, k+2` (`k ∈ {0, 3, 6}`).
-/// Like the forward pass it loads/transforms/stores each group of 8 once, so the 9-stage inverse NTT
-/// is 3 passes instead of 9. Twiddles come from `IGMB` at the inverse-stage offsets.
+/// One radix-8 inverse pass over the `n` coefficients, merging inverse stages `k, k+1, k+2`
+/// (`k ∈ {0, 3, 6}`). Like the forward pass it loads/transforms/stores each group of 8 once, so the
+/// 9-stage inverse NTT is 3 passes instead of 9. Twiddles come from `IGMB` at the inverse-stage
+/// offsets.
 #[inline(always)]
-unsafe fn radix8_pass_intt(ptr: *mut u16, k: usize) {
+unsafe fn radix8_pass_intt(ptr: *mut u16, n: usize, k: usize, igmb: &[u16]) {
     let tk = 1usize << k; // span between the 8 inputs
-    // Twiddle bases follow the inverse-NTT table layout (stage s reads IGMB[2^(8-s) + blk]):
-    // 256 >> k, 128 >> k, 64 >> k for the three merged stages k, k+1, k+2.
-    let base0 = (N >> 1) >> k;
-    let base1 = (N >> 2) >> k;
-    let base2 = (N >> 3) >> k;
+    // Twiddle bases follow the inverse-NTT table layout (stage s reads IGMB[(n / 2) >> s + blk]):
+    // 256 >> k, 128 >> k, 64 >> k for the three merged stages k, k+1, k+2 (n = 512).
+    let base0 = (n >> 1) >> k;
+    let base1 = (n >> 2) >> k;
+    let base2 = (n >> 3) >> k;
     let nblocks = base2;
     let blocksize = 8 * tk;
 
     let mut bidx = 0usize;
     while bidx != nblocks {
-        let sa = IGMB[base0 + 4 * bidx];
-        let sb = IGMB[base0 + 4 * bidx + 1];
-        let sc = IGMB[base0 + 4 * bidx + 2];
-        let sd = IGMB[base0 + 4 * bidx + 3];
-        let ua = IGMB[base1 + 2 * bidx];
-        let ub = IGMB[base1 + 2 * bidx + 1];
-        let s2c = IGMB[base2 + bidx];
+        let sa = igmb[base0 + 4 * bidx];
+        let sb = igmb[base0 + 4 * bidx + 1];
+        let sc = igmb[base0 + 4 * bidx + 2];
+        let sd = igmb[base0 + 4 * bidx + 3];
+        let ua = igmb[base1 + 2 * bidx];
+        let ub = igmb[base1 + 2 * bidx + 1];
+        let s2c = igmb[base2 + bidx];
         let pp = bidx * blocksize;
 
         let mut p = pp;
@@ -510,22 +599,61 @@ unsafe fn radix8_pass_intt(ptr: *mut u16, k: usize) {
 /// * `p` - A mutable slice representing the polynomial coefficients in NTT domain.
 #[inline(always)]
 pub fn mq_intt(p: &mut [u16; N]) {
+    mq_intt_with::<Falcon512, N>(p);
+}
+
+/// [`mq_intt`] for any parameter set: the radix-8 passes, then the remaining inverse stages as
+/// radix-2 passes (reducing their inputs `% q`), then the 1/N scaling. The scaling constant is
+/// `R / N mod q` (`2^16 / N`, reduced for `N < 8`).
+#[inline(always)]
+pub fn mq_intt_with<P: FalconParams<N>, const N: usize>(p: &mut [u16; N]) {
+    let logn = P::LOGN as usize;
+    let igmb = P::IGMB;
+
     unsafe {
         let ptr = p.as_mut_ptr();
 
-        radix8_pass_intt(ptr, 0);
-        for i in 0..N {
-            *ptr.add(i) %= Q;
+        if logn >= 3 {
+            radix8_pass_intt(ptr, N, 0, igmb);
         }
-        radix8_pass_intt(ptr, 3);
-        for i in 0..N {
-            *ptr.add(i) %= Q;
+        if logn >= 6 {
+            for i in 0..N {
+                *ptr.add(i) %= Q;
+            }
+            radix8_pass_intt(ptr, N, 3, igmb);
+        }
+        if logn >= 9 {
+            for i in 0..N {
+                *ptr.add(i) %= Q;
+            }
+            radix8_pass_intt(ptr, N, 6, igmb);
+        }
+
+        // inverse stage k: blocks of 2t (t = 2^k), pairs (j, j + t) with IGMB[(N / 2) >> k + block]
+        for k in (logn / 3) * 3..logn {
+            let t = 1usize << k;
+            let hm = (N >> 1) >> k;
+
+            for i in 0..hm {
+                let s = igmb[hm + i];
+                let j1 = 2 * i * t;
+
+                for j in j1..j1 + t {
+                    let u = *ptr.add(j) % Q;
+                    let v = *ptr.add(j + t) % Q;
+
+                    *ptr.add(j) = u + v;
+                    *ptr.add(j + t) = mq_montymul(u + Q - v, s);
+                }
+            }
         }
-        radix8_pass_intt(ptr, 6);
 
-        // final 1/N scaling (× 0x80); also reduces the < 4q output into [0, q).
+        // final 1/N scaling (× 2^16 / N, 0x80 for N = 512); also reduces the < 4q output into
+        // [0, q).
+        let ni = ((0x10000 / N) % Q as usize) as u16;
+
         for i in 0..N {
-            *ptr.add(i) = mq_montymul(*ptr.add(i), 0x80);
+            *ptr.add(i) = mq_montymul(*ptr.add(i), ni);
         }
     }
 }
@@ -540,10 +668,16 @@ pub fn mq_intt(p: &mut [u16; N]) {
 /// * `p` - A mutable slice representing the polynomial to be converted.
 #[inline(always)]
 pub fn mq_poly_tomonty(p: &mut [u16; N]) {
+    mq_poly_tomonty_n(p);
+}
+
+/// Degree-generic core of [`mq_poly_tomonty`].
+#[inline(always)]
+pub(crate) fn mq_poly_tomonty_n<const DEG: usize>(p: &mut [u16; DEG]) {
     unsafe {
         let ptr = p.as_mut_ptr();
 
-        for i in 0..N {
+        for i in 0..DEG {
             *ptr.add(i) = mq_montymul(*ptr.add(i), R2);
         }
     }
@@ -560,11 +694,17 @@ pub fn mq_poly_tomonty(p: &mut [u16; N]) {
 /// * `g` - An immutable slice for the second polynomial, `g`.
 #[inline(always)]
 pub fn mq_poly_montymul_ntt(f: &mut [u16; N], g: &[u16; N]) {
+    mq_poly_montymul_ntt_n(f, g);
+}
+
+/// Degree-generic core of [`mq_poly_montymul_ntt`].
+#[inline(always)]
+pub(crate) fn mq_poly_montymul_ntt_n<const DEG: usize>(f: &mut [u16; DEG], g: &[u16; DEG]) {
     unsafe {
         let f_ptr = f.as_mut_ptr();
         let g_ptr = g.as_ptr();
 
-        for i in 0..N {
+        for i in 0..DEG {
             *f_ptr.add(i) = mq_montymul(*f_ptr.add(i), *g_ptr.add(i));
         }
     }
@@ -578,11 +718,17 @@ pub fn mq_poly_montymul_ntt(f: &mut [u16; N], g: &[u16; N]) {
 /// * `g` - An immutable slice for the second polynomial, `g`.
 #[inline(always)]
 pub fn mq_poly_sub(f: &mut [u16; N], g: &[u16; N]) {
+    mq_poly_sub_n(f, g);
+}
+
+/// Degree-generic core of [`mq_poly_sub`].
+#[inline(always)]
+pub(crate) fn mq_poly_sub_n<const DEG: usize>(f: &mut [u16; DEG], g: &[u16; DEG]) {
     unsafe {
         let f_ptr = f.as_mut_ptr();
         let g_ptr = g.as_ptr();
 
-        for i in 0..N {
+        for i in 0..DEG {
             *f_ptr.add(i) = mq_sub(*f_ptr.add(i), *g_ptr.add(i));
         }
     }
@@ -594,8 +740,14 @@ pub fn mq_poly_sub(f: &mut [u16; N], g: &[u16; N]) {
 /// * `pubkey` - A mutable slice of the Falcon public key. The result is stored here.
 #[inline(always)]
 pub fn to_ntt_monty(pubkey: &mut [u16; N]) {
-    mq_ntt(pubkey);
-    mq_poly_tomonty(pubkey);
+    to_ntt_monty_with::<Falcon512, N>(pubkey);
+}
+
+/// [`to_ntt_monty`] for any parameter set.
+#[inline(always)]
+pub fn to_ntt_monty_with<P: FalconParams<N>, const N: usize>(pubkey: &mut [u16; N]) {
+    mq_ntt_with::<P, N>(pubkey);
+    mq_poly_tomonty_n(pubkey);
 }
 
 /// Computes the squared Euclidean distance between two vectors, with sign extension.
@@ -610,6 +762,11 @@ pub fn to_ntt_monty(pubkey: &mut [u16; N]) {
 /// # Safety
 /// Uses unsafe pointer arithmetic for performance.
 pub fn distance(s1: &[u16; N], s2: &[u16; N]) -> u32 {
+    distance_n(s1, s2)
+}
+
+/// Degree-generic core of [`distance`].
+pub(crate) fn distance_n<const DEG: usize>(s1: &[u16; DEG], s2: &[u16; DEG]) -> u32 {
     let mut s: u32 = 0;
     let mut ng: u32 = 0;
 
@@ -617,7 +774,7 @@ pub fn distance(s1: &[u16; N], s2: &[u16; N]) -> u32 {
         let s1_ptr = s1.as_ptr();
         let s2_ptr = s2.as_ptr();
 
-        for i in 0..N {
+        for i in 0..DEG {
             let z: u32 = sign_extend_u16_to_u32(*s1_ptr.add(i));
 
             s += z * z;
@@ -646,7 +803,12 @@ pub fn distance(s1: &[u16; N], s2: &[u16; N]) -> u32 {
 /// # Returns
 /// `true` if the vector is considered "short" (acceptable as a signature), otherwise `false`.
 pub fn is_short(s1: &[u16; N], s2: &[u16; N]) -> bool {
-    distance(s1, s2) <= 34034726
+    is_short_with::<Falcon512, N>(s1, s2)
+}
+
+/// [`is_short`] against the acceptance bound of any parameter set.
+pub fn is_short_with<P: FalconParams<N>, const N: usize>(s1: &[u16; N], s2: &[u16; N]) -> bool {
+    distance_n(s1, s2) <= P::SIG_L2_BOUND
 }
 
 /// Decodes the public key into an internal format.
@@ -657,14 +819,33 @@ pub fn is_short(s1: &[u16; N], s2: &[u16; N]) -> bool {
 /// - `offset`: Offset into the input buffer to start decoding from.
 ///
 /// # Returns
-/// The number of bytes read from the input buffer.
-pub fn mq_decode(x: &mut [u16; N], input: &[u8; FALCON_PK_SIZE], offset: usize) -> usize {
+/// The number of bytes read from the input buffer, or [`Error::BadLength`] if it is too short,
+/// [`Error::CoefficientOutOfRange`] if a coefficient is `>= q`, [`Error::TrailingBits`] if the
+/// padding bits of the last byte are not zero.
+pub fn mq_decode(
+    x: &mut [u16; N],
+    input: &[u8; FALCON_PK_SIZE],
+    offset: usize,
+) -> Result<usize, Error> {
+    mq_decode_with::<Falcon512, N>(x, input, offset)
+}
+
+/// [`mq_decode`] for any parameter set: reads `(N * 14 + 7) / 8` bytes from `input` at `offset`.
+pub fn mq_decode_with<P: FalconParams<N>, const N: usize>(
+    x: &mut [u16; N],
+    input: &[u8],
+    offset: usize,
+) -> Result<usize, Error> {
     let mut acc: u64 = 0;
     let mut in_offset = offset;
     let mut acc_len: u8 = 0;
     let mut u = 0;
     // ((_N * 14) + 7) >> 3
-    let mut ret = 896;
+    let ret = ((N * 14) + 7) >> 3;
+
+    if input.len() < offset + ret {
+        return Err(Error::BadLength);
+    }
 
     loop {
         // byte(0, input[in_offset])
@@ -693,16 +874,67 @@ pub fn mq_decode(x: &mut [u16; N], input: &[u8; FALCON_PK_SIZE], offset: usize)
             continue;
         }
 
-        ret = 0;
-
-        break;
+        return Err(Error::CoefficientOutOfRange);
     }
 
     if (acc & (1 << acc_len) - 1) != 0 {
-        ret = 0;
+        return Err(Error::TrailingBits);
+    }
+
+    Ok(ret)
+}
+
+/// Encodes polynomial coefficients (each `< q`) into the 14-bit packed public key format; inverse
+/// of [`mq_decode`].
+///
+/// # Parameters
+/// - `x`: Polynomial coefficients, as `[u16; N]`, all in `[0, q)`.
+/// - `out`: Output buffer, as mutable `[u8; FALCON_PK_SIZE]`.
+/// - `offset`: Offset into the output buffer to start encoding at.
+///
+/// # Returns
+/// The number of bytes written, or 0 if a coefficient is out of range.
+pub fn mq_encode(x: &[u16; N], out: &mut [u8; FALCON_PK_SIZE], offset: usize) -> usize {
+    mq_encode_with::<Falcon512, N>(x, out, offset)
+}
+
+/// [`mq_encode`] for any parameter set: writes `(N * 14 + 7) / 8` bytes (the last one zero-padded)
+/// to `out` at `offset`.
+///
+/// # Returns
+/// The number of bytes written, or 0 if a coefficient is out of range or `out` is too small.
+pub fn mq_encode_with<P: FalconParams<N>, const N: usize>(
+    x: &[u16; N],
+    out: &mut [u8],
+    offset: usize,
+) -> usize {
+    let out_len = ((N * 14) + 7) >> 3;
+
+    if x.iter().any(|&w| w >= Q) || out.len() < offset + out_len {
+        return 0;
+    }
+
+    let mut acc: u32 = 0;
+    let mut acc_len: u8 = 0;
+    let mut out_offset = offset;
+
+    for &w in x.iter() {
+        acc = (acc << 0xe) | w as u32;
+        acc_len += 0xe;
+
+        while acc_len >= 8 {
+            acc_len -= 8;
+            out[out_offset] = (acc >> acc_len) as u8;
+            out_offset += 1;
+        }
+    }
+
+    // nothing left over for N >= 4 (14 * N bits)
+    if acc_len > 0 {
+        out[out_offset] = (acc << (8 - acc_len)) as u8;
     }
 
-    ret
+    out_len
 }
 
 /// Decodes a compressed vector from a byte buffer.
@@ -715,6 +947,11 @@ pub fn mq_decode(x: &mut [u16; N], input: &[u8; FALCON_PK_SIZE], offset: usize)
 ///   - The decoded vector as `[u16; N]`
 ///   - The number of bytes read from the buffer.
 pub fn comp_decode(input: &[u8]) -> ([u16; N], usize) {
+    comp_decode_with::<Falcon512, N>(input)
+}
+
+/// [`comp_decode`] for any parameter set: decodes `N` coefficients.
+pub fn comp_decode_with<P: FalconParams<N>, const N: usize>(input: &[u8]) -> ([u16; N], usize) {
     let in_max = input.len();
     let mut out = [0u16; N];
     let mut v = 0;
@@ -782,6 +1019,73 @@ pub fn comp_decode(input: &[u8]) -> ([u16; N], usize) {
     (out, v)
 }
 
+/// Encodes a vector into the compressed signature format; inverse of [`comp_decode`].
+///
+/// Each coefficient is written as a sign bit, its 7 low magnitude bits, then its high magnitude
+/// bits in unary (that many `0`s, then a `1`). The last byte is zero-padded. Zero is always written
+/// with a `0` sign bit (`comp_decode` rejects "-0"), so the encoding of a vector is unique.
+///
+/// # Parameters
+/// - `x`: The vector to encode, as `[u16; N]` (two's complement coefficients).
+/// - `out`: The output buffer.
+///
+/// # Returns
+/// The number of bytes written, or 0 if a coefficient is outside `[-2047, 2047]` or the encoding
+/// does not fit in `out` or in [`SIG_COMP_MAXSIZE`] bytes.
+pub fn comp_encode(x: &[u16; N], out: &mut [u8]) -> usize {
+    comp_encode_with::<Falcon512, N>(x, out)
+}
+
+/// [`comp_encode`] for any parameter set (at most [`FalconParams::SIG_COMP_MAXSIZE`] bytes).
+pub fn comp_encode_with<P: FalconParams<N>, const N: usize>(x: &[u16; N], out: &mut [u8]) -> usize {
+    let out_max = out.len().min(P::SIG_COMP_MAXSIZE);
+
+    if x.iter().any(|&w| (w as i16).unsigned_abs() > 2047) {
+        return 0;
+    }
+
+    let mut acc: u32 = 0;
+    let mut acc_len: u32 = 0;
+    let mut v = 0;
+
+    for &w in x.iter() {
+        let t = w as i16;
+        let mut m = t.unsigned_abs() as u32;
+
+        // sign bit, then the low 7 bits of the magnitude
+        acc = (acc << 1) | (t < 0) as u32;
+        acc = (acc << 0x7) | (m & 0x7f);
+        m >>= 0x7;
+        acc_len += 8;
+
+        // high bits (at most 15), in unary: at most 8 + 7 + 16 = 31 bits pending
+        acc = (acc << (m + 1)) | 1;
+        acc_len += m + 1;
+
+        while acc_len >= 8 {
+            acc_len -= 8;
+
+            if v >= out_max {
+                return 0;
+            }
+
+            out[v] = (acc >> acc_len) as u8;
+            v += 1;
+        }
+    }
+
+    if acc_len > 0 {
+        if v >= out_max {
+            return 0;
+        }
+
+        out[v] = (acc << (8 - acc_len)) as u8;
+        v += 1;
+    }
+
+    v
+}
+
 /// Internal signature verification routine.
 ///
 /// # Parameters
@@ -793,6 +1097,29 @@ pub fn comp_decode(input: &[u8]) -> ([u16; N], usize) {
 /// # Returns
 /// `true` if the signature is valid, `false` otherwise.
 pub fn verify_raw(c0: &mut [u16; N], s2: &[u16; N], h: &[u16; N], s1: &mut [u16; N]) -> bool {
+    verify_raw_with::<Falcon512, N>(c0, s2, h, s1)
+}
+
+/// [`verify_raw`] for any parameter set.
+#[inline(always)]
+pub fn verify_raw_with<P: FalconParams<N>, const N: usize>(
+    c0: &mut [u16; N],
+    s2: &[u16; N],
+    h: &[u16; N],
+    s1: &mut [u16; N],
+) -> bool {
+    verify_raw_norm::<P, N>(c0, s2, h, s1) <= P::SIG_L2_BOUND
+}
+
+/// Core of [`verify_raw_with`]: returns the squared norm of `(s1, s2)` (saturated to `u32::MAX` on
+/// overflow), for the caller to compare with the acceptance bound.
+#[inline(always)]
+pub(crate) fn verify_raw_norm<P: FalconParams<N>, const N: usize>(
+    c0: &mut [u16; N],
+    s2: &[u16; N],
+    h: &[u16; N],
+    s1: &mut [u16; N],
+) -> u32 {
     // reduce s2_ elements modulo q ([0..q-1] range).
     unsafe {
         let s1_ptr = s1.as_mut_ptr();
@@ -812,9 +1139,9 @@ pub fn verify_raw(c0: &mut [u16; N], s2: &[u16; N], h: &[u16; N], s1: &mut [u16;
     // dependency) and interleaving the branchless Montgomery reduction defeats vectorization and
     // measures ~+5% instructions on a callgrind run.
 
-    mq_ntt(s1);
-    mq_poly_montymul_ntt(s1, h);
-    mq_intt(s1);
+    mq_ntt_with::<P, N>(s1);
+    mq_poly_montymul_ntt_n(s1, h);
+    mq_intt_with::<P, N>(s1);
 
     // Subtract c0 and normalize into [-q/2, q/2] in one pass. Both are pure element-wise maps (no
     // loop-carried dependency), so fusing them keeps the vectorization and removes one 512-element
@@ -832,7 +1159,7 @@ pub fn verify_raw(c0: &mut [u16; N], s2: &[u16; N], h: &[u16; N], s1: &mut [u16;
         }
     }
 
-    is_short(s1, s2)
+    distance_n(s1, s2)
 }
 
 /// Converts a serialized public key to NTT format, verifying structure.
@@ -841,31 +1168,82 @@ pub fn verify_raw(c0: &mut [u16; N], s2: &[u16; N], h: &[u16; N], s1: &mut [u16;
 /// - `pk`: Serialized public key bytes, as `[u8; FALCON_PK_SIZE]`.
 ///
 /// # Returns
-/// The decoded public key in NTT format as `[u16; N]`.
-///
-/// # Panics
-/// Panics if the public key is invalid.
-pub fn pk_to_ntt_fmt(pk: &[u8; FALCON_PK_SIZE]) -> [u16; N] {
+/// The decoded public key in NTT format as `[u16; N]`, or [`Error::BadHeader`] if the header is
+/// not `LOGN`, otherwise the [`mq_decode`] error.
+pub fn pk_to_ntt_fmt(pk: &[u8; FALCON_PK_SIZE]) -> Result<[u16; N], Error> {
+    pk_to_ntt_fmt_with::<Falcon512, N>(pk)
+}
+
+/// [`pk_to_ntt_fmt`] for any parameter set: `pk` must be [`FalconParams::PK_SIZE`] bytes
+/// ([`Error::BadLength`] otherwise), with header [`FalconParams::LOGN`].
+#[inline(always)]
+pub fn pk_to_ntt_fmt_with<P: FalconParams<N>, const N: usize>(
+    pk: &[u8],
+) -> Result<[u16; N], Error> {
+    if pk.len() != P::PK_SIZE {
+        return Err(Error::BadLength);
+    }
+
     // 1st byte should have the form "0000nnnn"
-    if (pk[0] >> 0x4) != 0 || pk[0] & 0xf != LOGN {
-        revert(E_INVALID_PUBLIC_KEY);
+    if (pk[0] >> 0x4) != 0 || pk[0] & 0xf != P::LOGN {
+        return Err(Error::BadHeader);
     }
 
     let mut pk_ntt_fmt = [0u16; N];
 
     // decode public key
-    // let sz1 = mq_decode2(ref pk_ntt_fmt, pk.span(), 1);
-    let sz1 = mq_decode(&mut pk_ntt_fmt, pk, 1);
+    mq_decode_with::<P, N>(&mut pk_ntt_fmt, pk, 1)?;
+
+    // pk_ntt_fmt now contains decoded public key
+
+    to_ntt_monty_with::<P, N>(&mut pk_ntt_fmt);
+
+    Ok(pk_ntt_fmt)
+}
+
+/// Converts a public key in NTT format back to the canonical serialized key; inverse of
+/// [`pk_to_ntt_fmt`].
+///
+/// # Parameters
+/// - `pk_ntt_fmt`: Public key in NTT + Montgomery format, as `[u16; N]`.
+///
+/// # Returns
+/// The serialized public key (header `LOGN`, then `h` on 14 bits per coefficient), or
+/// [`Error::CoefficientOutOfRange`] if a coefficient is not in `[0, q)`.
+pub fn pk_from_ntt_fmt(pk_ntt_fmt: &[u16; N]) -> Result<[u8; FALCON_PK_SIZE], Error> {
+    let mut pk = [0u8; FALCON_PK_SIZE];
+
+    pk_from_ntt_fmt_with::<Falcon512, N>(pk_ntt_fmt, &mut pk)?;
+
+    Ok(pk)
+}
 
-    if sz1 != FALCON_PK_SIZE - 1 {
-        revert(E_INVALID_PUBLIC_KEY);
+/// [`pk_from_ntt_fmt`] for any parameter set: writes the [`FalconParams::PK_SIZE`]-byte key to the
+/// start of `out`.
+///
+/// # Returns
+/// The number of bytes written, [`Error::CoefficientOutOfRange`] if a coefficient is not in
+/// `[0, q)`, or [`Error::BadLength`] if `out` is too small.
+pub fn pk_from_ntt_fmt_with<P: FalconParams<N>, const N: usize>(
+    pk_ntt_fmt: &[u16; N],
+    out: &mut [u8],
+) -> Result<usize, Error> {
+    if pk_ntt_fmt.iter().any(|&w| w >= Q) {
+        return Err(Error::CoefficientOutOfRange);
     }
 
-    // pk_ntt_fmt now contains decoded public key
+    if out.len() < P::PK_SIZE {
+        return Err(Error::BadLength);
+    }
+
+    // out of Montgomery (x * 1 / R), then back from the NTT domain
+    let mut h = pk_ntt_fmt.map(|w| mq_montymul(w, 1));
 
-    to_ntt_monty(&mut pk_ntt_fmt);
+    mq_intt_with::<P, N>(&mut h);
 
-    pk_ntt_fmt
+    out[0] = P::LOGN;
+
+    Ok(mq_encode_with::<P, N>(&h, out, 1) + 1)
 }
 
 /// Verifies that the given signature, message, and public key match.
@@ -876,13 +1254,8 @@ pub fn pk_to_ntt_fmt(pk: &[u8; FALCON_PK_SIZE]) -> [u16; N] {
 /// - `pk_ntt_fmt`: Public key in NTT format, as `[u16; N]`.
 ///
 /// # Returns
-/// `true` if the signature is valid, otherwise `false`.
-///
-/// # Panics
-/// Panics if the public key is invalid.
-/// Panics if the signature len is invalid.
-/// Panics if fails decoding signature.
-/// Panics if the nonce + message len is invalid (0 len message).
+/// `true` if the signature is valid, otherwise `false`: a signature with an invalid length or
+/// encoding, or a nonce without a message, is rejected. Use [`verify_sig`] to learn why.
 pub fn verify(nonce_msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> bool {
     let sig_len = sig.len();
 
@@ -921,3 +1294,1280 @@ pub fn verify(nonce_msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> bool {
 
     verify_raw(&mut hash_nonce_msg, &decoded_sig, pk_ntt_fmt, &mut s1)
 }
+
+/// [`verify`] for any parameter set. (Falcon-512's own [`verify`] keeps its tuned call structure,
+/// with the squeeze and [`verify_raw`] out of line.)
+///
+/// # Parameters
+/// - `nonce_msg`: Message (and nonce) bytes.
+/// - `sig`: Compressed signature bytes (without header and nonce).
+/// - `pk_ntt_fmt`: Public key in NTT format ([`pk_to_ntt_fmt_with`]), as `[u16; N]`.
+///
+/// # Returns
+/// `true` if the signature is valid, otherwise `false`.
+#[inline(always)]
+pub fn verify_with<P: FalconParams<N>, const N: usize>(
+    nonce_msg: &[u8],
+    sig: &[u8],
+    pk_ntt_fmt: &[u16; N],
+) -> bool {
+    let sig_len = sig.len();
+
+    // sig must have a minimum length of 42 bytes
+    // sig type must have the correct sig length in the pub key
+    if sig_len < 1 || sig_len > P::SIG_COMP_MAXSIZE || nonce_msg.len() == NONCE_LEN as usize {
+        return false;
+    }
+
+    // sigLen (supplied arg) typical value is in the order of 650 to 660,
+    // yielding cb_sig_proper in the order of 609 to 619
+    let (decoded_sig, sz2) = comp_decode_with::<P, N>(sig);
+
+    if sz2 != sig_len {
+        return false;
+    }
+
+    // decoded_sig now contains decoded signature
+
+    let mut shake_ctx = [0u64; 26];
+
+    shake_inject(&mut shake_ctx, nonce_msg);
+    shake_flip(&mut shake_ctx);
+
+    // Squeeze only the rate blocks the variable-time sampler needs (Finding B: 9 for Falcon-512),
+    // not the 11 the constant-time oversample required.
+    let extracted = P::Squeeze::squeeze(&mut shake_ctx);
+
+    let mut hash_nonce_msg = [0u16; N];
+
+    // Verification input is public - use the variable-time rejection sampler (Falcon's verify
+    // path), not the constant-time sorting network. Produces the identical challenge `c`.
+    hash_to_point_vartime_with::<P, N>(extracted.as_ref(), &mut hash_nonce_msg);
+
+    let mut s1 = [0u16; N];
+
+    verify_raw_with::<P, N>(&mut hash_nonce_msg, &decoded_sig, pk_ntt_fmt, &mut s1)
+}
+
+/// What [`verify_detailed`] or [`verify_sig_detailed`] found: the verdict and, once `s2` is
+/// decoded, the squared norm of `(s1, s2)` against the acceptance bound.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// `Ok(())` if the signature is valid, otherwise the stage that rejected it
+    /// ([`Error::NormTooLarge`] for the norm check).
+    pub result: Result<(), Error>,
+    /// The squared norm of `(s1, s2)` ([`distance`]), `None` if the signature was rejected before.
+    pub norm: Option<u32>,
+    /// The acceptance bound [`FalconParams::SIG_L2_BOUND`] (34034726 for Falcon-512).
+    pub bound: u32,
+}
+
+impl VerifyReport {
+    /// `true` if the signature is valid.
+    pub fn is_valid(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// [`verify`], reporting why a signature is rejected, for monitoring and interop debugging.
+///
+/// # Returns
+/// The report: [`Error::BadLength`] if `sig` is empty or longer than [`SIG_COMP_MAXSIZE`] (or
+/// `nonce_msg` has no message), [`Error::DecodeFailed`] if `sig` is not exactly a compressed `s2`,
+/// otherwise the norm and [`Error::NormTooLarge`] if it is above the bound.
+pub fn verify_detailed(nonce_msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> VerifyReport {
+    verify_detailed_with::<Falcon512, N>(nonce_msg, sig, pk_ntt_fmt)
+}
+
+/// [`verify_detailed`] for any parameter set.
+pub fn verify_detailed_with<P: FalconParams<N>, const N: usize>(
+    nonce_msg: &[u8],
+    sig: &[u8],
+    pk_ntt_fmt: &[u16; N],
+) -> VerifyReport {
+    let sig_len = sig.len();
+
+    if sig_len < 1 || sig_len > P::SIG_COMP_MAXSIZE || nonce_msg.len() == NONCE_LEN as usize {
+        return rejected::<P, N>(Error::BadLength);
+    }
+
+    let (decoded_sig, sz2) = comp_decode_with::<P, N>(sig);
+
+    if sz2 != sig_len {
+        return rejected::<P, N>(Error::DecodeFailed);
+    }
+
+    let mut shake_ctx = [0u64; 26];
+
+    shake_inject(&mut shake_ctx, nonce_msg);
+    shake_flip(&mut shake_ctx);
+
+    norm_report::<P, N>(&mut shake_ctx, &decoded_sig, pk_ntt_fmt)
+}
+
+fn rejected<P: FalconParams<N>, const N: usize>(error: Error) -> VerifyReport {
+    VerifyReport {
+        result: Err(error),
+        norm: None,
+        bound: P::SIG_L2_BOUND,
+    }
+}
+
+/// Hashes to the challenge from a flipped SHAKE256 context and checks the norm of `(s1, s2)`.
+pub(crate) fn norm_report<P: FalconParams<N>, const N: usize>(
+    shake_ctx: &mut [u64; 26],
+    s2: &[u16; N],
+    pk_ntt_fmt: &[u16; N],
+) -> VerifyReport {
+    let extracted = P::Squeeze::squeeze(shake_ctx);
+    let mut hash_nonce_msg = [0u16; N];
+
+    hash_to_point_vartime_with::<P, N>(extracted.as_ref(), &mut hash_nonce_msg);
+
+    let mut s1 = [0u16; N];
+    let norm = verify_raw_norm::<P, N>(&mut hash_nonce_msg, s2, pk_ntt_fmt, &mut s1);
+    let result = if norm <= P::SIG_L2_BOUND {
+        Ok(())
+    } else {
+        Err(Error::NormTooLarge)
+    };
+
+    VerifyReport {
+        result,
+        norm: Some(norm),
+        bound: P::SIG_L2_BOUND,
+    }
+}
+
+/// The encodings of a complete signature `header ‖ nonce ‖ s2`. The header byte holds the format
+/// in its high nibble and `logn` in its low nibble.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SigFormat {
+    /// Header `0x30 + logn` (`0x39`), variable-length compressed `s2`.
+    Compressed,
+    /// Header `0x30 + logn`, compressed `s2` zero-padded to a fixed total size.
+    Padded,
+    /// Header `0x50 + logn` (`0x59`), `s2` at a fixed bit width (constant-time decodable).
+    Ct,
+}
+
+/// A decoded signature: its format, nonce and `s2` (two's complement coefficients).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedSig<'a, const N: usize> {
+    pub format: SigFormat,
+    pub nonce: &'a [u8; NONCE_LEN as usize],
+    pub s2: [u16; N],
+}
+
+/// Decodes a complete Falcon-512 signature (compressed, padded or constant-time format).
+///
+/// # Returns
+/// The decoded signature, or [`Error::BadHeader`], [`Error::BadLength`], [`Error::DecodeFailed`]
+/// (invalid `s2`) or [`Error::TrailingBits`] (non-zero padding).
+pub fn sig_decode(sig: &[u8]) -> Result<DecodedSig<'_, N>, Error> {
+    sig_decode_with::<Falcon512, N>(sig)
+}
+
+/// [`sig_decode`] for any parameter set.
+///
+/// The low nibble of the header must be `logn`. A `0x30` header is a compressed signature if `s2`
+/// fills the rest of `sig` exactly (within [`FalconParams::SIG_COMP_MAXSIZE`]), or a padded one
+/// if `sig` is [`FalconParams::SIG_PADDED_SIZE`] bytes and everything after `s2` is zero. A `0x50`
+/// header requires exactly [`FalconParams::SIG_CT_SIZE`] bytes.
+pub fn sig_decode_with<P: FalconParams<N>, const N: usize>(
+    sig: &[u8],
+) -> Result<DecodedSig<'_, N>, Error> {
+    let nonce_len = NONCE_LEN as usize;
+
+    if sig.len() <= 1 + nonce_len {
+        return Err(Error::BadLength);
+    }
+
+    if sig[0] & 0x0f != P::LOGN {
+        return Err(Error::BadHeader);
+    }
+
+    let nonce = sig[1..1 + nonce_len].try_into().unwrap();
+    let body = &sig[1 + nonce_len..];
+
+    match sig[0] & 0xf0 {
+        SIG_HEADER_COMP => {
+            let (s2, len) = comp_decode_with::<P, N>(body);
+
+            if len == 0 {
+                return Err(Error::DecodeFailed);
+            }
+
+            let format = if len == body.len() && len <= P::SIG_COMP_MAXSIZE {
+                SigFormat::Compressed
+            } else if sig.len() != P::SIG_PADDED_SIZE {
+                return Err(Error::BadLength);
+            } else if body[len..].iter().any(|&b| b != 0) {
+                return Err(Error::TrailingBits);
+            } else {
+                SigFormat::Padded
+            };
+
+            Ok(DecodedSig { format, nonce, s2 })
+        }
+        SIG_HEADER_CT => {
+            let mut s2 = [0u16; N];
+
+            if sig.len() != P::SIG_CT_SIZE {
+                return Err(Error::BadLength);
+            }
+
+            if trim_i16_decode(&mut s2, P::SIG_CT_BITS, body, 0) == 0 {
+                return Err(Error::DecodeFailed);
+            }
+
+            Ok(DecodedSig {
+                format: SigFormat::Ct,
+                nonce,
+                s2,
+            })
+        }
+        _ => Err(Error::BadHeader),
+    }
+}
+
+/// Encodes signed coefficients (two's complement `u16`) at a fixed bit width, big-endian bit
+/// order, as used for the `s2` of constant-time signatures.
+///
+/// # Parameters
+/// - `x`: Coefficients, all in `[-(2^(bits-1) - 1), 2^(bits-1) - 1]`.
+/// - `bits`: Bits per coefficient (at most 16).
+/// - `out`: Output buffer.
+/// - `offset`: Offset into the output buffer to start encoding at.
+///
+/// # Returns
+/// The number of bytes written, or 0 if a coefficient is out of range or `out` is too small.
+pub fn trim_i16_encode<const DEG: usize>(
+    x: &[u16; DEG],
+    bits: u32,
+    out: &mut [u8],
+    offset: usize,
+) -> usize {
+    let maxv = (1i32 << (bits - 1)) - 1;
+    let out_len = (DEG * bits as usize + 7) >> 0x3;
+
+    if x.iter().any(|&w| (w as i16 as i32).abs() > maxv) || out.len() < offset + out_len {
+        return 0;
+    }
+
+    let mask = (1u32 << bits) - 1;
+    let mut acc: u32 = 0;
+    let mut acc_len: u32 = 0;
+    let mut out_offset = offset;
+
+    for &w in x.iter() {
+        acc = (acc << bits) | (w as u32 & mask);
+        acc_len += bits;
+
+        while acc_len >= 8 {
+            acc_len -= 8;
+            out[out_offset] = (acc >> acc_len) as u8;
+            out_offset += 1;
+        }
+    }
+
+    if acc_len > 0 {
+        out[out_offset] = (acc << (8 - acc_len)) as u8;
+    }
+
+    out_len
+}
+
+/// Decodes signed coefficients encoded by [`trim_i16_encode`].
+///
+/// # Returns
+/// The number of bytes read, or 0 if `input` is too short, a coefficient is `-2^(bits-1)`
+/// (forbidden) or the padding bits of the last byte are not zero.
+pub fn trim_i16_decode<const DEG: usize>(
+    x: &mut [u16; DEG],
+    bits: u32,
+    input: &[u8],
+    offset: usize,
+) -> usize {
+    let in_len = (DEG * bits as usize + 7) >> 0x3;
+
+    if input.len() < offset + in_len {
+        return 0;
+    }
+
+    let mask1 = (1u32 << bits) - 1;
+    let mask2 = 1u32 << (bits - 1);
+    let mut acc: u32 = 0;
+    let mut acc_len: u32 = 0;
+    let mut in_offset = offset;
+    let mut u = 0;
+
+    while u < DEG {
+        acc = (acc << 8) | input[in_offset] as u32;
+        in_offset += 1;
+        acc_len += 8;
+
+        while acc_len >= bits && u < DEG {
+            acc_len -= bits;
+
+            // sign extension
+            let mut w = (acc >> acc_len) & mask1;
+
+            w |= (w & mask2).wrapping_neg();
+
+            if w == mask2.wrapping_neg() {
+                return 0;
+            }
+
+            x[u] = w as u16;
+            u += 1;
+        }
+    }
+
+    if acc & ((1u32 << acc_len) - 1) != 0 {
+        return 0;
+    }
+
+    in_len
+}
+
+/// Absorbs `nonce ‖ msg` without a concatenation buffer: the nonce and the start of the message
+/// fill the first rate block, which leaves the context at a block boundary for the rest.
+fn shake_inject_nonce_msg(shake_ctx: &mut [u64; 26], nonce: &[u8], msg: &[u8]) {
+    let mut block = [0u8; SHAKE256_RATE as usize];
+    let nonce_len = nonce.len();
+    let head = msg.len().min(block.len() - nonce_len);
+
+    block[..nonce_len].copy_from_slice(nonce);
+    block[nonce_len..nonce_len + head].copy_from_slice(&msg[..head]);
+    shake_inject(shake_ctx, &block[..nonce_len + head]);
+
+    if head < msg.len() {
+        shake_inject(shake_ctx, &msg[head..]);
+    }
+}
+
+/// Verifies a complete Falcon-512 signature (`header ‖ nonce ‖ s2`, in any [`SigFormat`]) on
+/// `msg`.
+///
+/// # Returns
+/// `Ok(())` if the signature is valid, the [`sig_decode`] error if it is malformed, or
+/// [`Error::NormTooLarge`] if it does not verify.
+pub fn verify_sig(msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> Result<(), Error> {
+    verify_sig_with::<Falcon512, N>(msg, sig, pk_ntt_fmt)
+}
+
+/// [`verify_sig`] for any parameter set.
+pub fn verify_sig_with<P: FalconParams<N>, const N: usize>(
+    msg: &[u8],
+    sig: &[u8],
+    pk_ntt_fmt: &[u16; N],
+) -> Result<(), Error> {
+    verify_sig_detailed_with::<P, N>(msg, sig, pk_ntt_fmt).result
+}
+
+/// [`verify_sig`], with the squared norm of `(s1, s2)` once the signature is decoded (see
+/// [`VerifyReport`]).
+pub fn verify_sig_detailed(msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> VerifyReport {
+    verify_sig_detailed_with::<Falcon512, N>(msg, sig, pk_ntt_fmt)
+}
+
+/// [`verify_sig_detailed`] for any parameter set.
+pub fn verify_sig_detailed_with<P: FalconParams<N>, const N: usize>(
+    msg: &[u8],
+    sig: &[u8],
+    pk_ntt_fmt: &[u16; N],
+) -> VerifyReport {
+    let decoded = match sig_decode_with::<P, N>(sig) {
+        Ok(decoded) => decoded,
+        Err(error) => return rejected::<P, N>(error),
+    };
+
+    let mut shake_ctx = [0u64; 26];
+
+    shake_inject_nonce_msg(&mut shake_ctx, decoded.nonce, msg);
+    shake_flip(&mut shake_ctx);
+
+    norm_report::<P, N>(&mut shake_ctx, &decoded.s2, pk_ntt_fmt)
+}
+
+/// A serialized Falcon-512 public key (header `0x09`, then `h` on 14 bits per coefficient),
+/// checked on construction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey([u8; FALCON_PK_SIZE]);
+
+/// A Falcon-512 public key in NTT + Montgomery format ([`pk_to_ntt_fmt`]), ready for
+/// verification. Every coefficient is in `[0, q)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PreparedPublicKey([u16; N]);
+
+/// A complete Falcon-512 signature (`header ‖ nonce ‖ s2`, in any [`SigFormat`]), checked on
+/// construction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    bytes: [u8; SIG_CT_SIZE],
+    len: usize,
+    format: SigFormat,
+}
+
+impl PublicKey {
+    /// The serialized key.
+    pub fn as_bytes(&self) -> &[u8; FALCON_PK_SIZE] {
+        &self.0
+    }
+
+    /// Converts the key to NTT format, for repeated verifications.
+    pub fn prepare(&self) -> PreparedPublicKey {
+        // checked on construction
+        PreparedPublicKey(pk_to_ntt_fmt(&self.0).unwrap())
+    }
+
+    /// Verifies `sig` on `msg` (see [`verify_sig`]). Prepares the key on each call; keep a
+    /// [`PreparedPublicKey`] to verify many signatures.
+    pub fn verify(&self, msg: &[u8], sig: &Signature) -> Result<(), Error> {
+        self.prepare().verify(msg, sig)
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = Error;
+
+    /// Checks the length, the header and that every coefficient is `< q`.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let pk: [u8; FALCON_PK_SIZE] = bytes.try_into().map_err(|_| Error::BadLength)?;
+        let mut h = [0u16; N];
+
+        if pk[0] != LOGN {
+            return Err(Error::BadHeader);
+        }
+
+        mq_decode(&mut h, &pk, 1)?;
+
+        Ok(PublicKey(pk))
+    }
+}
+
+impl From<&PreparedPublicKey> for PublicKey {
+    fn from(pk: &PreparedPublicKey) -> Self {
+        // coefficients checked on construction
+        PublicKey(pk_from_ntt_fmt(&pk.0).unwrap())
+    }
+}
+
+impl PreparedPublicKey {
+    /// Wraps a key already in NTT + Montgomery format.
+    ///
+    /// # Returns
+    /// The key, or [`Error::CoefficientOutOfRange`] if a coefficient is not in `[0, q)`.
+    pub fn from_ntt_fmt(pk_ntt_fmt: &[u16; N]) -> Result<Self, Error> {
+        if pk_ntt_fmt.iter().any(|&w| w >= Q) {
+            return Err(Error::CoefficientOutOfRange);
+        }
+
+        Ok(PreparedPublicKey(*pk_ntt_fmt))
+    }
+
+    /// The key in NTT + Montgomery format.
+    pub fn as_ntt_fmt(&self) -> &[u16; N] {
+        &self.0
+    }
+
+    /// Verifies `sig` on `msg` (see [`verify_sig`]).
+    pub fn verify(&self, msg: &[u8], sig: &Signature) -> Result<(), Error> {
+        verify_sig(msg, sig.as_bytes(), &self.0)
+    }
+}
+
+impl TryFrom<&[u8]> for PreparedPublicKey {
+    type Error = Error;
+
+    /// Parses and prepares a serialized public key.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        PublicKey::try_from(bytes).map(|pk| pk.prepare())
+    }
+}
+
+impl From<&PublicKey> for PreparedPublicKey {
+    fn from(pk: &PublicKey) -> Self {
+        pk.prepare()
+    }
+}
+
+impl Signature {
+    /// Builds a compressed signature from the nonce and compressed `s2` returned by [`sign`].
+    ///
+    /// # Returns
+    /// The signature, or the [`sig_decode`] error if `comp_s2` is not a valid compressed `s2`.
+    pub fn from_compressed(
+        nonce: &[u8; NONCE_LEN as usize],
+        comp_s2: &[u8],
+    ) -> Result<Self, Error> {
+        let nonce_len = NONCE_LEN as usize;
+        let len = 1 + nonce_len + comp_s2.len();
+
+        if comp_s2.len() > SIG_COMP_MAXSIZE as usize {
+            return Err(Error::BadLength);
+        }
+
+        let mut bytes = [0u8; SIG_CT_SIZE];
+
+        bytes[0] = SIG_HEADER_COMP | LOGN;
+        bytes[1..1 + nonce_len].copy_from_slice(nonce);
+        bytes[1 + nonce_len..len].copy_from_slice(comp_s2);
+
+        Signature::try_from(&bytes[..len])
+    }
+
+    /// The encoded signature.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// The encoding of the signature.
+    pub fn format(&self) -> SigFormat {
+        self.format
+    }
+
+    /// The nonce.
+    pub fn nonce(&self) -> &[u8; NONCE_LEN as usize] {
+        self.bytes[1..1 + NONCE_LEN as usize].try_into().unwrap()
+    }
+
+    /// The decoded `s2` (two's complement coefficients). Decodes `s2` on each call:
+    /// [`decode`](Self::decode) returns it with the nonce and the format.
+    pub fn s2(&self) -> [u16; N] {
+        self.decode().s2
+    }
+
+    /// The format, the nonce and the decoded `s2`, in one decoding of `s2`.
+    pub fn decode(&self) -> DecodedSig<'_, N> {
+        // checked on construction, with the format
+        let body = &self.as_bytes()[1 + NONCE_LEN as usize..];
+        let s2 = match self.format {
+            SigFormat::Compressed | SigFormat::Padded => comp_decode(body).0,
+            SigFormat::Ct => {
+                let mut s2 = [0u16; N];
+
+                trim_i16_decode(&mut s2, Falcon512::SIG_CT_BITS, body, 0);
+                s2
+            }
+        };
+
+        DecodedSig {
+            format: self.format,
+            nonce: self.nonce(),
+            s2,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    /// Checks the header, the length and the encoding of `s2` ([`sig_decode`]).
+    fn try_from(sig: &[u8]) -> Result<Self, Self::Error> {
+        let format = sig_decode(sig)?.format;
+        let mut bytes = [0u8; SIG_CT_SIZE];
+
+        bytes[..sig.len()].copy_from_slice(sig);
+
+        Ok(Signature {
+            bytes,
+            len: sig.len(),
+            format,
+        })
+    }
+}
+
+/// Computes `f^(q - 2) = 1 / f mod q` for every coefficient of a polynomial in NTT + Montgomery
+/// format, in-place (Fermat's little theorem; a zero coefficient stays zero).
+///
+/// # Arguments
+/// * `p` - A mutable slice of the polynomial, in NTT + Montgomery format. The result is stored here.
+fn mq_poly_inv_ntt_monty(p: &mut [u16; N]) {
+    // q - 2 = 12287 = 0b10_1111_1111_1111
+    for x in p.iter_mut() {
+        let mut r = *x;
+        let mut bit = 12;
+
+        loop {
+            r = mq_montymul(r, r);
+
+            if (Q - 2) >> bit & 1 != 0 {
+                r = mq_montymul(r, *x);
+            }
+
+            if bit == 0 {
+                break;
+            }
+
+            bit -= 1;
+        }
+
+        *x = r;
+    }
+}
+
+/// Generates a Falcon-512 key pair.
+///
+/// Samples the NTRU basis `(f, g)` and solves `f * G - g * F = q` for `(F, G)`, then computes the
+/// public key `h = g / f mod q` with [`pk_from_sk`] (`f` is guaranteed invertible modulo `q`).
+///
+/// # Parameters
+/// - `rng`: The randomness source; the key pair is a deterministic function of its seed.
+///
+/// # Returns
+/// A tuple containing:
+///   - The private key.
+///   - The serialized public key (header byte `0x00 | LOGN`, then the 14-bit packed `h`), as
+///     accepted by [`pk_to_ntt_fmt`].
+#[cfg(feature = "std")]
+pub fn keygen(rng: &mut Shake256Prng) -> (SecretKey, [u8; FALCON_PK_SIZE]) {
+    let (f, g, big_f, big_g) = ntru_gen(rng);
+    let to_i8 = |x: [i32; N]| x.map(|c| c as i8);
+    let sk = SecretKey {
+        f: to_i8(f),
+        g: to_i8(g),
+        big_f: to_i8(big_f),
+        big_g: to_i8(big_g),
+    };
+    let pk = pk_from_sk(&sk);
+
+    (sk, pk)
+}
+
+/// Computes the public key `h = g / f mod q` of a private key.
+///
+/// # Parameters
+/// - `sk`: The private key (`f` invertible modulo `q`, as for any key from [`keygen`] or
+///   [`sk_decode`]).
+///
+/// # Returns
+/// The serialized public key, as returned by [`keygen`].
+pub fn pk_from_sk(sk: &SecretKey) -> [u8; FALCON_PK_SIZE] {
+    let mut fq = small_to_mq(&sk.f);
+    let mut h = small_to_mq(&sk.g);
+
+    // h = g * (1 / f): f^-1 is taken in Montgomery format, so the Montgomery product of the
+    // plain NTT(g) with it lands back in the plain domain.
+    to_ntt_monty(&mut fq);
+    mq_poly_inv_ntt_monty(&mut fq);
+    mq_ntt(&mut h);
+    mq_poly_montymul_ntt(&mut h, &fq);
+    mq_intt(&mut h);
+
+    let mut pk = [0u8; FALCON_PK_SIZE];
+
+    pk[0] = LOGN;
+    mq_encode(&h, &mut pk, 1);
+
+    pk
+}
+
+/// Encodes small signed coefficients at a fixed bit width (two's complement, big-endian bit
+/// order), as used for the secret key.
+///
+/// # Parameters
+/// - `x`: Coefficients, as `[i8; N]`, all in `[-(2^(bits-1) - 1), 2^(bits-1) - 1]`.
+/// - `bits`: Bits per coefficient (at most 8).
+/// - `out`: Output buffer.
+/// - `offset`: Offset into the output buffer to start encoding at.
+///
+/// # Returns
+/// The number of bytes written, or 0 if a coefficient is out of range or `out` is too small.
+pub fn trim_i8_encode(x: &[i8; N], bits: u32, out: &mut [u8], offset: usize) -> usize {
+    let maxv = (1i32 << (bits - 1)) - 1;
+    let out_len = (N * bits as usize + 7) >> 0x3;
+
+    if x.iter().any(|&w| (w as i32).abs() > maxv) || out.len() < offset + out_len {
+        return 0;
+    }
+
+    let mask = (1u32 << bits) - 1;
+    let mut acc: u32 = 0;
+    let mut acc_len: u32 = 0;
+    let mut out_offset = offset;
+
+    for &w in x.iter() {
+        acc = (acc << bits) | (w as u8 as u32 & mask);
+        acc_len += bits;
+
+        while acc_len >= 8 {
+            acc_len -= 8;
+            out[out_offset] = (acc >> acc_len) as u8;
+            out_offset += 1;
+        }
+    }
+
+    if acc_len > 0 {
+        out[out_offset] = (acc << (8 - acc_len)) as u8;
+    }
+
+    out_len
+}
+
+/// Decodes small signed coefficients encoded by [`trim_i8_encode`].
+///
+/// # Parameters
+/// - `x`: Output coefficients, as mutable `[i8; N]`.
+/// - `bits`: Bits per coefficient (at most 8).
+/// - `input`: Input buffer.
+/// - `offset`: Offset into the input buffer to start decoding from.
+///
+/// # Returns
+/// The number of bytes read, or 0 if `input` is too short, a coefficient is `-2^(bits-1)`
+/// (forbidden) or the padding bits of the last byte are not zero.
+pub fn trim_i8_decode(x: &mut [i8; N], bits: u32, input: &[u8], offset: usize) -> usize {
+    let in_len = (N * bits as usize + 7) >> 0x3;
+
+    if input.len() < offset + in_len {
+        return 0;
+    }
+
+    let mask1 = (1u32 << bits) - 1;
+    let mask2 = 1u32 << (bits - 1);
+    let mut acc: u32 = 0;
+    let mut acc_len: u32 = 0;
+    let mut in_offset = offset;
+    let mut u = 0;
+
+    while u < N {
+        acc = (acc << 8) | input[in_offset] as u32;
+        in_offset += 1;
+        acc_len += 8;
+
+        while acc_len >= bits && u < N {
+            acc_len -= bits;
+
+            // sign extension
+            let mut w = (acc >> acc_len) & mask1;
+
+            w |= (w & mask2).wrapping_neg();
+
+            if w == mask2.wrapping_neg() {
+                return 0;
+            }
+
+            x[u] = w as i32 as i8;
+            u += 1;
+        }
+    }
+
+    if acc & ((1u32 << acc_len) - 1) != 0 {
+        return 0;
+    }
+
+    in_len
+}
+
+/// Maps small signed coefficients to `[0, q)`.
+fn small_to_mq(x: &[i8; N]) -> [u16; N] {
+    x.map(|c| (c as i32).rem_euclid(Q as i32) as u16)
+}
+
+/// Serializes a private key.
+///
+/// Layout: header byte `0x50 | LOGN`, then `f` and `g` at `SK_FG_BITS` and `F` at `SK_BIG_F_BITS`
+/// bits per coefficient ([`trim_i8_encode`]). `G` is not stored: [`sk_decode`] recomputes it.
+///
+/// # Parameters
+/// - `sk`: The private key.
+///
+/// # Returns
+/// The serialized private key.
+///
+/// # Panics
+/// Panics if a coefficient does not fit its encoding (not a key from [`keygen`] or
+/// [`sk_decode`]).
+pub fn sk_encode(sk: &SecretKey) -> [u8; FALCON_SK_SIZE] {
+    let mut out = [0u8; FALCON_SK_SIZE];
+    let mut u = 1;
+
+    out[0] = 0x50 | LOGN;
+
+    for (x, bits) in [
+        (&sk.f, SK_FG_BITS),
+        (&sk.g, SK_FG_BITS),
+        (&sk.big_f, SK_BIG_F_BITS),
+    ] {
+        let v = trim_i8_encode(x, bits, &mut out, u);
+
+        assert!(v != 0, "private key coefficient out of range");
+
+        u += v;
+    }
+
+    out
+}
+
+/// Deserializes a private key produced by [`sk_encode`] (or any Falcon-512 implementation using
+/// the standard layout), recomputing `G = g * F / f mod q`.
+///
+/// # Parameters
+/// - `sk`: Serialized private key bytes, as `[u8; FALCON_SK_SIZE]`.
+///
+/// # Returns
+/// The private key, or [`Error::InvalidSecretKey`] if the header is not `0x50 | LOGN`, a
+/// coefficient encoding is invalid (forbidden value or non-zero padding), `f` is not invertible
+/// modulo `q`, or `G` does not fit in `[-127, 127]`.
+pub fn sk_decode(sk: &[u8; FALCON_SK_SIZE]) -> Result<SecretKey, Error> {
+    // 1st byte should have the form "0101nnnn"
+    if sk[0] != 0x50 | LOGN {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    let mut f = [0i8; N];
+    let mut g = [0i8; N];
+    let mut big_f = [0i8; N];
+    let mut u = 1;
+
+    for (x, bits) in [
+        (&mut f, SK_FG_BITS),
+        (&mut g, SK_FG_BITS),
+        (&mut big_f, SK_BIG_F_BITS),
+    ] {
+        let v = trim_i8_decode(x, bits, sk, u);
+
+        if v == 0 {
+            return Err(Error::InvalidSecretKey);
+        }
+
+        u += v;
+    }
+
+    if u != FALCON_SK_SIZE {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    // G = (q + g * F) / f = g * F / f mod q
+    let mut fq = small_to_mq(&f);
+    let mut gq = small_to_mq(&g);
+    let mut big_fq = small_to_mq(&big_f);
+
+    to_ntt_monty(&mut fq);
+
+    if fq.contains(&0) {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    mq_poly_inv_ntt_monty(&mut fq);
+    mq_ntt(&mut gq);
+    to_ntt_monty(&mut big_fq);
+    mq_poly_montymul_ntt(&mut gq, &big_fq);
+    mq_poly_montymul_ntt(&mut gq, &fq);
+    mq_intt(&mut gq);
+
+    let mut big_g = [0i8; N];
+
+    for (c, &w) in big_g.iter_mut().zip(gq.iter()) {
+        let w = w as i32 - ((Q as i32) & -((w > Q >> 0x1) as i32));
+
+        if w.abs() > SK_COEFF_MAX {
+            return Err(Error::InvalidSecretKey);
+        }
+
+        *c = w as i8;
+    }
+
+    Ok(SecretKey { f, g, big_f, big_g })
+}
+
+/// Loads the secret basis `B = [[g, -f], [G, -F]]` in FFT representation, as
+/// `[b00, b01, b10, b11]`.
+fn basis_fft<F: Fpr>(sk: &SecretKey) -> [[F; N]; 4] {
+    let logn = LOGN as u32;
+    let mut b = [
+        sk.g.map(|x| F::of(x as i64)),
+        sk.f.map(|x| -F::of(x as i64)),
+        sk.big_g.map(|x| F::of(x as i64)),
+        sk.big_f.map(|x| -F::of(x as i64)),
+    ];
+
+    for x in b.iter_mut() {
+        fft(x, logn);
+    }
+
+    b
+}
+
+/// Computes the Gram matrix `G = B * B*` of the secret basis (self-adjoint, only the upper
+/// triangle is kept), as `[g00, g01, g11]`:
+///   - `g00 = b00 * adj(b00) + b01 * adj(b01)`
+///   - `g01 = b00 * adj(b10) + b01 * adj(b11)`
+///   - `g11 = b10 * adj(b10) + b11 * adj(b11)`
+fn gram_fft<F: Fpr>(b: &[[F; N]; 4]) -> [[F; N]; 3] {
+    let logn = LOGN as u32;
+    let [b00, b01, b10, b11] = b;
+    let mut g00 = *b00;
+    let mut g01 = *b00;
+    let mut g11 = *b10;
+    let mut t = *b01;
+
+    poly_mulselfadj_fft(&mut g00, logn);
+    poly_mulselfadj_fft(&mut t, logn);
+    poly_add(&mut g00, &t, logn);
+
+    t = *b01;
+    poly_muladj_fft(&mut g01, b10, logn);
+    poly_muladj_fft(&mut t, b11, logn);
+    poly_add(&mut g01, &t, logn);
+
+    t = *b11;
+    poly_mulselfadj_fft(&mut g11, logn);
+    poly_mulselfadj_fft(&mut t, logn);
+    poly_add(&mut g11, &t, logn);
+
+    [g00, g01, g11]
+}
+
+/// Computes the sampling target `(t0, t1) = (hm, 0) * B^-1 = (hm * -F / q, hm * f / q)`, in FFT
+/// representation.
+fn sign_target<F: Fpr>(hm: &[u16; N], b: &[[F; N]; 4]) -> ([F; N], [F; N]) {
+    let logn = LOGN as u32;
+    let ni = F::from_f64(1.0 / Q as f64);
+    let mut t0 = hm.map(|x| F::of(x as i64));
+
+    fft(&mut t0, logn);
+
+    let mut t1 = t0;
+
+    poly_mul_fft(&mut t1, &b[1], logn);
+    poly_mulconst(&mut t1, -ni, logn);
+    poly_mul_fft(&mut t0, &b[3], logn);
+    poly_mulconst(&mut t0, ni, logn);
+
+    (t0, t1)
+}
+
+/// Maps the sample `z = (z0, z1)` back to the lattice point `z * B`, sets `s2` and checks that the
+/// signature vector `(s1, s2) = (hm, 0) - z * B` is short.
+///
+/// # Parameters
+/// - `z0`, `z1`: The sample, in FFT representation.
+/// - `b`: The secret basis, in FFT representation.
+/// - `hm`: The hashed message.
+/// - `s2`: Output, the second half of the signature vector (two's complement coefficients).
+///
+/// # Returns
+/// `true` if `(s1, s2)` is within the acceptance bound, `false` if the attempt must be restarted.
+fn sign_finish<F: Fpr>(
+    z0: &[F; N],
+    z1: &[F; N],
+    b: &[[F; N]; 4],
+    hm: &[u16; N],
+    s2: &mut [u16; N],
+) -> bool {
+    let logn = LOGN as u32;
+    let [b00, b01, b10, b11] = b;
+
+    // lattice point (tx, ty) = z * B
+    let mut tx = *z0;
+    let mut ty = *z1;
+    let mut t1 = *z1;
+
+    poly_mul_fft(&mut tx, b00, logn);
+    poly_mul_fft(&mut ty, b10, logn);
+    poly_add(&mut tx, &ty, logn);
+
+    ty = *z0;
+    poly_mul_fft(&mut ty, b01, logn);
+    poly_mul_fft(&mut t1, b11, logn);
+    poly_add(&mut t1, &ty, logn);
+
+    ifft(&mut tx, logn);
+    ifft(&mut t1, logn);
+
+    // s1 = hm - tx, s2 = -ty; the squared norm saturates to u32::MAX on overflow.
+    let mut sqn: u32 = 0;
+    let mut ng: u32 = 0;
+
+    for i in 0..N {
+        let z = hm[i] as i32 - tx[i].rint() as i32;
+
+        sqn = sqn.wrapping_add(z.wrapping_mul(z) as u32);
+        ng |= sqn;
+    }
+
+    for i in 0..N {
+        s2[i] = (t1[i].rint() as i32).wrapping_neg() as u16;
+
+        let z = sign_extend_u16_to_u32(s2[i]);
+
+        sqn = sqn.wrapping_add(z.wrapping_mul(z));
+        ng |= sqn;
+    }
+
+    sqn |= 0 - (ng >> 0x1f);
+
+    sqn <= SIG_L2_BOUND
+}
+
+/// One signing attempt with the "dynamic" tree: the Falcon tree is recomputed from the Gram
+/// matrix as the sampler walks it.
+///
+/// # Parameters
+/// - `prng`: The randomness source of the integer sampler.
+/// - `sk`: The private key.
+/// - `hm`: The hashed message (`hash_to_point` of `nonce || msg`).
+/// - `s2`: Output, the second half of the signature vector (two's complement coefficients).
+///
+/// # Returns
+/// `true` if `(s1, s2)` is within the acceptance bound, `false` if the attempt must be restarted.
+fn sign_dyn<F: Fpr>(
+    prng: &mut Shake256Prng,
+    sk: &SecretKey,
+    hm: &[u16; N],
+    s2: &mut [u16; N],
+) -> bool {
+    let b = basis_fft::<F>(sk);
+    let [mut g00, mut g01, mut g11] = gram_fft(&b);
+    let (mut t0, mut t1) = sign_target(hm, &b);
+    let mut tmp = [F::from_f64(0.0); 4 * N];
+
+    ff_sampling_dyn(
+        prng,
+        &mut t0,
+        &mut t1,
+        &mut g00,
+        &mut g01,
+        &mut g11,
+        LOGN as u32,
+        F::from_f64(SIGN_INV_SIGMA),
+        F::from_f64(SIGN_SIGMA_MIN),
+        &mut tmp,
+    );
+
+    sign_finish(&t0, &t1, &b, hm, s2)
+}
+
+/// Draws the nonce, hashes `nonce || msg` and runs signing attempts until one yields a short and
+/// encodable `s2`; shared by [`sign_with`] and [`sign_tree`].
+///
+/// # Parameters
+/// - `msg`: The message to sign.
+/// - `rng`: The randomness source (nonce and sampler seed).
+/// - `attempt`: One signing attempt, as (sampler randomness, `hm`, output `s2`) -> accepted.
+fn sign_loop(
+    msg: &[u8],
+    rng: &mut Shake256Prng,
+    mut attempt: impl FnMut(&mut Shake256Prng, &[u16; N], &mut [u16; N]) -> bool,
+) -> (
+    [u8; NONCE_LEN as usize],
+    [u8; SIG_COMP_MAXSIZE as usize],
+    usize,
+) {
+    let mut nonce = [0u8; NONCE_LEN as usize];
+
+    rng.fill_bytes(&mut nonce);
+
+    let mut shake_ctx = [0u64; 26];
+
+    shake_inject_nonce_msg(&mut shake_ctx, &nonce, msg);
+    shake_flip(&mut shake_ctx);
+
+    let extracted = shake_extract(&mut shake_ctx);
+    let mut hm = [0u16; N];
+    let mut tt1 = [0u16; N];
+
+    hash_to_point_ct(&extracted, &mut hm, &mut tt1);
+
+    let mut seed = [0u8; SIGN_SEED_LEN];
+
+    rng.fill_bytes(&mut seed);
+
+    let mut prng = Shake256Prng::from_seed(&seed);
+    let mut s2 = [0u16; N];
+    let mut sig = [0u8; SIG_COMP_MAXSIZE as usize];
+
+    loop {
+        if attempt(&mut prng, &hm, &mut s2) {
+            let len = comp_encode(&s2, &mut sig);
+
+            if len != 0 {
+                return (nonce, sig, len);
+            }
+        }
+    }
+}
+
+/// Signs a message with a Falcon-512 private key.
+///
+/// Draws a fresh 40-byte nonce and a sampler seed from `rng`, hashes `nonce || msg` to a point
+/// with the constant-time [`hash_to_point_ct`] (the hashed message is secret-adjacent on this
+/// path), then samples short `(s1, s2)` with `s1 + s2 * h = hm mod q` until `s2` is short enough
+/// and fits the compressed encoding.
+///
+/// # Parameters
+/// - `sk`: The private key.
+/// - `msg`: The message to sign.
+/// - `rng`: The randomness source (nonce and sampler seed).
+///
+/// # Returns
+/// A tuple containing:
+///   - The nonce.
+///   - The compressed `s2`, in a `SIG_COMP_MAXSIZE` buffer.
+///   - The length of the compressed `s2`.
+///
+/// `verify(nonce || msg, &sig[..len], pk)` accepts the result for the matching public key.
+///
+/// The floating-point computations run on [`SignFpr`] (see [`sign_with`] to pick the backend).
+pub fn sign(
+    sk: &SecretKey,
+    msg: &[u8],
+    rng: &mut Shake256Prng,
+) -> (
+    [u8; NONCE_LEN as usize],
+    [u8; SIG_COMP_MAXSIZE as usize],
+    usize,
+) {
+    sign_with::<SignFpr>(sk, msg, rng)
+}
+
+/// [`sign`] on an explicit floating-point backend.
+///
+/// All backends are correctly rounded binary64, so for the same key, message and `rng` seed,
+/// `sign_with::<f64>` and `sign_with::<FprEmu>` return the same signature.
+///
+/// [`FprEmu`]: crate::fpr::FprEmu
+pub fn sign_with<F: Fpr>(
+    sk: &SecretKey,
+    msg: &[u8],
+    rng: &mut Shake256Prng,
+) -> (
+    [u8; NONCE_LEN as usize],
+    [u8; SIG_COMP_MAXSIZE as usize],
+    usize,
+) {
+    sign_loop(msg, rng, |prng, hm, s2| sign_dyn::<F>(prng, sk, hm, s2))
+}
+
+/// Expands a private key for [`sign_tree`], on [`SignFpr`] (see [`expand_secret_key_with`] to pick
+/// the backend).
+pub fn expand_secret_key(sk: &SecretKey) -> ExpandedSecretKey {
+    expand_secret_key_with::<SignFpr>(sk)
+}
+
+/// [`expand_secret_key`] on an explicit floating-point backend.
+pub fn expand_secret_key_with<F: Fpr>(sk: &SecretKey) -> ExpandedSecretKey<F> {
+    let basis = basis_fft::<F>(sk);
+    let [g00, g01, g11] = gram_fft(&basis);
+    let mut tree = [F::from_f64(0.0); FFLDL_TREE_SIZE];
+    let mut tmp = [F::from_f64(0.0); 3 * N];
+
+    ffldl_fft(&mut tree, &g00, &g01, &g11, LOGN as u32, &mut tmp);
+    ffldl_binary_normalize(&mut tree, LOGN as u32, F::from_f64(SIGN_INV_SIGMA));
+
+    ExpandedSecretKey { basis, tree }
+}
+
+/// Signs a message with an expanded private key.
+///
+/// Same as [`sign`] (same nonce, hashing, sampling and output), but the basis and the Falcon tree
+/// are read from `esk` instead of being recomputed: for the same key and `rng` seed, `sign_tree`
+/// and [`sign_with`] on the same backend return the same signature.
+pub fn sign_tree<F: Fpr>(
+    esk: &ExpandedSecretKey<F>,
+    msg: &[u8],
+    rng: &mut Shake256Prng,
+) -> (
+    [u8; NONCE_LEN as usize],
+    [u8; SIG_COMP_MAXSIZE as usize],
+    usize,
+) {
+    let sigma_min = F::from_f64(SIGN_SIGMA_MIN);
+    let mut z0 = [F::from_f64(0.0); N];
+    let mut z1 = [F::from_f64(0.0); N];
+    let mut tmp = [F::from_f64(0.0); 2 * N];
+
+    sign_loop(msg, rng, |prng, hm, s2| {
+        let (t0, t1) = sign_target(hm, &esk.basis);
+
+        ff_sampling_tree(
+            prng,
+            &mut z0,
+            &mut z1,
+            &esk.tree,
+            &t0,
+            &t1,
+            LOGN as u32,
+            sigma_min,
+            &mut tmp,
+        );
+
+        sign_finish(&z0, &z1, &esk.basis, hm, s2)
+    })
+}
+
+/// Serializes an expanded private key: a header byte `0x60 | LOGN`, then `b00`, `b01`, `b10`,
+/// `b11` and the tree, each value as its binary64 bit pattern (little-endian).
+///
+/// The encoding is specific to this crate and holds the secret basis: protect it like the private
+/// key itself.
+pub fn expanded_sk_encode<F: Fpr>(esk: &ExpandedSecretKey<F>) -> [u8; FALCON_EXPANDED_SK_SIZE] {
+    let mut out = [0u8; FALCON_EXPANDED_SK_SIZE];
+
+    out[0] = 0x60 + LOGN;
+
+    let values = esk.basis.iter().flatten().chain(esk.tree.iter());
+
+    for (chunk, x) in out[1..].chunks_exact_mut(8).zip(values) {
+        chunk.copy_from_slice(&x.to_bits().to_le_bytes());
+    }
+
+    out
+}
+
+/// Deserializes an expanded private key produced by [`expanded_sk_encode`].
+///
+/// # Returns
+/// The expanded private key, or [`Error::InvalidSecretKey`] if the header is not `0x60 | LOGN`, a
+/// value is not finite, or a leaf of the tree is not positive.
+pub fn expanded_sk_decode<F: Fpr>(
+    esk: &[u8; FALCON_EXPANDED_SK_SIZE],
+) -> Result<ExpandedSecretKey<F>, Error> {
+    if esk[0] != 0x60 + LOGN {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    let mut out = ExpandedSecretKey {
+        basis: [[F::from_f64(0.0); N]; 4],
+        tree: [F::from_f64(0.0); FFLDL_TREE_SIZE],
+    };
+    let values = out.basis.iter_mut().flatten().chain(out.tree.iter_mut());
+
+    for (chunk, x) in esk[1..].chunks_exact(8).zip(values) {
+        let v = f64::from_bits(u64::from_le_bytes(chunk.try_into().unwrap()));
+
+        if !v.is_finite() {
+            return Err(Error::InvalidSecretKey);
+        }
+
+        *x = F::from_f64(v);
+    }
+
+    if !tree_leaves_positive(&out.tree, LOGN as u32) {
+        return Err(Error::InvalidSecretKey);
+    }
+
+    Ok(out)
+}
+
+/// Checks that every leaf (sampler inverse standard deviation) of a normalized tree is positive.
+fn tree_leaves_positive<F: Fpr>(tree: &[F], logn: u32) -> bool {
+    if logn == 0 {
+        let bits = tree[0].to_bits();
+
+        return bits >> 63 == 0 && bits != 0;
+    }
+
+    let n = 1usize << logn;
+    let (tree0, tree1) = tree[n..].split_at(ffldl_treesize(logn - 1));
+
+    tree_leaves_positive(tree0, logn - 1) && tree_leaves_positive(tree1, logn - 1)
+}
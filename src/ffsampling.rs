@@ -0,0 +1,340 @@
+//! Fast Fourier sampling (`ffSampling`) over the Falcon tree, for signing.
+//!
+//! Given a target `(t0, t1)` and the Gram matrix `G = B * B*` of the secret basis (all in FFT
+//! representation), samples `z` such that `(t - z) * B` is short. Two variants:
+//! - [`ff_sampling_dyn`]: the LDL* tree of `G` is not kept. Each level decomposes its 2x2 block,
+//!   splits the diagonal into the two half-size sub-matrices and recurses - the "dynamic tree"
+//!   variant of the Falcon reference, which trades some recomputation per signature for not having
+//!   to store (or expand) the tree.
+//! - [`ff_sampling_tree`]: walks a tree computed once by [`ffldl_fft`] and
+//!   [`ffldl_binary_normalize`], for signing many messages with the same key.
+//!
+//! Both variants perform the same operations in the same order, so they draw the same samples
+//! from the same randomness.
+
+use crate::{
+    fft::{
+        poly_add, poly_ldl_fft, poly_ldlmv_fft, poly_merge_fft, poly_mul_fft, poly_split_fft,
+        poly_sub,
+    },
+    fpr::Fpr,
+    sampler::sampler_z,
+    shake256::Shake256Prng,
+};
+
+/// Samples `(z0, z1)` for the target `(t0, t1)` and the Gram matrix `[[g00, g01], [g01*, g11]]`.
+///
+/// # Parameters
+/// - `prng`: The randomness source of the integer sampler.
+/// - `t0`, `t1`: The target, `2^logn` values each (FFT representation); replaced by the sample.
+/// - `g00`, `g01`, `g11`: The Gram matrix, `2^logn` values each (FFT representation); used as
+///   scratch space (destroyed).
+/// - `logn`: Log2 of the current degree.
+/// - `inv_sigma`: `1 / sigma`, the inverse of the signing standard deviation.
+/// - `sigma_min`: The smallest standard deviation of a leaf.
+/// - `tmp`: Scratch space of at least `4 * 2^logn` values.
+#[allow(clippy::too_many_arguments)]
+pub fn ff_sampling_dyn<F: Fpr>(
+    prng: &mut Shake256Prng,
+    t0: &mut [F],
+    t1: &mut [F],
+    g00: &mut [F],
+    g01: &mut [F],
+    g11: &mut [F],
+    logn: u32,
+    inv_sigma: F,
+    sigma_min: F,
+    tmp: &mut [F],
+) {
+    if logn == 0 {
+        let leaf = g00[0].sqrt() * inv_sigma;
+
+        t0[0] = F::of(sampler_z(prng, t0[0], leaf, sigma_min) as i64);
+        t1[0] = F::of(sampler_z(prng, t1[0], leaf, sigma_min) as i64);
+
+        return;
+    }
+
+    let n = 1usize << logn;
+    let hn = n >> 1;
+
+    // LDL* in place: g00 = d00, g01 = l10, g11 = d11.
+    poly_ldl_fft(g00, g01, g11, logn);
+
+    // Split d00 and d11 into the half-size Gram matrices of the two sub-trees, and keep l10 in
+    // tmp[0..n]:
+    //   - left sub-tree: g00[0..hn], g00[hn..n], g01[0..hn]
+    //   - right sub-tree: g11[0..hn], g11[hn..n], g01[hn..n]
+    let (l10, tmp) = tmp.split_at_mut(n);
+
+    {
+        let (a, b) = l10.split_at_mut(hn);
+
+        poly_split_fft(a, b, g00, logn);
+        g00[..hn].copy_from_slice(a);
+        g00[hn..n].copy_from_slice(b);
+        poly_split_fft(a, b, g11, logn);
+        g11[..hn].copy_from_slice(a);
+        g11[hn..n].copy_from_slice(b);
+    }
+
+    l10.copy_from_slice(&g01[..n]);
+    g01[..hn].copy_from_slice(&g00[..hn]);
+    g01[hn..n].copy_from_slice(&g11[..hn]);
+
+    // Right sub-tree first, on the split t1; the merged z1 lands in the
+    // scratch space right after it.
+    {
+        let (z1, tmp) = tmp.split_at_mut(n);
+        let (z1a, z1b) = z1.split_at_mut(hn);
+        let (g11a, g11b) = g11.split_at_mut(hn);
+
+        poly_split_fft(z1a, z1b, t1, logn);
+        ff_sampling_dyn(
+            prng,
+            z1a,
+            z1b,
+            g11a,
+            g11b,
+            &mut g01[hn..n],
+            logn - 1,
+            inv_sigma,
+            sigma_min,
+            tmp,
+        );
+        poly_merge_fft(tmp, z1a, z1b, logn);
+
+        // tb0 = t0 + (t1 - z1) * l10
+        z1.copy_from_slice(&t1[..n]);
+        poly_sub(z1, tmp, logn);
+        t1[..n].copy_from_slice(&tmp[..n]);
+        poly_mul_fft(l10, z1, logn);
+        poly_add(t0, l10, logn);
+    }
+
+    // Left sub-tree, on the split tb0.
+    let (z0a, z0b) = l10.split_at_mut(hn);
+    let (g00a, g00b) = g00.split_at_mut(hn);
+
+    poly_split_fft(z0a, z0b, t0, logn);
+    ff_sampling_dyn(
+        prng,
+        z0a,
+        z0b,
+        g00a,
+        g00b,
+        &mut g01[..hn],
+        logn - 1,
+        inv_sigma,
+        sigma_min,
+        tmp,
+    );
+    poly_merge_fft(t0, z0a, z0b, logn);
+}
+
+/// Returns the number of values in the Falcon tree of degree `2^logn`: `(logn + 1) * 2^logn`.
+///
+/// A tree of degree `n > 1` is `l10` (`n` values) followed by the left sub-tree and the right
+/// sub-tree (degree `n / 2` each); a leaf is a single value.
+pub const fn ffldl_treesize(logn: u32) -> usize {
+    ((logn + 1) as usize) << logn
+}
+
+/// Computes the Falcon tree of the Gram matrix `[[g00, g01], [g01*, g11]]` (FFT representation,
+/// `2^logn` values each). The leaves hold the diagonal of the final decomposition; see
+/// [`ffldl_binary_normalize`].
+///
+/// # Parameters
+/// - `tree`: Output, `ffldl_treesize(logn)` values.
+/// - `g00`, `g01`, `g11`: The Gram matrix (not modified).
+/// - `logn`: Log2 of the degree.
+/// - `tmp`: Scratch space of at least `3 * 2^logn` values.
+pub fn ffldl_fft<F: Fpr>(
+    tree: &mut [F],
+    g00: &[F],
+    g01: &[F],
+    g11: &[F],
+    logn: u32,
+    tmp: &mut [F],
+) {
+    if logn == 0 {
+        tree[0] = g00[0];
+
+        return;
+    }
+
+    let n = 1usize << logn;
+    let hn = n >> 1;
+    let (d00, tmp) = tmp.split_at_mut(n);
+    let (d11, tmp) = tmp.split_at_mut(n);
+
+    d00.copy_from_slice(&g00[..n]);
+    poly_ldlmv_fft(d11, &mut tree[..n], g00, g01, g11, logn);
+
+    {
+        let (a, b) = tmp[..n].split_at_mut(hn);
+
+        poly_split_fft(a, b, d00, logn);
+    }
+
+    {
+        let (a, b) = d00.split_at_mut(hn);
+
+        poly_split_fft(a, b, d11, logn);
+    }
+
+    d11.copy_from_slice(&tmp[..n]);
+
+    // left sub-tree on split(d00), right sub-tree on split(d11)
+    let (tree0, tree1) = tree[n..].split_at_mut(ffldl_treesize(logn - 1));
+    let (a, b) = d11.split_at_mut(hn);
+
+    ffldl_fft_inner(tree0, a, b, logn - 1, tmp);
+
+    let (a, b) = d00.split_at_mut(hn);
+
+    ffldl_fft_inner(tree1, a, b, logn - 1, tmp);
+}
+
+/// [`ffldl_fft`] for the quasi-cyclic Gram matrix `[[g0, g1], [g1*, g0]]` of a sub-tree.
+///
+/// `g0` and `g1` (`2^logn` values each) are destroyed; `tmp` needs at least `2^logn` values.
+fn ffldl_fft_inner<F: Fpr>(tree: &mut [F], g0: &mut [F], g1: &mut [F], logn: u32, tmp: &mut [F]) {
+    if logn == 0 {
+        tree[0] = g0[0];
+
+        return;
+    }
+
+    let n = 1usize << logn;
+    let hn = n >> 1;
+
+    // d00 = g0 is split into g1, d11 (in tmp) into g0
+    poly_ldlmv_fft(tmp, &mut tree[..n], g0, g1, g0, logn);
+
+    {
+        let (a, b) = g1.split_at_mut(hn);
+
+        poly_split_fft(a, b, g0, logn);
+    }
+
+    {
+        let (a, b) = g0.split_at_mut(hn);
+
+        poly_split_fft(a, b, tmp, logn);
+    }
+
+    let (tree0, tree1) = tree[n..].split_at_mut(ffldl_treesize(logn - 1));
+    let (a, b) = g1.split_at_mut(hn);
+
+    ffldl_fft_inner(tree0, a, b, logn - 1, tmp);
+
+    let (a, b) = g0.split_at_mut(hn);
+
+    ffldl_fft_inner(tree1, a, b, logn - 1, tmp);
+}
+
+/// Replaces each leaf `x` of a tree computed by [`ffldl_fft`] with the standard deviation of its
+/// sampler, `sqrt(x) * inv_sigma`.
+pub fn ffldl_binary_normalize<F: Fpr>(tree: &mut [F], logn: u32, inv_sigma: F) {
+    if logn == 0 {
+        tree[0] = tree[0].sqrt() * inv_sigma;
+
+        return;
+    }
+
+    let n = 1usize << logn;
+    let (tree0, tree1) = tree[n..].split_at_mut(ffldl_treesize(logn - 1));
+
+    ffldl_binary_normalize(tree0, logn - 1, inv_sigma);
+    ffldl_binary_normalize(tree1, logn - 1, inv_sigma);
+}
+
+/// Samples `(z0, z1)` for the target `(t0, t1)`, walking a precomputed (normalized) Falcon tree.
+///
+/// # Parameters
+/// - `prng`: The randomness source of the integer sampler.
+/// - `z0`, `z1`: Output, the sample, `2^logn` values each (FFT representation).
+/// - `tree`: The normalized Falcon tree ([`ffldl_fft`] then [`ffldl_binary_normalize`]).
+/// - `t0`, `t1`: The target, `2^logn` values each (FFT representation).
+/// - `logn`: Log2 of the current degree.
+/// - `sigma_min`: The smallest standard deviation of a leaf.
+/// - `tmp`: Scratch space of at least `2 * 2^logn` values.
+#[allow(clippy::too_many_arguments)]
+pub fn ff_sampling_tree<F: Fpr>(
+    prng: &mut Shake256Prng,
+    z0: &mut [F],
+    z1: &mut [F],
+    tree: &[F],
+    t0: &[F],
+    t1: &[F],
+    logn: u32,
+    sigma_min: F,
+    tmp: &mut [F],
+) {
+    if logn == 0 {
+        let leaf = tree[0];
+
+        z0[0] = F::of(sampler_z(prng, t0[0], leaf, sigma_min) as i64);
+        z1[0] = F::of(sampler_z(prng, t1[0], leaf, sigma_min) as i64);
+
+        return;
+    }
+
+    let n = 1usize << logn;
+    let hn = n >> 1;
+    let (tree0, tree1) = tree[n..].split_at(ffldl_treesize(logn - 1));
+    let (tmp, rest) = tmp.split_at_mut(n);
+
+    // Right sub-tree first, on the split t1.
+    {
+        let (a, b) = z1[..n].split_at_mut(hn);
+
+        poly_split_fft(a, b, t1, logn);
+    }
+
+    {
+        let (a, b) = tmp.split_at_mut(hn);
+
+        ff_sampling_tree(
+            prng,
+            a,
+            b,
+            tree1,
+            &z1[..hn],
+            &z1[hn..n],
+            logn - 1,
+            sigma_min,
+            rest,
+        );
+        poly_merge_fft(z1, a, b, logn);
+    }
+
+    // tb0 = t0 + (t1 - z1) * l10
+    tmp.copy_from_slice(&t1[..n]);
+    poly_sub(tmp, z1, logn);
+    poly_mul_fft(tmp, &tree[..n], logn);
+    poly_add(tmp, t0, logn);
+
+    // Left sub-tree, on the split tb0.
+    {
+        let (a, b) = z0[..n].split_at_mut(hn);
+
+        poly_split_fft(a, b, tmp, logn);
+    }
+
+    let (a, b) = tmp.split_at_mut(hn);
+
+    ff_sampling_tree(
+        prng,
+        a,
+        b,
+        tree0,
+        &z0[..hn],
+        &z0[hn..n],
+        logn - 1,
+        sigma_min,
+        rest,
+    );
+    poly_merge_fft(z0, a, b, logn);
+}
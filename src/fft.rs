@@ -0,0 +1,323 @@
+//! Floating-point FFT over `R[X] / (X^n + 1)`, `n = 2^logn`, used by key generation and signing.
+//!
+//! A real polynomial of degree `< n` is evaluated at the `n/2` roots of `X^n + 1` with positive
+//! imaginary part (the other half are their conjugates and carry no extra information). The FFT
+//! representation is stored in place, in the same `n`-slot array: real parts in `[0, n/2)`,
+//! imaginary parts in `[n/2, n)`, in bit-reversed order - the layout of the Falcon reference, so
+//! that the split/merge steps of the Falcon tree are plain index arithmetic.
+//!
+//! Unlike the NTT (`mq_ntt`), which is fixed to `N`, these routines take `logn` because the NTRU
+//! solver and the fast Fourier sampler recurse over every degree from `N` down to 1. They are
+//! generic over the float backend ([`Fpr`]): key generation runs them on `f64`, signing on
+//! whichever backend `sign` was built with.
+
+use crate::{constants::FFT_GM, fpr::Fpr};
+
+/// Complex multiplication `(a_re + i a_im) * (b_re + i b_im)`.
+#[inline(always)]
+fn fpc_mul<F: Fpr>(a_re: F, a_im: F, b_re: F, b_im: F) -> (F, F) {
+    (a_re * b_re - a_im * b_im, a_re * b_im + a_im * b_re)
+}
+
+/// Complex division `(a_re + i a_im) / (b_re + i b_im)`.
+#[inline(always)]
+fn fpc_div<F: Fpr>(a_re: F, a_im: F, b_re: F, b_im: F) -> (F, F) {
+    let m = F::from_f64(1.0) / (b_re * b_re + b_im * b_im);
+    let b_re = b_re * m;
+    let b_im = -(b_im * m);
+
+    fpc_mul(a_re, a_im, b_re, b_im)
+}
+
+/// Computes the FFT of a real polynomial in-place.
+///
+/// # Parameters
+/// - `f`: The `2^logn` coefficients; replaced by the FFT representation.
+/// - `logn`: Log2 of the degree.
+pub fn fft<F: Fpr>(f: &mut [F], logn: u32) {
+    let n = 1usize << logn;
+    let hn = n >> 1;
+    let mut t = hn;
+    let mut m = 2;
+
+    for _ in 1..logn {
+        let ht = t >> 1;
+        let hm = m >> 1;
+        let mut j1 = 0;
+
+        for i1 in 0..hm {
+            let s_re = F::from_f64(FFT_GM[(m + i1) << 1]);
+            let s_im = F::from_f64(FFT_GM[((m + i1) << 1) + 1]);
+
+            for j in j1..j1 + ht {
+                let x_re = f[j];
+                let x_im = f[j + hn];
+                let (y_re, y_im) = fpc_mul(f[j + ht], f[j + ht + hn], s_re, s_im);
+
+                f[j] = x_re + y_re;
+                f[j + hn] = x_im + y_im;
+                f[j + ht] = x_re - y_re;
+                f[j + ht + hn] = x_im - y_im;
+            }
+
+            j1 += t;
+        }
+
+        t = ht;
+        m <<= 1;
+    }
+}
+
+/// Computes the inverse FFT in-place, going back to the (real) coefficient representation.
+///
+/// # Parameters
+/// - `f`: The FFT representation; replaced by the `2^logn` coefficients.
+/// - `logn`: Log2 of the degree.
+pub fn ifft<F: Fpr>(f: &mut [F], logn: u32) {
+    let n = 1usize << logn;
+    let hn = n >> 1;
+    let mut t = 1;
+    let mut m = n;
+
+    for _ in (2..=logn).rev() {
+        let hm = m >> 1;
+        let dt = t << 1;
+        let mut j1 = 0;
+        let mut i1 = 0;
+
+        while j1 < hn {
+            let s_re = F::from_f64(FFT_GM[(hm + i1) << 1]);
+            let s_im = -F::from_f64(FFT_GM[((hm + i1) << 1) + 1]);
+
+            for j in j1..j1 + t {
+                let x_re = f[j];
+                let x_im = f[j + hn];
+                let y_re = f[j + t];
+                let y_im = f[j + t + hn];
+
+                f[j] = x_re + y_re;
+                f[j + hn] = x_im + y_im;
+
+                let (z_re, z_im) = fpc_mul(x_re - y_re, x_im - y_im, s_re, s_im);
+
+                f[j + t] = z_re;
+                f[j + t + hn] = z_im;
+            }
+
+            i1 += 1;
+            j1 += dt;
+        }
+
+        t = dt;
+        m = hm;
+    }
+
+    // each of the logn - 1 inverse layers doubled the values
+    if logn > 0 {
+        let ni = F::from_f64(2.0 / n as f64);
+
+        for x in f[..n].iter_mut() {
+            *x = *x * ni;
+        }
+    }
+}
+
+/// Adds polynomial `b` to polynomial `a` (either representation); the result is written over `a`.
+pub fn poly_add<F: Fpr>(a: &mut [F], b: &[F], logn: u32) {
+    for (x, y) in a[..1 << logn].iter_mut().zip(b) {
+        *x = *x + *y;
+    }
+}
+
+/// Subtracts polynomial `b` from polynomial `a` (either representation); the result is written
+/// over `a`.
+pub fn poly_sub<F: Fpr>(a: &mut [F], b: &[F], logn: u32) {
+    for (x, y) in a[..1 << logn].iter_mut().zip(b) {
+        *x = *x - *y;
+    }
+}
+
+/// Negates polynomial `a` (either representation) in-place.
+pub fn poly_neg<F: Fpr>(a: &mut [F], logn: u32) {
+    for x in a[..1 << logn].iter_mut() {
+        *x = -*x;
+    }
+}
+
+/// Replaces `a` (FFT representation) with its adjoint `a*` (complex conjugate of every point).
+pub fn poly_adj_fft<F: Fpr>(a: &mut [F], logn: u32) {
+    let n = 1usize << logn;
+
+    for x in a[n >> 1..n].iter_mut() {
+        *x = -*x;
+    }
+}
+
+/// Multiplies `a` by `b` (both in FFT representation); the result is written over `a`.
+pub fn poly_mul_fft<F: Fpr>(a: &mut [F], b: &[F], logn: u32) {
+    let hn = (1usize << logn) >> 1;
+
+    for u in 0..hn {
+        let (re, im) = fpc_mul(a[u], a[u + hn], b[u], b[u + hn]);
+
+        a[u] = re;
+        a[u + hn] = im;
+    }
+}
+
+/// Multiplies `a` by the adjoint of `b` (both in FFT representation); the result is written over
+/// `a`.
+pub fn poly_muladj_fft<F: Fpr>(a: &mut [F], b: &[F], logn: u32) {
+    let hn = (1usize << logn) >> 1;
+
+    for u in 0..hn {
+        let (re, im) = fpc_mul(a[u], a[u + hn], b[u], -b[u + hn]);
+
+        a[u] = re;
+        a[u + hn] = im;
+    }
+}
+
+/// Replaces `a` (FFT representation) with `a * a*`, which is self-adjoint (all imaginary parts
+/// are zero).
+pub fn poly_mulselfadj_fft<F: Fpr>(a: &mut [F], logn: u32) {
+    let hn = (1usize << logn) >> 1;
+
+    for u in 0..hn {
+        let a_re = a[u];
+        let a_im = a[u + hn];
+
+        a[u] = a_re * a_re + a_im * a_im;
+        a[u + hn] = F::from_f64(0.0);
+    }
+}
+
+/// Multiplies `a` (either representation) by the real constant `x`, in-place.
+pub fn poly_mulconst<F: Fpr>(a: &mut [F], x: F, logn: u32) {
+    for v in a[..1 << logn].iter_mut() {
+        *v = *v * x;
+    }
+}
+
+/// Divides `a` by `b` (both in FFT representation); the result is written over `a`.
+pub fn poly_div_fft<F: Fpr>(a: &mut [F], b: &[F], logn: u32) {
+    let hn = (1usize << logn) >> 1;
+
+    for u in 0..hn {
+        let (re, im) = fpc_div(a[u], a[u + hn], b[u], b[u + hn]);
+
+        a[u] = re;
+        a[u + hn] = im;
+    }
+}
+
+/// Divides `a` by the self-adjoint polynomial `b` (FFT representation, only the real halves of
+/// `b` are read); the result is written over `a`.
+pub fn poly_div_autoadj_fft<F: Fpr>(a: &mut [F], b: &[F], logn: u32) {
+    let hn = (1usize << logn) >> 1;
+
+    for u in 0..hn {
+        let ib = F::from_f64(1.0) / b[u];
+
+        a[u] = a[u] * ib;
+        a[u + hn] = a[u + hn] * ib;
+    }
+}
+
+/// Computes the LDL* decomposition of the self-adjoint 2x2 matrix `[[g00, g01], [g01*, g11]]`
+/// (FFT representation), in-place: `g00` is left unchanged (`d00 = g00`), `g01` receives `l10*`
+/// and `g11` receives `d11`.
+pub fn poly_ldl_fft<F: Fpr>(g00: &[F], g01: &mut [F], g11: &mut [F], logn: u32) {
+    let hn = (1usize << logn) >> 1;
+
+    for u in 0..hn {
+        let (mu_re, mu_im) = fpc_div(g01[u], g01[u + hn], g00[u], g00[u + hn]);
+        let (t_re, t_im) = fpc_mul(mu_re, mu_im, g01[u], -g01[u + hn]);
+
+        g11[u] = g11[u] - t_re;
+        g11[u + hn] = g11[u + hn] - t_im;
+        g01[u] = mu_re;
+        g01[u + hn] = -mu_im;
+    }
+}
+
+/// Same as [`poly_ldl_fft`], but writes `d11` and `l10` to separate outputs and leaves the input
+/// matrix unchanged.
+pub fn poly_ldlmv_fft<F: Fpr>(
+    d11: &mut [F],
+    l10: &mut [F],
+    g00: &[F],
+    g01: &[F],
+    g11: &[F],
+    logn: u32,
+) {
+    let hn = (1usize << logn) >> 1;
+
+    for u in 0..hn {
+        let (mu_re, mu_im) = fpc_div(g01[u], g01[u + hn], g00[u], g00[u + hn]);
+        let (t_re, t_im) = fpc_mul(mu_re, mu_im, g01[u], -g01[u + hn]);
+
+        d11[u] = g11[u] - t_re;
+        d11[u + hn] = g11[u + hn] - t_im;
+        l10[u] = mu_re;
+        l10[u + hn] = -mu_im;
+    }
+}
+
+/// Splits `f` (FFT representation, degree `2^logn`) into `f0` and `f1` (FFT representation,
+/// degree `2^(logn - 1)`) such that `f(X) = f0(X^2) + X * f1(X^2)`.
+pub fn poly_split_fft<F: Fpr>(f0: &mut [F], f1: &mut [F], f: &[F], logn: u32) {
+    let hn = (1usize << logn) >> 1;
+    let qn = hn >> 1;
+
+    // logn = 1: a single complex value, the loop below is skipped
+    f0[0] = f[0];
+    f1[0] = f[hn];
+
+    for u in 0..qn {
+        let a_re = f[u << 1];
+        let a_im = f[(u << 1) + hn];
+        let b_re = f[(u << 1) + 1];
+        let b_im = f[(u << 1) + 1 + hn];
+
+        f0[u] = (a_re + b_re).half();
+        f0[u + qn] = (a_im + b_im).half();
+
+        let (t_re, t_im) = fpc_mul(
+            a_re - b_re,
+            a_im - b_im,
+            F::from_f64(FFT_GM[(u + hn) << 1]),
+            -F::from_f64(FFT_GM[((u + hn) << 1) + 1]),
+        );
+
+        f1[u] = t_re.half();
+        f1[u + qn] = t_im.half();
+    }
+}
+
+/// Merges `f0` and `f1` (FFT representation, degree `2^(logn - 1)`) into
+/// `f(X) = f0(X^2) + X * f1(X^2)` (FFT representation, degree `2^logn`); inverse of
+/// [`poly_split_fft`].
+pub fn poly_merge_fft<F: Fpr>(f: &mut [F], f0: &[F], f1: &[F], logn: u32) {
+    let hn = (1usize << logn) >> 1;
+    let qn = hn >> 1;
+
+    f[0] = f0[0];
+    f[hn] = f1[0];
+
+    for u in 0..qn {
+        let a_re = f0[u];
+        let a_im = f0[u + qn];
+        let (b_re, b_im) = fpc_mul(
+            f1[u],
+            f1[u + qn],
+            F::from_f64(FFT_GM[(u + hn) << 1]),
+            F::from_f64(FFT_GM[((u + hn) << 1) + 1]),
+        );
+
+        f[u << 1] = a_re + b_re;
+        f[(u << 1) + hn] = a_im + b_im;
+        f[(u << 1) + 1] = a_re - b_re;
+        f[(u << 1) + 1 + hn] = a_im - b_im;
+    }
+}
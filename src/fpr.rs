@@ -0,0 +1,493 @@
+//! Floating-point backends for signing.
+//!
+//! The signing path (FFT, fast Fourier sampling, `SamplerZ`) is generic over [`Fpr`], with two
+//! implementations:
+//! - `f64`: the native FPU. Fast, but only as portable as the target's floating point.
+//! - [`FprEmu`]: IEEE-754 binary64 emulated over `u64` with integer operations only (port of the
+//!   Falcon reference `fpr.c`). Every operation is constant-time and correctly rounded (round to
+//!   nearest, ties to even), so it produces bit-for-bit the same values as a conforming FPU -
+//!   hence the same signatures - on targets with no (or an untrusted) FPU.
+//!
+//! `sign` uses [`SignFpr`]: the emulated backend with the `fpr-emu` feature or without `std`
+//! (`core` has no `f64::sqrt` / `floor`), `f64` otherwise.
+//!
+//! Subnormals are never produced by Falcon; the emulation flushes them to zero. It may also return
+//! +0 where the FPU returns -0 (e.g. `0 / -x`), which no Falcon computation depends on.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The float type `sign` runs on.
+#[cfg(any(feature = "fpr-emu", not(feature = "std")))]
+pub type SignFpr = FprEmu;
+
+/// The float type `sign` runs on.
+#[cfg(all(not(feature = "fpr-emu"), feature = "std"))]
+pub type SignFpr = f64;
+
+/// The floating-point operations needed by the signing path.
+///
+/// All operations are those of IEEE-754 binary64 with round to nearest, ties to even.
+pub trait Fpr:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Converts an integer (`|i| < 2^63`).
+    fn of(i: i64) -> Self;
+
+    /// Converts a native constant (exact: the binary64 bit pattern is reused).
+    fn from_f64(x: f64) -> Self;
+
+    /// Returns the binary64 bit pattern (inverse of `from_f64(f64::from_bits(..))`).
+    fn to_bits(self) -> u64;
+
+    /// Returns `self / 2`.
+    fn half(self) -> Self;
+
+    /// Returns the square root of `self` (`self >= 0`).
+    fn sqrt(self) -> Self;
+
+    /// Rounds to the nearest integer, ties to even.
+    fn rint(self) -> i64;
+
+    /// Rounds toward minus infinity.
+    fn floor(self) -> i64;
+
+    /// Rounds toward zero.
+    fn trunc(self) -> i64;
+}
+
+#[cfg(feature = "std")]
+impl Fpr for f64 {
+    #[inline(always)]
+    fn of(i: i64) -> Self {
+        i as f64
+    }
+
+    #[inline(always)]
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    #[inline(always)]
+    fn to_bits(self) -> u64 {
+        f64::to_bits(self)
+    }
+
+    #[inline(always)]
+    fn half(self) -> Self {
+        self * 0.5
+    }
+
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    #[inline(always)]
+    fn rint(self) -> i64 {
+        self.round_ties_even() as i64
+    }
+
+    #[inline(always)]
+    fn floor(self) -> i64 {
+        f64::floor(self) as i64
+    }
+
+    #[inline(always)]
+    fn trunc(self) -> i64 {
+        self as i64
+    }
+}
+
+/// An emulated binary64 value (same bit layout as `f64`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FprEmu(pub u64);
+
+/// Constant-time `x >> n`, `0 <= n < 64`.
+#[inline(always)]
+fn ursh(mut x: u64, n: i32) -> u64 {
+    x ^= (x ^ (x >> 32)) & 0u64.wrapping_sub((n >> 5) as u64);
+
+    x >> (n & 31)
+}
+
+/// Constant-time `x >> n` (arithmetic), `0 <= n < 64`.
+#[inline(always)]
+fn irsh(mut x: i64, n: i32) -> i64 {
+    x ^= (x ^ (x >> 32)) & -((n >> 5) as i64);
+
+    x >> (n & 31)
+}
+
+/// Constant-time `x << n`, `0 <= n < 64`.
+#[inline(always)]
+fn ulsh(mut x: u64, n: i32) -> u64 {
+    x ^= (x ^ (x << 32)) & 0u64.wrapping_sub((n >> 5) as u64);
+
+    x << (n & 31)
+}
+
+/// Normalizes `m` so that its top bit is set (unless `m = 0`), adjusting the exponent `e` such
+/// that `m * 2^e` is unchanged, minus 63.
+#[inline(always)]
+fn norm64(m: &mut u64, e: &mut i32) {
+    *e -= 63;
+
+    for k in [32u32, 16, 8, 4, 2, 1] {
+        let mut nt = (*m >> (64 - k)) as u32;
+
+        nt = (nt | nt.wrapping_neg()) >> 31;
+        *m ^= (*m ^ (*m << k)) & (nt as u64).wrapping_sub(1);
+        *e += (nt << k.trailing_zeros()) as i32;
+    }
+}
+
+/// Packs sign `s`, exponent `e` and mantissa `m` (`2^54 <= m < 2^55`, or 0) into the value
+/// `(-1)^s * m * 2^e`, rounding the 2 extra low bits of `m` (the lowest one is sticky).
+/// Values below the normal range are flushed to zero.
+#[inline(always)]
+fn make(s: u32, mut e: i32, mut m: u64) -> FprEmu {
+    e += 1076;
+
+    let t = (e as u32) >> 31;
+
+    m &= (t as u64).wrapping_sub(1);
+
+    let t = (m >> 54) as u32;
+
+    e &= -(t as i32);
+
+    // the top bit of m lands on the exponent field, hence the bias 1076 instead of 1077
+    let mut x = (((s as u64) << 63) | (m >> 2)).wrapping_add(((e as u32) as u64) << 52);
+    let f = (m as u32) & 7;
+
+    x += ((0xc8u32 >> f) & 1) as u64;
+
+    FprEmu(x)
+}
+
+impl FprEmu {
+    /// Returns `i * 2^sc`.
+    fn scaled(i: i64, sc: i32) -> Self {
+        let s = ((i as u64) >> 63) as u32;
+        let i = (i ^ -(s as i64)) + s as i64;
+        let mut m = i as u64;
+        let mut e = 9 + sc;
+
+        norm64(&mut m, &mut e);
+
+        // 2^63..2^64-1 down to 2^54..2^55-1, the dropped bits go to the sticky bit
+        m |= ((m as u32 & 0x1ff) + 0x1ff) as u64;
+        m >>= 9;
+
+        let t = ((i | i.wrapping_neg()) as u64 >> 63) as u32;
+
+        m &= 0u64.wrapping_sub(t as u64);
+        e &= -(t as i32);
+
+        make(s, e, m)
+    }
+}
+
+impl Add for FprEmu {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let (mut x, mut y) = (self.0, other.0);
+
+        // Swap so that |x| >= |y|; on |x| = |y| with opposite signs, x gets the positive one so
+        // that an exact zero comes out as +0.
+        let mut m = (1u64 << 63) - 1;
+        let za = (x & m).wrapping_sub(y & m);
+        let cs = (za >> 63) as u32
+            | ((1u32.wrapping_sub((za.wrapping_neg() >> 63) as u32)) & (x >> 63) as u32);
+
+        m = (x ^ y) & 0u64.wrapping_sub(cs as u64);
+        x ^= m;
+        y ^= m;
+
+        // mantissae scaled to 2^55..2^56-1 (0 for a zero), unbiased exponents
+        let mut ex = (x >> 52) as i32;
+        let sx = (ex >> 11) as u32;
+
+        ex &= 0x7ff;
+        m = (((ex + 0x7ff) >> 11) as u32 as u64) << 52;
+
+        let mut xu = ((x & ((1u64 << 52) - 1)) | m) << 3;
+
+        ex -= 1078;
+
+        let mut ey = (y >> 52) as i32;
+        let sy = (ey >> 11) as u32;
+
+        ey &= 0x7ff;
+        m = (((ey + 0x7ff) >> 11) as u32 as u64) << 52;
+
+        let mut yu = ((y & ((1u64 << 52) - 1)) | m) << 3;
+
+        ey -= 1078;
+
+        // align y on x (clamped to zero beyond 59 bits), the lowest bit is sticky
+        let mut cc = ex - ey;
+
+        yu &= 0u64.wrapping_sub((((cc - 60) as u32) >> 31) as u64);
+        cc &= 63;
+        m = ulsh(1, cc) - 1;
+        yu |= (yu & m).wrapping_add(m);
+        yu = ursh(yu, cc);
+
+        // add or subtract the mantissae
+        xu = xu.wrapping_add(yu.wrapping_sub((yu << 1) & 0u64.wrapping_sub((sx ^ sy) as u64)));
+
+        norm64(&mut xu, &mut ex);
+        xu |= ((xu as u32 & 0x1ff) + 0x1ff) as u64;
+        xu >>= 9;
+        ex += 9;
+
+        make(sx, ex, xu)
+    }
+}
+
+impl Sub for FprEmu {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl Neg for FprEmu {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        FprEmu(self.0 ^ (1u64 << 63))
+    }
+}
+
+impl Mul for FprEmu {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let (x, y) = (self.0, other.0);
+        let xu = (x & ((1u64 << 52) - 1)) | (1u64 << 52);
+        let yu = (y & ((1u64 << 52) - 1)) | (1u64 << 52);
+
+        // 53 x 53 bits with 25-bit low limbs: the two low limbs only feed the sticky bit
+        let x0 = xu as u32 & 0x01ff_ffff;
+        let x1 = (xu >> 25) as u32;
+        let y0 = yu as u32 & 0x01ff_ffff;
+        let y1 = (yu >> 25) as u32;
+        let mut w = x0 as u64 * y0 as u64;
+        let z0 = w as u32 & 0x01ff_ffff;
+        let mut z1 = (w >> 25) as u32;
+
+        w = x0 as u64 * y1 as u64;
+        z1 += w as u32 & 0x01ff_ffff;
+
+        let mut z2 = (w >> 25) as u32;
+
+        w = x1 as u64 * y0 as u64;
+        z1 += w as u32 & 0x01ff_ffff;
+        z2 += (w >> 25) as u32;
+
+        let mut zu = x1 as u64 * y1 as u64;
+
+        z2 += z1 >> 25;
+        z1 &= 0x01ff_ffff;
+        zu += z2 as u64;
+
+        // product in 2^104..2^106-1, kept as 2^54..2^56-1 with a sticky bit, then normalized
+        zu |= (((z0 | z1) + 0x01ff_ffff) >> 25) as u64;
+
+        let zv = (zu >> 1) | (zu & 1);
+        let w = zu >> 55;
+
+        zu ^= (zu ^ zv) & 0u64.wrapping_sub(w);
+
+        // 2 * (1023 + 52) of biases, minus the 50 (or 51) bits shifted out
+        let ex = ((x >> 52) & 0x7ff) as i32;
+        let ey = ((y >> 52) & 0x7ff) as i32;
+        let e = ex + ey - 2100 + w as i32;
+        let s = ((x ^ y) >> 63) as u32;
+
+        // zero operand
+        let d = ((ex + 0x7ff) & (ey + 0x7ff)) >> 11;
+
+        zu &= 0u64.wrapping_sub(d as u64);
+
+        make(s, e, zu)
+    }
+}
+
+impl Div for FprEmu {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let (x, y) = (self.0, other.0);
+        let mut xu = (x & ((1u64 << 52) - 1)) | (1u64 << 52);
+        let yu = (y & ((1u64 << 52) - 1)) | (1u64 << 52);
+
+        // bit-by-bit division, 55 quotient bits
+        let mut q: u64 = 0;
+
+        for _ in 0..55 {
+            let b = (xu.wrapping_sub(yu) >> 63).wrapping_sub(1);
+
+            xu -= b & yu;
+            q |= b & 1;
+            xu <<= 1;
+            q <<= 1;
+        }
+
+        // the 56th bit is sticky, then normalize to 2^54..2^55-1
+        q |= (xu | xu.wrapping_neg()) >> 63;
+
+        let q2 = (q >> 1) | (q & 1);
+        let w = q >> 55;
+
+        q ^= (q ^ q2) & 0u64.wrapping_sub(w);
+
+        let ex = ((x >> 52) & 0x7ff) as i32;
+        let ey = ((y >> 52) & 0x7ff) as i32;
+        let mut e = ex - ey - 55 + w as i32;
+        let mut s = ((x ^ y) >> 63) as u32;
+
+        // x = 0 (division by zero is not supported)
+        let d = (ex + 0x7ff) >> 11;
+
+        s &= d as u32;
+        e &= -d;
+        q &= 0u64.wrapping_sub(d as u64);
+
+        make(s, e, q)
+    }
+}
+
+impl Fpr for FprEmu {
+    #[inline(always)]
+    fn of(i: i64) -> Self {
+        Self::scaled(i, 0)
+    }
+
+    #[inline(always)]
+    fn from_f64(x: f64) -> Self {
+        FprEmu(x.to_bits())
+    }
+
+    #[inline(always)]
+    fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    #[inline(always)]
+    fn half(self) -> Self {
+        let mut x = self.0.wrapping_sub(1u64 << 52);
+        let t = ((((x >> 52) as u32) & 0x7ff) + 1) >> 11;
+
+        x &= (t as u64).wrapping_sub(1);
+
+        FprEmu(x)
+    }
+
+    fn sqrt(self) -> Self {
+        let x = self.0;
+        let mut xu = (x & ((1u64 << 52) - 1)) | (1u64 << 52);
+        let ex = ((x >> 52) & 0x7ff) as i32;
+        let mut e = ex - 1023;
+
+        // odd exponent: double the mantissa; then halve the exponent
+        xu += xu & 0u64.wrapping_sub((e & 1) as u64);
+        e >>= 1;
+        xu <<= 1;
+
+        // xu in 2^53..2^55-1 (1 to 4 with 53 fractional bits), square root bit by bit
+        let mut q: u64 = 0;
+        let mut s: u64 = 0;
+        let mut r: u64 = 1u64 << 53;
+
+        for _ in 0..54 {
+            let t = s + r;
+            let b = (xu.wrapping_sub(t) >> 63).wrapping_sub(1);
+
+            s += (r << 1) & b;
+            xu -= t & b;
+            q += r & b;
+            xu <<= 1;
+            r >>= 1;
+        }
+
+        // guard bit, then sticky bit
+        q <<= 1;
+        q |= (xu | xu.wrapping_neg()) >> 63;
+        e -= 54;
+
+        // zero operand
+        q &= 0u64.wrapping_sub(((ex + 0x7ff) >> 11) as u64);
+
+        make(0, e, q)
+    }
+
+    fn rint(self) -> i64 {
+        let x = self.0;
+        let mut m = ((x << 10) | (1u64 << 62)) & ((1u64 << 63) - 1);
+        let mut e = 1085 - ((x >> 52) as i32 & 0x7ff);
+
+        // shift of 64 bits or more (including a zero operand): m = 0
+        m &= 0u64.wrapping_sub((((e - 64) as u32) >> 31) as u64);
+        e &= 63;
+
+        // dropped bits and the lowest kept bit, shrunk to 3 bits (the lowest one sticky)
+        let d = ulsh(m, 63 - e);
+        let dd = d as u32 | ((d >> 32) as u32 & 0x1fff_ffff);
+        let f = (d >> 61) as u32 | ((dd | dd.wrapping_neg()) >> 31);
+
+        m = ursh(m, e) + ((0xc8u32 >> f) & 1) as u64;
+
+        let s = (x >> 63) as i64;
+
+        ((m as i64) ^ -s) + s
+    }
+
+    fn floor(self) -> i64 {
+        let x = self.0;
+        let e = (x >> 52) as i32 & 0x7ff;
+        let t = (x >> 63) as i64;
+        let mut xi = (((x << 10) | (1u64 << 62)) & ((1u64 << 63) - 1)) as i64;
+
+        xi = (xi ^ -t) + t;
+
+        // arithmetic shift: floor semantics for both signs
+        let cc = 1085 - e;
+
+        xi = irsh(xi, cc & 63);
+
+        // shift of 64 bits or more: 0 or -1
+        xi ^= (xi ^ -t) & -((((63 - cc) as u32) >> 31) as i64);
+
+        xi
+    }
+
+    fn trunc(self) -> i64 {
+        let x = self.0;
+        let e = (x >> 52) as i32 & 0x7ff;
+        let mut xu = ((x << 10) | (1u64 << 62)) & ((1u64 << 63) - 1);
+        let cc = 1085 - e;
+
+        xu = ursh(xu, cc & 63);
+
+        // shift of 64 bits or more (including a zero operand): 0
+        xu &= 0u64.wrapping_sub((((cc - 64) as u32) >> 31) as u64);
+
+        let t = x >> 63;
+
+        xu = (xu ^ t.wrapping_neg()).wrapping_add(t);
+
+        xu as i64
+    }
+}
@@ -1,13 +1,79 @@
+//! Falcon-512 / Falcon-1024 signatures.
+//!
+//! The crate is `#![no_std]` without allocation by default: verification, key and signature
+//! encoding, signing on the emulated floating point ([`fpr::FprEmu`]) and the SHAKE256 core only
+//! use `core`. The `std` feature adds what needs the standard library: key generation (multi-
+//! precision arithmetic on the heap), signing on the native `f64`, and the `std::error::Error`
+//! impls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
 pub mod constants;
+mod der;
+pub mod error;
+pub mod falcon1024;
 pub mod falcon512;
+pub mod ffsampling;
+pub mod fft;
+pub mod fpr;
+#[cfg(feature = "std")]
+pub mod ntru;
+pub mod params;
+pub mod pkix;
+pub mod sampler;
+pub mod sha3;
 pub mod shake256;
+pub mod sp800_185;
+pub mod stream;
 pub mod utils;
+pub mod x509;
+
+#[cfg(feature = "capi")]
+mod capi;
+
+#[cfg(feature = "signature")]
+mod rustcrypto;
+
+#[cfg(feature = "python")]
+mod python;
+
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "signature")]
+pub use signature;
 
 #[cfg(any(test, feature = "bench"))]
 pub mod tests {
+    pub mod capi_tests;
+    pub mod expanded_key_tests;
+    pub mod falcon1024_tests_0;
     pub mod falcon512_fuzz_tests;
+    pub mod falcon512_tests_0;
+    pub mod fft_tests;
+    pub mod fpr_tests;
     pub mod fuzz_capture;
     pub mod hash_to_point_ab;
-    pub mod falcon512_tests_0;
+    pub mod keygen_tests;
+    pub mod no_std_tests;
+    pub mod params_tests;
+    pub mod pkix_tests;
+    pub mod python_tests;
+    pub mod rustcrypto_tests;
+    pub mod serde_tests;
+    pub mod sha3_tests;
+    pub mod shake256_tests;
+    pub mod sig_format_tests;
+    pub mod sign_tests;
+    pub mod sk_codec_tests;
+    pub mod sp800_185_tests;
+    pub mod stream_tests;
     pub mod test_utils;
+    pub mod typed_api_tests;
+    pub mod verify_report_tests;
+    pub mod wasm_tests;
+    pub mod x509_tests;
 }
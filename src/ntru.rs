@@ -0,0 +1,729 @@
+//! Falcon key generation internals: sampling `(f, g)` and solving the NTRU equation.
+//!
+//! The solver follows the recursive approach of the Falcon specification (and of its Python
+//! reference): the field norms of `f` and `g` are taken down to degree 1, where an extended GCD
+//! gives `(F, G)` with `fG - gF = q`; each level then lifts the solution back up
+//! (`F = F'(x^2) * g(-x)`) and size-reduces it against `(f, g)` with Babai's round-off, computed in
+//! floating point on the top 53 bits of every coefficient.
+//!
+//! Intermediate coefficients grow to thousands of bits, so polynomials are held as [`ZPoly`]:
+//! every coefficient is a fixed-width two's complement big integer of `words` 32-bit limbs,
+//! sized from the actual bit lengths at each level. Working modulo `2^(32 * words)` keeps
+//! additions and subtractions branch-free; widths are always chosen so the true values fit.
+
+use crate::{
+    constants::{
+        FG_SAMPLES, FG_SIGMA_STAR, GS_NORM_BOUND, LOGN, N, Q, SK_COEFF_MAX, SK_FG_COEFF_MAX,
+    },
+    falcon512::mq_ntt,
+    fft::{
+        fft, ifft, poly_add, poly_adj_fft, poly_div_autoadj_fft, poly_muladj_fft,
+        poly_mulselfadj_fft,
+    },
+    sampler::sampler_z,
+    shake256::Shake256Prng,
+};
+
+/// A polynomial of `n` big-integer coefficients, each `words` limbs of two's complement
+/// (little-endian limbs), stored coefficient after coefficient.
+struct ZPoly {
+    n: usize,
+    words: usize,
+    data: Vec<u32>,
+}
+
+impl ZPoly {
+    /// A zero polynomial.
+    fn zero(n: usize, words: usize) -> Self {
+        Self {
+            n,
+            words,
+            data: vec![0; n * words],
+        }
+    }
+
+    /// Builds a polynomial from small signed coefficients.
+    fn from_i64(x: &[i64], words: usize) -> Self {
+        let mut p = Self::zero(x.len(), words.max(2));
+
+        for (i, &v) in x.iter().enumerate() {
+            let c = p.coeff_mut(i);
+            let fill = if v < 0 { u32::MAX } else { 0 };
+
+            c[0] = v as u32;
+            c[1] = (v >> 32) as u32;
+            c[2..].fill(fill);
+        }
+
+        p
+    }
+
+    #[inline(always)]
+    fn coeff(&self, i: usize) -> &[u32] {
+        &self.data[i * self.words..(i + 1) * self.words]
+    }
+
+    #[inline(always)]
+    fn coeff_mut(&mut self, i: usize) -> &mut [u32] {
+        &mut self.data[i * self.words..(i + 1) * self.words]
+    }
+
+    /// Largest bit length of `|c|` over all coefficients.
+    fn max_bits(&self) -> usize {
+        (0..self.n)
+            .map(|i| zint_bits(self.coeff(i)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Re-encodes every coefficient on `words` limbs (sign extension or truncation).
+    fn resize(&self, words: usize) -> Self {
+        let mut p = Self::zero(self.n, words);
+
+        for i in 0..self.n {
+            let src = self.coeff(i);
+            let fill = zint_fill(src);
+
+            for (k, w) in p.coeff_mut(i).iter_mut().enumerate() {
+                *w = if k < src.len() { src[k] } else { fill };
+            }
+        }
+
+        p
+    }
+
+    /// Shrinks the limb count to the smallest that holds every coefficient.
+    fn shrink(&self) -> Self {
+        self.resize(words_for_bits(self.max_bits()))
+    }
+
+    /// Coefficients divided by `2^sh` (truncated to about 64 significant bits), as floats.
+    fn to_f64_shr(&self, sh: usize) -> Vec<f64> {
+        (0..self.n)
+            .map(|i| zint_to_f64_shr(self.coeff(i), sh))
+            .collect()
+    }
+}
+
+/// Limb count needed to hold a signed value of `bits` magnitude bits (plus the sign bit).
+#[inline(always)]
+fn words_for_bits(bits: usize) -> usize {
+    (bits + 1).div_ceil(32).max(1)
+}
+
+/// Sign-extension limb of a two's complement value.
+#[inline(always)]
+fn zint_fill(x: &[u32]) -> u32 {
+    0u32.wrapping_sub(x[x.len() - 1] >> 31)
+}
+
+/// Bit length of `|x|` (of `-x - 1` for negative `x`, which is the same up to a power of 2).
+fn zint_bits(x: &[u32]) -> usize {
+    let fill = zint_fill(x);
+
+    for k in (0..x.len()).rev() {
+        let w = x[k] ^ fill;
+
+        if w != 0 {
+            return (k << 5) + 32 - w.leading_zeros() as usize;
+        }
+    }
+
+    0
+}
+
+/// Magnitude and sign of a two's complement value.
+fn zint_abs(x: &[u32], out: &mut [u32]) -> bool {
+    let neg = zint_fill(x) != 0;
+    let mut cc = neg as u32;
+
+    for (o, &w) in out.iter_mut().zip(x) {
+        let w = if neg { !w } else { w };
+        let (r, c) = w.overflowing_add(cc);
+
+        *o = r;
+        cc = c as u32;
+    }
+
+    neg
+}
+
+/// Negates `x` in-place (two's complement).
+fn zint_neg(x: &mut [u32]) {
+    let mut cc = 1u32;
+
+    for w in x.iter_mut() {
+        let (r, c) = (!*w).overflowing_add(cc);
+
+        *w = r;
+        cc = c as u32;
+    }
+}
+
+/// `x` divided by `2^sh`, as a float (about 64 significant bits are kept, then rounded).
+fn zint_to_f64_shr(x: &[u32], sh: usize) -> f64 {
+    let mut m = vec![0u32; x.len()];
+    let neg = zint_abs(x, &mut m);
+    let (sw, sb) = (sh >> 5, sh & 31);
+    let mut acc: u128 = 0;
+
+    // gather bits [sh, sh + 96) of the magnitude
+    for k in 0..4 {
+        let w = m.get(sw + k).copied().unwrap_or(0) as u128;
+
+        acc |= w << (k << 5);
+    }
+
+    let v = ((acc >> sb) as u64) as f64;
+
+    if neg {
+        -v
+    } else {
+        v
+    }
+}
+
+/// `acc += a * b` (or `acc -= a * b` if `neg`), with `a` and `b` unsigned magnitudes and `acc`
+/// a two's complement value; computed modulo `2^(32 * acc.len())`.
+fn zint_add_mul(acc: &mut [u32], a: &[u32], b: &[u32], neg: bool) {
+    let len = acc.len();
+
+    for (i, &ai) in a.iter().enumerate() {
+        if ai == 0 || i >= len {
+            continue;
+        }
+
+        let mut cc: u64 = 0;
+        let mut k = i;
+
+        for &bj in b {
+            if k == len {
+                break;
+            }
+
+            let p = ai as u64 * bj as u64 + cc;
+            let (r, c) = if neg {
+                acc[k].overflowing_sub(p as u32)
+            } else {
+                acc[k].overflowing_add(p as u32)
+            };
+
+            acc[k] = r;
+            cc = (p >> 32) + c as u64;
+            k += 1;
+        }
+
+        while cc != 0 && k != len {
+            let t = if neg {
+                (acc[k] as i64) - cc as i64
+            } else {
+                (acc[k] as i64) + cc as i64
+            };
+
+            acc[k] = t as u32;
+            cc = (t >> 32).unsigned_abs();
+            k += 1;
+        }
+    }
+}
+
+/// `x -= y << sh`, with `y` sign-extended; computed modulo `2^(32 * x.len())`.
+fn zint_sub_shifted(x: &mut [u32], y: &[u32], sh: usize) {
+    let fill = zint_fill(y);
+    let (sw, sb) = (sh >> 5, (sh & 31) as u32);
+    let word = |k: isize| -> u32 {
+        if k < 0 {
+            0
+        } else {
+            y.get(k as usize).copied().unwrap_or(fill)
+        }
+    };
+    let mut cc = 0u32;
+
+    for (k, w) in x.iter_mut().enumerate() {
+        let k = k as isize - sw as isize;
+        let mut s = word(k) << sb;
+
+        if sb != 0 {
+            s |= word(k - 1) >> (32 - sb);
+        }
+
+        let (r, c1) = w.overflowing_sub(s);
+        let (r, c2) = r.overflowing_sub(cc);
+
+        *w = r;
+        cc = (c1 | c2) as u32;
+    }
+}
+
+/// `out += a * b mod (X^n + 1)`.
+fn zpoly_mul_acc(out: &mut ZPoly, a: &ZPoly, b: &ZPoly) {
+    let n = out.n;
+    let mut am = vec![0u32; a.data.len()];
+    let mut bm = vec![0u32; b.data.len()];
+    let mut asg = vec![false; n];
+    let mut bsg = vec![false; n];
+
+    for i in 0..n {
+        asg[i] = zint_abs(a.coeff(i), &mut am[i * a.words..(i + 1) * a.words]);
+        bsg[i] = zint_abs(b.coeff(i), &mut bm[i * b.words..(i + 1) * b.words]);
+    }
+
+    let mut ab = Vec::with_capacity(n);
+    let mut bb = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let x = &am[i * a.words..(i + 1) * a.words];
+        let y = &bm[i * b.words..(i + 1) * b.words];
+
+        ab.push(x.iter().rposition(|&w| w != 0).map(|l| &x[..=l]));
+        bb.push(y.iter().rposition(|&w| w != 0).map(|l| &y[..=l]));
+    }
+
+    for i in 0..n {
+        let Some(x) = ab[i] else { continue };
+
+        for j in 0..n {
+            let Some(y) = bb[j] else { continue };
+            let mut k = i + j;
+            let mut neg = asg[i] ^ bsg[j];
+
+            // X^n = -1
+            if k >= n {
+                k -= n;
+                neg = !neg;
+            }
+
+            zint_add_mul(out.coeff_mut(k), x, y, neg);
+        }
+    }
+}
+
+/// `a * b mod (X^n + 1)`, on `words` limbs.
+fn zpoly_mul(a: &ZPoly, b: &ZPoly, words: usize) -> ZPoly {
+    let mut out = ZPoly::zero(a.n, words);
+
+    zpoly_mul_acc(&mut out, a, b);
+
+    out
+}
+
+/// Field norm `N(f) = f0^2 - x * f1^2` over `Z[x] / (x^(n/2) + 1)`, where
+/// `f(x) = f0(x^2) + x * f1(x^2)`.
+fn field_norm(f: &ZPoly) -> ZPoly {
+    let hn = f.n >> 1;
+    let mut f0 = ZPoly::zero(hn, f.words);
+    let mut f1 = ZPoly::zero(hn, f.words);
+
+    for i in 0..hn {
+        f0.coeff_mut(i).copy_from_slice(f.coeff(i << 1));
+        f1.coeff_mut(i).copy_from_slice(f.coeff((i << 1) + 1));
+    }
+
+    // |coefficients| <= 2 * (n/2) * max|f|^2
+    let words = words_for_bits(2 * f.max_bits() + f.n.trailing_zeros() as usize + 1);
+    let mut r = zpoly_mul(&f0, &f0, words);
+    let f1sq = zpoly_mul(&f1, &f1, words);
+
+    for i in 0..hn {
+        let t = f1sq.coeff((i + hn - 1) % hn);
+        let rc = r.coeff_mut(i);
+
+        if i == 0 {
+            // x * x^(n/2 - 1) = -1
+            let mut nt = t.to_vec();
+
+            zint_neg(&mut nt);
+            zint_sub_shifted(rc, &nt, 0);
+        } else {
+            zint_sub_shifted(rc, t, 0);
+        }
+    }
+
+    r.shrink()
+}
+
+/// `f(x) -> f(x^2)`: degree `n` to degree `2n`.
+fn lift(f: &ZPoly) -> ZPoly {
+    let mut r = ZPoly::zero(f.n << 1, f.words);
+
+    for i in 0..f.n {
+        r.coeff_mut(i << 1).copy_from_slice(f.coeff(i));
+    }
+
+    r
+}
+
+/// `f(x) -> f(-x)`.
+fn galois_conjugate(f: &ZPoly) -> ZPoly {
+    let mut r = f.resize(f.words);
+
+    for i in (1..f.n).step_by(2) {
+        zint_neg(r.coeff_mut(i));
+    }
+
+    r
+}
+
+/// Constant-free binary extended GCD (HAC 14.61) on positive two's complement values.
+///
+/// # Returns
+/// `(d, a, b)` with `a * x + b * y = d = gcd(x, y)`, or `None` if `x` and `y` are both even.
+fn zint_xgcd(x: &[u32], y: &[u32]) -> Option<(Vec<u32>, Vec<u32>, Vec<u32>)> {
+    let len = x.len() + 1;
+    let ext = |v: &[u32]| {
+        let mut r = v.to_vec();
+
+        r.resize(len, 0);
+        r
+    };
+    let is_even = |v: &[u32]| v[0] & 1 == 0;
+    let is_zero = |v: &[u32]| v.iter().all(|&w| w == 0);
+    let add = |a: &mut [u32], b: &[u32]| {
+        let mut cc = 0u32;
+
+        for (w, &v) in a.iter_mut().zip(b) {
+            let (r, c1) = w.overflowing_add(v);
+            let (r, c2) = r.overflowing_add(cc);
+
+            *w = r;
+            cc = (c1 | c2) as u32;
+        }
+    };
+    let sub = |a: &mut [u32], b: &[u32]| zint_sub_shifted(a, b, 0);
+    let half = |a: &mut [u32]| {
+        let fill = zint_fill(a);
+
+        for k in 0..a.len() {
+            let hi = if k + 1 < a.len() { a[k + 1] } else { fill };
+
+            a[k] = (a[k] >> 1) | (hi << 31);
+        }
+    };
+    // unsigned comparison u >= v (both non-negative)
+    let geq = |a: &[u32], b: &[u32]| {
+        for k in (0..a.len()).rev() {
+            if a[k] != b[k] {
+                return a[k] > b[k];
+            }
+        }
+
+        true
+    };
+
+    let (x, y) = (ext(x), ext(y));
+
+    if is_zero(&x) || is_zero(&y) || (is_even(&x) && is_even(&y)) {
+        return None;
+    }
+
+    let (mut u, mut v) = (x.clone(), y.clone());
+    let (mut a, mut b, mut c, mut d) = (ext(&[1]), ext(&[0]), ext(&[0]), ext(&[1]));
+
+    loop {
+        while is_even(&u) {
+            half(&mut u);
+
+            if !(is_even(&a) && is_even(&b)) {
+                add(&mut a, &y);
+                sub(&mut b, &x);
+            }
+
+            half(&mut a);
+            half(&mut b);
+        }
+
+        while is_even(&v) {
+            half(&mut v);
+
+            if !(is_even(&c) && is_even(&d)) {
+                add(&mut c, &y);
+                sub(&mut d, &x);
+            }
+
+            half(&mut c);
+            half(&mut d);
+        }
+
+        if geq(&u, &v) {
+            sub(&mut u, &v);
+            sub(&mut a, &c);
+            sub(&mut b, &d);
+        } else {
+            sub(&mut v, &u);
+            sub(&mut c, &a);
+            sub(&mut d, &b);
+        }
+
+        if is_zero(&u) {
+            return Some((v, c, d));
+        }
+    }
+}
+
+/// Degree-1 NTRU equation: `F, G` with `f * G - g * F = q`, or `None` if `gcd(f, g) != 1`.
+fn solve_deepest(f: &ZPoly, g: &ZPoly) -> Option<(ZPoly, ZPoly)> {
+    let mut fm = vec![0u32; f.words];
+    let mut gm = vec![0u32; g.words];
+    let words = f.words.max(g.words);
+    let fneg = zint_abs(&f.resize(words).data, &mut fm);
+    let gneg = zint_abs(&g.resize(words).data, &mut gm);
+
+    fm.resize(words, 0);
+    gm.resize(words, 0);
+
+    let (d, mut u, mut v) = zint_xgcd(&fm, &gm)?;
+
+    if d[0] != 1 || d[1..].iter().any(|&w| w != 0) {
+        return None;
+    }
+
+    // u * |f| + v * |g| = 1  =>  (+-u) * f + (+-v) * g = 1
+    if fneg {
+        zint_neg(&mut u);
+    }
+    if !gneg {
+        zint_neg(&mut v);
+    }
+
+    // F = -q * v, G = q * u
+    let words = u.len() + 1;
+    let qm = [Q as u32];
+    let mut big_f = ZPoly::zero(1, words);
+    let mut big_g = ZPoly::zero(1, words);
+    let mut um = vec![0u32; u.len()];
+    let mut vm = vec![0u32; v.len()];
+    let usg = zint_abs(&u, &mut um);
+    let vsg = zint_abs(&v, &mut vm);
+
+    zint_add_mul(big_f.coeff_mut(0), &vm, &qm, vsg);
+    zint_add_mul(big_g.coeff_mut(0), &um, &qm, usg);
+
+    Some((big_f.shrink(), big_g.shrink()))
+}
+
+/// Babai round-off of `(F, G)` against `(f, g)`: repeatedly subtracts `k * (f, g)` with
+/// `k = round((F f* + G g*) / (f f* + g g*))`, computed in floating point on the top 53 bits.
+/// `f G - g F` is left unchanged.
+fn reduce(f: &ZPoly, g: &ZPoly, big_f: &mut ZPoly, big_g: &mut ZPoly) {
+    let n = f.n;
+    let logn = n.trailing_zeros();
+    let size = f.max_bits().max(g.max_bits()).max(53);
+    let mut fa = f.to_f64_shr(size - 53);
+    let mut ga = g.to_f64_shr(size - 53);
+
+    fft(&mut fa, logn);
+    fft(&mut ga, logn);
+
+    let mut den = fa.clone();
+    let mut t = ga.clone();
+
+    poly_mulselfadj_fft(&mut den, logn);
+    poly_mulselfadj_fft(&mut t, logn);
+    poly_add(&mut den, &t, logn);
+
+    let mut last_size = usize::MAX;
+
+    loop {
+        let big_size = big_f.max_bits().max(big_g.max_bits()).max(53);
+
+        // stop once (F, G) is no longer than (f, g), or when a round made no progress
+        if big_size < size || big_size >= last_size {
+            break;
+        }
+
+        last_size = big_size;
+
+        let mut k = big_f.to_f64_shr(big_size - 53);
+        let mut gt = big_g.to_f64_shr(big_size - 53);
+
+        fft(&mut k, logn);
+        fft(&mut gt, logn);
+        poly_muladj_fft(&mut k, &fa, logn);
+        poly_muladj_fft(&mut gt, &ga, logn);
+        poly_add(&mut k, &gt, logn);
+        poly_div_autoadj_fft(&mut k, &den, logn);
+        ifft(&mut k, logn);
+
+        let k: Vec<i64> = k.iter().map(|v| v.round() as i64).collect();
+
+        if k.iter().all(|&v| v == 0) {
+            break;
+        }
+
+        let kp = ZPoly::from_i64(&k, 2);
+        let sh = big_size - size;
+        let fk = zpoly_mul(f, &kp, f.words + 3);
+        let gk = zpoly_mul(g, &kp, g.words + 3);
+
+        for i in 0..n {
+            zint_sub_shifted(big_f.coeff_mut(i), fk.coeff(i), sh);
+            zint_sub_shifted(big_g.coeff_mut(i), gk.coeff(i), sh);
+        }
+    }
+}
+
+/// Solves `f * G - g * F = q mod (X^n + 1)` recursively.
+fn solve(f: &ZPoly, g: &ZPoly) -> Option<(ZPoly, ZPoly)> {
+    if f.n == 1 {
+        return solve_deepest(f, g);
+    }
+
+    let (fp, gp) = solve(&field_norm(f), &field_norm(g))?;
+    let fp = lift(&fp);
+    let gp = lift(&gp);
+    let fc = galois_conjugate(f);
+    let gc = galois_conjugate(g);
+
+    // |F| <= n * max|F'| * max|g|, plus headroom for the reduction's shifted subtractions
+    let logn = f.n.trailing_zeros() as usize;
+    let bits = fp.max_bits().max(gp.max_bits()) + f.max_bits().max(g.max_bits()) + logn + 2;
+    let words = words_for_bits(bits);
+    let mut big_f = zpoly_mul(&fp, &gc, words);
+    let mut big_g = zpoly_mul(&gp, &fc, words);
+
+    reduce(f, g, &mut big_f, &mut big_g);
+
+    Some((big_f.shrink(), big_g.shrink()))
+}
+
+/// Samples a polynomial with coefficients following `D_{Z, sigma_fg}`: each coefficient sums
+/// `4096 / N` draws of standard deviation [`FG_SIGMA_STAR`].
+fn gen_poly(prng: &mut Shake256Prng) -> [i32; N] {
+    let mut out = [0i32; N];
+    let isigma = 1.0 / FG_SIGMA_STAR;
+    let sigma_min = FG_SIGMA_STAR - 0.001;
+
+    for c in out.iter_mut() {
+        for _ in 0..FG_SAMPLES / N {
+            *c += sampler_z(prng, 0.0, isigma, sigma_min);
+        }
+    }
+
+    out
+}
+
+/// Squared Gram-Schmidt norm of the NTRU basis generated by `(f, g)`: the larger of
+/// `||(g, -f)||^2` and `||(q f* / (f f* + g g*), q g* / (f f* + g g*))||^2`.
+fn gs_norm(f: &[i32; N], g: &[i32; N]) -> f64 {
+    let logn = LOGN as u32;
+    let sqnorm_fg: f64 = f.iter().chain(g).map(|&x| (x * x) as f64).sum();
+    let mut ft = [0f64; N];
+    let mut gt = [0f64; N];
+    let mut den = [0f64; N];
+    let mut t = [0f64; N];
+
+    for i in 0..N {
+        ft[i] = g[i] as f64;
+        gt[i] = f[i] as f64;
+    }
+
+    fft(&mut ft, logn);
+    fft(&mut gt, logn);
+    den.copy_from_slice(&ft);
+    t.copy_from_slice(&gt);
+    poly_mulselfadj_fft(&mut den, logn);
+    poly_mulselfadj_fft(&mut t, logn);
+    poly_add(&mut den, &t, logn);
+
+    // Ft = adj(g) / (f f* + g g*), Gt = adj(f) / (f f* + g g*)
+    poly_adj_fft(&mut ft, logn);
+    poly_adj_fft(&mut gt, logn);
+    poly_div_autoadj_fft(&mut ft, &den, logn);
+    poly_div_autoadj_fft(&mut gt, &den, logn);
+    ifft(&mut ft, logn);
+    ifft(&mut gt, logn);
+
+    let q = Q as f64;
+    let sqnorm_big: f64 = q * q * ft.iter().chain(gt.iter()).map(|x| x * x).sum::<f64>();
+
+    sqnorm_fg.max(sqnorm_big)
+}
+
+/// Returns `true` if `f` is invertible modulo `(q, X^N + 1)`, i.e. has no zero NTT coefficient.
+fn is_invertible_mod_q(f: &[i32; N]) -> bool {
+    let mut fq = [0u16; N];
+
+    for i in 0..N {
+        fq[i] = f[i].rem_euclid(Q as i32) as u16;
+    }
+
+    mq_ntt(&mut fq);
+
+    fq.iter().all(|&x| x % Q != 0)
+}
+
+/// Returns `true` if `f * G - g * F = q` holds exactly over `Z[X] / (X^N + 1)`.
+pub fn check_ntru_equation(f: &[i32; N], g: &[i32; N], big_f: &[i32; N], big_g: &[i32; N]) -> bool {
+    let mut r = [0i64; N];
+
+    for i in 0..N {
+        for j in 0..N {
+            let v = f[i] as i64 * big_g[j] as i64 - g[i] as i64 * big_f[j] as i64;
+
+            if i + j < N {
+                r[i + j] += v;
+            } else {
+                r[i + j - N] -= v;
+            }
+        }
+    }
+
+    r[0] == Q as i64 && r[1..].iter().all(|&v| v == 0)
+}
+
+/// Generates an NTRU basis `(f, g, F, G)` with `f * G - g * F = q mod (X^N + 1)`.
+///
+/// `f` and `g` are resampled until the basis has a short enough Gram-Schmidt norm, `f` is
+/// invertible modulo `q`, the NTRU equation is solvable and all four fit the secret key encoding.
+///
+/// # Parameters
+/// - `prng`: The randomness source.
+///
+/// # Returns
+/// `(f, g, F, G)`, with coefficients in `[-31, 31]` for `f` and `g`, `[-127, 127]` for `F` and `G`.
+pub fn ntru_gen(prng: &mut Shake256Prng) -> ([i32; N], [i32; N], [i32; N], [i32; N]) {
+    loop {
+        let f = gen_poly(prng);
+        let g = gen_poly(prng);
+
+        if f.iter().chain(&g).any(|x| x.abs() > SK_FG_COEFF_MAX) {
+            continue;
+        }
+
+        if gs_norm(&f, &g) > GS_NORM_BOUND || !is_invertible_mod_q(&f) {
+            continue;
+        }
+
+        let to_zpoly = |x: &[i32; N]| {
+            let v: Vec<i64> = x.iter().map(|&c| c as i64).collect();
+
+            ZPoly::from_i64(&v, 1).shrink()
+        };
+        let Some((zf, zg)) = solve(&to_zpoly(&f), &to_zpoly(&g)) else {
+            continue;
+        };
+
+        // (F, G) must have been reduced all the way down to small coefficients
+        if zf.max_bits() > 7 || zg.max_bits() > 7 {
+            continue;
+        }
+
+        let (zf, zg) = (zf.resize(1), zg.resize(1));
+        let mut big_f = [0i32; N];
+        let mut big_g = [0i32; N];
+
+        for i in 0..N {
+            big_f[i] = zf.coeff(i)[0] as i32;
+            big_g[i] = zg.coeff(i)[0] as i32;
+        }
+
+        if big_f.iter().chain(&big_g).any(|x| x.abs() > SK_COEFF_MAX)
+            || !check_ntru_equation(&f, &g, &big_f, &big_g)
+        {
+            continue;
+        }
+
+        return (f, g, big_f, big_g);
+    }
+}
@@ -0,0 +1,137 @@
+//! Falcon parameter sets.
+//!
+//! The verification pipeline (`mq_ntt`, `comp_decode`, `hash_to_point_vartime`, `is_short`,
+//! `verify`, ...) is generic over a parameter set `P: FalconParams<N>`, with the degree `N` as a
+//! const generic array size: `verify_with::<Falcon512, 512>` is the Falcon-512 `verify`, and the
+//! same code runs at Falcon-1024 or at the toy degrees `2..=256` of [`Toy`].
+//!
+//! The degree is a parameter of the trait (rather than an associated constant) so that arrays can
+//! be sized by it on stable Rust; each parameter set only implements `FalconParams` for its own
+//! degree, so a mismatched `N` does not compile.
+
+use crate::{
+    constants::{
+        falcon1024, FALCON_PK_SIZE, GMB, IGMB, LOGN, SHAKE_VARTIME_WORDS, SIG_COMP_MAXSIZE,
+        SIG_CT_BITS, SIG_CT_SIZE, SIG_L2_BOUND, SIG_PADDED_SIZE,
+    },
+    shake256::shake_extract_blocks,
+};
+
+/// A Falcon parameter set of degree `N = 2^LOGN`.
+pub trait FalconParams<const N: usize> {
+    /// Log2 of the degree `N` (also the public key header).
+    const LOGN: u8;
+
+    /// Size of a serialized public key: a header byte, then 14 bits per coefficient.
+    const PK_SIZE: usize;
+
+    /// Maximum size of a compressed `s2`, without the header byte and the nonce.
+    const SIG_COMP_MAXSIZE: usize;
+
+    /// Total size of a padded signature (header, nonce and zero-padded compressed `s2`).
+    const SIG_PADDED_SIZE: usize;
+
+    /// Total size of a constant-time signature (header, nonce and fixed-width `s2`).
+    const SIG_CT_SIZE: usize;
+
+    /// Bits per `s2` coefficient in the constant-time format.
+    const SIG_CT_BITS: u32;
+
+    /// Acceptance bound on the squared norm of a signature vector `(s1, s2)`.
+    const SIG_L2_BOUND: u32;
+
+    /// Twiddle factors of the NTT (Montgomery representation, bit-reversed order), at least `N`
+    /// entries.
+    const GMB: &'static [u16] = &GMB;
+
+    /// Twiddle factors of the inverse NTT, at least `N` entries.
+    const IGMB: &'static [u16] = &IGMB;
+
+    /// The SHAKE256 output buffer of the variable-time hash-to-point: enough full rate blocks to
+    /// accept `N` coefficients with an overwhelming margin.
+    type Squeeze: SqueezeBuffer;
+}
+
+/// A fixed-size SHAKE256 output buffer (`[u64; W]`, `W` a multiple of the 17-word rate).
+pub trait SqueezeBuffer: AsRef<[u64]> {
+    /// Squeezes `W / 17` rate blocks from a flipped SHAKE256 context.
+    fn squeeze(shake_ctx: &mut [u64; 26]) -> Self;
+}
+
+impl<const W: usize> SqueezeBuffer for [u64; W] {
+    #[inline(always)]
+    fn squeeze(shake_ctx: &mut [u64; 26]) -> Self {
+        shake_extract_blocks(shake_ctx)
+    }
+}
+
+/// Falcon-512 (NIST level 1).
+pub struct Falcon512;
+
+impl FalconParams<512> for Falcon512 {
+    const LOGN: u8 = LOGN;
+    const PK_SIZE: usize = FALCON_PK_SIZE;
+    const SIG_COMP_MAXSIZE: usize = SIG_COMP_MAXSIZE as usize;
+    const SIG_PADDED_SIZE: usize = SIG_PADDED_SIZE;
+    const SIG_CT_SIZE: usize = SIG_CT_SIZE;
+    const SIG_CT_BITS: u32 = SIG_CT_BITS;
+    const SIG_L2_BOUND: u32 = SIG_L2_BOUND;
+
+    type Squeeze = [u64; SHAKE_VARTIME_WORDS];
+}
+
+/// Falcon-1024 (NIST level 5).
+pub struct Falcon1024;
+
+impl FalconParams<1024> for Falcon1024 {
+    const LOGN: u8 = falcon1024::LOGN;
+    const PK_SIZE: usize = falcon1024::FALCON_PK_SIZE;
+    const SIG_COMP_MAXSIZE: usize = falcon1024::SIG_COMP_MAXSIZE as usize;
+    const SIG_PADDED_SIZE: usize = falcon1024::SIG_PADDED_SIZE;
+    const SIG_CT_SIZE: usize = falcon1024::SIG_CT_SIZE;
+    const SIG_CT_BITS: u32 = SIG_CT_BITS;
+    const SIG_L2_BOUND: u32 = falcon1024::SIG_L2_BOUND;
+
+    type Squeeze = [u64; falcon1024::SHAKE_VARTIME_WORDS];
+}
+
+/// Acceptance bounds of the reference implementation, indexed by `logn` (`1..=10`).
+const L2_BOUNDS: [u32; 11] = [
+    0, 101498, 208714, 428865, 892039, 1852696, 3842630, 7959734, 16468416, 34034726, 70265242,
+];
+
+/// Toy parameter sets of degree `N` in `2..=256` (a power of two), for fast tests and
+/// experiments: no security, the reference sizes and bounds for `logn = log2(N)`.
+///
+/// Using an unsupported `N` is a compile-time error (when `LOGN` is evaluated).
+pub struct Toy<const N: usize>;
+
+impl<const N: usize> FalconParams<N> for Toy<N> {
+    const LOGN: u8 = {
+        assert!(
+            N.is_power_of_two() && N >= 2 && N <= 256,
+            "toy degree must be 2..=256"
+        );
+
+        N.trailing_zeros() as u8
+    };
+    const PK_SIZE: usize = 1 + ((N * 14 + 7) >> 3);
+    const SIG_COMP_MAXSIZE: usize = ((11 << Self::LOGN) + (101 >> (10 - Self::LOGN)) + 7) >> 3;
+    const SIG_PADDED_SIZE: usize = {
+        let s = 10 - Self::LOGN;
+
+        44 + 3 * (256 >> s) + 2 * (128 >> s) + 3 * (64 >> s) + 2 * (16 >> s)
+            - 2 * (2 >> s)
+            - 8 * (1 >> s)
+    };
+    const SIG_CT_SIZE: usize = 41 + ((((Self::SIG_CT_BITS as usize) << Self::LOGN) + 7) >> 3);
+    const SIG_CT_BITS: u32 = match Self::LOGN {
+        1 => 10,
+        2 | 3 => 11,
+        _ => 12,
+    };
+    const SIG_L2_BOUND: u32 = L2_BOUNDS[Self::LOGN as usize];
+
+    // 9 rate blocks (612 draws) for at most 256 coefficients
+    type Squeeze = [u64; SHAKE_VARTIME_WORDS];
+}
@@ -0,0 +1,305 @@
+//! Falcon-512 keys in SubjectPublicKeyInfo (public, RFC 5280) and PKCS#8 (private, RFC 5958)
+//! containers, as DER or PEM.
+//!
+//! The layout is the one of the Open Quantum Safe provider for OpenSSL (oqs-provider), so keys
+//! can be exchanged with OpenSSL-based tooling:
+//!
+//! ```text
+//! SubjectPublicKeyInfo ::= SEQUENCE {
+//!     algorithm         SEQUENCE { OBJECT IDENTIFIER },     -- no parameters
+//!     subjectPublicKey  BIT STRING                          -- the 897-byte public key
+//! }
+//! PrivateKeyInfo ::= SEQUENCE {
+//!     version           INTEGER (0),
+//!     algorithm         SEQUENCE { OBJECT IDENTIFIER },
+//!     privateKey        OCTET STRING { OCTET STRING }       -- sk (1281 bytes) || pk (897 bytes)
+//! }
+//! ```
+//!
+//! NIST has not assigned FN-DSA object identifiers yet (FIPS 206 is a draft); the encoders use the
+//! OQS arc identifier [`FALCON512_OID`] (`1.3.9999.3.11`), and the decoders also accept the older
+//! OQS identifier [`FALCON512_LEGACY_OID`] (`1.3.9999.3.6`). The decoders tolerate parameters
+//! encoded as `NULL`, a private key without the inner `OCTET STRING` or without the trailing
+//! public key, and PKCS#8 attributes (up to [`PKCS8_ATTRIBUTES_MAX_LEN`] bytes); a trailing
+//! public key must match the private key.
+//!
+//! Everything works on fixed-size buffers (`no_std`, no allocation); PEM output is written into a
+//! caller buffer of [`SPKI_PEM_SIZE`] / [`PKCS8_PEM_SIZE`] bytes, and the PEM decoders hold
+//! anything the DER decoders accept ([`SPKI_DER_MAX_SIZE`] / [`PKCS8_DER_MAX_SIZE`] bytes).
+
+use crate::{
+    constants::{FALCON_PK_SIZE, FALCON_SK_SIZE},
+    der::{
+        pem_decode, pem_encode, pem_len, tlv_len, Reader, Writer, TAG_BIT_STRING, TAG_INTEGER,
+        TAG_OCTET_STRING, TAG_OID, TAG_SEQUENCE,
+    },
+    error::Error,
+    falcon512::{pk_from_sk, sk_decode, sk_encode, PublicKey, SecretKey},
+};
+
+/// `1.3.9999.3.11`: Falcon-512 in the OQS arc (oqs-provider), DER content octets.
+pub const FALCON512_OID: &[u8] = &[0x2b, 0xce, 0x0f, 0x03, 0x0b];
+
+/// `1.3.9999.3.6`: Falcon-512 in older oqs-provider releases, DER content octets (decoding only).
+pub const FALCON512_LEGACY_OID: &[u8] = &[0x2b, 0xce, 0x0f, 0x03, 0x06];
+
+/// PEM label of a SubjectPublicKeyInfo.
+pub const SPKI_PEM_LABEL: &str = "PUBLIC KEY";
+
+/// PEM label of a PKCS#8 PrivateKeyInfo.
+pub const PKCS8_PEM_LABEL: &str = "PRIVATE KEY";
+
+const ALG_ID_LEN: usize = tlv_len(tlv_len(FALCON512_OID.len()));
+const SPKI_BITS_LEN: usize = 1 + FALCON_PK_SIZE;
+const PKCS8_KEY_LEN: usize = FALCON_SK_SIZE + FALCON_PK_SIZE;
+
+const SPKI_CONTENT_LEN: usize = ALG_ID_LEN + tlv_len(SPKI_BITS_LEN);
+const PKCS8_CONTENT_LEN: usize = tlv_len(1) + ALG_ID_LEN + tlv_len(tlv_len(PKCS8_KEY_LEN));
+
+/// Size of a DER SubjectPublicKeyInfo from [`pk_to_spki_der`].
+pub const SPKI_DER_SIZE: usize = tlv_len(SPKI_CONTENT_LEN);
+
+/// Size of a DER PKCS#8 PrivateKeyInfo from [`sk_to_pkcs8_der`].
+pub const PKCS8_DER_SIZE: usize = tlv_len(PKCS8_CONTENT_LEN);
+
+/// Largest PKCS#8 attributes (content of the `[0]` field) accepted by [`sk_from_pkcs8_der`].
+pub const PKCS8_ATTRIBUTES_MAX_LEN: usize = 1024;
+
+// the largest accepted AlgorithmIdentifier: the longer identifier, with NULL parameters
+const OID_MAX_LEN: usize = if FALCON512_LEGACY_OID.len() > FALCON512_OID.len() {
+    FALCON512_LEGACY_OID.len()
+} else {
+    FALCON512_OID.len()
+};
+const ALG_ID_MAX_LEN: usize = tlv_len(tlv_len(OID_MAX_LEN) + tlv_len(0));
+
+/// Size of the largest DER SubjectPublicKeyInfo accepted by [`pk_from_spki_der`].
+pub const SPKI_DER_MAX_SIZE: usize = tlv_len(ALG_ID_MAX_LEN + tlv_len(SPKI_BITS_LEN));
+
+/// Size of the largest DER PKCS#8 PrivateKeyInfo accepted by [`sk_from_pkcs8_der`].
+pub const PKCS8_DER_MAX_SIZE: usize = tlv_len(
+    tlv_len(1)
+        + ALG_ID_MAX_LEN
+        + tlv_len(tlv_len(PKCS8_KEY_LEN))
+        + tlv_len(PKCS8_ATTRIBUTES_MAX_LEN),
+);
+
+/// Size of a PEM SubjectPublicKeyInfo from [`pk_to_spki_pem`] (`\n` line endings).
+pub const SPKI_PEM_SIZE: usize = pem_len(SPKI_PEM_LABEL.len(), SPKI_DER_SIZE);
+
+/// Size of a PEM PKCS#8 PrivateKeyInfo from [`sk_to_pkcs8_pem`] (`\n` line endings).
+pub const PKCS8_PEM_SIZE: usize = pem_len(PKCS8_PEM_LABEL.len(), PKCS8_DER_SIZE);
+
+fn write_algorithm(w: &mut Writer) {
+    w.header(TAG_SEQUENCE, tlv_len(FALCON512_OID.len()));
+    w.tlv(TAG_OID, FALCON512_OID);
+}
+
+/// Whether `oid` (content octets) names Falcon-512: [`FALCON512_OID`] or
+/// [`FALCON512_LEGACY_OID`].
+pub fn is_falcon512_oid(oid: &[u8]) -> bool {
+    oid == FALCON512_OID || oid == FALCON512_LEGACY_OID
+}
+
+/// Reads an AlgorithmIdentifier and checks that it names Falcon-512.
+fn read_algorithm(r: &mut Reader) -> Result<(), Error> {
+    if !is_falcon512_oid(r.read_algorithm()?) {
+        return Err(Error::UnknownAlgorithm);
+    }
+
+    Ok(())
+}
+
+/// Encodes a public key as a DER SubjectPublicKeyInfo.
+///
+/// # Parameters
+/// - `pk`: The public key.
+///
+/// # Returns
+/// The DER encoding.
+pub fn pk_to_spki_der(pk: &PublicKey) -> [u8; SPKI_DER_SIZE] {
+    let mut out = [0u8; SPKI_DER_SIZE];
+    let mut w = Writer::new(&mut out);
+
+    w.header(TAG_SEQUENCE, SPKI_CONTENT_LEN);
+    write_algorithm(&mut w);
+    w.header(TAG_BIT_STRING, SPKI_BITS_LEN);
+    w.bytes(&[0]);
+    w.bytes(pk.as_bytes());
+
+    debug_assert_eq!(w.position(), SPKI_DER_SIZE);
+
+    out
+}
+
+/// Decodes a DER SubjectPublicKeyInfo holding a Falcon-512 public key.
+///
+/// # Parameters
+/// - `der`: The DER encoding (nothing may follow it).
+///
+/// # Returns
+/// The public key, checked as by [`PublicKey::try_from`], or:
+///   - [`Error::MalformedDer`] if the container is not valid DER or not a SubjectPublicKeyInfo.
+///   - [`Error::UnknownAlgorithm`] if it holds a key of another algorithm.
+pub fn pk_from_spki_der(der: &[u8]) -> Result<PublicKey, Error> {
+    let mut outer = Reader::new(der);
+    let mut spki = Reader::new(outer.read(TAG_SEQUENCE)?);
+
+    outer.finish()?;
+    read_algorithm(&mut spki)?;
+
+    let bits = spki.read(TAG_BIT_STRING)?;
+
+    spki.finish()?;
+
+    match bits.split_first() {
+        Some((0, pk)) => PublicKey::try_from(pk),
+        _ => Err(Error::MalformedDer),
+    }
+}
+
+/// Encodes a public key as a PEM SubjectPublicKeyInfo (`PUBLIC KEY`).
+///
+/// # Parameters
+/// - `pk`: The public key.
+/// - `out`: Output buffer.
+///
+/// # Returns
+/// The PEM document, borrowed from `out`.
+pub fn pk_to_spki_pem<'o>(pk: &PublicKey, out: &'o mut [u8; SPKI_PEM_SIZE]) -> &'o str {
+    pem_encode(SPKI_PEM_LABEL, &pk_to_spki_der(pk), out)
+}
+
+/// Decodes a PEM SubjectPublicKeyInfo (`PUBLIC KEY`) holding a Falcon-512 public key.
+///
+/// # Parameters
+/// - `pem`: The PEM document (surrounding whitespace allowed).
+///
+/// # Returns
+/// The public key, or [`Error::MalformedPem`] if the armoring is invalid or holds more than
+/// [`SPKI_DER_MAX_SIZE`] bytes, or the errors of [`pk_from_spki_der`].
+pub fn pk_from_spki_pem(pem: &str) -> Result<PublicKey, Error> {
+    let mut der = [0u8; SPKI_DER_MAX_SIZE];
+
+    pk_from_spki_der(pem_decode(SPKI_PEM_LABEL, pem, &mut der)?)
+}
+
+/// Encodes a private key as a DER PKCS#8 PrivateKeyInfo, with its public key.
+///
+/// # Parameters
+/// - `sk`: The private key.
+///
+/// # Returns
+/// The DER encoding.
+///
+/// # Panics
+/// Panics if `sk` cannot be encoded (see [`sk_encode`]).
+pub fn sk_to_pkcs8_der(sk: &SecretKey) -> [u8; PKCS8_DER_SIZE] {
+    let mut out = [0u8; PKCS8_DER_SIZE];
+    let mut w = Writer::new(&mut out);
+
+    w.header(TAG_SEQUENCE, PKCS8_CONTENT_LEN);
+    w.tlv(TAG_INTEGER, &[0]);
+    write_algorithm(&mut w);
+    w.header(TAG_OCTET_STRING, tlv_len(PKCS8_KEY_LEN));
+    w.header(TAG_OCTET_STRING, PKCS8_KEY_LEN);
+    w.bytes(&sk_encode(sk));
+    w.bytes(&pk_from_sk(sk));
+
+    debug_assert_eq!(w.position(), PKCS8_DER_SIZE);
+
+    out
+}
+
+/// Decodes a DER PKCS#8 PrivateKeyInfo holding a Falcon-512 private key.
+///
+/// # Parameters
+/// - `der`: The DER encoding (nothing may follow it).
+///
+/// # Returns
+/// The private key and its public key, or:
+///   - [`Error::MalformedDer`] if the container is not valid DER or not a PrivateKeyInfo
+///     (version 0).
+///   - [`Error::UnknownAlgorithm`] if it holds a key of another algorithm.
+///   - [`Error::BadLength`] if the key has the wrong size or the attributes are longer than
+///     [`PKCS8_ATTRIBUTES_MAX_LEN`] bytes.
+///   - [`Error::InvalidSecretKey`] if the private key encoding is invalid.
+///   - [`Error::KeyMismatch`] if a public key follows the private key and is not its public key.
+pub fn sk_from_pkcs8_der(der: &[u8]) -> Result<(SecretKey, PublicKey), Error> {
+    let mut outer = Reader::new(der);
+    let mut info = Reader::new(outer.read(TAG_SEQUENCE)?);
+
+    outer.finish()?;
+
+    if info.read(TAG_INTEGER)? != [0] {
+        return Err(Error::MalformedDer);
+    }
+
+    read_algorithm(&mut info)?;
+
+    let mut key = info.read(TAG_OCTET_STRING)?;
+
+    // attributes [0] IMPLICIT SET OF Attribute are ignored; bounded, so that the PEM decoder
+    // accepts the same documents
+    if info
+        .read_optional(0xa0)?
+        .is_some_and(|attributes| attributes.len() > PKCS8_ATTRIBUTES_MAX_LEN)
+    {
+        return Err(Error::BadLength);
+    }
+
+    info.finish()?;
+
+    // oqs-provider wraps the key bytes in a second OCTET STRING (the key header is 0x59, never an
+    // OCTET STRING tag)
+    if key.first() == Some(&TAG_OCTET_STRING) {
+        let mut inner = Reader::new(key);
+
+        key = inner.read(TAG_OCTET_STRING)?;
+        inner.finish()?;
+    }
+
+    if key.len() != FALCON_SK_SIZE && key.len() != PKCS8_KEY_LEN {
+        return Err(Error::BadLength);
+    }
+
+    let (sk_bytes, pk_bytes) = key.split_at(FALCON_SK_SIZE);
+    let sk = sk_decode(sk_bytes.try_into().unwrap())?;
+    let pk = pk_from_sk(&sk);
+
+    if !pk_bytes.is_empty() && pk_bytes != pk {
+        return Err(Error::KeyMismatch);
+    }
+
+    // h = g / f is reduced modulo q, hence a valid public key
+    Ok((sk, PublicKey::try_from(&pk[..]).unwrap()))
+}
+
+/// Encodes a private key as a PEM PKCS#8 PrivateKeyInfo (`PRIVATE KEY`), with its public key.
+///
+/// # Parameters
+/// - `sk`: The private key.
+/// - `out`: Output buffer.
+///
+/// # Returns
+/// The PEM document, borrowed from `out`.
+///
+/// # Panics
+/// Panics if `sk` cannot be encoded (see [`sk_encode`]).
+pub fn sk_to_pkcs8_pem<'o>(sk: &SecretKey, out: &'o mut [u8; PKCS8_PEM_SIZE]) -> &'o str {
+    pem_encode(PKCS8_PEM_LABEL, &sk_to_pkcs8_der(sk), out)
+}
+
+/// Decodes a PEM PKCS#8 PrivateKeyInfo (`PRIVATE KEY`) holding a Falcon-512 private key.
+///
+/// # Parameters
+/// - `pem`: The PEM document (surrounding whitespace allowed).
+///
+/// # Returns
+/// The private key and its public key, or [`Error::MalformedPem`] if the armoring is invalid
+/// or holds more than [`PKCS8_DER_MAX_SIZE`] bytes, or the errors of [`sk_from_pkcs8_der`].
+pub fn sk_from_pkcs8_pem(pem: &str) -> Result<(SecretKey, PublicKey), Error> {
+    let mut der = [0u8; PKCS8_DER_MAX_SIZE];
+
+    sk_from_pkcs8_der(pem_decode(PKCS8_PEM_LABEL, pem, &mut der)?)
+}
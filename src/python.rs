@@ -0,0 +1,154 @@
+//! Python extension module (`python` feature), through PyO3.
+//!
+//! Built as `falcon512_rs` with maturin (`pyproject.toml`), or by copying the shared library built
+//! with `cargo rustc --lib --crate-type cdylib --features python` to `falcon512_rs.so`. Keys,
+//! messages and signatures are `bytes`: the 897-byte public key and the complete signature
+//! (`header ‖ nonce ‖ s2`, any [`SigFormat`](crate::falcon512::SigFormat)).
+//!
+//! ```python
+//! import falcon512_rs
+//!
+//! key = falcon512_rs.prepare_public_key(pk)  # reuse for many signatures
+//! key.verify(msg, sig)                       # None, or raises VerificationError
+//! falcon512_rs.verify(pk, msg, sig)          # one-shot
+//! falcon512_rs.decode_compressed(s2)         # list of 512 signed coefficients
+//! ```
+//!
+//! Failures raise `FalconError` (a `ValueError`), whose `kind` is the [`Error`] variant name and
+//! `code` the matching C API error code. Verification failures raise its subclass
+//! `VerificationError`, which adds the squared `norm` of `(s1, s2)` (`None` if the signature was
+//! rejected before the norm check) and the acceptance `bound` (see [`VerifyReport`]).
+
+use pyo3::{create_exception, exceptions::PyValueError, prelude::*};
+
+use crate::{
+    capi::error_code,
+    error::Error,
+    falcon512::{comp_decode, verify_sig_detailed, PreparedPublicKey, PublicKey, VerifyReport},
+};
+
+create_exception!(
+    falcon512_rs,
+    FalconError,
+    PyValueError,
+    "A malformed key or signature: `kind` is the error name, `code` the C API error code."
+);
+create_exception!(
+    falcon512_rs,
+    VerificationError,
+    FalconError,
+    "A rejected signature: adds the squared `norm` of (s1, s2), or None, and the `bound`."
+);
+
+fn falcon_error(py: Python<'_>, err: Error) -> PyErr {
+    let py_err = FalconError::new_err(err.to_string());
+
+    set_kind(py, &py_err, err);
+    py_err
+}
+
+fn verification_error(py: Python<'_>, report: &VerifyReport) -> PyErr {
+    // only built for a rejected report
+    let err = report.result.unwrap_err();
+    let py_err = VerificationError::new_err(err.to_string());
+
+    set_kind(py, &py_err, err);
+
+    let value = py_err.value(py);
+
+    value.setattr("norm", report.norm).unwrap();
+    value.setattr("bound", report.bound).unwrap();
+    py_err
+}
+
+fn set_kind(py: Python<'_>, py_err: &PyErr, err: Error) {
+    // the attributes of a fresh exception instance can always be set
+    let value = py_err.value(py);
+
+    value.setattr("kind", format!("{:?}", err)).unwrap();
+    value.setattr("code", error_code(err)).unwrap();
+}
+
+/// A public key in NTT format, for repeated verifications.
+#[pyclass(name = "PreparedPublicKey", module = "falcon512_rs", frozen)]
+struct PyPreparedPublicKey(PreparedPublicKey);
+
+#[pymethods]
+impl PyPreparedPublicKey {
+    /// Parses and prepares a serialized public key; raises `FalconError` if it is malformed.
+    #[new]
+    fn new(py: Python<'_>, pk: &[u8]) -> PyResult<Self> {
+        PreparedPublicKey::try_from(pk)
+            .map(PyPreparedPublicKey)
+            .map_err(|err| falcon_error(py, err))
+    }
+
+    /// Verifies `sig` on `msg`; raises `VerificationError` if it is rejected.
+    fn verify(&self, py: Python<'_>, msg: &[u8], sig: &[u8]) -> PyResult<()> {
+        let report = verify_sig_detailed(msg, sig, self.0.as_ntt_fmt());
+
+        match report.result {
+            Ok(()) => Ok(()),
+            Err(_) => Err(verification_error(py, &report)),
+        }
+    }
+
+    /// The coefficients of the key in NTT + Montgomery format.
+    fn ntt_coefficients(&self) -> Vec<u16> {
+        self.0.as_ntt_fmt().to_vec()
+    }
+
+    /// The serialized (897-byte) public key.
+    fn __bytes__(&self) -> Vec<u8> {
+        PublicKey::from(&self.0).as_bytes().to_vec()
+    }
+}
+
+/// Parses and prepares a serialized public key; raises `FalconError` if it is malformed.
+#[pyfunction]
+fn prepare_public_key(py: Python<'_>, pk: &[u8]) -> PyResult<PyPreparedPublicKey> {
+    PyPreparedPublicKey::new(py, pk)
+}
+
+/// Verifies `sig` on `msg` with a serialized public key; raises `FalconError` if the key is
+/// malformed, `VerificationError` if the signature is rejected.
+#[pyfunction]
+fn verify(py: Python<'_>, pk: &[u8], msg: &[u8], sig: &[u8]) -> PyResult<()> {
+    PyPreparedPublicKey::new(py, pk)?.verify(py, msg, sig)
+}
+
+/// Decodes a compressed `s2` (as in the NIST signed messages, without header and nonce) into its
+/// 512 coefficients, in `[-2047, 2047]`; zero padding after the encoding is accepted. Raises
+/// `FalconError` if the encoding is invalid.
+#[pyfunction]
+fn decode_compressed(py: Python<'_>, s2: &[u8]) -> PyResult<Vec<i16>> {
+    decode_s2(s2).map_err(|err| falcon_error(py, err))
+}
+
+pub(crate) fn decode_s2(s2: &[u8]) -> Result<Vec<i16>, Error> {
+    let (coeffs, len) = comp_decode(s2);
+
+    if len == 0 {
+        return Err(Error::DecodeFailed);
+    }
+
+    if s2[len..].iter().any(|&b| b != 0) {
+        return Err(Error::TrailingBits);
+    }
+
+    Ok(coeffs.iter().map(|&c| c as i16).collect())
+}
+
+#[pymodule]
+fn falcon512_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+
+    m.add("FalconError", py.get_type::<FalconError>())?;
+    m.add("VerificationError", py.get_type::<VerificationError>())?;
+    m.add_class::<PyPreparedPublicKey>()?;
+    m.add_function(wrap_pyfunction!(prepare_public_key, m)?)?;
+    m.add_function(wrap_pyfunction!(verify, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_compressed, m)?)?;
+
+    Ok(())
+}
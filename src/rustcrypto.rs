@@ -0,0 +1,86 @@
+//! RustCrypto [`signature`] traits for the typed Falcon-512 API (`signature` feature):
+//! [`Verifier`] for [`PublicKey`] and [`PreparedPublicKey`], [`SignatureEncoding`] for
+//! [`Signature`] (the complete signature, any [`SigFormat`](crate::falcon512::SigFormat)) and
+//! [`RandomizedSigner`] for [`SecretKey`] and [`ExpandedSecretKey`].
+//!
+//! Falcon signing draws a fresh nonce and samples with fresh randomness, so the keys implement
+//! `RandomizedSigner` and not the deterministic `Signer`: each signature seeds a
+//! [`Shake256Prng`] with [`SIGN_SEED_LEN`] bytes of the caller's RNG. Signatures are compressed.
+//!
+//! Rejections map to an opaque [`signature::Error`]; with the `std` feature its source is the
+//! crate's [`Error`].
+
+use signature::{rand_core::CryptoRngCore, RandomizedSigner, SignatureEncoding, Verifier};
+
+use crate::{
+    constants::SIGN_SEED_LEN,
+    error::Error,
+    falcon512::{
+        sign, sign_tree, ExpandedSecretKey, PreparedPublicKey, PublicKey, SecretKey, Signature,
+    },
+    fpr::Fpr,
+    shake256::Shake256Prng,
+};
+
+fn signature_error(error: Error) -> signature::Error {
+    #[cfg(feature = "std")]
+    {
+        signature::Error::from_source(error)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = error;
+
+        signature::Error::new()
+    }
+}
+
+fn seeded(rng: &mut impl CryptoRngCore) -> Result<Shake256Prng, signature::Error> {
+    let mut seed = [0u8; SIGN_SEED_LEN];
+
+    rng.try_fill_bytes(&mut seed)?;
+
+    Ok(Shake256Prng::from_seed(&seed))
+}
+
+impl Verifier<Signature> for PublicKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), signature::Error> {
+        PublicKey::verify(self, msg, signature).map_err(signature_error)
+    }
+}
+
+impl Verifier<Signature> for PreparedPublicKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), signature::Error> {
+        PreparedPublicKey::verify(self, msg, signature).map_err(signature_error)
+    }
+}
+
+impl SignatureEncoding for Signature {
+    // variable length: the signature is its own byte representation
+    type Repr = Signature;
+}
+
+impl RandomizedSigner<Signature> for SecretKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<Signature, signature::Error> {
+        let (nonce, sig, len) = sign(self, msg, &mut seeded(rng)?);
+
+        Signature::from_compressed(&nonce, &sig[..len]).map_err(signature_error)
+    }
+}
+
+impl<F: Fpr> RandomizedSigner<Signature> for ExpandedSecretKey<F> {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<Signature, signature::Error> {
+        let (nonce, sig, len) = sign_tree(self, msg, &mut seeded(rng)?);
+
+        Signature::from_compressed(&nonce, &sig[..len]).map_err(signature_error)
+    }
+}
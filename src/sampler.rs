@@ -0,0 +1,158 @@
+//! Discrete Gaussian sampling over the integers (Falcon `SamplerZ`).
+//!
+//! A half-Gaussian base sampler (table based, `sigma0 = 1.8205`) is turned into a bimodal
+//! Gaussian and then, by rejection with a Bernoulli trial of probability `exp(-x)`, into a
+//! Gaussian of arbitrary center `mu` and standard deviation `sigma` in `[1, 2)`. The rejection
+//! rate does not depend on `mu` or `sigma` (the Bernoulli trial is scaled by `sigma_min / sigma`),
+//! which is what makes the sampler suitable for secret centers.
+//!
+//! All randomness is drawn from a [`Shake256Prng`]. The float computations are generic over the
+//! [`Fpr`] backend.
+
+use crate::{
+    constants::{EXPM_P63_COEFFS, GAUSS0_RCDT, INV_2SQRSIGMA0},
+    fpr::Fpr,
+    shake256::Shake256Prng,
+};
+
+/// 2^63, as a float.
+const PTWO63: f64 = 9223372036854775808.0;
+
+/// Samples `z >= 0` from a half-Gaussian centered on 0 with standard deviation `sigma0`.
+///
+/// A 72-bit random value is compared against every entry of [`GAUSS0_RCDT`]; `z` is the number of
+/// entries the value is below of. All comparisons are done, branch-free.
+///
+/// # Parameters
+/// - `prng`: The randomness source.
+///
+/// # Returns
+/// The sampled value.
+#[inline(always)]
+fn gaussian0(prng: &mut Shake256Prng) -> i32 {
+    let lo = prng.next_u64();
+    let hi = prng.next_u8() as u32;
+    let v0 = lo as u32 & 0xffffff;
+    let v1 = (lo >> 24) as u32 & 0xffffff;
+    let v2 = (lo >> 48) as u32 | (hi << 16);
+    let mut z = 0;
+
+    for w in GAUSS0_RCDT.chunks_exact(3) {
+        let mut cc = v0.wrapping_sub(w[2]) >> 31;
+
+        cc = v1.wrapping_sub(w[1]).wrapping_sub(cc) >> 31;
+        cc = v2.wrapping_sub(w[0]).wrapping_sub(cc) >> 31;
+        z += cc as i32;
+    }
+
+    z
+}
+
+/// Returns the top 64 bits of the 128-bit product `a * b`.
+#[inline(always)]
+fn mulhi(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) >> 64) as u64
+}
+
+/// Computes `ccs * exp(-x) * 2^63`, for `0 <= x < log(2)` and `0 <= ccs <= 1`.
+///
+/// The polynomial approximation is evaluated in 63-bit fixed point (Horner scheme on
+/// [`EXPM_P63_COEFFS`]); only the conversion of `x` and `ccs` to fixed point uses the float type.
+/// The result is accurate to about 2^-50.
+///
+/// # Parameters
+/// - `x`: The exponent argument.
+/// - `ccs`: The scaling factor.
+///
+/// # Returns
+/// The scaled value, as a 64-bit integer.
+pub fn expm_p63<F: Fpr>(x: F, ccs: F) -> u64 {
+    let mut y = EXPM_P63_COEFFS[0];
+    let z = ((x * F::from_f64(PTWO63)).trunc() as u64) << 1;
+
+    for c in &EXPM_P63_COEFFS[1..] {
+        y = c.wrapping_sub(mulhi(z, y));
+    }
+
+    let z = ((ccs * F::from_f64(PTWO63)).trunc() as u64) << 1;
+
+    mulhi(z, y)
+}
+
+/// Returns `true` with probability `ccs * exp(-x)`, for `x >= 0`.
+///
+/// # Parameters
+/// - `prng`: The randomness source.
+/// - `x`: The exponent argument.
+/// - `ccs`: The scaling factor, in `[0, 1]`.
+#[inline(always)]
+fn ber_exp<F: Fpr>(prng: &mut Shake256Prng, x: F, ccs: F) -> bool {
+    // x = s * log(2) + r, with s an integer and 0 <= r < log(2).
+    let s = (x * F::from_f64(core::f64::consts::LOG2_E)).trunc();
+    let r = x - F::of(s) * F::from_f64(core::f64::consts::LN_2);
+
+    // s >= 64 only happens with probability ~2^-32, and then exp(-x) < 2^-64: saturate at 63.
+    let mut sw = s as u32;
+
+    sw ^= (sw ^ 63) & 0u32.wrapping_sub(63u32.wrapping_sub(sw) >> 31);
+
+    // exp(-x) = 2^-s * exp(-r). The -1 keeps an r = 0 result within 64 bits.
+    let z = (expm_p63(r, ccs) << 1).wrapping_sub(1) >> sw;
+
+    // Compare z with a random 64-bit value lazily, one byte at a time, high byte first.
+    let mut i = 64;
+    let mut w;
+
+    loop {
+        i -= 8;
+        w = (prng.next_u8() as u32).wrapping_sub(((z >> i) & 0xff) as u32);
+
+        if w != 0 || i == 0 {
+            break;
+        }
+    }
+
+    (w >> 31) != 0
+}
+
+/// Samples an integer from the discrete Gaussian of center `mu` and standard deviation
+/// `sigma = 1 / isigma`.
+///
+/// # Parameters
+/// - `prng`: The randomness source.
+/// - `mu`: The center.
+/// - `isigma`: The inverse of the standard deviation; `sigma` must lie in `[sigma_min, 2)`.
+/// - `sigma_min`: The smallest `sigma` the caller will ever use; it sets the (constant) rejection
+///   rate.
+///
+/// # Returns
+/// The sampled integer.
+pub fn sampler_z<F: Fpr>(prng: &mut Shake256Prng, mu: F, isigma: F, sigma_min: F) -> i32 {
+    // mu = s + r, with s an integer and 0 <= r < 1.
+    let s = mu.floor();
+    let r = mu - F::of(s);
+
+    // dss = 1 / (2 * sigma^2)
+    let dss = (isigma * isigma).half();
+
+    // ccs = sigma_min / sigma
+    let ccs = isigma * sigma_min;
+
+    loop {
+        // Bimodal Gaussian: b = 1 -> z = z0 + 1 (centered on 1), b = 0 -> z = -z0 (centered on 0).
+        let z0 = gaussian0(prng);
+        let b = (prng.next_u8() & 1) as i32;
+        let z = b + ((b << 1) - 1) * z0;
+
+        // Keep z with probability S(z) / G(z) = exp(-x), where S targets the center r and G is
+        // the bimodal distribution z was drawn from.
+        let zr = F::of(z as i64) - r;
+        let mut x = zr * zr * dss;
+
+        x = x - F::of((z0 * z0) as i64) * F::from_f64(INV_2SQRSIGMA0);
+
+        if ber_exp(prng, x, ccs) {
+            return s as i32 + z;
+        }
+    }
+}
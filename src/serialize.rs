@@ -0,0 +1,162 @@
+//! Serde support for the typed Falcon-512 API (`serde` feature).
+//!
+//! [`PublicKey`], [`PreparedPublicKey`] and [`Signature`] serialize as their canonical bytes: the
+//! 897-byte public key (also for the prepared key) and the complete signature. Human-readable
+//! formats (JSON, TOML, ...) get a lowercase hex string, binary formats (bincode, ...) raw bytes.
+//!
+//! Deserialization runs the checks of the `TryFrom<&[u8]>` impls (length, header, coefficients
+//! `< q` and padding for keys as [`pk_to_ntt_fmt`](crate::falcon512::pk_to_ntt_fmt); header,
+//! length and `s2` encoding for signatures as [`sig_decode`](crate::falcon512::sig_decode)), so a
+//! deserialized value is always valid. Neither direction allocates.
+
+use core::fmt;
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    constants::{FALCON_PK_SIZE, SIG_CT_SIZE},
+    falcon512::{PreparedPublicKey, PublicKey, Signature},
+};
+
+/// Lowercase hex of a byte string.
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.collect_str(&Hex(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Collects at most `MAX` bytes, from a hex string, a byte string or a sequence of `u8`.
+struct BytesVisitor<const MAX: usize>;
+
+impl<'de, const MAX: usize> Visitor<'de> for BytesVisitor<MAX> {
+    type Value = ([u8; MAX], usize);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "at most {} bytes (a hex string in human-readable formats)",
+            MAX
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let digits = v.as_bytes();
+        let mut out = [0u8; MAX];
+
+        if digits.len() & 1 != 0 || digits.len() / 2 > MAX {
+            return Err(E::invalid_length(digits.len(), &self));
+        }
+
+        for (o, pair) in out.iter_mut().zip(digits.chunks_exact(2)) {
+            let hi = hex_digit(pair[0]);
+            let lo = hex_digit(pair[1]);
+
+            *o = match (hi, lo) {
+                (Some(hi), Some(lo)) => (hi << 4) | lo,
+                _ => return Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+            };
+        }
+
+        Ok((out, digits.len() / 2))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let mut out = [0u8; MAX];
+
+        if v.len() > MAX {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        out[..v.len()].copy_from_slice(v);
+
+        Ok((out, v.len()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut out = [0u8; MAX];
+        let mut len = 0;
+
+        while let Some(b) = seq.next_element::<u8>()? {
+            if len == MAX {
+                return Err(de::Error::invalid_length(len + 1, &self));
+            }
+
+            out[len] = b;
+            len += 1;
+        }
+
+        Ok((out, len))
+    }
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn deserialize_bytes<'de, D: Deserializer<'de>, const MAX: usize>(
+    deserializer: D,
+) -> Result<([u8; MAX], usize), D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor::<MAX>)
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor::<MAX>)
+    }
+}
+
+impl Serialize for PublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (bytes, len) = deserialize_bytes::<D, FALCON_PK_SIZE>(deserializer)?;
+
+        PublicKey::try_from(&bytes[..len]).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for PreparedPublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PublicKey::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PreparedPublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PublicKey::deserialize(deserializer).map(|pk| pk.prepare())
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (bytes, len) = deserialize_bytes::<D, SIG_CT_SIZE>(deserializer)?;
+
+        Signature::try_from(&bytes[..len]).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,243 @@
+//! The SHA-3 family of FIPS 202 on the Keccak-f\[1600\] permutation of [`process_block`]: the
+//! SHA3-224/256/384/512 hash functions and the SHAKE128/SHAKE256 extendable-output functions.
+//!
+//! The sponge is generic over its rate in bytes (`RATE`, a multiple of 8 below 200; the capacity
+//! is the rest of the 200-byte state): [`Sha3`] and [`Shake`], with the FIPS 202 instances as
+//! aliases ([`Sha3_256`], [`Shake128`], ...). [`Shake256`], also re-exported by
+//! [`crate::shake256`], is the incremental form of the Falcon hashing: its padding is the same as
+//! [`shake_flip`](crate::shake256::shake_flip)'s. Input is absorbed in any number of `update`
+//! calls, with arbitrary chunk boundaries, and SHAKE output is squeezed in any number of `read`
+//! calls. Nothing allocates.
+
+use crate::{constants::SHAKE256_RATE, shake256::process_block};
+
+// domain separation bits and first padding bit (FIPS 202, B.2)
+const SHA3_PAD: u8 = 0x06;
+const SHAKE_PAD: u8 = 0x1f;
+
+/// SHA3-224: 28-byte digests, rate 144.
+pub type Sha3_224 = Sha3<144, 28>;
+/// SHA3-256: 32-byte digests, rate 136.
+pub type Sha3_256 = Sha3<136, 32>;
+/// SHA3-384: 48-byte digests, rate 104.
+pub type Sha3_384 = Sha3<104, 48>;
+/// SHA3-512: 64-byte digests, rate 72.
+pub type Sha3_512 = Sha3<72, 64>;
+/// SHAKE128: rate 168.
+pub type Shake128 = Shake<168>;
+/// The output of a [`Shake128`] context.
+pub type Shake128Reader = ShakeReader<168>;
+/// SHAKE256: rate 136.
+pub type Shake256 = Shake<{ SHAKE256_RATE as usize }>;
+/// The output of a [`Shake256`] context.
+pub type Shake256Reader = ShakeReader<{ SHAKE256_RATE as usize }>;
+
+/// Keccak sponge absorbing `RATE` bytes per permutation. The state has the layout of
+/// [`shake_inject`](crate::shake256::shake_inject): 25 lanes, then the number of bytes absorbed
+/// into the current block.
+#[derive(Clone)]
+struct Sponge<const RATE: usize> {
+    state: [u64; 26],
+}
+
+impl<const RATE: usize> Sponge<RATE> {
+    const RATE_CHECK: () = assert!(
+        RATE != 0 && RATE < 200 && RATE & 0x7 == 0,
+        "the rate must be a multiple of 8 below 200"
+    );
+
+    fn new() -> Self {
+        let () = Self::RATE_CHECK;
+
+        Self { state: [0u64; 26] }
+    }
+
+    fn update(&mut self, input: &[u8]) {
+        let mut pos = self.state[25] as usize;
+        let mut input = input;
+
+        // completes the current block
+        if pos != 0 {
+            let n = input.len().min(RATE - pos);
+
+            xor_bytes(&mut self.state, pos, &input[..n]);
+            input = &input[n..];
+            pos += n;
+
+            if pos != RATE {
+                self.state[25] = pos as u64;
+                return;
+            }
+
+            process_block(&mut self.state);
+        }
+
+        // full blocks, then the start of the next one
+        let mut blocks = input.chunks_exact(RATE);
+
+        for block in blocks.by_ref() {
+            for (lane, word) in self.state.iter_mut().zip(block.chunks_exact(8)) {
+                *lane ^= u64::from_le_bytes(word.try_into().unwrap());
+            }
+
+            process_block(&mut self.state);
+        }
+
+        let rest = blocks.remainder();
+
+        xor_bytes(&mut self.state, 0, rest);
+        self.state[25] = rest.len() as u64;
+    }
+
+    /// Pads the input with the domain separation bits `pad` (pad10*1) and returns the state, in
+    /// the output mode of [`shake_flip`](crate::shake256::shake_flip): the next permutation
+    /// yields the first output block.
+    fn pad(mut self, pad: u8) -> [u64; 26] {
+        let pos = self.state[25] as usize;
+
+        xor_bytes(&mut self.state, pos, &[pad]);
+        xor_bytes(&mut self.state, RATE - 1, &[0x80]);
+        self.state[25] = RATE as u64;
+
+        self.state
+    }
+}
+
+/// A SHA-3 hash function with rate `RATE` and `OUT`-byte digests.
+#[derive(Clone)]
+pub struct Sha3<const RATE: usize, const OUT: usize> {
+    sponge: Sponge<RATE>,
+}
+
+impl<const RATE: usize, const OUT: usize> Sha3<RATE, OUT> {
+    /// Creates a context with nothing absorbed.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    /// Absorbs `input`, after everything absorbed so far.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.update(input);
+    }
+
+    /// Pads the input and returns the digest.
+    pub fn finalize(self) -> [u8; OUT] {
+        let mut reader = ShakeReader::<RATE>::new(self.sponge.pad(SHA3_PAD));
+        let mut out = [0u8; OUT];
+
+        reader.read(&mut out);
+
+        out
+    }
+
+    /// The digest of `input`.
+    pub fn digest(input: &[u8]) -> [u8; OUT] {
+        let mut ctx = Self::new();
+
+        ctx.update(input);
+        ctx.finalize()
+    }
+}
+
+impl<const RATE: usize, const OUT: usize> Default for Sha3<RATE, OUT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A SHAKE extendable-output function with rate `RATE`: absorbs input in any number of
+/// [`update`](Self::update) calls, then [`finalize`](Self::finalize)s into a [`ShakeReader`]
+/// squeezing any length of output.
+#[derive(Clone)]
+pub struct Shake<const RATE: usize> {
+    sponge: Sponge<RATE>,
+}
+
+impl<const RATE: usize> Shake<RATE> {
+    /// Creates a context with nothing absorbed.
+    pub fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    /// Absorbs `input`, after everything absorbed so far.
+    pub fn update(&mut self, input: &[u8]) {
+        self.sponge.update(input);
+    }
+
+    /// Pads the input and switches to output mode.
+    pub fn finalize(self) -> ShakeReader<RATE> {
+        ShakeReader::new(self.sponge.pad(SHAKE_PAD))
+    }
+
+    /// [`finalize`](Self::finalize), with the domain separation bits `pad` instead of SHAKE's
+    /// (cSHAKE, see [`crate::sp800_185`]).
+    pub(crate) fn finalize_with(self, pad: u8) -> ShakeReader<RATE> {
+        ShakeReader::new(self.sponge.pad(pad))
+    }
+}
+
+impl Shake256 {
+    /// Pads the input and returns the flipped state, for the fixed-size squeezes of the Falcon
+    /// verification path ([`shake_extract_vartime`](crate::shake256::shake_extract_vartime), ...).
+    pub(crate) fn flip(self) -> [u64; 26] {
+        self.sponge.pad(SHAKE_PAD)
+    }
+}
+
+impl<const RATE: usize> Default for Shake<RATE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The output of a [`Shake`] context: an unbounded byte stream, read in any number of calls.
+#[derive(Clone)]
+pub struct ShakeReader<const RATE: usize> {
+    state: [u64; 26],
+    // Bytes of the current rate block already read (`RATE` = block exhausted).
+    pos: usize,
+}
+
+impl<const RATE: usize> ShakeReader<RATE> {
+    fn new(state: [u64; 26]) -> Self {
+        Self { state, pos: RATE }
+    }
+
+    /// Fills `out` with the next output bytes.
+    pub fn read(&mut self, out: &mut [u8]) {
+        let mut out = out;
+
+        while !out.is_empty() {
+            if self.pos == RATE {
+                process_block(&mut self.state);
+                self.pos = 0;
+            }
+
+            let n = out.len().min(RATE - self.pos);
+            let (chunk, rest) = out.split_at_mut(n);
+
+            for (i, b) in chunk.iter_mut().enumerate() {
+                let p = self.pos + i;
+
+                *b = (self.state[p >> 0x3] >> ((p & 0x7) << 0x3)) as u8;
+            }
+
+            self.pos += n;
+            out = rest;
+        }
+    }
+}
+
+/// XORs `bytes` into the rate of `state`, starting at byte `pos` (within one block).
+#[inline(always)]
+fn xor_bytes(state: &mut [u64; 26], pos: usize, bytes: &[u8]) {
+    for (i, &b) in bytes.iter().enumerate() {
+        let p = pos + i;
+
+        state[p >> 0x3] ^= (b as u64) << ((p & 0x7) << 0x3);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::constants::{
     M, SHAKE256_RATE, SHAKE256_RATE_WORDS, SHAKE_EXTRACT_OUT_CAPACITY_WORDS, SHAKE_ROUND_CONSTANTS,
-    SHAKE_VARTIME_BLOCKS, SHAKE_VARTIME_WORDS,
+    SHAKE_VARTIME_WORDS,
 };
 
 /// Performs the Theta and Rho steps (step 1) of the Keccak permutation.
@@ -549,6 +549,7 @@ fn absorb_full_block(shake_ctx: &mut [u64; 26], input_ptr: *const u8) {
 ///
 /// # Note
 /// This function does not support consecutive calls; call `shake_flip()` before further extraction or other injection.
+/// [`Shake256`] absorbs input in several calls.
 pub fn shake_inject(shake_ctx: &mut [u64; 26], input: &[u8]) {
     let mut in_len = input.len();
     let mut offset: usize = 0;
@@ -690,8 +691,17 @@ pub fn shake_extract(shake_ctx: &mut [u64; 26]) -> [u64; SHAKE_EXTRACT_OUT_CAPAC
 ///
 /// # Returns
 /// `SHAKE_VARTIME_WORDS` (153) squeezed `u64` words = 612 big-endian draws.
+///
+/// [`SHAKE_VARTIME_BLOCKS`]: crate::constants::SHAKE_VARTIME_BLOCKS
 pub fn shake_extract_vartime(shake_ctx: &mut [u64; 26]) -> [u64; SHAKE_VARTIME_WORDS] {
-    let mut out = [0u64; SHAKE_VARTIME_WORDS];
+    shake_extract_blocks(shake_ctx)
+}
+
+/// Squeezes `WORDS / 17` full rate blocks (`WORDS` a multiple of 17); the core of
+/// [`shake_extract_vartime`], also sized for Falcon-1024's challenge.
+#[inline(always)]
+pub(crate) fn shake_extract_blocks<const WORDS: usize>(shake_ctx: &mut [u64; 26]) -> [u64; WORDS] {
+    let mut out = [0u64; WORDS];
     let out_ptr = out.as_mut_ptr();
 
     unsafe {
@@ -699,7 +709,7 @@ pub fn shake_extract_vartime(shake_ctx: &mut [u64; 26]) -> [u64; SHAKE_VARTIME_W
         let mut block = 0usize;
         let mut word = 0usize;
 
-        while block != SHAKE_VARTIME_BLOCKS {
+        while block != WORDS / SHAKE256_RATE_WORDS {
             process_block(shake_ctx);
 
             // Copy the full 17-word (136-byte) rate block.
@@ -714,3 +724,70 @@ pub fn shake_extract_vartime(shake_ctx: &mut [u64; 26]) -> [u64; SHAKE_VARTIME_W
 
     out
 }
+
+/// SHAKE256-based pseudo-random generator: the randomness source of key generation and signing.
+///
+/// The seed is absorbed once (`shake_inject` + `shake_flip`) and output is then squeezed one rate
+/// block at a time, byte-granular, for as long as the caller needs. The same seed always yields
+/// the same stream, so keygen and signing are reproducible from their seed; callers are expected
+/// to seed it from a proper entropy source (at least 32 bytes).
+pub struct Shake256Prng {
+    shake_ctx: [u64; 26],
+    // Bytes of the current rate block already handed out (`SHAKE256_RATE` = block exhausted).
+    pos: usize,
+}
+
+impl Shake256Prng {
+    /// Creates a generator from `seed`.
+    ///
+    /// # Parameters
+    /// - `seed`: Seed bytes, absorbed into a fresh SHAKE256 context.
+    pub fn from_seed(seed: &[u8]) -> Self {
+        let mut shake_ctx = [0u64; 26];
+
+        shake_inject(&mut shake_ctx, seed);
+        shake_flip(&mut shake_ctx);
+
+        Self {
+            shake_ctx,
+            pos: SHAKE256_RATE as usize,
+        }
+    }
+
+    /// Returns the next output byte.
+    #[inline(always)]
+    pub fn next_u8(&mut self) -> u8 {
+        if self.pos == SHAKE256_RATE as usize {
+            process_block(&mut self.shake_ctx);
+            self.pos = 0;
+        }
+
+        let b = (self.shake_ctx[self.pos >> 0x3] >> ((self.pos & 0x7) << 0x3)) as u8;
+
+        self.pos += 1;
+
+        b
+    }
+
+    /// Returns the next 8 output bytes, interpreted as a little-endian `u64`.
+    #[inline(always)]
+    pub fn next_u64(&mut self) -> u64 {
+        let mut r: u64 = 0;
+
+        for i in 0..8 {
+            r |= (self.next_u8() as u64) << (i << 0x3);
+        }
+
+        r
+    }
+
+    /// Fills `out` with output bytes.
+    pub fn fill_bytes(&mut self, out: &mut [u8]) {
+        for b in out.iter_mut() {
+            *b = self.next_u8();
+        }
+    }
+}
+
+// incremental SHAKE256 is the rate-136 sponge of `crate::sha3`
+pub use crate::sha3::{Shake256, Shake256Reader};
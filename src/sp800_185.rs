@@ -0,0 +1,190 @@
+//! The SHA-3 derived functions of NIST SP 800-185, on the sponge of [`crate::sha3`]:
+//! cSHAKE128/256 (SHAKE with a function name and a customization string), KMAC128/256 (a MAC
+//! keyed through cSHAKE, with fixed-length and XOF output) and TupleHash128/256 (an unambiguous
+//! hash of a sequence of byte strings, with fixed-length and XOF output).
+//!
+//! Like [`Shake`], every context absorbs input in any number of `update` calls and squeezes its
+//! output through a [`ShakeReader`]. Nothing allocates.
+
+use crate::sha3::{Shake, ShakeReader};
+
+// domain separation bits and first padding bit of cSHAKE (SP 800-185, 3.3)
+const CSHAKE_PAD: u8 = 0x04;
+
+/// cSHAKE128: rate 168.
+pub type CShake128 = CShake<168>;
+/// cSHAKE256: rate 136.
+pub type CShake256 = CShake<136>;
+/// KMAC128 / KMACXOF128: rate 168.
+pub type Kmac128 = Kmac<168>;
+/// KMAC256 / KMACXOF256: rate 136.
+pub type Kmac256 = Kmac<136>;
+/// TupleHash128 / TupleHashXOF128: rate 168.
+pub type TupleHash128 = TupleHash<168>;
+/// TupleHash256 / TupleHashXOF256: rate 136.
+pub type TupleHash256 = TupleHash<136>;
+
+/// cSHAKE with rate `RATE`: SHAKE, domain-separated by a function name `N` (reserved for NIST
+/// functions, empty otherwise) and a customization string `S`. With both empty, it is exactly
+/// [`Shake`].
+#[derive(Clone)]
+pub struct CShake<const RATE: usize> {
+    shake: Shake<RATE>,
+    plain: bool,
+}
+
+impl<const RATE: usize> CShake<RATE> {
+    /// Creates a context for the function name `function_name` and the customization string
+    /// `customization`, with nothing absorbed.
+    pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+        let mut shake = Shake::new();
+        let plain = function_name.is_empty() && customization.is_empty();
+
+        if !plain {
+            absorb_bytepad(&mut shake, &[function_name, customization]);
+        }
+
+        Self { shake, plain }
+    }
+
+    /// Absorbs `input`, after everything absorbed so far.
+    pub fn update(&mut self, input: &[u8]) {
+        self.shake.update(input);
+    }
+
+    /// Pads the input and switches to output mode.
+    pub fn finalize(self) -> ShakeReader<RATE> {
+        if self.plain {
+            self.shake.finalize()
+        } else {
+            self.shake.finalize_with(CSHAKE_PAD)
+        }
+    }
+}
+
+/// KMAC with rate `RATE`: cSHAKE named `"KMAC"`, keyed with `bytepad(encode_string(K))`.
+///
+/// [`finalize`](Self::finalize) binds the tag length to the output (KMAC128/256: a shorter tag
+/// is not a prefix of a longer one); [`finalize_xof`](Self::finalize_xof) does not (KMACXOF).
+#[derive(Clone)]
+pub struct Kmac<const RATE: usize> {
+    cshake: CShake<RATE>,
+}
+
+impl<const RATE: usize> Kmac<RATE> {
+    /// Creates a context for the key `key` and the customization string `customization`.
+    pub fn new(key: &[u8], customization: &[u8]) -> Self {
+        let mut cshake = CShake::new(b"KMAC", customization);
+
+        absorb_bytepad(&mut cshake.shake, &[key]);
+
+        Self { cshake }
+    }
+
+    /// Absorbs `input`, after everything absorbed so far.
+    pub fn update(&mut self, input: &[u8]) {
+        self.cshake.update(input);
+    }
+
+    /// Fills `out` with the tag (KMAC128/256, `L = 8 * out.len()`).
+    pub fn finalize(self, out: &mut [u8]) {
+        finish(self.cshake, out.len() as u64 * 8).read(out);
+    }
+
+    /// Switches to output mode, for a tag of any length (KMACXOF128/256).
+    pub fn finalize_xof(self) -> ShakeReader<RATE> {
+        finish(self.cshake, 0)
+    }
+}
+
+/// TupleHash with rate `RATE`: cSHAKE named `"TupleHash"` of the encoded elements of a tuple of
+/// byte strings, so that `("ab", "c")` and `("a", "bc")` hash differently.
+///
+/// [`finalize`](Self::finalize) binds the output length to the output (TupleHash128/256);
+/// [`finalize_xof`](Self::finalize_xof) does not (TupleHashXOF).
+#[derive(Clone)]
+pub struct TupleHash<const RATE: usize> {
+    cshake: CShake<RATE>,
+}
+
+impl<const RATE: usize> TupleHash<RATE> {
+    /// Creates a context for the customization string `customization`, with the empty tuple.
+    pub fn new(customization: &[u8]) -> Self {
+        Self {
+            cshake: CShake::new(b"TupleHash", customization),
+        }
+    }
+
+    /// Appends the element `element` to the tuple. Unlike the other `update` functions, each
+    /// call is a separate element: `update(a); update(b)` differs from `update(a ‖ b)`.
+    pub fn update(&mut self, element: &[u8]) {
+        let mut buf = [0u8; 9];
+
+        self.cshake
+            .update(left_encode(element.len() as u64 * 8, &mut buf));
+        self.cshake.update(element);
+    }
+
+    /// Fills `out` with the hash of the tuple (TupleHash128/256, `L = 8 * out.len()`).
+    pub fn finalize(self, out: &mut [u8]) {
+        finish(self.cshake, out.len() as u64 * 8).read(out);
+    }
+
+    /// Switches to output mode, for a hash of any length (TupleHashXOF128/256).
+    pub fn finalize_xof(self) -> ShakeReader<RATE> {
+        finish(self.cshake, 0)
+    }
+}
+
+/// Absorbs `right_encode(out_bits)` (0 for the XOF variants) and switches to output mode.
+fn finish<const RATE: usize>(mut cshake: CShake<RATE>, out_bits: u64) -> ShakeReader<RATE> {
+    let mut buf = [0u8; 9];
+
+    cshake.update(right_encode(out_bits, &mut buf));
+    cshake.finalize()
+}
+
+/// Absorbs `bytepad(encode_string(strings[0]) ‖ encode_string(strings[1]) ‖ ..., RATE)`: the
+/// encoded strings after `left_encode(RATE)`, zero-padded to a whole number of blocks.
+fn absorb_bytepad<const RATE: usize>(shake: &mut Shake<RATE>, strings: &[&[u8]]) {
+    let mut buf = [0u8; 9];
+    let mut len = 0;
+    let mut absorb = |shake: &mut Shake<RATE>, bytes: &[u8]| {
+        shake.update(bytes);
+        len += bytes.len();
+    };
+
+    absorb(shake, left_encode(RATE as u64, &mut buf));
+
+    for s in strings {
+        absorb(shake, left_encode(s.len() as u64 * 8, &mut buf));
+        absorb(shake, s);
+    }
+
+    shake.update(&[0u8; 200][..(RATE - len % RATE) % RATE]);
+}
+
+/// `left_encode(x)`: the byte length `n` of `x` (at least 1), then `x` on `n` big-endian bytes.
+fn left_encode(x: u64, buf: &mut [u8; 9]) -> &[u8] {
+    let n = encoded_len(x);
+
+    buf[0] = n as u8;
+    buf[1..=n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+
+    &buf[..=n]
+}
+
+/// `right_encode(x)`: `x` on `n` big-endian bytes, then its byte length `n` (at least 1).
+fn right_encode(x: u64, buf: &mut [u8; 9]) -> &[u8] {
+    let n = encoded_len(x);
+
+    buf[..n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+    buf[n] = n as u8;
+
+    &buf[..=n]
+}
+
+#[inline(always)]
+fn encoded_len(x: u64) -> usize {
+    (71 - (x | 1).leading_zeros() as usize) >> 3
+}
@@ -0,0 +1,124 @@
+//! Streaming verification, for messages too large to hold in memory (firmware images, ...).
+//!
+//! [`verify`](crate::falcon512::verify) hashes `nonce ‖ message` as one slice. A
+//! [`StreamVerifier`] absorbs the nonce, then the message chunk by chunk into an incremental
+//! [`Shake256`], and runs the same challenge derivation (`hash_to_point_vartime`) and norm check
+//! (`verify_raw`) on [`finish`](StreamVerifier::finish): any chunking gives the verdict of
+//! [`verify_sig`](crate::falcon512::verify_sig) on the whole message. With the `std` feature,
+//! the verifier is an `std::io::Write` sink and `verify_reader` verifies what a reader yields.
+
+use crate::{
+    constants::{N, NONCE_LEN, SIG_COMP_MAXSIZE},
+    error::Error,
+    falcon512::{comp_decode, norm_report, PreparedPublicKey, Signature, VerifyReport},
+    params::Falcon512,
+    shake256::Shake256,
+};
+
+/// Verifies a Falcon-512 signature on a message supplied in chunks.
+#[derive(Clone)]
+pub struct StreamVerifier {
+    pk_ntt_fmt: [u16; N],
+    s2: [u16; N],
+    shake: Shake256,
+}
+
+impl StreamVerifier {
+    /// Starts verifying a signature in the raw format of [`verify`](crate::falcon512::verify).
+    ///
+    /// # Parameters
+    /// - `pk`: The public key.
+    /// - `sig`: The compressed `s2` (without header and nonce).
+    /// - `nonce`: The signature nonce.
+    ///
+    /// # Returns
+    /// The verifier, or [`Error::BadLength`] if `sig` is empty or longer than
+    /// [`SIG_COMP_MAXSIZE`], [`Error::DecodeFailed`] if it is not exactly a compressed `s2`.
+    pub fn new(
+        pk: &PreparedPublicKey,
+        sig: &[u8],
+        nonce: &[u8; NONCE_LEN as usize],
+    ) -> Result<Self, Error> {
+        if sig.is_empty() || sig.len() > SIG_COMP_MAXSIZE as usize {
+            return Err(Error::BadLength);
+        }
+
+        let (s2, len) = comp_decode(sig);
+
+        if len != sig.len() {
+            return Err(Error::DecodeFailed);
+        }
+
+        Ok(Self::start(pk, s2, nonce))
+    }
+
+    /// Starts verifying a complete signature (any format).
+    pub fn from_signature(pk: &PreparedPublicKey, sig: &Signature) -> Self {
+        let decoded = sig.decode();
+
+        Self::start(pk, decoded.s2, decoded.nonce)
+    }
+
+    fn start(pk: &PreparedPublicKey, s2: [u16; N], nonce: &[u8; NONCE_LEN as usize]) -> Self {
+        let mut shake = Shake256::new();
+
+        shake.update(nonce);
+
+        Self {
+            pk_ntt_fmt: *pk.as_ntt_fmt(),
+            s2,
+            shake,
+        }
+    }
+
+    /// Absorbs the next chunk of the message.
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.shake.update(chunk);
+    }
+
+    /// Completes the verification.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid for the message absorbed, or [`Error::NormTooLarge`].
+    pub fn finish(self) -> Result<(), Error> {
+        self.finish_detailed().result
+    }
+
+    /// [`finish`](Self::finish), with the squared norm of `(s1, s2)` (see [`VerifyReport`]).
+    pub fn finish_detailed(self) -> VerifyReport {
+        norm_report::<Falcon512, N>(&mut self.shake.flip(), &self.s2, &self.pk_ntt_fmt)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for StreamVerifier {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Verifies `sig` on everything `reader` yields, without buffering the message.
+///
+/// # Returns
+/// `Ok(())` if the signature is valid, the read error, or an [`std::io::ErrorKind::InvalidData`]
+/// error wrapping the verification [`Error`] (see [`StreamVerifier::finish`]).
+#[cfg(feature = "std")]
+pub fn verify_reader<R: std::io::Read>(
+    pk: &PreparedPublicKey,
+    sig: &Signature,
+    mut reader: R,
+) -> std::io::Result<()> {
+    let mut verifier = StreamVerifier::from_signature(pk, sig);
+
+    std::io::copy(&mut reader, &mut verifier)?;
+
+    verifier
+        .finish()
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
@@ -0,0 +1,150 @@
+//! Checks `include/falcon512_rs.h` against the C API of `src/capi.rs`, then builds the shared
+//! library (with the `std` feature, for key generation), compiles `tests/capi/test_capi.c` against
+//! it and runs it (with `cc`, or `$CC`, which the benchmark dev-dependencies already need).
+#[cfg(all(test, feature = "capi"))]
+pub mod tests {
+    use std::{ffi::CStr, path::Path, process::Command};
+
+    use crate::{
+        capi::{error_code, FALCON512_RS_error_message, FALCON512_RS_ERR_NULL_POINTER},
+        constants::{FALCON_PK_SIZE, FALCON_SK_SIZE, N, NONCE_LEN, SIG_COMP_MAXSIZE},
+        error::Error,
+    };
+
+    const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+    fn header() -> String {
+        std::fs::read_to_string(format!("{}/include/falcon512_rs.h", ROOT)).unwrap()
+    }
+
+    // value of `#define NAME value` (parentheses stripped)
+    fn define(header: &str, name: &str) -> i64 {
+        header
+            .lines()
+            .find_map(|l| {
+                l.strip_prefix("#define ")?
+                    .strip_prefix(name)?
+                    .strip_prefix(' ')
+            })
+            .unwrap_or_else(|| panic!("{} not defined", name))
+            .trim_matches(|c| c == '(' || c == ')')
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn header_matches_rust() {
+        let h = header();
+
+        assert_eq!(
+            define(&h, "FALCON512_RS_CRYPTO_SECRETKEYBYTES"),
+            FALCON_SK_SIZE as i64
+        );
+        assert_eq!(
+            define(&h, "FALCON512_RS_CRYPTO_PUBLICKEYBYTES"),
+            FALCON_PK_SIZE as i64
+        );
+        assert_eq!(
+            define(&h, "FALCON512_RS_CRYPTO_BYTES"),
+            1 + NONCE_LEN as i64 + SIG_COMP_MAXSIZE as i64
+        );
+        assert_eq!(
+            define(&h, "FALCON512_RS_PREPARED_PUBLICKEY_COEFFS"),
+            N as i64
+        );
+        assert_eq!(
+            define(&h, "FALCON512_RS_ERR_NULL_POINTER"),
+            FALCON512_RS_ERR_NULL_POINTER as i64
+        );
+
+        for (name, err) in [
+            ("BAD_HEADER", Error::BadHeader),
+            ("COEFFICIENT_OUT_OF_RANGE", Error::CoefficientOutOfRange),
+            ("TRAILING_BITS", Error::TrailingBits),
+            ("BAD_LENGTH", Error::BadLength),
+            ("DECODE_FAILED", Error::DecodeFailed),
+            ("NORM_TOO_LARGE", Error::NormTooLarge),
+            ("MALFORMED_DER", Error::MalformedDer),
+            ("MALFORMED_PEM", Error::MalformedPem),
+            ("UNKNOWN_ALGORITHM", Error::UnknownAlgorithm),
+            ("INVALID_SECRET_KEY", Error::InvalidSecretKey),
+            ("KEY_MISMATCH", Error::KeyMismatch),
+            ("ISSUER_MISMATCH", Error::IssuerMismatch),
+        ] {
+            let code = error_code(err);
+            let msg = unsafe { CStr::from_ptr(FALCON512_RS_error_message(code)) };
+
+            assert_eq!(
+                define(&h, &format!("FALCON512_RS_ERR_{}", name)),
+                code as i64
+            );
+            assert_eq!(msg.to_str().unwrap(), err.to_string());
+        }
+
+        // every export is declared
+        let src = std::fs::read_to_string(format!("{}/src/capi.rs", ROOT)).unwrap();
+        let exports = src.lines().filter_map(|l| {
+            let name = l.split("extern \"C\" fn ").nth(1)?;
+
+            Some(&name[..name.find('(')?])
+        });
+
+        for name in exports {
+            assert!(h.contains(&format!("{}(", name)), "{} not declared", name);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn c_program_runs() {
+        let target = format!("{}/target/capi", ROOT);
+        let output = Command::new(env!("CARGO"))
+            .args(["rustc", "--offline", "--quiet", "--lib"])
+            .args(["--crate-type", "cdylib", "--features", "capi,std"])
+            .args(["--manifest-path", &format!("{}/Cargo.toml", ROOT)])
+            .args(["--target-dir", &target])
+            .output()
+            .expect("cargo not found");
+
+        assert!(
+            output.status.success(),
+            "shared library build failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let lib_dir = format!("{}/debug", target);
+        let exe = format!("{}/test_capi", target);
+        let cc = std::env::var("CC").unwrap_or_else(|_| "cc".into());
+        let compiled = Command::new(&cc)
+            .args(["-std=c99", "-Wall", "-Wextra", "-Werror"])
+            .arg(format!("-I{}/include", ROOT))
+            .arg(format!("{}/tests/capi/test_capi.c", ROOT))
+            .arg(format!("-L{}", lib_dir))
+            .arg("-lfalcon512_rs")
+            .arg(format!("-Wl,-rpath,{}", lib_dir))
+            .arg("-o")
+            .arg(&exe)
+            .output()
+            .unwrap_or_else(|_| panic!("{} not found (set CC to the C compiler to use)", cc));
+
+        assert!(
+            compiled.status.success(),
+            "test_capi.c failed to compile:\n{}",
+            String::from_utf8_lossy(&compiled.stderr)
+        );
+
+        // the test harness puts its own build of the library first in the search path
+        let run = Command::new(Path::new(&exe))
+            .env("LD_LIBRARY_PATH", &lib_dir)
+            .env("DYLD_LIBRARY_PATH", &lib_dir)
+            .output()
+            .unwrap();
+
+        assert!(
+            run.status.success(),
+            "test_capi failed:\n{}{}",
+            String::from_utf8_lossy(&run.stdout),
+            String::from_utf8_lossy(&run.stderr)
+        );
+    }
+}
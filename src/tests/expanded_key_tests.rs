@@ -0,0 +1,118 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::{FALCON_EXPANDED_SK_SIZE, N},
+        error::Error,
+        falcon512::{
+            expand_secret_key, expand_secret_key_with, expanded_sk_decode, expanded_sk_encode,
+            keygen, pk_to_ntt_fmt, sign_tree, sign_with, verify, ExpandedSecretKey,
+        },
+        fpr::FprEmu,
+        shake256::Shake256Prng,
+    };
+
+    #[test]
+    fn sign_tree_matches_sign() {
+        let (sk, _) = keygen(&mut Shake256Prng::from_seed(b"expanded key sign"));
+        let esk = expand_secret_key_with::<f64>(&sk);
+
+        for seed in [&b"sign tree 0"[..], b"sign tree 1", b"sign tree 2"] {
+            let msg = [seed, b" message"].concat();
+            let expected = sign_with::<f64>(&sk, &msg, &mut Shake256Prng::from_seed(seed));
+            let actual = sign_tree(&esk, &msg, &mut Shake256Prng::from_seed(seed));
+
+            assert_eq!(actual.0, expected.0);
+            assert_eq!(actual.2, expected.2);
+            assert_eq!(actual.1[..actual.2], expected.1[..expected.2]);
+        }
+    }
+
+    #[test]
+    fn sign_tree_then_verify() {
+        let mut rng = Shake256Prng::from_seed(b"sign tree verify");
+        let (sk, pk) = keygen(&mut rng);
+        let pk_ntt = pk_to_ntt_fmt(&pk).unwrap();
+        let esk = expand_secret_key(&sk);
+
+        for msg in [b"hello falcon".to_vec(), vec![0x5a; 1000]] {
+            let (nonce, sig, len) = sign_tree(&esk, &msg, &mut rng);
+
+            assert!(verify(&[&nonce[..], &msg].concat(), &sig[..len], &pk_ntt));
+        }
+    }
+
+    #[test]
+    fn emulated_expanded_key_matches_native() {
+        let (sk, _) = keygen(&mut Shake256Prng::from_seed(b"expanded key emu"));
+        let native = expanded_sk_encode(&expand_secret_key_with::<f64>(&sk));
+        let emulated = expanded_sk_encode(&expand_secret_key_with::<FprEmu>(&sk));
+
+        assert_eq!(native, emulated);
+
+        let esk = expanded_sk_decode::<FprEmu>(&emulated).unwrap();
+        let a = sign_tree(&esk, b"msg", &mut Shake256Prng::from_seed(b"emu"));
+        let b = sign_with::<f64>(&sk, b"msg", &mut Shake256Prng::from_seed(b"emu"));
+
+        assert_eq!(a.1[..a.2], b.1[..b.2]);
+    }
+
+    #[test]
+    fn expanded_sk_round_trip() {
+        let (sk, _) = keygen(&mut Shake256Prng::from_seed(b"expanded key codec"));
+        let bytes = expanded_sk_encode(&expand_secret_key_with::<f64>(&sk));
+        let decoded: ExpandedSecretKey<f64> = expanded_sk_decode(&bytes).unwrap();
+
+        assert_eq!(bytes.len(), FALCON_EXPANDED_SK_SIZE);
+        assert_eq!(bytes[0], 0x69);
+        assert_eq!(expanded_sk_encode(&decoded), bytes);
+
+        let a = sign_tree(&decoded, b"msg", &mut Shake256Prng::from_seed(b"rt"));
+        let b = sign_with::<f64>(&sk, b"msg", &mut Shake256Prng::from_seed(b"rt"));
+
+        assert_eq!(a.1[..a.2], b.1[..b.2]);
+    }
+
+    fn encoded_expanded_key() -> [u8; FALCON_EXPANDED_SK_SIZE] {
+        let (sk, _) = keygen(&mut Shake256Prng::from_seed(b"expanded key reject"));
+
+        expanded_sk_encode(&expand_secret_key_with::<f64>(&sk))
+    }
+
+    #[test]
+    fn expanded_sk_rejects_bad_header() {
+        let mut bytes = encoded_expanded_key();
+
+        bytes[0] = 0x59;
+
+        assert_eq!(
+            expanded_sk_decode::<f64>(&bytes).err(),
+            Some(Error::InvalidSecretKey)
+        );
+    }
+
+    #[test]
+    fn expanded_sk_rejects_nan() {
+        let mut bytes = encoded_expanded_key();
+
+        bytes[1..9].copy_from_slice(&f64::NAN.to_bits().to_le_bytes());
+
+        assert_eq!(
+            expanded_sk_decode::<f64>(&bytes).err(),
+            Some(Error::InvalidSecretKey)
+        );
+    }
+
+    #[test]
+    fn expanded_sk_rejects_negative_leaf() {
+        let mut bytes = encoded_expanded_key();
+        // the first leaf follows the basis and the l10 of each level (N, N/2, ..., 1 values)
+        let leaf = 1 + 8 * (4 * N + 2 * N - 1);
+
+        bytes[leaf + 7] |= 0x80;
+
+        assert_eq!(
+            expanded_sk_decode::<f64>(&bytes).err(),
+            Some(Error::InvalidSecretKey)
+        );
+    }
+}
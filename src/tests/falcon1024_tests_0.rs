@@ -0,0 +1,1936 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        error::Error,
+        falcon1024::{
+            comp_decode, comp_encode, pk_from_ntt_fmt, pk_to_ntt_fmt, sig_decode, verify,
+            verify_detailed, verify_sig,
+        },
+        falcon512::{trim_i16_encode, SigFormat},
+        tests::test_utils::verify_distance_1024,
+    };
+
+    #[test]
+    pub fn nist_test_verify_0() {
+        let nonce_msg = [
+            51, 179, 192, 117, 7, 228, 32, 23, 72, 73, 77, 131, 43, 110, 226, 166, 201, 59, 255,
+            155, 14, 227, 67, 181, 80, 209, 248, 90, 61, 13, 224, 215, 4, 198, 209, 120, 66, 149,
+            19, 9, 216, 28, 77, 141, 115, 79, 203, 251, 234, 222, 61, 63, 138, 3, 159, 170, 42, 44,
+            153, 87, 232, 53, 173, 85, 178, 46, 117, 191, 87, 187, 85, 106, 200,
+        ];
+        let sig = [
+            180, 154, 91, 33, 105, 108, 137, 84, 99, 234, 220, 104, 190, 19, 41, 62, 242, 187, 54,
+            54, 141, 31, 145, 110, 221, 109, 237, 221, 23, 237, 127, 39, 6, 30, 97, 229, 74, 145,
+            146, 141, 52, 216, 253, 219, 101, 175, 66, 44, 211, 108, 44, 145, 44, 81, 145, 157, 39,
+            141, 57, 195, 89, 109, 198, 25, 71, 64, 50, 16, 169, 235, 151, 69, 105, 179, 90, 190,
+            209, 148, 136, 152, 68, 163, 103, 5, 231, 231, 63, 151, 159, 158, 111, 251, 178, 226,
+            17, 191, 82, 66, 169, 163, 30, 38, 213, 1, 27, 194, 214, 201, 25, 238, 52, 174, 4, 140,
+            172, 154, 237, 77, 38, 97, 104, 143, 66, 109, 22, 127, 27, 108, 96, 136, 118, 21, 140,
+            150, 165, 83, 139, 206, 126, 122, 70, 170, 169, 10, 40, 193, 205, 164, 24, 206, 143,
+            210, 94, 106, 44, 52, 143, 222, 37, 132, 25, 159, 119, 53, 92, 77, 239, 219, 164, 161,
+            189, 244, 236, 185, 218, 246, 50, 82, 126, 98, 151, 24, 221, 203, 113, 115, 72, 10, 5,
+            67, 53, 156, 238, 232, 228, 15, 153, 25, 18, 40, 89, 184, 137, 166, 10, 62, 190, 145,
+            39, 97, 73, 11, 138, 94, 249, 82, 234, 9, 50, 82, 172, 242, 169, 2, 130, 233, 97, 134,
+            221, 205, 40, 60, 139, 102, 57, 202, 102, 89, 2, 89, 129, 38, 114, 14, 56, 209, 217,
+            169, 226, 32, 38, 208, 46, 100, 34, 22, 151, 64, 181, 117, 116, 105, 29, 47, 52, 159,
+            70, 229, 160, 98, 242, 175, 13, 123, 95, 54, 111, 112, 185, 94, 43, 33, 82, 123, 37,
+            17, 126, 68, 134, 215, 156, 32, 165, 8, 160, 41, 89, 74, 225, 6, 67, 168, 215, 205,
+            108, 96, 203, 201, 152, 131, 110, 141, 74, 133, 15, 53, 142, 253, 164, 196, 233, 2,
+            239, 124, 167, 212, 196, 186, 158, 68, 246, 213, 175, 215, 138, 218, 145, 15, 81, 132,
+            154, 152, 246, 203, 79, 2, 81, 12, 186, 179, 209, 87, 54, 86, 253, 21, 9, 132, 220, 20,
+            233, 179, 63, 191, 218, 254, 76, 57, 165, 139, 195, 191, 217, 175, 126, 143, 166, 221,
+            244, 124, 94, 185, 236, 94, 252, 153, 186, 217, 229, 242, 8, 107, 108, 89, 59, 62, 36,
+            157, 109, 99, 168, 134, 129, 110, 51, 246, 105, 30, 99, 28, 226, 83, 203, 202, 172,
+            206, 173, 202, 254, 111, 167, 58, 217, 232, 77, 137, 199, 33, 153, 68, 142, 162, 208,
+            146, 180, 174, 49, 134, 207, 237, 74, 231, 99, 69, 8, 81, 177, 78, 180, 72, 201, 16,
+            52, 104, 189, 80, 164, 46, 86, 105, 34, 116, 170, 220, 209, 18, 73, 84, 20, 113, 62,
+            119, 201, 211, 229, 16, 41, 13, 209, 61, 140, 111, 57, 235, 214, 241, 42, 196, 182, 28,
+            216, 20, 29, 4, 103, 238, 141, 42, 190, 91, 112, 108, 171, 26, 199, 229, 152, 188, 86,
+            252, 228, 69, 182, 222, 122, 76, 243, 41, 164, 173, 46, 106, 166, 127, 209, 201, 244,
+            187, 207, 252, 111, 137, 143, 229, 109, 204, 252, 67, 226, 208, 39, 154, 199, 204, 135,
+            47, 25, 97, 254, 134, 183, 106, 74, 130, 151, 180, 242, 150, 221, 10, 66, 88, 183, 155,
+            71, 179, 95, 206, 218, 242, 226, 65, 27, 108, 1, 32, 162, 164, 121, 22, 178, 65, 33,
+            227, 211, 33, 196, 253, 33, 46, 84, 202, 175, 45, 170, 78, 116, 61, 19, 190, 196, 118,
+            158, 180, 137, 173, 130, 252, 165, 108, 222, 36, 73, 201, 29, 187, 212, 216, 205, 39,
+            104, 157, 47, 119, 91, 38, 41, 20, 41, 231, 158, 29, 244, 243, 133, 169, 79, 175, 216,
+            52, 200, 181, 35, 133, 11, 247, 183, 112, 84, 45, 110, 33, 175, 59, 194, 136, 100, 92,
+            57, 223, 219, 203, 133, 103, 155, 46, 51, 96, 129, 109, 94, 194, 70, 230, 208, 12, 163,
+            150, 95, 74, 252, 238, 138, 147, 205, 216, 51, 83, 18, 125, 225, 147, 118, 248, 100,
+            144, 84, 42, 50, 89, 84, 201, 33, 140, 252, 220, 62, 63, 156, 227, 68, 59, 223, 179,
+            202, 200, 170, 44, 219, 254, 151, 102, 56, 71, 141, 40, 76, 90, 214, 122, 187, 59, 133,
+            127, 153, 75, 118, 72, 207, 169, 173, 251, 99, 5, 217, 74, 81, 102, 90, 152, 154, 105,
+            242, 223, 106, 70, 4, 255, 213, 164, 150, 70, 194, 45, 169, 228, 106, 200, 128, 255,
+            209, 183, 88, 124, 217, 168, 150, 186, 226, 202, 166, 106, 169, 251, 36, 102, 86, 49,
+            174, 123, 72, 198, 177, 205, 2, 207, 196, 177, 242, 116, 240, 7, 69, 33, 155, 119, 88,
+            155, 22, 92, 133, 24, 19, 91, 237, 163, 237, 121, 49, 222, 122, 53, 140, 251, 50, 48,
+            118, 43, 130, 127, 229, 37, 135, 21, 72, 130, 56, 51, 139, 74, 63, 24, 112, 204, 231,
+            89, 84, 156, 197, 74, 116, 54, 80, 147, 111, 176, 244, 88, 226, 13, 251, 232, 154, 42,
+            93, 103, 197, 32, 105, 157, 62, 74, 214, 226, 206, 23, 8, 196, 145, 9, 214, 113, 217,
+            153, 165, 51, 119, 152, 174, 93, 229, 48, 51, 149, 107, 152, 36, 48, 88, 157, 206, 243,
+            15, 173, 152, 97, 143, 87, 41, 118, 234, 65, 102, 204, 42, 220, 11, 22, 246, 85, 28,
+            106, 92, 55, 131, 11, 233, 130, 21, 234, 138, 46, 151, 37, 62, 41, 86, 113, 29, 77,
+            225, 63, 175, 209, 65, 132, 59, 188, 40, 168, 212, 75, 203, 253, 82, 61, 154, 166, 64,
+            85, 136, 236, 9, 206, 67, 90, 104, 68, 223, 11, 130, 104, 180, 57, 7, 181, 120, 182,
+            31, 76, 76, 101, 98, 161, 181, 110, 154, 27, 116, 211, 209, 117, 41, 129, 43, 148, 244,
+            157, 152, 180, 45, 211, 75, 159, 14, 156, 113, 37, 19, 125, 60, 189, 50, 108, 163, 83,
+            133, 49, 63, 81, 150, 237, 198, 151, 185, 187, 32, 74, 228, 41, 141, 223, 159, 40, 97,
+            179, 244, 69, 254, 198, 168, 251, 106, 140, 44, 252, 113, 17, 120, 185, 134, 79, 50,
+            14, 78, 16, 137, 100, 237, 28, 182, 238, 148, 174, 247, 34, 250, 174, 54, 166, 139,
+            196, 189, 163, 4, 57, 81, 87, 148, 248, 129, 163, 151, 189, 120, 42, 84, 50, 33, 141,
+            37, 49, 38, 46, 198, 181, 97, 13, 227, 213, 107, 71, 222, 95, 202, 130, 193, 37, 26,
+            102, 98, 33, 205, 116, 123, 249, 13, 30, 87, 251, 174, 73, 32, 221, 234, 105, 168, 67,
+            32, 189, 185, 203, 50, 95, 227, 171, 18, 249, 125, 144, 48, 133, 7, 14, 159, 194, 160,
+            84, 137, 243, 54, 196, 51, 207, 151, 13, 147, 114, 53, 21, 46, 202, 137, 84, 142, 229,
+            81, 175, 143, 66, 25, 72, 194, 86, 31, 7, 243, 237, 230, 188, 185, 219, 74, 172, 21,
+            20, 136, 98, 187, 102, 89, 246, 215, 161, 84, 56, 243, 152, 129, 36, 143, 43, 199, 173,
+            57, 120, 1, 184, 148, 70, 246, 205, 221, 98, 254, 86, 105, 108, 124, 188, 100, 115,
+            233, 90, 141, 3, 197, 115, 224,
+        ];
+        let pk = [
+            10, 4, 65, 169, 183, 63, 73, 77, 22, 85, 102, 128, 177, 43, 15, 68, 106, 101, 39, 0,
+            228, 48, 65, 81, 188, 49, 6, 131, 196, 63, 32, 171, 40, 73, 47, 245, 128, 112, 128,
+            104, 250, 6, 66, 117, 193, 176, 208, 132, 82, 252, 124, 50, 65, 84, 146, 156, 168, 80,
+            212, 230, 243, 66, 91, 15, 20, 148, 117, 161, 68, 104, 199, 64, 190, 152, 66, 210, 193,
+            187, 185, 62, 32, 1, 244, 32, 32, 104, 208, 96, 193, 170, 159, 153, 165, 246, 126, 134,
+            128, 15, 46, 42, 72, 252, 233, 90, 30, 159, 87, 10, 18, 212, 161, 27, 34, 172, 184,
+            103, 22, 251, 110, 187, 69, 182, 206, 16, 32, 231, 244, 78, 66, 48, 16, 55, 19, 236,
+            52, 96, 85, 212, 7, 201, 105, 96, 93, 159, 118, 203, 139, 47, 10, 242, 187, 225, 172,
+            31, 74, 39, 128, 9, 38, 111, 222, 234, 10, 250, 218, 37, 152, 227, 106, 73, 46, 11, 64,
+            234, 225, 37, 57, 164, 177, 228, 77, 21, 13, 71, 193, 146, 217, 137, 92, 160, 141, 30,
+            145, 210, 78, 83, 92, 109, 100, 144, 3, 140, 98, 144, 69, 145, 117, 8, 202, 129, 94,
+            20, 244, 1, 244, 169, 165, 193, 94, 1, 18, 4, 208, 18, 208, 187, 113, 135, 106, 189,
+            90, 140, 117, 169, 79, 50, 254, 6, 40, 40, 157, 180, 102, 74, 150, 180, 94, 73, 77, 37,
+            40, 234, 144, 120, 26, 48, 152, 232, 218, 215, 111, 213, 131, 168, 144, 239, 239, 174,
+            134, 30, 129, 93, 194, 104, 148, 236, 89, 101, 254, 143, 56, 156, 20, 236, 215, 123,
+            32, 50, 124, 68, 178, 2, 203, 222, 43, 69, 102, 185, 247, 58, 2, 47, 160, 100, 27, 248,
+            28, 170, 183, 14, 130, 32, 101, 182, 31, 94, 159, 201, 25, 35, 141, 234, 248, 11, 164,
+            193, 114, 109, 213, 12, 100, 46, 57, 218, 218, 19, 236, 137, 53, 233, 147, 106, 149,
+            118, 111, 253, 248, 104, 196, 217, 93, 178, 193, 166, 112, 151, 34, 92, 70, 78, 250,
+            168, 222, 5, 216, 6, 188, 94, 71, 247, 150, 67, 24, 1, 66, 213, 239, 83, 168, 142, 126,
+            6, 195, 100, 165, 152, 119, 156, 4, 131, 11, 8, 230, 145, 4, 149, 249, 147, 138, 241,
+            147, 172, 84, 151, 15, 237, 141, 182, 150, 0, 18, 86, 69, 31, 145, 57, 108, 103, 241,
+            169, 15, 141, 93, 81, 186, 156, 169, 11, 33, 122, 143, 39, 220, 132, 64, 150, 68, 143,
+            117, 177, 44, 66, 139, 208, 255, 41, 132, 96, 15, 149, 185, 214, 1, 206, 202, 249, 103,
+            198, 160, 98, 163, 153, 171, 31, 182, 125, 161, 16, 35, 158, 115, 158, 97, 149, 168,
+            17, 69, 159, 33, 180, 87, 15, 108, 7, 125, 248, 88, 85, 12, 79, 237, 144, 114, 64, 68,
+            42, 204, 254, 81, 149, 190, 246, 140, 44, 149, 117, 110, 136, 147, 120, 208, 95, 126,
+            222, 114, 35, 174, 39, 97, 141, 106, 145, 16, 94, 140, 100, 146, 217, 172, 179, 5, 38,
+            172, 163, 89, 118, 52, 63, 212, 108, 18, 132, 164, 103, 88, 84, 187, 68, 233, 220, 235,
+            50, 73, 158, 166, 164, 244, 82, 221, 89, 64, 11, 240, 150, 23, 91, 6, 12, 21, 229, 237,
+            80, 27, 235, 178, 74, 156, 12, 169, 109, 213, 243, 72, 246, 110, 39, 72, 141, 240, 184,
+            149, 69, 105, 228, 107, 150, 164, 9, 173, 178, 209, 172, 226, 56, 137, 225, 122, 234,
+            37, 50, 136, 197, 69, 244, 139, 130, 193, 43, 41, 86, 224, 156, 0, 141, 69, 92, 147,
+            20, 95, 99, 131, 72, 80, 35, 20, 235, 39, 29, 146, 76, 237, 59, 79, 94, 159, 189, 61,
+            16, 179, 206, 166, 119, 139, 80, 97, 33, 20, 14, 226, 84, 20, 236, 86, 165, 206, 5,
+            122, 36, 34, 234, 116, 192, 160, 33, 53, 40, 34, 231, 100, 54, 99, 100, 71, 49, 122,
+            18, 29, 74, 253, 37, 65, 0, 138, 153, 123, 21, 243, 162, 152, 222, 117, 135, 170, 220,
+            144, 59, 166, 68, 168, 89, 236, 64, 163, 216, 215, 82, 84, 203, 165, 129, 33, 115, 128,
+            249, 92, 51, 164, 213, 20, 185, 70, 203, 87, 58, 80, 184, 25, 248, 112, 42, 53, 2, 150,
+            69, 176, 8, 235, 8, 222, 241, 133, 82, 231, 6, 244, 239, 241, 71, 201, 59, 104, 61,
+            237, 189, 106, 124, 164, 24, 59, 210, 245, 171, 56, 144, 213, 179, 44, 71, 128, 190,
+            32, 84, 235, 21, 29, 24, 45, 84, 165, 2, 87, 111, 57, 88, 153, 198, 213, 72, 201, 22,
+            180, 189, 5, 142, 17, 98, 67, 136, 125, 86, 196, 98, 169, 166, 22, 171, 226, 130, 4,
+            237, 90, 26, 50, 57, 201, 133, 146, 100, 81, 59, 2, 193, 31, 12, 48, 201, 118, 193,
+            246, 130, 91, 177, 82, 232, 212, 164, 33, 41, 167, 49, 55, 3, 23, 36, 50, 35, 34, 183,
+            146, 134, 100, 195, 44, 172, 208, 218, 122, 41, 252, 135, 200, 8, 162, 160, 206, 145,
+            148, 66, 75, 7, 124, 30, 239, 84, 53, 95, 3, 245, 10, 135, 8, 137, 134, 130, 117, 219,
+            213, 38, 140, 83, 178, 201, 133, 75, 187, 105, 255, 18, 247, 93, 17, 52, 56, 223, 58,
+            111, 18, 151, 84, 202, 118, 34, 176, 102, 237, 91, 69, 100, 38, 108, 224, 17, 165, 128,
+            75, 123, 225, 197, 226, 77, 225, 225, 113, 152, 72, 147, 106, 153, 120, 192, 20, 143,
+            8, 178, 230, 16, 9, 12, 153, 88, 93, 50, 54, 149, 170, 218, 26, 51, 90, 117, 144, 247,
+            238, 80, 31, 40, 77, 245, 253, 28, 117, 126, 76, 155, 146, 234, 175, 115, 127, 32, 2,
+            107, 41, 147, 81, 53, 12, 138, 168, 193, 6, 13, 120, 97, 49, 80, 18, 197, 32, 17, 142,
+            39, 234, 8, 144, 202, 119, 66, 5, 20, 94, 231, 36, 76, 129, 30, 208, 210, 169, 207,
+            154, 204, 195, 197, 160, 28, 148, 180, 128, 203, 210, 180, 31, 183, 181, 1, 133, 9, 68,
+            194, 196, 137, 8, 158, 234, 158, 198, 99, 156, 154, 17, 57, 183, 86, 196, 11, 161, 32,
+            250, 218, 144, 76, 124, 6, 119, 42, 19, 24, 88, 174, 41, 134, 194, 39, 142, 81, 38, 33,
+            94, 99, 21, 145, 80, 94, 241, 255, 40, 30, 32, 27, 189, 20, 157, 122, 172, 162, 146,
+            109, 140, 187, 39, 41, 170, 153, 119, 230, 121, 245, 222, 98, 161, 56, 237, 252, 154,
+            209, 31, 9, 169, 132, 230, 112, 78, 92, 175, 63, 100, 81, 1, 14, 211, 218, 181, 224,
+            208, 53, 115, 24, 117, 67, 252, 198, 122, 173, 109, 134, 187, 86, 19, 131, 6, 222, 121,
+            129, 238, 76, 103, 107, 25, 160, 172, 189, 160, 23, 251, 20, 1, 75, 30, 11, 212, 203,
+            217, 137, 165, 10, 157, 3, 239, 33, 247, 93, 182, 49, 4, 239, 7, 192, 79, 148, 118, 22,
+            125, 71, 236, 163, 16, 69, 23, 191, 141, 192, 11, 1, 143, 145, 120, 67, 124, 104, 16,
+            231, 21, 174, 96, 54, 132, 117, 80, 84, 100, 158, 95, 142, 186, 43, 51, 124, 40, 174,
+            55, 118, 116, 241, 43, 2, 180, 40, 92, 201, 209, 236, 31, 69, 154, 232, 141, 212, 72,
+            111, 48, 168, 252, 127, 227, 213, 166, 172, 132, 166, 219, 5, 109, 5, 220, 3, 93, 225,
+            203, 41, 137, 11, 116, 208, 94, 244, 67, 45, 228, 81, 108, 9, 131, 254, 25, 101, 160,
+            1, 215, 55, 199, 222, 45, 136, 93, 211, 214, 54, 225, 183, 137, 140, 158, 203, 106,
+            158, 167, 166, 161, 91, 74, 24, 210, 161, 160, 244, 200, 119, 236, 1, 147, 10, 117, 34,
+            51, 104, 168, 42, 34, 181, 10, 118, 129, 216, 137, 112, 222, 18, 152, 95, 152, 120,
+            101, 245, 165, 137, 140, 213, 35, 112, 18, 61, 99, 138, 234, 179, 120, 41, 181, 171,
+            177, 218, 140, 41, 137, 238, 83, 42, 229, 56, 83, 89, 115, 176, 34, 73, 16, 51, 22,
+            125, 81, 196, 106, 6, 182, 225, 124, 49, 131, 236, 166, 91, 117, 21, 248, 101, 213, 48,
+            143, 253, 141, 105, 133, 85, 82, 92, 246, 215, 150, 83, 89, 127, 78, 70, 209, 38, 230,
+            214, 127, 20, 37, 25, 241, 65, 10, 220, 105, 88, 155, 35, 22, 93, 15, 135, 234, 197,
+            247, 222, 79, 60, 19, 209, 75, 100, 59, 96, 138, 50, 217, 128, 209, 37, 86, 126, 156,
+            173, 30, 176, 149, 196, 196, 187, 5, 213, 169, 177, 238, 204, 62, 154, 173, 65, 116,
+            24, 40, 65, 241, 232, 198, 34, 4, 17, 110, 113, 159, 243, 71, 78, 70, 99, 173, 169,
+            134, 220, 160, 140, 53, 1, 98, 41, 139, 72, 139, 170, 221, 179, 118, 29, 37, 206, 81,
+            20, 250, 182, 76, 151, 158, 95, 205, 174, 106, 2, 78, 247, 168, 6, 121, 162, 65, 90,
+            172, 50, 68, 8, 35, 35, 99, 209, 34, 133, 221, 51, 166, 144, 179, 32, 81, 117, 230,
+            199, 90, 133, 179, 104, 248, 177, 254, 91, 187, 2, 234, 250, 98, 76, 97, 147, 139, 194,
+            248, 5, 233, 77, 0, 26, 170, 144, 230, 162, 238, 136, 82, 248, 43, 87, 61, 9, 82, 77,
+            174, 214, 73, 51, 160, 57, 24, 200, 126, 3, 187, 197, 249, 164, 52, 147, 8, 102, 110,
+            131, 49, 140, 150, 138, 132, 134, 200, 167, 34, 177, 57, 140, 132, 41, 169, 129, 154,
+            123, 245, 9, 87, 57, 150, 156, 3, 190, 173, 247, 147, 122, 93, 250, 22, 220, 124, 68,
+            168, 227, 211, 85, 144, 10, 125, 64, 137, 165, 211, 0, 187, 105, 12, 216, 99, 59, 77,
+            227, 102, 112, 217, 55, 73, 151, 160, 48, 158, 17, 118, 48, 19, 28, 178, 105, 244, 177,
+            239, 158, 241, 41, 128, 192, 243, 244, 14, 100, 35, 197, 71, 184, 193, 66, 160, 77, 77,
+            84, 160, 5, 66, 98, 119, 104, 135, 53, 136, 97, 34, 141, 16, 82, 217, 249, 96, 168,
+            119, 248, 158, 11, 135, 104, 195, 7, 198, 135, 166, 131, 148, 31, 169, 164, 115, 17,
+            15, 135, 150, 108, 181, 106, 129, 175, 148, 201, 140, 97, 71, 64, 201, 69, 57, 153,
+            166, 208, 211, 177, 45, 227, 97, 173, 115, 117, 235, 211, 2, 45, 194, 183, 98, 106, 40,
+            106, 99, 184, 68, 137, 71, 202, 204,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 59500586);
+        assert_eq!(pk_from_ntt_fmt(&pk_ntt_fmt).unwrap(), pk);
+
+        let report = verify_detailed(&nonce_msg, &sig, &pk_ntt_fmt);
+
+        assert_eq!(report.result, Ok(()));
+        assert_eq!(report.norm, Some(59500586));
+        assert_eq!(report.bound, 70265242);
+
+        assert!(verify(&nonce_msg, &sig, &pk_ntt_fmt));
+
+        let mut tampered = sig;
+
+        tampered[100] ^= 0x10;
+        assert!(!verify(&nonce_msg, &tampered, &pk_ntt_fmt));
+
+        let mut other_msg = nonce_msg;
+
+        other_msg[50] ^= 1;
+        assert!(!verify(&other_msg, &sig, &pk_ntt_fmt));
+
+        // complete signatures: compressed (0x3a) and constant-time (0x5a)
+        let (nonce, msg) = nonce_msg.split_at(40);
+        let comp = [&[0x3a][..], nonce, &sig].concat();
+
+        assert_eq!(sig_decode(&comp).unwrap().format, SigFormat::Compressed);
+        assert_eq!(verify_sig(msg, &comp, &pk_ntt_fmt), Ok(()));
+
+        let mut ct = [&[0x5a][..], nonce, &[0u8; 1536]].concat();
+        let (s2, _) = comp_decode(&sig);
+        let mut reencoded = [0u8; 1421];
+
+        assert_eq!(comp_encode(&s2, &mut reencoded), sig.len());
+        assert_eq!(reencoded[..sig.len()], sig);
+
+        assert_eq!(trim_i16_encode(&s2, 12, &mut ct, 41), 1536);
+        assert_eq!(ct.len(), 1577);
+        assert_eq!(verify_sig(msg, &ct, &pk_ntt_fmt), Ok(()));
+
+        // Falcon-512 header
+        let mut wrong_logn = comp;
+
+        wrong_logn[0] = 0x39;
+        assert_eq!(
+            verify_sig(msg, &wrong_logn, &pk_ntt_fmt),
+            Err(Error::BadHeader)
+        );
+    }
+
+    #[test]
+    pub fn nist_test_verify_1() {
+        let nonce_msg = [
+            8, 226, 85, 56, 72, 76, 215, 241, 97, 50, 72, 254, 108, 159, 107, 78, 193, 75, 230,
+            132, 198, 222, 253, 209, 228, 19, 51, 182, 233, 5, 42, 196, 52, 14, 49, 78, 234, 44,
+            153, 247, 34, 93, 92, 226, 206, 172, 97, 147, 10, 7, 80, 63, 181, 159, 124, 47, 147,
+            106, 62, 7, 84, 129, 218, 60, 162, 153, 168, 15, 140, 93, 249, 34, 58, 7, 62, 123, 144,
+            224, 46, 191, 152, 202, 34, 39, 235, 163, 140, 26, 178, 86, 130, 9, 228, 109, 186, 150,
+            24, 105, 198, 248, 57, 131, 177, 125, 205, 73,
+        ];
+        let sig = [
+            187, 56, 220, 97, 133, 175, 242, 227, 123, 139, 218, 196, 80, 213, 166, 185, 46, 179,
+            238, 97, 141, 70, 1, 160, 207, 158, 120, 172, 15, 49, 210, 62, 220, 107, 126, 178, 2,
+            187, 166, 95, 173, 70, 47, 47, 26, 105, 46, 125, 202, 174, 121, 55, 169, 194, 113, 184,
+            51, 22, 166, 62, 21, 244, 133, 212, 130, 23, 184, 250, 152, 144, 93, 85, 62, 140, 222,
+            47, 132, 114, 88, 95, 138, 113, 58, 39, 44, 28, 153, 255, 92, 172, 147, 216, 90, 189,
+            220, 124, 177, 240, 175, 190, 30, 184, 195, 154, 115, 54, 133, 51, 81, 5, 235, 231,
+            164, 225, 54, 216, 102, 203, 188, 146, 60, 82, 172, 34, 205, 82, 182, 190, 152, 131,
+            211, 176, 197, 153, 150, 77, 163, 222, 48, 233, 57, 142, 158, 65, 207, 170, 109, 98,
+            163, 51, 228, 186, 204, 176, 218, 164, 90, 143, 141, 30, 89, 116, 196, 59, 178, 140,
+            229, 168, 174, 81, 95, 196, 58, 235, 139, 33, 37, 225, 244, 109, 173, 53, 250, 216,
+            201, 45, 255, 26, 134, 138, 112, 156, 243, 239, 151, 30, 31, 205, 160, 235, 92, 210,
+            132, 67, 128, 163, 244, 164, 205, 185, 20, 109, 219, 62, 220, 26, 251, 64, 55, 24, 180,
+            177, 226, 206, 151, 53, 211, 103, 52, 246, 212, 160, 101, 93, 6, 76, 169, 10, 131, 48,
+            194, 161, 8, 90, 197, 11, 154, 8, 149, 211, 75, 129, 125, 98, 229, 53, 59, 222, 28,
+            119, 20, 71, 126, 241, 213, 95, 137, 13, 179, 81, 186, 218, 59, 61, 77, 118, 130, 204,
+            59, 169, 51, 137, 90, 207, 77, 195, 74, 179, 53, 174, 116, 186, 139, 22, 129, 83, 21,
+            172, 179, 240, 196, 36, 136, 110, 211, 134, 177, 17, 248, 197, 45, 15, 200, 99, 87,
+            116, 165, 51, 65, 73, 114, 48, 171, 95, 48, 105, 74, 163, 120, 74, 57, 50, 89, 103, 83,
+            134, 148, 249, 39, 202, 20, 103, 34, 133, 176, 233, 34, 155, 183, 89, 226, 25, 83, 12,
+            36, 80, 68, 135, 181, 225, 219, 156, 60, 240, 157, 58, 237, 60, 254, 114, 154, 142,
+            164, 139, 63, 85, 171, 35, 69, 47, 35, 60, 137, 161, 200, 253, 26, 103, 14, 122, 89,
+            58, 196, 203, 102, 88, 180, 212, 122, 162, 15, 168, 123, 247, 189, 157, 222, 67, 229,
+            70, 237, 58, 120, 151, 198, 27, 108, 12, 223, 105, 180, 48, 217, 45, 213, 218, 255,
+            194, 194, 16, 203, 178, 183, 111, 230, 65, 226, 234, 182, 127, 211, 140, 189, 192, 227,
+            220, 228, 5, 45, 116, 43, 78, 174, 86, 93, 170, 118, 234, 182, 62, 11, 201, 37, 197,
+            70, 88, 28, 210, 248, 219, 218, 185, 147, 138, 29, 246, 113, 141, 152, 78, 124, 156, 7,
+            43, 161, 230, 250, 203, 20, 104, 27, 193, 107, 95, 182, 177, 232, 0, 145, 105, 112,
+            156, 252, 60, 9, 150, 168, 229, 58, 8, 131, 110, 249, 225, 215, 25, 106, 58, 232, 76,
+            188, 219, 234, 210, 101, 60, 69, 175, 54, 11, 50, 187, 61, 254, 102, 91, 204, 114, 56,
+            97, 93, 84, 170, 234, 206, 34, 47, 86, 235, 66, 180, 201, 203, 50, 98, 168, 157, 211,
+            8, 229, 99, 14, 186, 127, 5, 241, 194, 211, 70, 129, 178, 246, 104, 109, 153, 75, 109,
+            177, 205, 218, 139, 195, 205, 163, 49, 59, 124, 24, 199, 193, 212, 68, 8, 237, 245,
+            127, 172, 98, 55, 229, 70, 183, 171, 174, 186, 74, 96, 229, 107, 63, 99, 181, 31, 223,
+            41, 206, 113, 44, 38, 38, 193, 199, 194, 12, 163, 107, 179, 87, 54, 206, 222, 65, 84,
+            78, 241, 60, 71, 252, 129, 76, 184, 219, 150, 19, 144, 128, 138, 124, 41, 36, 119, 34,
+            43, 25, 229, 76, 86, 135, 107, 122, 152, 119, 228, 108, 74, 0, 72, 212, 191, 100, 193,
+            19, 36, 76, 98, 5, 201, 131, 185, 101, 225, 247, 151, 254, 45, 77, 235, 157, 148, 73,
+            50, 216, 29, 227, 37, 212, 181, 168, 12, 212, 153, 42, 79, 60, 71, 225, 197, 72, 33,
+            219, 123, 66, 153, 11, 27, 37, 133, 12, 70, 44, 82, 99, 73, 102, 66, 198, 153, 127,
+            114, 141, 16, 67, 19, 248, 171, 103, 129, 123, 172, 123, 15, 87, 83, 108, 94, 90, 169,
+            78, 250, 158, 219, 235, 177, 124, 55, 4, 177, 96, 201, 201, 237, 150, 10, 82, 149, 65,
+            185, 211, 40, 61, 31, 156, 213, 107, 136, 71, 82, 166, 109, 147, 189, 34, 244, 94, 0,
+            115, 233, 168, 164, 154, 174, 72, 91, 76, 155, 105, 174, 48, 185, 50, 155, 239, 176,
+            32, 211, 253, 56, 212, 41, 135, 105, 176, 160, 32, 57, 68, 23, 203, 32, 88, 101, 47,
+            137, 69, 244, 203, 240, 99, 143, 33, 113, 155, 44, 189, 125, 139, 87, 92, 159, 154, 41,
+            156, 141, 57, 205, 220, 230, 68, 189, 75, 160, 173, 178, 84, 69, 140, 155, 141, 18,
+            236, 36, 49, 216, 107, 81, 205, 156, 254, 21, 249, 43, 41, 92, 99, 90, 187, 229, 11,
+            149, 52, 219, 200, 226, 246, 227, 111, 75, 148, 174, 238, 196, 221, 193, 174, 244, 156,
+            73, 133, 117, 220, 62, 196, 101, 161, 231, 55, 82, 244, 30, 0, 141, 221, 179, 148, 87,
+            101, 78, 106, 119, 200, 115, 235, 205, 79, 224, 132, 1, 187, 129, 145, 237, 172, 82,
+            100, 35, 46, 171, 38, 102, 28, 105, 167, 79, 247, 2, 151, 19, 133, 223, 14, 132, 216,
+            24, 202, 166, 204, 134, 185, 132, 5, 142, 129, 146, 111, 220, 85, 16, 78, 91, 200, 92,
+            227, 121, 181, 131, 229, 183, 229, 217, 204, 205, 181, 221, 21, 49, 181, 104, 143, 130,
+            178, 174, 246, 10, 98, 71, 58, 101, 218, 155, 247, 59, 2, 222, 167, 15, 15, 233, 238,
+            171, 209, 15, 228, 99, 104, 233, 37, 35, 45, 220, 139, 177, 206, 188, 234, 221, 192,
+            32, 228, 150, 76, 94, 204, 153, 128, 66, 91, 174, 101, 110, 148, 228, 31, 226, 241,
+            146, 35, 216, 184, 10, 163, 149, 242, 99, 206, 163, 60, 141, 42, 61, 229, 209, 218,
+            113, 204, 23, 102, 162, 67, 71, 138, 17, 215, 108, 53, 119, 244, 221, 209, 147, 216,
+            57, 116, 143, 77, 169, 208, 106, 55, 42, 207, 92, 104, 147, 159, 254, 147, 193, 176,
+            27, 172, 130, 64, 158, 226, 27, 172, 36, 50, 154, 150, 141, 178, 233, 132, 76, 51, 205,
+            9, 222, 227, 142, 219, 29, 191, 138, 248, 215, 26, 13, 239, 188, 141, 76, 92, 19, 98,
+            197, 181, 13, 73, 45, 74, 173, 178, 54, 106, 195, 49, 175, 71, 113, 81, 204, 135, 11,
+            104, 44, 24, 244, 247, 180, 153, 246, 233, 216, 207, 74, 35, 0, 105, 224, 109, 156, 81,
+            43, 214, 75, 185, 238, 210, 141, 175, 182, 16, 12, 8, 68, 55, 16, 228, 137, 170, 50,
+            207, 11, 9, 175, 195, 47, 111, 127, 65, 128, 66, 54, 124, 37, 18, 135, 204, 81, 146,
+            185, 77, 60, 208, 218, 76, 220, 222, 244, 181, 247, 209, 197, 53, 9, 239, 228, 221,
+            116,
+        ];
+        let pk = [
+            10, 61, 20, 142, 24, 252, 28, 49, 58, 254, 173, 98, 228, 221, 175, 99, 153, 246, 186,
+            92, 70, 241, 143, 237, 115, 149, 82, 204, 97, 69, 1, 43, 131, 71, 213, 183, 78, 92, 27,
+            17, 148, 215, 140, 166, 201, 129, 231, 130, 7, 90, 171, 10, 138, 70, 198, 134, 51, 71,
+            166, 67, 187, 214, 11, 19, 168, 212, 231, 67, 162, 88, 235, 154, 204, 61, 27, 93, 81,
+            77, 155, 226, 23, 99, 72, 70, 38, 109, 54, 52, 23, 186, 233, 140, 7, 17, 70, 24, 212,
+            205, 183, 120, 52, 210, 133, 32, 201, 140, 148, 28, 191, 154, 5, 172, 210, 2, 254, 252,
+            193, 28, 103, 41, 56, 113, 113, 178, 45, 195, 218, 171, 104, 16, 145, 158, 87, 92, 177,
+            222, 107, 10, 57, 173, 74, 151, 118, 212, 25, 10, 144, 59, 173, 225, 251, 193, 250, 68,
+            81, 155, 149, 28, 198, 45, 109, 86, 126, 109, 112, 113, 182, 200, 167, 130, 69, 93,
+            134, 188, 192, 149, 112, 38, 45, 181, 235, 196, 231, 183, 113, 109, 74, 10, 145, 8, 84,
+            45, 98, 139, 22, 134, 63, 124, 225, 209, 67, 226, 69, 59, 46, 108, 0, 27, 191, 143, 71,
+            120, 210, 99, 133, 12, 102, 234, 77, 117, 223, 214, 71, 92, 236, 88, 20, 159, 72, 72,
+            157, 16, 131, 41, 174, 150, 199, 138, 127, 109, 134, 186, 100, 29, 168, 200, 18, 113,
+            157, 90, 205, 168, 230, 4, 198, 76, 70, 211, 173, 243, 20, 226, 100, 199, 179, 231,
+            242, 23, 213, 35, 123, 85, 164, 101, 175, 20, 229, 130, 233, 197, 222, 71, 157, 195,
+            217, 142, 20, 71, 90, 198, 127, 150, 209, 141, 151, 63, 65, 19, 171, 249, 134, 17, 14,
+            181, 245, 179, 65, 65, 209, 184, 42, 213, 171, 40, 174, 234, 124, 94, 6, 193, 35, 4,
+            41, 83, 176, 121, 245, 70, 38, 93, 157, 45, 62, 132, 232, 170, 13, 60, 36, 138, 235,
+            213, 211, 85, 16, 128, 17, 225, 147, 192, 232, 112, 2, 78, 170, 70, 23, 239, 33, 117,
+            22, 50, 122, 166, 140, 3, 18, 186, 170, 240, 193, 253, 46, 154, 167, 91, 227, 79, 186,
+            110, 149, 129, 148, 222, 204, 106, 103, 122, 187, 119, 147, 165, 203, 174, 217, 160,
+            168, 253, 1, 208, 18, 202, 192, 165, 178, 42, 180, 179, 56, 62, 75, 32, 210, 122, 144,
+            34, 40, 83, 31, 235, 180, 130, 162, 74, 91, 9, 12, 2, 11, 245, 235, 249, 60, 227, 106,
+            27, 238, 68, 235, 123, 192, 17, 156, 188, 43, 67, 178, 90, 219, 109, 192, 46, 106, 167,
+            231, 101, 62, 36, 125, 201, 207, 238, 237, 38, 92, 7, 1, 83, 146, 255, 64, 50, 74, 148,
+            127, 35, 5, 146, 110, 153, 200, 247, 164, 93, 24, 72, 22, 85, 221, 231, 224, 176, 2,
+            216, 210, 112, 46, 235, 61, 86, 126, 89, 96, 137, 161, 106, 45, 243, 82, 229, 67, 187,
+            38, 12, 119, 225, 238, 162, 133, 19, 90, 17, 209, 1, 164, 158, 27, 134, 119, 10, 52,
+            171, 240, 142, 37, 183, 167, 228, 201, 35, 140, 132, 22, 144, 227, 216, 225, 149, 181,
+            96, 179, 214, 91, 144, 39, 254, 116, 198, 50, 0, 28, 15, 53, 241, 36, 237, 65, 148, 67,
+            241, 69, 185, 93, 27, 242, 123, 242, 118, 55, 138, 105, 220, 28, 152, 186, 37, 232,
+            236, 110, 76, 162, 91, 88, 74, 135, 8, 179, 82, 102, 23, 30, 165, 139, 85, 9, 150, 38,
+            171, 2, 255, 3, 73, 156, 87, 139, 162, 168, 91, 225, 39, 187, 249, 83, 59, 20, 120,
+            195, 40, 28, 156, 39, 165, 109, 214, 250, 80, 192, 151, 62, 12, 62, 67, 137, 43, 185,
+            128, 2, 231, 20, 114, 1, 192, 45, 148, 65, 71, 240, 231, 164, 99, 41, 126, 119, 254,
+            168, 156, 29, 57, 73, 52, 129, 67, 228, 11, 177, 218, 138, 252, 148, 247, 177, 241, 50,
+            119, 1, 152, 0, 144, 141, 59, 129, 80, 211, 20, 106, 192, 229, 119, 28, 38, 232, 48,
+            12, 3, 182, 125, 81, 219, 180, 182, 86, 38, 163, 82, 174, 25, 98, 95, 245, 96, 45, 194,
+            168, 220, 138, 230, 125, 171, 115, 15, 218, 40, 19, 32, 43, 223, 164, 44, 244, 164,
+            139, 143, 98, 24, 188, 171, 118, 11, 217, 97, 69, 244, 121, 170, 78, 218, 246, 155, 32,
+            115, 1, 78, 26, 115, 127, 153, 66, 1, 15, 154, 85, 129, 241, 18, 212, 77, 95, 8, 154,
+            28, 190, 10, 117, 90, 58, 176, 142, 102, 233, 17, 21, 145, 16, 29, 219, 86, 54, 145,
+            75, 173, 34, 59, 56, 60, 2, 219, 129, 189, 7, 244, 199, 213, 83, 70, 109, 32, 196, 245,
+            95, 176, 174, 9, 6, 19, 24, 109, 51, 39, 68, 233, 185, 5, 237, 128, 142, 172, 191, 242,
+            32, 144, 159, 168, 9, 184, 57, 118, 230, 19, 126, 217, 154, 247, 203, 218, 168, 187,
+            22, 39, 68, 99, 215, 8, 122, 44, 132, 244, 112, 107, 137, 53, 77, 184, 162, 156, 231,
+            39, 93, 106, 250, 80, 164, 206, 99, 36, 118, 236, 54, 161, 206, 144, 182, 26, 50, 39,
+            137, 86, 161, 177, 108, 40, 201, 76, 235, 2, 65, 52, 18, 83, 63, 227, 58, 57, 112, 135,
+            99, 91, 88, 64, 57, 232, 104, 25, 228, 135, 72, 47, 26, 107, 107, 223, 0, 51, 103, 189,
+            26, 181, 118, 81, 9, 112, 29, 133, 105, 239, 197, 37, 127, 169, 26, 163, 122, 222, 30,
+            25, 244, 73, 97, 105, 35, 187, 75, 237, 32, 194, 54, 20, 90, 148, 88, 154, 170, 153,
+            154, 55, 172, 54, 247, 176, 130, 46, 207, 67, 157, 92, 153, 163, 165, 241, 164, 241,
+            43, 9, 5, 70, 190, 161, 200, 193, 146, 235, 102, 175, 37, 226, 212, 197, 194, 198, 61,
+            81, 0, 3, 250, 131, 110, 126, 41, 57, 181, 19, 185, 39, 138, 165, 174, 170, 83, 65,
+            233, 115, 188, 1, 172, 91, 189, 161, 86, 127, 130, 195, 244, 79, 143, 169, 132, 121,
+            140, 129, 123, 208, 188, 14, 32, 39, 89, 196, 170, 202, 5, 138, 248, 134, 122, 139,
+            194, 176, 118, 114, 73, 112, 102, 55, 137, 217, 209, 250, 110, 85, 208, 170, 112, 8,
+            132, 174, 84, 146, 154, 21, 95, 240, 124, 210, 147, 170, 245, 149, 118, 91, 102, 18,
+            125, 178, 229, 198, 81, 41, 180, 138, 173, 56, 5, 253, 140, 141, 124, 112, 234, 7, 20,
+            214, 237, 160, 73, 166, 105, 187, 193, 164, 7, 233, 123, 148, 253, 7, 75, 13, 234, 168,
+            35, 228, 144, 138, 174, 156, 35, 179, 106, 133, 4, 159, 186, 152, 250, 58, 198, 83,
+            136, 229, 63, 56, 107, 40, 168, 100, 185, 214, 138, 50, 75, 158, 133, 253, 244, 200,
+            238, 157, 27, 185, 52, 54, 187, 93, 23, 161, 44, 125, 23, 106, 82, 20, 14, 77, 150, 24,
+            216, 81, 209, 120, 180, 166, 237, 49, 119, 33, 100, 60, 249, 132, 75, 38, 18, 116, 180,
+            253, 110, 242, 145, 31, 179, 15, 114, 134, 53, 125, 20, 129, 26, 42, 250, 162, 65, 132,
+            124, 46, 136, 142, 248, 37, 152, 131, 175, 229, 135, 38, 179, 110, 105, 116, 194, 250,
+            2, 37, 128, 36, 92, 133, 158, 139, 54, 239, 41, 90, 202, 180, 90, 214, 203, 5, 171, 34,
+            0, 129, 52, 15, 223, 75, 226, 251, 40, 38, 227, 35, 184, 93, 251, 88, 127, 232, 31,
+            117, 110, 124, 224, 14, 165, 234, 79, 138, 136, 4, 229, 24, 243, 11, 196, 110, 50, 23,
+            153, 202, 185, 164, 108, 105, 190, 89, 74, 149, 230, 156, 102, 76, 34, 171, 78, 116,
+            65, 194, 64, 221, 109, 187, 88, 222, 85, 39, 157, 177, 130, 0, 120, 35, 116, 110, 255,
+            149, 189, 11, 184, 31, 82, 113, 153, 216, 24, 129, 3, 109, 203, 95, 134, 128, 168, 100,
+            138, 221, 102, 204, 156, 115, 113, 133, 88, 69, 170, 133, 20, 194, 161, 53, 139, 204,
+            185, 123, 28, 80, 26, 149, 133, 194, 72, 242, 93, 157, 88, 180, 221, 129, 122, 97, 21,
+            65, 213, 28, 250, 221, 231, 245, 21, 196, 43, 121, 44, 161, 169, 49, 148, 122, 213, 36,
+            79, 20, 34, 152, 55, 88, 230, 98, 85, 55, 142, 153, 101, 181, 234, 135, 169, 128, 161,
+            152, 96, 175, 249, 37, 245, 205, 25, 137, 142, 173, 176, 246, 191, 33, 95, 32, 234, 24,
+            98, 194, 202, 92, 84, 175, 84, 57, 89, 3, 242, 213, 55, 56, 116, 160, 180, 190, 134,
+            221, 130, 36, 116, 155, 52, 29, 85, 1, 156, 2, 119, 151, 214, 30, 24, 197, 48, 90, 35,
+            86, 32, 82, 58, 81, 19, 97, 129, 244, 55, 164, 166, 139, 80, 145, 7, 170, 150, 87, 19,
+            71, 89, 43, 157, 110, 29, 179, 95, 192, 219, 147, 52, 161, 131, 18, 105, 217, 85, 108,
+            236, 82, 246, 215, 56, 50, 56, 220, 5, 36, 194, 228, 81, 244, 149, 121, 110, 37, 65,
+            116, 60, 1, 27, 10, 235, 125, 67, 100, 213, 104, 150, 70, 236, 74, 22, 80, 180, 8, 16,
+            125, 71, 238, 193, 83, 144, 10, 155, 146, 64, 211, 161, 44, 23, 243, 110, 184, 138, 18,
+            59, 91, 189, 4, 81, 233, 240, 7, 38, 118, 166, 202, 16, 2, 143, 136, 23, 82, 118, 10,
+            180, 150, 227, 194, 110, 102, 196, 120, 166, 19, 75, 28, 232, 15, 241, 228, 41, 161,
+            122, 86, 199, 251, 23, 29, 124, 146, 113, 159, 144, 40, 23, 96, 135, 93, 198, 216, 26,
+            230, 209, 145, 192, 45, 249, 171, 39, 152, 125, 22, 134, 146, 210, 67, 215, 186, 213,
+            156, 40, 165, 26, 70, 85, 65, 116, 75, 38, 192, 81, 16, 98, 69, 154, 157, 66, 117, 110,
+            161, 199, 115, 55, 32, 227, 148, 36, 93, 130, 252, 162, 133, 69, 214, 218, 100, 72, 42,
+            187, 176, 97, 189, 229, 180, 137, 84, 179, 60, 34, 202, 85, 19, 97, 69, 158, 69, 72,
+            117, 164, 60, 3, 162, 248, 153, 98, 233, 127, 173, 230, 212, 169, 41, 226, 200, 7, 202,
+            38, 49, 198, 192, 197, 168, 121, 56, 227, 141, 144, 86, 177, 14, 156, 81, 165, 96, 209,
+            72, 153, 245, 186, 12, 80, 250, 179, 178, 139, 225, 205, 93, 212, 48, 92, 184, 149, 34,
+            72, 153, 170, 9, 230, 165, 78, 88, 220,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 59842484);
+    }
+
+    #[test]
+    pub fn nist_test_verify_2() {
+        let nonce_msg = [
+            135, 166, 112, 75, 29, 202, 60, 218, 84, 114, 80, 219, 202, 28, 148, 164, 40, 156, 141,
+            97, 230, 166, 202, 169, 70, 64, 151, 130, 249, 252, 48, 92, 177, 245, 37, 127, 155,
+            204, 104, 3, 43, 140, 75, 15, 41, 54, 62, 174, 228, 105, 167, 227, 53, 36, 83, 138,
+            160, 102, 174, 152, 152, 14, 170, 25, 209, 241, 5, 147, 32, 61, 162, 20, 59, 158, 158,
+            25, 115, 247, 255, 14, 108, 106, 170, 60, 11, 144, 14, 80, 208, 3, 65, 46, 254, 150,
+            222, 236, 227, 4, 109, 140, 70, 188, 119, 9, 34, 135, 137, 119, 90, 189, 245, 106, 237,
+            100, 22, 201, 0, 51, 120, 12, 183, 164, 152, 72, 21, 218, 27, 20, 102, 13, 207, 52,
+            170, 52, 191, 130, 206, 187, 207,
+        ];
+        let sig = [
+            180, 172, 166, 220, 36, 229, 136, 69, 202, 23, 205, 254, 139, 127, 215, 89, 221, 187,
+            195, 237, 200, 214, 40, 206, 67, 99, 102, 107, 225, 255, 30, 70, 94, 15, 34, 209, 165,
+            202, 253, 130, 75, 161, 119, 163, 215, 122, 140, 90, 247, 206, 55, 24, 116, 253, 226,
+            148, 31, 242, 36, 218, 74, 33, 198, 142, 224, 234, 250, 176, 92, 46, 115, 13, 22, 33,
+            5, 186, 225, 63, 69, 58, 150, 110, 71, 187, 179, 28, 73, 141, 146, 214, 223, 232, 22,
+            54, 13, 70, 35, 42, 10, 240, 120, 162, 173, 229, 166, 238, 227, 159, 137, 26, 2, 139,
+            94, 55, 127, 8, 18, 247, 0, 44, 229, 33, 199, 87, 182, 250, 136, 179, 95, 249, 125,
+            164, 184, 125, 54, 14, 78, 72, 62, 85, 215, 129, 35, 115, 63, 100, 228, 115, 209, 166,
+            162, 81, 8, 27, 147, 11, 193, 241, 202, 144, 8, 164, 237, 28, 80, 89, 205, 139, 137,
+            13, 137, 163, 104, 47, 115, 3, 47, 78, 39, 49, 93, 161, 94, 196, 190, 216, 85, 124,
+            191, 82, 89, 134, 79, 78, 207, 150, 180, 188, 158, 115, 176, 98, 218, 246, 231, 245,
+            203, 127, 135, 68, 255, 208, 88, 2, 54, 223, 108, 211, 148, 33, 3, 37, 116, 213, 42,
+            67, 51, 202, 4, 135, 5, 241, 240, 81, 119, 102, 19, 29, 157, 146, 127, 140, 182, 47,
+            179, 11, 143, 199, 89, 8, 138, 120, 71, 77, 114, 217, 23, 114, 185, 207, 108, 137, 33,
+            193, 143, 254, 145, 46, 146, 45, 209, 73, 23, 106, 14, 173, 43, 144, 205, 182, 7, 57,
+            58, 136, 176, 55, 153, 153, 165, 129, 22, 245, 165, 91, 135, 79, 232, 219, 131, 150,
+            180, 82, 58, 109, 159, 45, 125, 206, 107, 33, 173, 189, 69, 8, 178, 61, 42, 196, 46,
+            152, 242, 80, 190, 157, 82, 7, 33, 132, 151, 74, 17, 78, 107, 103, 104, 132, 225, 62,
+            194, 226, 74, 214, 37, 216, 67, 102, 155, 82, 16, 160, 242, 78, 133, 23, 6, 199, 124,
+            9, 239, 109, 12, 74, 148, 13, 17, 47, 157, 124, 154, 29, 52, 73, 162, 134, 200, 214,
+            78, 167, 194, 165, 158, 96, 202, 214, 28, 81, 206, 150, 43, 190, 196, 182, 252, 239,
+            43, 4, 71, 116, 187, 206, 166, 114, 222, 170, 16, 164, 148, 219, 26, 76, 206, 241, 97,
+            113, 213, 137, 7, 7, 209, 19, 89, 52, 10, 60, 16, 250, 224, 181, 31, 101, 237, 108,
+            151, 39, 241, 230, 133, 90, 246, 188, 185, 210, 6, 209, 39, 123, 102, 41, 252, 109,
+            198, 225, 233, 166, 213, 188, 136, 52, 69, 11, 97, 78, 77, 178, 170, 197, 52, 178, 171,
+            221, 106, 225, 146, 54, 60, 42, 251, 220, 231, 216, 71, 136, 220, 34, 182, 27, 58, 132,
+            213, 228, 96, 124, 76, 17, 71, 19, 196, 125, 196, 233, 74, 197, 1, 150, 65, 241, 58,
+            141, 11, 108, 205, 149, 189, 46, 169, 2, 138, 175, 136, 236, 159, 141, 223, 214, 106,
+            95, 231, 93, 176, 126, 94, 169, 5, 56, 78, 171, 158, 10, 165, 9, 75, 155, 56, 117, 251,
+            147, 129, 3, 38, 126, 101, 190, 82, 82, 184, 158, 137, 79, 231, 41, 156, 92, 18, 117,
+            121, 75, 56, 126, 61, 117, 94, 239, 36, 174, 176, 5, 29, 38, 143, 22, 27, 156, 16, 231,
+            62, 140, 42, 27, 52, 168, 82, 211, 118, 70, 28, 69, 36, 31, 94, 169, 77, 149, 71, 123,
+            13, 98, 173, 50, 195, 33, 235, 252, 185, 68, 69, 242, 219, 154, 157, 217, 29, 245, 229,
+            19, 78, 27, 195, 234, 224, 192, 92, 108, 3, 68, 78, 105, 98, 89, 248, 94, 63, 20, 54,
+            136, 177, 79, 39, 45, 36, 113, 121, 137, 212, 69, 253, 212, 167, 84, 121, 233, 226, 48,
+            200, 45, 38, 165, 156, 230, 153, 243, 9, 251, 90, 87, 190, 63, 55, 241, 13, 38, 217,
+            53, 59, 73, 138, 49, 231, 127, 72, 163, 203, 237, 48, 237, 219, 135, 184, 129, 237,
+            209, 150, 31, 109, 203, 92, 32, 45, 36, 61, 195, 217, 43, 69, 58, 15, 147, 66, 84, 28,
+            31, 38, 127, 165, 176, 168, 83, 175, 164, 93, 141, 117, 151, 119, 202, 249, 133, 106,
+            141, 85, 119, 10, 218, 179, 210, 149, 183, 163, 5, 78, 60, 48, 116, 212, 166, 231, 68,
+            25, 201, 238, 145, 2, 240, 249, 97, 152, 115, 224, 136, 217, 92, 206, 145, 7, 47, 145,
+            246, 230, 134, 196, 39, 139, 42, 118, 49, 148, 41, 142, 141, 23, 105, 168, 16, 229, 90,
+            45, 168, 73, 169, 56, 87, 235, 46, 249, 51, 244, 244, 135, 151, 255, 227, 56, 62, 90,
+            6, 47, 38, 124, 235, 142, 21, 82, 217, 227, 86, 171, 15, 182, 83, 21, 170, 226, 155,
+            173, 185, 211, 140, 73, 90, 24, 165, 158, 110, 142, 126, 36, 119, 152, 57, 55, 246, 85,
+            11, 218, 239, 157, 140, 126, 221, 163, 136, 171, 182, 242, 164, 32, 121, 22, 180, 158,
+            64, 149, 162, 240, 37, 151, 207, 36, 158, 230, 76, 11, 150, 138, 77, 86, 105, 46, 210,
+            171, 113, 105, 149, 90, 115, 57, 8, 89, 58, 107, 111, 233, 181, 142, 97, 233, 74, 156,
+            223, 89, 40, 228, 170, 43, 109, 124, 197, 156, 53, 78, 78, 99, 9, 186, 83, 243, 46,
+            140, 3, 68, 103, 44, 127, 4, 107, 194, 83, 210, 101, 50, 131, 134, 58, 73, 65, 158, 31,
+            172, 97, 165, 86, 60, 132, 8, 168, 106, 209, 39, 14, 105, 61, 225, 195, 118, 86, 115,
+            249, 197, 78, 112, 163, 243, 197, 193, 178, 104, 58, 125, 200, 225, 95, 161, 240, 133,
+            27, 109, 142, 193, 17, 194, 7, 151, 52, 199, 75, 100, 110, 17, 24, 26, 124, 115, 178,
+            220, 154, 254, 141, 126, 79, 176, 9, 54, 153, 172, 47, 211, 39, 41, 0, 199, 161, 63,
+            218, 158, 153, 52, 160, 196, 31, 14, 65, 33, 12, 252, 183, 73, 59, 63, 26, 52, 118, 7,
+            116, 205, 114, 237, 10, 68, 194, 233, 26, 198, 19, 2, 42, 100, 86, 125, 26, 125, 99,
+            64, 243, 137, 253, 205, 147, 41, 172, 241, 15, 58, 113, 74, 189, 55, 213, 174, 96, 217,
+            111, 4, 79, 165, 184, 156, 235, 223, 157, 101, 174, 6, 135, 163, 44, 250, 219, 143,
+            122, 107, 118, 232, 12, 172, 250, 71, 89, 56, 176, 150, 97, 126, 105, 180, 241, 84,
+            112, 168, 196, 50, 117, 215, 77, 22, 107, 60, 1, 175, 236, 41, 191, 170, 164, 201, 185,
+            242, 71, 72, 54, 95, 87, 56, 109, 72, 37, 49, 3, 202, 203, 82, 4, 4, 160, 152, 249, 25,
+            1, 185, 47, 175, 98, 227, 41, 216, 240, 24, 87, 130, 98, 153, 119, 220, 51, 119, 66,
+            196, 226, 170, 184, 196, 217, 27, 157, 241, 20, 199, 44, 209, 73, 149, 135, 239, 203,
+            203, 142, 252, 32, 85, 73, 194, 140, 70, 99, 18, 223, 92, 234, 173, 138, 210, 207, 42,
+            135, 54, 28, 97, 25, 108, 219, 155, 194, 125, 239, 202, 125, 232, 161, 188, 180, 221,
+            5, 226,
+        ];
+        let pk = [
+            10, 76, 233, 161, 197, 64, 225, 194, 90, 145, 57, 123, 243, 64, 165, 104, 102, 26, 53,
+            95, 150, 17, 19, 152, 211, 233, 247, 238, 41, 169, 26, 205, 4, 162, 224, 210, 77, 254,
+            41, 192, 246, 203, 192, 96, 148, 147, 23, 207, 87, 249, 170, 0, 153, 242, 105, 212, 13,
+            86, 214, 107, 130, 2, 78, 97, 137, 77, 162, 73, 216, 231, 101, 8, 169, 66, 165, 150,
+            83, 2, 104, 197, 193, 179, 92, 173, 250, 84, 237, 210, 224, 53, 70, 197, 122, 202, 165,
+            171, 22, 42, 87, 78, 184, 111, 203, 161, 32, 240, 191, 145, 135, 148, 210, 100, 75, 7,
+            36, 124, 9, 197, 164, 158, 140, 110, 212, 214, 172, 223, 111, 133, 133, 82, 186, 18,
+            145, 217, 47, 94, 73, 226, 230, 37, 252, 48, 244, 179, 196, 173, 93, 222, 20, 66, 82,
+            229, 145, 23, 113, 78, 246, 66, 240, 131, 5, 60, 106, 48, 14, 233, 74, 137, 200, 128,
+            253, 206, 134, 24, 154, 154, 81, 145, 15, 193, 198, 206, 74, 34, 206, 116, 241, 27,
+            228, 111, 141, 97, 80, 212, 195, 17, 75, 53, 224, 243, 196, 246, 72, 174, 175, 52, 204,
+            87, 228, 195, 24, 21, 41, 142, 35, 135, 225, 47, 111, 181, 242, 171, 124, 33, 76, 163,
+            209, 234, 234, 4, 78, 172, 17, 166, 46, 96, 64, 67, 117, 39, 221, 241, 129, 112, 226,
+            200, 97, 162, 54, 41, 123, 92, 197, 42, 149, 232, 116, 222, 24, 76, 27, 24, 70, 226,
+            165, 214, 229, 14, 38, 86, 192, 33, 102, 83, 82, 180, 248, 84, 128, 15, 119, 12, 129,
+            112, 183, 15, 229, 122, 177, 158, 93, 65, 231, 171, 4, 67, 138, 162, 66, 67, 211, 39,
+            4, 53, 173, 128, 62, 111, 37, 151, 250, 247, 84, 157, 106, 254, 147, 252, 165, 67, 3,
+            85, 108, 74, 240, 19, 42, 130, 130, 67, 37, 129, 7, 98, 144, 73, 183, 28, 126, 185,
+            242, 236, 1, 100, 105, 25, 72, 148, 150, 79, 75, 132, 98, 157, 64, 27, 235, 127, 69,
+            253, 69, 97, 28, 45, 105, 36, 114, 225, 201, 132, 95, 121, 89, 41, 120, 23, 2, 80, 145,
+            94, 238, 71, 120, 128, 234, 119, 24, 28, 167, 245, 76, 195, 130, 92, 109, 217, 198,
+            214, 31, 165, 156, 228, 255, 202, 216, 27, 74, 50, 100, 160, 23, 114, 149, 50, 64, 208,
+            160, 101, 88, 70, 125, 54, 153, 55, 49, 47, 103, 154, 117, 21, 189, 218, 28, 152, 240,
+            0, 161, 196, 241, 237, 203, 189, 121, 57, 1, 19, 22, 204, 166, 114, 77, 116, 22, 164,
+            137, 39, 105, 237, 207, 54, 213, 84, 64, 25, 48, 87, 98, 192, 149, 49, 81, 241, 65, 53,
+            203, 82, 167, 174, 25, 101, 27, 118, 28, 2, 173, 140, 203, 166, 108, 228, 180, 117, 1,
+            239, 9, 0, 195, 9, 11, 202, 25, 152, 189, 203, 210, 33, 192, 178, 195, 181, 203, 47,
+            56, 164, 93, 55, 228, 42, 68, 61, 202, 22, 213, 58, 83, 74, 148, 238, 90, 224, 168, 65,
+            165, 74, 226, 99, 122, 116, 103, 7, 85, 186, 183, 227, 185, 228, 95, 25, 56, 61, 161,
+            70, 175, 201, 150, 161, 153, 82, 185, 36, 40, 152, 234, 169, 147, 68, 174, 162, 7, 188,
+            186, 90, 160, 137, 156, 191, 156, 185, 209, 62, 235, 122, 103, 132, 3, 147, 130, 147,
+            52, 36, 22, 153, 123, 210, 83, 141, 139, 69, 134, 10, 205, 192, 187, 134, 4, 66, 51,
+            13, 162, 60, 72, 22, 112, 17, 201, 32, 224, 79, 0, 110, 165, 52, 86, 132, 151, 234,
+            231, 193, 40, 135, 33, 94, 1, 81, 181, 145, 11, 4, 77, 248, 182, 1, 85, 217, 188, 134,
+            64, 184, 208, 247, 162, 161, 47, 9, 178, 37, 188, 90, 0, 8, 112, 120, 89, 118, 84, 155,
+            66, 192, 200, 212, 126, 21, 214, 119, 245, 135, 52, 115, 165, 158, 60, 249, 153, 50,
+            106, 160, 49, 23, 237, 88, 234, 228, 7, 0, 56, 38, 98, 57, 146, 0, 229, 167, 213, 23,
+            53, 235, 146, 134, 185, 5, 184, 150, 91, 164, 177, 19, 28, 42, 105, 64, 170, 32, 31,
+            172, 20, 184, 65, 38, 77, 25, 236, 178, 4, 239, 70, 235, 115, 181, 7, 121, 132, 109,
+            30, 58, 186, 235, 251, 118, 39, 86, 151, 144, 237, 170, 97, 18, 61, 25, 232, 201, 139,
+            105, 11, 78, 137, 104, 100, 199, 53, 45, 6, 40, 22, 78, 214, 91, 221, 211, 2, 200, 253,
+            247, 246, 80, 172, 39, 94, 91, 57, 125, 133, 3, 58, 252, 79, 102, 207, 170, 197, 153,
+            162, 154, 106, 64, 133, 236, 141, 81, 177, 180, 152, 26, 46, 63, 216, 161, 160, 238,
+            81, 36, 61, 249, 159, 244, 61, 200, 36, 78, 197, 133, 151, 167, 196, 89, 140, 253, 105,
+            52, 190, 95, 243, 34, 126, 10, 84, 179, 146, 115, 150, 121, 110, 53, 84, 12, 43, 179,
+            212, 40, 137, 221, 136, 107, 10, 229, 199, 38, 98, 210, 2, 185, 134, 219, 96, 111, 192,
+            127, 180, 134, 46, 113, 105, 238, 71, 125, 86, 148, 70, 4, 221, 188, 5, 161, 21, 138,
+            87, 137, 220, 130, 8, 19, 135, 213, 111, 188, 97, 178, 223, 208, 238, 89, 196, 178, 21,
+            169, 185, 191, 167, 230, 8, 117, 42, 106, 27, 207, 225, 114, 228, 145, 203, 55, 162,
+            148, 16, 241, 219, 235, 218, 98, 193, 61, 118, 191, 66, 244, 231, 2, 132, 104, 176,
+            104, 106, 168, 53, 64, 113, 123, 19, 108, 172, 70, 1, 244, 188, 51, 200, 129, 173, 141,
+            177, 91, 193, 161, 100, 195, 8, 165, 181, 48, 224, 41, 255, 40, 221, 61, 145, 177, 161,
+            220, 109, 46, 151, 199, 34, 105, 138, 36, 170, 135, 180, 57, 66, 244, 121, 230, 36, 17,
+            213, 26, 70, 72, 5, 86, 2, 195, 64, 54, 54, 113, 20, 0, 5, 215, 66, 40, 253, 95, 75,
+            100, 212, 140, 20, 118, 93, 88, 94, 90, 47, 187, 241, 177, 87, 217, 144, 247, 3, 2,
+            235, 134, 170, 146, 157, 93, 70, 227, 176, 137, 110, 200, 100, 128, 206, 68, 196, 135,
+            229, 156, 100, 72, 2, 251, 45, 182, 87, 80, 150, 39, 123, 228, 118, 18, 228, 190, 218,
+            188, 208, 114, 63, 168, 70, 230, 255, 41, 101, 158, 212, 78, 178, 74, 28, 156, 48, 136,
+            0, 72, 200, 30, 152, 59, 161, 110, 114, 133, 26, 80, 18, 214, 58, 209, 65, 14, 30, 109,
+            66, 14, 106, 199, 33, 158, 159, 193, 193, 74, 167, 238, 23, 62, 188, 42, 242, 83, 104,
+            52, 173, 246, 3, 81, 33, 16, 48, 80, 184, 255, 97, 145, 33, 252, 122, 97, 142, 131, 58,
+            42, 66, 23, 190, 239, 119, 243, 232, 60, 53, 133, 108, 89, 151, 74, 40, 76, 244, 163,
+            58, 116, 82, 128, 0, 188, 165, 224, 211, 0, 198, 47, 44, 247, 91, 31, 162, 152, 145,
+            156, 219, 69, 166, 86, 142, 57, 98, 38, 128, 237, 111, 151, 43, 164, 190, 248, 108,
+            197, 151, 117, 12, 219, 180, 166, 72, 99, 60, 41, 40, 228, 168, 110, 101, 88, 86, 159,
+            230, 147, 148, 47, 27, 229, 124, 144, 19, 135, 219, 68, 81, 174, 53, 89, 36, 204, 150,
+            97, 193, 171, 7, 93, 125, 97, 226, 205, 34, 106, 239, 8, 188, 5, 227, 148, 227, 237,
+            186, 154, 40, 154, 245, 63, 199, 226, 12, 13, 0, 6, 135, 217, 164, 133, 190, 139, 128,
+            139, 70, 123, 49, 181, 133, 55, 173, 91, 185, 59, 225, 60, 59, 110, 35, 48, 62, 248,
+            122, 230, 114, 111, 35, 14, 1, 224, 149, 200, 15, 193, 15, 220, 87, 90, 90, 238, 252,
+            123, 78, 167, 121, 45, 88, 88, 190, 5, 29, 226, 86, 205, 227, 133, 18, 215, 209, 159,
+            67, 55, 73, 200, 44, 161, 255, 157, 105, 137, 69, 73, 42, 193, 143, 124, 144, 152, 186,
+            35, 121, 40, 165, 113, 32, 214, 89, 46, 31, 24, 17, 98, 231, 199, 126, 108, 31, 56,
+            164, 190, 4, 61, 36, 190, 108, 93, 132, 48, 207, 98, 102, 96, 66, 125, 195, 140, 94,
+            105, 41, 89, 158, 83, 199, 203, 70, 159, 46, 99, 118, 203, 3, 245, 112, 234, 160, 184,
+            130, 107, 180, 165, 104, 3, 233, 8, 139, 43, 150, 106, 158, 88, 210, 158, 67, 122, 190,
+            139, 177, 182, 5, 55, 148, 86, 42, 71, 23, 207, 219, 14, 130, 86, 71, 245, 44, 243,
+            110, 13, 54, 114, 71, 86, 220, 121, 46, 155, 234, 27, 37, 213, 172, 104, 190, 36, 43,
+            18, 26, 118, 126, 16, 49, 145, 17, 231, 177, 246, 218, 0, 60, 130, 207, 187, 181, 68,
+            144, 188, 209, 60, 151, 212, 37, 73, 27, 215, 228, 166, 169, 129, 49, 100, 99, 60, 136,
+            45, 31, 118, 215, 25, 194, 34, 174, 221, 21, 15, 197, 133, 18, 52, 160, 21, 24, 161,
+            229, 186, 25, 199, 84, 118, 71, 20, 156, 173, 242, 32, 106, 19, 96, 110, 56, 85, 210,
+            193, 102, 172, 86, 249, 36, 35, 209, 211, 31, 116, 17, 75, 119, 167, 154, 185, 148,
+            182, 184, 100, 79, 96, 219, 189, 58, 23, 6, 17, 214, 80, 181, 129, 6, 33, 157, 71, 69,
+            115, 101, 96, 4, 179, 194, 250, 97, 214, 126, 25, 93, 218, 65, 189, 102, 23, 163, 67,
+            169, 151, 64, 116, 82, 130, 182, 82, 2, 33, 188, 68, 193, 92, 20, 89, 97, 128, 97, 99,
+            118, 85, 238, 21, 168, 168, 243, 153, 228, 187, 95, 13, 173, 122, 217, 133, 17, 83,
+            192, 121, 196, 35, 152, 243, 14, 120, 5, 39, 155, 170, 72, 74, 185, 63, 70, 124, 14,
+            29, 168, 125, 1, 232, 100, 16, 248, 66, 242, 133, 180, 50, 142, 106, 10, 204, 177, 53,
+            217, 76, 103, 44, 126, 214, 117, 173, 73, 128, 173, 45, 173, 178, 213, 145, 188, 84,
+            205, 184, 54, 12, 136, 96, 8, 136, 90, 11, 67, 36, 119, 82, 174, 153, 135, 78, 45, 212,
+            54, 185, 41, 151, 161, 46, 247, 104, 30, 130, 22, 214, 85, 199, 105, 188, 244, 192,
+            147, 152, 228, 109, 84, 121, 246, 5, 38, 205, 121, 130, 76, 169, 6, 196, 102, 178, 200,
+            19, 178, 77, 203, 40, 132, 100, 115, 2, 157, 26, 135, 145, 109, 11, 27, 164, 172, 146,
+            105, 12, 170, 26, 41, 108, 35,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 58284048);
+    }
+
+    #[test]
+    pub fn nist_test_verify_3() {
+        let nonce_msg = [
+            70, 120, 32, 27, 53, 123, 13, 45, 173, 232, 99, 160, 160, 160, 77, 12, 2, 31, 235, 176,
+            57, 62, 2, 15, 2, 193, 19, 155, 111, 211, 36, 97, 179, 215, 198, 33, 195, 145, 131,
+            172, 47, 122, 245, 181, 42, 4, 100, 113, 239, 205, 114, 12, 147, 132, 145, 155, 224,
+            90, 97, 205, 232, 232, 176, 18, 81, 197, 171, 136, 94, 130, 15, 211, 110, 217, 255,
+            111, 223, 69, 120, 62, 200, 26, 134, 114, 140, 187, 116, 180, 38, 173, 255, 150, 18,
+            60, 8, 250, 194, 188, 108, 88, 169, 192, 221, 113, 118, 18, 146, 38, 44, 101, 242, 13,
+            244, 119, 81, 240, 131, 23, 112, 166, 187, 123, 55, 96, 187, 127, 94, 255, 251, 110,
+            17, 172, 53, 243, 83, 166, 242, 68, 0, 184, 11, 40, 120, 52, 233, 44, 156, 240, 211,
+            201, 73, 214, 220, 163, 27, 11, 148, 224, 227, 49, 46, 139, 208, 33, 116, 177, 112,
+            194, 202, 147, 85, 254,
+        ];
+        let sig = [
+            156, 66, 120, 186, 134, 63, 63, 89, 138, 42, 150, 244, 250, 135, 89, 78, 61, 221, 148,
+            35, 193, 89, 151, 195, 53, 8, 130, 175, 153, 132, 14, 95, 79, 129, 84, 18, 61, 247,
+            110, 140, 201, 108, 52, 40, 246, 95, 103, 54, 140, 116, 185, 106, 154, 83, 163, 140,
+            38, 243, 232, 217, 50, 255, 181, 42, 139, 10, 167, 148, 93, 62, 153, 105, 240, 232, 99,
+            217, 86, 191, 62, 245, 234, 48, 113, 39, 28, 180, 33, 168, 131, 239, 134, 238, 251,
+            163, 17, 195, 88, 186, 248, 45, 227, 182, 156, 38, 185, 108, 179, 207, 81, 209, 55, 49,
+            33, 198, 155, 110, 203, 211, 147, 236, 164, 248, 216, 76, 11, 15, 68, 89, 88, 228, 67,
+            91, 83, 216, 62, 4, 89, 211, 249, 90, 33, 102, 203, 174, 76, 105, 68, 219, 98, 252, 59,
+            186, 248, 162, 60, 230, 86, 159, 137, 115, 26, 194, 36, 38, 149, 166, 123, 82, 228,
+            129, 36, 238, 127, 125, 79, 4, 129, 145, 246, 21, 203, 75, 61, 144, 154, 25, 164, 183,
+            94, 98, 10, 156, 70, 75, 140, 97, 153, 47, 148, 146, 149, 82, 75, 159, 166, 36, 166,
+            73, 228, 6, 226, 243, 38, 140, 144, 121, 68, 239, 184, 137, 177, 125, 166, 14, 123,
+            180, 97, 111, 58, 117, 197, 117, 185, 67, 26, 210, 152, 129, 44, 90, 230, 18, 113, 20,
+            58, 108, 26, 115, 45, 70, 123, 250, 125, 117, 231, 196, 232, 108, 96, 77, 250, 118, 64,
+            88, 86, 30, 132, 155, 169, 230, 89, 209, 222, 192, 224, 157, 221, 221, 163, 38, 68, 14,
+            161, 86, 133, 151, 72, 188, 2, 46, 176, 176, 6, 234, 37, 220, 199, 33, 24, 220, 187, 5,
+            66, 151, 42, 19, 183, 207, 89, 223, 255, 162, 30, 108, 162, 81, 208, 155, 177, 12, 100,
+            141, 241, 161, 33, 77, 22, 103, 47, 13, 113, 56, 238, 157, 100, 253, 52, 4, 176, 207,
+            36, 231, 126, 26, 78, 188, 185, 19, 25, 198, 243, 74, 232, 98, 250, 167, 239, 63, 74,
+            141, 62, 58, 228, 236, 39, 223, 62, 109, 79, 17, 240, 121, 62, 57, 247, 66, 178, 194,
+            232, 183, 89, 250, 242, 96, 197, 146, 37, 243, 229, 246, 207, 125, 28, 63, 230, 8, 216,
+            52, 194, 61, 61, 205, 66, 147, 223, 146, 176, 129, 201, 95, 200, 151, 158, 99, 20, 76,
+            181, 120, 6, 70, 102, 170, 116, 51, 43, 171, 31, 26, 99, 227, 45, 126, 207, 101, 48,
+            247, 107, 164, 81, 226, 211, 246, 229, 160, 109, 202, 119, 15, 206, 231, 136, 161, 138,
+            91, 168, 61, 25, 182, 94, 237, 28, 141, 39, 195, 108, 188, 64, 212, 94, 236, 123, 29,
+            254, 65, 19, 227, 134, 145, 58, 18, 234, 45, 246, 64, 105, 140, 15, 195, 34, 222, 122,
+            169, 27, 54, 212, 232, 245, 252, 116, 250, 149, 198, 105, 96, 163, 50, 14, 71, 229,
+            146, 165, 41, 16, 51, 214, 79, 85, 36, 199, 166, 72, 27, 137, 233, 250, 195, 121, 98,
+            30, 205, 162, 156, 183, 148, 119, 96, 217, 112, 212, 247, 58, 45, 233, 120, 118, 229,
+            74, 122, 209, 225, 254, 180, 62, 22, 205, 79, 142, 178, 49, 235, 71, 95, 151, 226, 112,
+            117, 183, 79, 207, 251, 52, 44, 18, 211, 248, 189, 185, 63, 172, 74, 136, 198, 84, 35,
+            207, 174, 65, 107, 128, 46, 184, 55, 66, 121, 191, 220, 127, 163, 104, 186, 55, 100,
+            97, 213, 220, 170, 140, 177, 242, 174, 52, 187, 13, 42, 170, 94, 18, 147, 107, 250,
+            209, 86, 34, 37, 211, 53, 134, 236, 63, 240, 157, 249, 73, 219, 226, 204, 192, 218, 10,
+            133, 58, 53, 187, 243, 224, 145, 114, 208, 177, 187, 173, 91, 68, 204, 230, 188, 43,
+            100, 46, 25, 92, 119, 116, 209, 236, 145, 44, 181, 208, 244, 33, 4, 139, 74, 41, 120,
+            104, 203, 77, 77, 133, 80, 9, 206, 217, 75, 105, 85, 183, 202, 254, 65, 202, 197, 197,
+            29, 204, 158, 11, 163, 220, 196, 236, 38, 45, 141, 89, 217, 87, 6, 105, 0, 151, 179,
+            73, 239, 2, 129, 181, 21, 91, 37, 193, 14, 146, 193, 233, 239, 140, 143, 89, 131, 99,
+            198, 79, 101, 115, 75, 34, 108, 97, 26, 150, 42, 208, 216, 161, 48, 219, 99, 197, 55,
+            131, 3, 54, 183, 124, 108, 146, 71, 40, 97, 237, 253, 89, 235, 237, 121, 220, 34, 52,
+            110, 250, 28, 217, 203, 235, 216, 18, 229, 227, 115, 211, 87, 187, 79, 15, 53, 134, 41,
+            80, 72, 103, 74, 132, 233, 43, 82, 87, 50, 148, 242, 25, 42, 44, 233, 100, 159, 233,
+            107, 138, 245, 1, 5, 184, 4, 45, 212, 43, 236, 67, 118, 239, 218, 26, 219, 207, 85, 34,
+            141, 120, 106, 57, 209, 249, 200, 146, 199, 19, 35, 176, 190, 193, 32, 229, 141, 247,
+            105, 73, 43, 133, 140, 159, 192, 27, 92, 194, 150, 216, 187, 88, 74, 66, 177, 98, 181,
+            181, 232, 211, 242, 216, 114, 175, 76, 90, 181, 133, 162, 119, 185, 53, 184, 83, 106,
+            139, 62, 133, 85, 108, 215, 175, 232, 29, 238, 191, 239, 215, 150, 25, 245, 121, 204,
+            43, 56, 55, 238, 186, 167, 169, 8, 141, 166, 189, 12, 64, 87, 58, 247, 133, 44, 116,
+            63, 151, 10, 100, 202, 249, 2, 135, 161, 199, 166, 35, 132, 222, 226, 150, 27, 68, 136,
+            82, 47, 25, 130, 89, 244, 206, 190, 105, 158, 59, 37, 63, 233, 218, 107, 77, 237, 99,
+            43, 60, 97, 79, 178, 253, 115, 114, 165, 176, 182, 30, 63, 169, 172, 250, 49, 189, 7,
+            87, 133, 135, 156, 243, 242, 180, 169, 69, 142, 47, 162, 125, 224, 205, 124, 54, 153,
+            67, 208, 166, 138, 126, 135, 22, 254, 84, 219, 79, 126, 54, 161, 160, 233, 63, 177,
+            231, 99, 125, 88, 200, 27, 187, 118, 111, 195, 50, 139, 120, 240, 235, 78, 99, 203, 35,
+            50, 188, 188, 239, 177, 51, 153, 201, 22, 186, 98, 190, 133, 182, 146, 125, 220, 2,
+            153, 8, 165, 218, 145, 210, 151, 40, 115, 181, 255, 8, 98, 218, 80, 82, 131, 58, 250,
+            71, 33, 88, 190, 20, 238, 238, 157, 57, 107, 146, 154, 64, 71, 227, 149, 20, 127, 92,
+            148, 151, 176, 140, 171, 109, 170, 202, 215, 174, 232, 102, 131, 250, 150, 59, 51, 47,
+            109, 38, 115, 83, 71, 222, 13, 90, 155, 149, 67, 160, 8, 210, 113, 82, 118, 185, 217,
+            26, 6, 210, 24, 63, 151, 82, 40, 110, 234, 14, 201, 199, 114, 220, 218, 139, 8, 241,
+            164, 108, 9, 86, 154, 14, 46, 181, 8, 68, 243, 5, 199, 160, 172, 219, 122, 49, 180,
+            109, 166, 182, 203, 57, 208, 251, 242, 104, 149, 67, 211, 195, 208, 148, 106, 164, 150,
+            136, 118, 155, 27, 166, 147, 103, 244, 214, 251, 139, 187, 115, 237, 231, 184, 28, 219,
+            100, 150, 244, 152, 167, 220, 126, 57, 176, 232, 34, 175, 109, 167, 98, 161, 178, 98,
+            184, 242, 164, 16, 82, 5,
+        ];
+        let pk = [
+            10, 181, 214, 183, 182, 181, 26, 31, 177, 106, 49, 121, 91, 5, 19, 19, 56, 148, 87,
+            205, 221, 33, 132, 57, 45, 234, 66, 225, 21, 78, 177, 135, 145, 249, 86, 21, 101, 5,
+            252, 130, 156, 217, 110, 172, 12, 225, 86, 112, 6, 217, 84, 170, 238, 155, 195, 10,
+            200, 94, 48, 120, 211, 209, 3, 215, 61, 241, 54, 25, 78, 102, 201, 109, 216, 172, 179,
+            227, 80, 179, 73, 116, 196, 9, 200, 162, 253, 156, 118, 124, 154, 211, 89, 162, 47,
+            124, 108, 217, 43, 99, 243, 147, 238, 10, 90, 254, 28, 124, 177, 244, 51, 119, 187,
+            138, 226, 66, 32, 34, 11, 35, 94, 230, 115, 157, 129, 147, 130, 77, 170, 140, 54, 153,
+            65, 106, 194, 167, 25, 164, 37, 36, 170, 133, 217, 52, 177, 200, 213, 119, 67, 17, 47,
+            158, 205, 57, 139, 39, 117, 86, 94, 100, 34, 60, 137, 226, 83, 198, 243, 170, 222, 6,
+            86, 110, 241, 183, 102, 253, 221, 56, 159, 124, 65, 147, 180, 164, 47, 2, 96, 231, 51,
+            167, 155, 35, 11, 89, 59, 243, 115, 233, 190, 35, 114, 55, 97, 210, 231, 48, 169, 217,
+            192, 57, 6, 135, 139, 96, 0, 166, 209, 48, 198, 92, 51, 68, 223, 182, 7, 35, 60, 25,
+            164, 82, 8, 50, 135, 3, 8, 177, 156, 160, 132, 137, 49, 103, 82, 89, 215, 211, 99, 110,
+            57, 234, 95, 96, 229, 2, 102, 154, 70, 77, 82, 236, 205, 130, 19, 28, 178, 120, 70, 64,
+            214, 176, 217, 153, 84, 118, 105, 89, 132, 149, 138, 131, 96, 141, 101, 108, 137, 183,
+            11, 208, 141, 146, 114, 162, 221, 3, 97, 72, 243, 190, 236, 234, 213, 75, 11, 237, 49,
+            57, 139, 19, 130, 198, 156, 84, 248, 124, 148, 218, 47, 234, 28, 137, 51, 38, 111, 160,
+            112, 130, 53, 23, 129, 94, 13, 250, 175, 64, 149, 26, 17, 21, 220, 17, 232, 240, 248,
+            231, 101, 199, 8, 189, 163, 249, 44, 172, 165, 118, 198, 156, 170, 255, 22, 16, 183,
+            78, 227, 118, 170, 205, 214, 145, 222, 58, 120, 235, 46, 195, 42, 109, 213, 144, 176,
+            6, 119, 92, 26, 147, 117, 157, 36, 33, 164, 142, 1, 104, 205, 10, 154, 189, 181, 196,
+            0, 89, 162, 211, 68, 146, 70, 134, 197, 168, 205, 17, 234, 174, 55, 51, 220, 126, 3, 0,
+            213, 75, 254, 102, 95, 97, 188, 87, 106, 95, 173, 171, 122, 222, 17, 122, 87, 166, 235,
+            93, 4, 120, 27, 231, 15, 133, 183, 212, 150, 228, 225, 215, 252, 15, 0, 109, 139, 82,
+            138, 231, 63, 154, 116, 154, 104, 173, 66, 146, 241, 167, 203, 4, 159, 139, 113, 73,
+            212, 37, 200, 134, 222, 0, 174, 43, 166, 193, 73, 164, 146, 86, 202, 40, 144, 93, 92,
+            127, 5, 170, 200, 99, 6, 202, 23, 216, 213, 3, 88, 204, 246, 85, 57, 239, 246, 180,
+            223, 102, 50, 162, 158, 203, 247, 87, 8, 92, 140, 158, 129, 181, 156, 89, 87, 60, 75,
+            151, 121, 239, 233, 32, 164, 249, 166, 84, 66, 107, 28, 106, 97, 235, 231, 225, 143,
+            170, 108, 244, 55, 184, 203, 199, 133, 146, 34, 196, 231, 23, 32, 20, 140, 55, 11, 188,
+            175, 103, 115, 204, 178, 197, 192, 41, 175, 54, 250, 236, 154, 16, 226, 10, 2, 24, 35,
+            179, 171, 150, 96, 175, 205, 88, 67, 135, 136, 118, 163, 186, 216, 167, 32, 3, 233, 30,
+            212, 201, 131, 202, 45, 59, 97, 154, 100, 210, 250, 45, 180, 155, 176, 30, 242, 21,
+            171, 77, 54, 46, 239, 20, 124, 6, 218, 150, 129, 18, 20, 111, 8, 154, 21, 141, 132, 20,
+            120, 229, 133, 165, 208, 143, 84, 0, 40, 174, 34, 84, 18, 105, 122, 144, 32, 52, 26,
+            89, 182, 166, 165, 164, 180, 161, 123, 114, 141, 14, 206, 149, 196, 70, 103, 162, 16,
+            229, 96, 102, 108, 137, 226, 137, 153, 185, 114, 33, 97, 228, 234, 182, 163, 218, 195,
+            138, 141, 148, 242, 3, 6, 69, 240, 183, 170, 139, 49, 113, 132, 69, 21, 163, 51, 139,
+            108, 42, 67, 244, 222, 119, 158, 160, 125, 11, 98, 96, 223, 119, 66, 68, 18, 214, 204,
+            30, 50, 109, 57, 97, 181, 230, 38, 178, 4, 141, 138, 99, 200, 17, 118, 38, 126, 227,
+            169, 28, 82, 70, 42, 15, 225, 10, 100, 58, 150, 36, 98, 242, 204, 110, 230, 42, 170,
+            30, 1, 43, 26, 182, 151, 14, 62, 128, 70, 197, 72, 146, 21, 137, 83, 191, 141, 22, 88,
+            108, 154, 153, 95, 154, 230, 32, 206, 69, 69, 179, 43, 25, 40, 177, 111, 115, 60, 144,
+            82, 3, 221, 42, 121, 214, 227, 21, 8, 174, 140, 51, 192, 88, 4, 49, 148, 111, 169, 58,
+            14, 52, 174, 148, 5, 51, 119, 75, 77, 23, 82, 142, 100, 8, 225, 230, 73, 248, 44, 65,
+            222, 67, 225, 130, 25, 231, 216, 103, 174, 43, 74, 62, 22, 20, 223, 239, 104, 1, 129,
+            106, 37, 119, 9, 164, 160, 62, 18, 71, 253, 33, 218, 147, 69, 15, 18, 90, 95, 105, 134,
+            21, 181, 160, 242, 149, 209, 158, 209, 156, 106, 195, 66, 233, 170, 13, 2, 99, 164,
+            227, 21, 36, 138, 76, 85, 114, 192, 26, 146, 89, 231, 235, 31, 164, 187, 26, 217, 211,
+            243, 90, 156, 67, 61, 90, 155, 114, 91, 152, 210, 17, 105, 41, 210, 44, 9, 59, 179,
+            133, 102, 119, 144, 194, 123, 69, 73, 183, 192, 218, 6, 153, 107, 245, 51, 225, 132,
+            162, 117, 89, 82, 141, 197, 5, 210, 98, 132, 173, 83, 10, 164, 96, 107, 110, 160, 84,
+            154, 133, 142, 186, 87, 200, 61, 83, 218, 9, 61, 80, 38, 183, 167, 69, 238, 44, 23, 30,
+            79, 69, 169, 227, 161, 3, 222, 246, 162, 95, 173, 152, 187, 184, 236, 180, 192, 111, 4,
+            132, 98, 202, 53, 162, 76, 172, 165, 177, 133, 119, 32, 108, 186, 31, 56, 191, 149, 40,
+            81, 158, 56, 112, 223, 84, 148, 15, 204, 74, 153, 57, 227, 21, 226, 103, 56, 126, 156,
+            196, 208, 129, 88, 128, 245, 18, 54, 26, 204, 47, 104, 86, 30, 50, 123, 129, 164, 5,
+            60, 192, 246, 86, 1, 180, 49, 76, 142, 53, 104, 210, 193, 44, 150, 79, 3, 52, 11, 229,
+            89, 152, 168, 42, 143, 82, 140, 117, 226, 72, 122, 243, 31, 70, 46, 194, 77, 128, 73,
+            103, 128, 166, 50, 39, 18, 218, 2, 42, 8, 133, 131, 219, 172, 136, 33, 86, 89, 128, 54,
+            178, 222, 11, 30, 201, 149, 75, 123, 207, 113, 164, 89, 181, 184, 72, 13, 191, 38, 156,
+            120, 201, 187, 141, 196, 63, 49, 183, 120, 237, 195, 141, 7, 160, 71, 128, 137, 138,
+            138, 133, 130, 100, 91, 219, 18, 95, 170, 205, 153, 176, 164, 102, 136, 116, 237, 140,
+            17, 168, 237, 182, 85, 252, 238, 6, 198, 1, 74, 161, 112, 59, 106, 79, 108, 73, 191, 1,
+            166, 178, 112, 13, 16, 48, 196, 83, 0, 95, 238, 111, 21, 33, 99, 228, 45, 140, 103,
+            160, 65, 73, 27, 222, 41, 228, 7, 137, 63, 91, 209, 89, 88, 169, 166, 68, 134, 109,
+            204, 158, 109, 52, 97, 41, 188, 0, 181, 37, 49, 48, 24, 177, 128, 245, 73, 38, 77, 50,
+            32, 140, 71, 149, 193, 78, 147, 85, 217, 190, 142, 29, 229, 4, 155, 158, 0, 40, 70, 11,
+            57, 198, 152, 226, 49, 86, 147, 134, 230, 87, 8, 179, 213, 153, 52, 121, 65, 209, 3,
+            33, 144, 160, 25, 206, 169, 12, 30, 93, 185, 230, 46, 141, 93, 209, 99, 216, 247, 172,
+            99, 19, 180, 149, 192, 236, 68, 73, 28, 140, 107, 117, 195, 14, 109, 72, 130, 48, 224,
+            152, 96, 182, 10, 82, 185, 27, 15, 163, 180, 46, 40, 26, 235, 26, 198, 137, 169, 214,
+            18, 136, 13, 19, 90, 156, 46, 165, 241, 58, 170, 95, 92, 125, 61, 161, 35, 19, 20, 8,
+            192, 157, 224, 187, 167, 17, 116, 10, 45, 147, 206, 9, 7, 144, 141, 191, 53, 122, 13,
+            245, 33, 228, 10, 90, 209, 108, 107, 55, 28, 42, 193, 128, 8, 17, 127, 152, 59, 181,
+            61, 218, 169, 85, 29, 182, 98, 185, 28, 93, 12, 210, 191, 5, 81, 132, 145, 112, 38,
+            183, 243, 216, 69, 167, 112, 245, 126, 147, 158, 157, 237, 60, 85, 82, 80, 209, 111,
+            41, 73, 141, 216, 11, 159, 169, 3, 130, 60, 159, 88, 81, 172, 145, 81, 134, 142, 179,
+            98, 194, 15, 96, 142, 212, 22, 158, 102, 18, 160, 0, 146, 144, 82, 227, 130, 135, 0,
+            19, 54, 157, 143, 4, 47, 48, 74, 165, 171, 70, 81, 186, 170, 41, 88, 49, 90, 157, 38,
+            141, 226, 151, 237, 130, 129, 60, 161, 192, 181, 14, 65, 106, 168, 30, 74, 179, 179,
+            235, 185, 118, 220, 68, 160, 169, 88, 135, 131, 54, 149, 2, 79, 174, 19, 63, 224, 123,
+            227, 120, 42, 29, 19, 164, 219, 7, 112, 172, 85, 162, 61, 80, 131, 103, 217, 188, 136,
+            96, 24, 244, 111, 151, 136, 122, 17, 161, 98, 140, 228, 109, 56, 50, 38, 87, 5, 90,
+            130, 45, 78, 174, 53, 101, 229, 212, 100, 33, 135, 228, 190, 161, 42, 25, 144, 16, 116,
+            205, 107, 91, 177, 144, 217, 244, 152, 172, 53, 149, 204, 38, 103, 150, 192, 250, 174,
+            110, 64, 75, 182, 19, 174, 134, 121, 186, 214, 168, 142, 179, 38, 160, 240, 199, 140,
+            210, 206, 40, 204, 163, 123, 45, 162, 25, 118, 112, 41, 160, 6, 231, 244, 129, 128,
+            189, 103, 237, 213, 232, 94, 210, 155, 87, 100, 159, 29, 101, 193, 18, 36, 219, 84,
+            228, 27, 110, 212, 201, 169, 209, 23, 18, 113, 121, 184, 138, 144, 71, 31, 160, 213,
+            139, 23, 28, 246, 10, 21, 106, 25, 0, 1, 235, 167, 88, 101, 10, 134, 19, 57, 157, 65,
+            122, 213, 158, 26, 197, 134, 22, 177, 11, 42, 130, 3, 43, 104, 209, 25, 218, 218, 97,
+            69, 178, 107, 37, 9, 159, 146, 40, 129, 109, 55, 228, 75, 5, 50, 130, 255, 115, 72, 6,
+            53, 101, 62, 190, 1, 81, 239, 137, 42, 30, 122, 7, 26, 164, 236, 37, 114, 161, 201,
+            230, 159, 114, 107, 90, 1, 178, 174, 78, 80,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 54925055);
+    }
+
+    #[test]
+    pub fn nist_test_verify_4() {
+        let nonce_msg = [
+            123, 137, 171, 91, 241, 31, 82, 9, 174, 54, 4, 72, 214, 107, 8, 110, 135, 202, 16, 58,
+            107, 91, 0, 122, 149, 188, 197, 191, 50, 243, 31, 251, 218, 182, 31, 49, 174, 18, 150,
+            131, 28, 223, 10, 225, 18, 71, 128, 168, 255, 0, 49, 143, 119, 154, 59, 134, 179, 80,
+            77, 5, 156, 167, 171, 63, 228, 214, 234, 233, 253, 70, 66, 141, 29, 171, 183, 4, 192,
+            115, 90, 143, 232, 112, 143, 64, 151, 65, 1, 123, 114, 61, 154, 48, 78, 84, 253, 197,
+            120, 154, 123, 7, 72, 194, 70, 75, 115, 8, 172, 150, 101, 17, 86, 68, 197, 105, 174,
+            37, 61, 82, 5, 117, 19, 66, 87, 76, 3, 52, 109, 221, 193, 149, 10, 98, 115, 84, 102,
+            22, 185, 109, 12, 94, 206, 10, 4, 74, 240, 237, 239, 190, 68, 95, 154, 227, 125, 165,
+            175, 184, 210, 42, 86, 217, 253, 24, 1, 66, 90, 10, 39, 111, 72, 67, 29, 122, 240, 57,
+            82, 30, 84, 149, 81, 72, 19, 145, 254, 95, 78, 191, 183, 100, 77, 159, 151, 130, 216,
+            58, 149, 19, 126, 132, 234, 58, 235, 60, 47, 128, 153,
+        ];
+        let sig = [
+            29, 175, 196, 25, 54, 101, 65, 194, 159, 238, 94, 50, 165, 38, 226, 149, 13, 97, 130,
+            253, 200, 149, 231, 47, 2, 138, 48, 131, 39, 152, 15, 140, 117, 120, 134, 189, 241, 42,
+            37, 203, 129, 31, 172, 166, 28, 247, 94, 125, 129, 206, 63, 74, 166, 51, 223, 196, 180,
+            65, 149, 185, 127, 10, 73, 28, 120, 49, 140, 73, 143, 66, 160, 145, 122, 6, 229, 22,
+            212, 235, 38, 18, 149, 63, 83, 57, 78, 90, 57, 5, 188, 174, 148, 56, 131, 156, 184,
+            202, 180, 212, 239, 108, 167, 163, 209, 233, 211, 117, 251, 246, 34, 221, 101, 88, 28,
+            75, 166, 5, 151, 210, 113, 214, 169, 198, 142, 36, 254, 55, 41, 95, 55, 170, 107, 80,
+            243, 54, 142, 162, 81, 61, 154, 10, 70, 134, 119, 8, 199, 211, 161, 122, 110, 149, 182,
+            123, 37, 170, 61, 245, 86, 152, 124, 153, 246, 78, 126, 217, 163, 189, 236, 195, 165,
+            110, 71, 218, 69, 183, 24, 174, 46, 111, 206, 180, 212, 162, 184, 252, 175, 113, 71,
+            171, 172, 9, 172, 199, 41, 205, 72, 27, 7, 111, 2, 156, 154, 205, 66, 47, 28, 68, 214,
+            150, 250, 137, 25, 97, 162, 73, 129, 140, 97, 153, 244, 61, 102, 241, 50, 200, 203,
+            162, 203, 216, 55, 246, 91, 154, 104, 138, 235, 121, 233, 106, 169, 156, 155, 234, 178,
+            136, 131, 145, 204, 237, 152, 5, 190, 197, 36, 200, 100, 16, 45, 228, 90, 191, 51, 162,
+            102, 59, 124, 4, 45, 175, 245, 86, 249, 237, 4, 73, 224, 122, 187, 252, 149, 182, 47,
+            74, 199, 106, 39, 245, 222, 213, 15, 2, 239, 197, 46, 52, 157, 151, 21, 207, 67, 248,
+            222, 242, 136, 75, 109, 91, 196, 2, 2, 8, 77, 138, 55, 21, 54, 168, 191, 205, 142, 136,
+            20, 127, 48, 172, 244, 141, 24, 130, 153, 86, 186, 204, 219, 159, 105, 115, 94, 146,
+            76, 24, 194, 86, 248, 238, 210, 228, 83, 57, 156, 152, 51, 114, 127, 71, 236, 135, 38,
+            241, 45, 150, 43, 225, 68, 98, 79, 246, 41, 121, 228, 244, 83, 153, 115, 227, 195, 65,
+            58, 127, 228, 15, 23, 147, 181, 76, 41, 5, 58, 243, 51, 237, 79, 204, 195, 201, 136,
+            86, 91, 199, 77, 145, 128, 53, 25, 108, 116, 221, 3, 27, 54, 209, 234, 142, 182, 218,
+            59, 132, 96, 226, 46, 229, 185, 181, 72, 216, 137, 243, 51, 241, 199, 178, 50, 73, 149,
+            33, 20, 71, 70, 205, 166, 202, 155, 234, 191, 15, 185, 124, 112, 175, 8, 43, 47, 230,
+            185, 240, 167, 103, 223, 86, 144, 235, 155, 14, 185, 169, 205, 199, 245, 30, 195, 209,
+            217, 203, 227, 31, 71, 49, 139, 44, 120, 100, 134, 60, 35, 241, 152, 251, 244, 142,
+            187, 207, 189, 75, 145, 167, 112, 36, 205, 206, 34, 16, 133, 75, 89, 216, 23, 46, 201,
+            73, 140, 43, 120, 30, 215, 222, 48, 130, 163, 210, 197, 207, 141, 23, 123, 45, 45, 243,
+            220, 136, 41, 198, 34, 60, 139, 243, 84, 86, 46, 125, 91, 148, 123, 243, 77, 60, 115,
+            29, 73, 132, 179, 203, 213, 50, 188, 168, 89, 186, 216, 6, 216, 232, 59, 185, 54, 193,
+            41, 202, 211, 40, 52, 140, 148, 85, 51, 244, 50, 61, 103, 254, 7, 6, 250, 201, 63, 206,
+            229, 193, 241, 253, 170, 136, 165, 69, 74, 209, 27, 38, 174, 32, 194, 196, 166, 173,
+            123, 72, 169, 241, 210, 28, 156, 189, 174, 173, 20, 89, 250, 33, 173, 142, 84, 124,
+            191, 249, 249, 231, 137, 243, 33, 109, 28, 210, 233, 50, 152, 94, 22, 150, 114, 224,
+            55, 53, 182, 5, 125, 81, 37, 46, 70, 44, 131, 206, 237, 211, 204, 203, 65, 58, 114,
+            215, 11, 228, 68, 162, 26, 234, 3, 145, 58, 115, 227, 15, 53, 161, 33, 126, 82, 42,
+            190, 84, 210, 77, 29, 207, 132, 8, 192, 174, 170, 54, 158, 11, 93, 216, 179, 122, 255,
+            171, 217, 113, 156, 253, 164, 198, 5, 237, 103, 45, 42, 170, 234, 186, 234, 152, 147,
+            198, 182, 177, 71, 29, 158, 132, 166, 251, 158, 199, 142, 148, 98, 113, 225, 159, 64,
+            155, 231, 94, 230, 198, 221, 72, 149, 159, 136, 39, 188, 248, 239, 87, 127, 32, 126,
+            237, 105, 173, 250, 49, 153, 66, 204, 22, 82, 21, 84, 174, 106, 89, 239, 90, 177, 149,
+            50, 40, 148, 41, 227, 47, 21, 15, 174, 227, 19, 81, 38, 87, 38, 42, 136, 178, 100, 111,
+            119, 236, 191, 95, 49, 23, 80, 62, 24, 84, 69, 24, 235, 107, 158, 230, 7, 57, 75, 151,
+            145, 76, 62, 86, 173, 11, 60, 58, 121, 39, 189, 133, 105, 154, 155, 253, 78, 131, 31,
+            66, 89, 79, 238, 183, 11, 169, 151, 231, 134, 184, 66, 31, 242, 32, 153, 72, 250, 149,
+            250, 73, 239, 145, 235, 13, 88, 196, 220, 127, 210, 252, 167, 199, 101, 92, 161, 220,
+            168, 73, 156, 59, 144, 83, 226, 125, 245, 131, 85, 177, 181, 251, 200, 2, 42, 180, 87,
+            208, 209, 201, 110, 231, 198, 111, 20, 195, 106, 94, 105, 35, 96, 236, 120, 161, 131,
+            186, 139, 226, 178, 211, 151, 41, 91, 97, 210, 101, 80, 199, 97, 165, 120, 242, 86, 32,
+            244, 90, 196, 25, 45, 242, 22, 249, 209, 250, 203, 101, 227, 16, 175, 207, 33, 179,
+            235, 114, 153, 53, 226, 10, 182, 144, 231, 11, 77, 75, 233, 203, 136, 238, 11, 94, 216,
+            220, 228, 48, 104, 69, 159, 135, 38, 228, 119, 144, 3, 147, 8, 16, 142, 12, 79, 201,
+            65, 159, 178, 71, 93, 201, 29, 235, 44, 57, 164, 101, 104, 232, 146, 123, 238, 131, 76,
+            11, 161, 250, 109, 164, 186, 189, 226, 195, 177, 144, 121, 169, 214, 118, 128, 148,
+            238, 124, 126, 251, 186, 206, 106, 157, 108, 114, 149, 157, 78, 205, 220, 89, 214, 134,
+            17, 85, 58, 148, 226, 111, 54, 153, 41, 243, 115, 10, 67, 251, 188, 207, 214, 139, 162,
+            70, 28, 90, 84, 90, 26, 153, 79, 89, 89, 105, 54, 181, 203, 38, 237, 135, 123, 171,
+            204, 166, 236, 145, 236, 55, 89, 52, 143, 190, 176, 45, 254, 29, 66, 162, 104, 44, 81,
+            164, 134, 18, 226, 63, 236, 187, 14, 153, 94, 173, 152, 125, 70, 102, 68, 213, 197,
+            102, 184, 67, 158, 133, 194, 163, 104, 102, 219, 176, 75, 35, 24, 230, 146, 137, 9,
+            146, 98, 78, 228, 176, 71, 205, 162, 89, 23, 101, 8, 115, 36, 225, 190, 234, 33, 106,
+            68, 216, 3, 69, 6, 69, 147, 146, 7, 5, 24, 125, 108, 245, 147, 83, 221, 116, 226, 206,
+            205, 145, 119, 118, 91, 206, 255, 53, 131, 90, 205, 241, 250, 188, 134, 104, 236, 195,
+            216, 41, 61, 34, 191, 133, 173, 53, 82, 47, 34, 137, 28, 164, 60, 200, 110, 147, 81,
+            175, 80, 76, 157, 99, 241, 113, 229, 178, 113, 146, 180, 118, 166, 221, 255, 36, 138,
+            78, 87, 108, 239, 125, 169, 205, 128,
+        ];
+        let pk = [
+            10, 6, 204, 228, 37, 147, 157, 136, 144, 209, 106, 4, 210, 128, 2, 89, 156, 12, 40,
+            169, 97, 203, 2, 24, 34, 119, 18, 168, 88, 84, 181, 109, 209, 113, 225, 96, 44, 225,
+            178, 251, 254, 224, 207, 131, 45, 52, 2, 239, 162, 243, 15, 20, 106, 120, 18, 217, 132,
+            106, 54, 51, 134, 86, 237, 114, 181, 82, 233, 25, 88, 132, 157, 191, 46, 200, 69, 194,
+            95, 180, 100, 12, 63, 8, 160, 175, 151, 53, 76, 82, 37, 44, 211, 9, 0, 66, 254, 24, 36,
+            226, 157, 50, 4, 26, 249, 225, 224, 15, 19, 53, 152, 7, 26, 231, 202, 146, 93, 49, 192,
+            197, 237, 99, 165, 141, 230, 17, 2, 83, 14, 127, 8, 146, 6, 255, 6, 129, 37, 172, 189,
+            3, 174, 67, 50, 188, 148, 19, 117, 233, 144, 216, 21, 110, 133, 230, 232, 69, 181, 107,
+            192, 161, 100, 78, 84, 170, 101, 78, 200, 233, 170, 77, 215, 95, 41, 117, 24, 84, 38,
+            148, 130, 173, 11, 184, 91, 197, 217, 55, 178, 187, 180, 119, 90, 200, 138, 248, 69,
+            27, 241, 202, 220, 185, 170, 46, 122, 210, 230, 225, 42, 121, 74, 24, 130, 100, 74,
+            167, 152, 250, 232, 243, 29, 44, 19, 66, 69, 107, 249, 5, 224, 141, 81, 100, 105, 124,
+            19, 221, 185, 64, 136, 4, 240, 12, 167, 117, 98, 78, 23, 52, 79, 33, 167, 24, 31, 166,
+            57, 216, 242, 138, 16, 84, 231, 172, 1, 17, 102, 73, 160, 82, 209, 64, 174, 95, 106,
+            73, 22, 177, 222, 61, 102, 12, 15, 160, 90, 94, 147, 98, 12, 227, 164, 131, 150, 29,
+            35, 26, 171, 195, 18, 132, 70, 130, 94, 42, 41, 152, 76, 232, 107, 226, 119, 178, 22,
+            66, 7, 104, 154, 149, 241, 214, 149, 114, 82, 10, 24, 128, 76, 212, 99, 0, 250, 173,
+            52, 44, 29, 54, 136, 29, 229, 133, 214, 214, 107, 125, 77, 230, 122, 218, 150, 64, 28,
+            189, 221, 2, 40, 103, 132, 80, 94, 159, 113, 130, 111, 62, 101, 29, 189, 225, 20, 220,
+            132, 42, 254, 171, 74, 130, 27, 161, 40, 190, 110, 9, 224, 129, 175, 173, 5, 59, 2,
+            245, 194, 6, 96, 128, 238, 192, 187, 158, 74, 100, 244, 236, 153, 149, 236, 194, 151,
+            246, 152, 123, 132, 7, 42, 166, 120, 35, 235, 51, 99, 3, 168, 92, 92, 230, 86, 84, 65,
+            143, 134, 225, 247, 72, 0, 50, 111, 230, 104, 53, 141, 46, 92, 40, 12, 242, 133, 195,
+            21, 165, 89, 105, 189, 180, 57, 207, 152, 132, 81, 224, 118, 171, 69, 171, 44, 38, 186,
+            4, 239, 40, 252, 186, 10, 208, 64, 137, 168, 26, 89, 102, 179, 219, 31, 27, 130, 118,
+            109, 217, 112, 116, 133, 7, 146, 157, 143, 7, 77, 207, 228, 115, 142, 21, 153, 234,
+            136, 234, 84, 129, 157, 208, 88, 66, 179, 166, 208, 26, 148, 59, 144, 226, 138, 105,
+            56, 233, 253, 110, 191, 108, 57, 196, 195, 241, 97, 220, 73, 176, 218, 196, 189, 233,
+            112, 61, 128, 127, 68, 42, 94, 222, 45, 225, 196, 183, 90, 72, 237, 174, 205, 53, 185,
+            153, 205, 120, 147, 246, 141, 57, 192, 162, 111, 66, 40, 115, 214, 253, 225, 232, 141,
+            137, 168, 122, 2, 163, 76, 147, 138, 109, 163, 181, 80, 53, 35, 2, 240, 224, 205, 235,
+            99, 67, 185, 123, 36, 163, 89, 139, 35, 89, 166, 224, 134, 89, 103, 127, 184, 54, 39,
+            138, 29, 36, 12, 140, 96, 208, 138, 204, 204, 72, 98, 92, 216, 131, 82, 175, 4, 225,
+            174, 199, 25, 136, 63, 151, 156, 155, 20, 78, 226, 193, 59, 173, 126, 104, 201, 208,
+            148, 122, 156, 149, 114, 170, 209, 95, 163, 193, 180, 163, 171, 207, 206, 75, 182, 153,
+            241, 169, 235, 32, 20, 228, 219, 135, 127, 82, 202, 20, 1, 74, 6, 89, 201, 66, 189, 32,
+            233, 98, 217, 128, 11, 19, 45, 254, 48, 185, 66, 164, 46, 149, 244, 195, 192, 67, 63,
+            73, 185, 148, 19, 52, 152, 47, 166, 48, 77, 6, 54, 166, 118, 51, 241, 5, 97, 48, 133,
+            247, 62, 13, 114, 86, 72, 230, 231, 26, 60, 98, 150, 107, 164, 170, 73, 168, 132, 91,
+            33, 70, 96, 79, 141, 15, 249, 78, 35, 18, 25, 193, 61, 123, 189, 109, 48, 52, 146, 245,
+            82, 254, 42, 132, 154, 120, 249, 160, 26, 136, 196, 129, 29, 185, 214, 246, 74, 111,
+            28, 38, 251, 52, 106, 18, 164, 55, 66, 224, 129, 226, 214, 226, 67, 193, 46, 9, 127,
+            70, 117, 17, 162, 81, 211, 6, 8, 157, 116, 204, 33, 144, 95, 35, 92, 132, 82, 148, 215,
+            227, 108, 32, 180, 212, 184, 232, 226, 164, 83, 182, 212, 36, 64, 131, 47, 176, 43,
+            250, 15, 102, 147, 218, 203, 171, 0, 111, 71, 95, 111, 201, 172, 20, 48, 198, 242, 192,
+            123, 138, 86, 80, 26, 215, 218, 11, 163, 242, 176, 66, 32, 164, 21, 101, 226, 120, 180,
+            29, 106, 205, 30, 64, 155, 52, 179, 153, 255, 61, 26, 16, 65, 163, 111, 174, 101, 40,
+            68, 7, 74, 142, 164, 150, 181, 40, 241, 167, 234, 92, 37, 176, 70, 113, 82, 105, 207,
+            16, 146, 250, 72, 142, 33, 66, 114, 180, 101, 203, 243, 236, 160, 1, 136, 182, 0, 218,
+            203, 223, 36, 25, 66, 213, 50, 42, 53, 106, 197, 165, 128, 88, 192, 14, 18, 85, 5, 224,
+            97, 38, 166, 163, 37, 100, 43, 36, 167, 74, 64, 158, 186, 10, 237, 234, 92, 62, 178,
+            118, 202, 243, 131, 173, 74, 64, 186, 216, 119, 100, 59, 184, 162, 58, 119, 28, 218,
+            254, 97, 196, 151, 22, 69, 155, 230, 128, 245, 74, 171, 232, 17, 106, 133, 132, 182,
+            118, 125, 215, 144, 36, 76, 111, 212, 195, 2, 239, 87, 48, 103, 198, 137, 1, 239, 155,
+            97, 65, 32, 230, 66, 62, 3, 110, 171, 194, 167, 129, 110, 149, 20, 139, 193, 147, 211,
+            34, 128, 150, 68, 248, 75, 149, 119, 79, 5, 220, 118, 218, 33, 40, 38, 222, 14, 147,
+            129, 207, 6, 34, 238, 28, 149, 131, 201, 224, 40, 52, 37, 241, 163, 12, 53, 49, 32,
+            240, 224, 144, 145, 152, 41, 106, 180, 224, 201, 85, 141, 105, 104, 101, 249, 30, 225,
+            94, 65, 253, 66, 97, 135, 230, 115, 74, 178, 141, 69, 182, 16, 166, 125, 78, 145, 229,
+            233, 98, 35, 30, 253, 226, 170, 195, 162, 126, 181, 82, 68, 147, 6, 5, 173, 137, 1,
+            200, 121, 221, 159, 126, 30, 77, 194, 194, 10, 209, 196, 53, 58, 199, 197, 120, 225,
+            155, 111, 97, 97, 165, 12, 44, 224, 118, 208, 0, 131, 163, 199, 120, 101, 166, 69, 136,
+            167, 87, 159, 66, 234, 114, 242, 125, 216, 84, 106, 152, 162, 133, 124, 23, 154, 9,
+            137, 182, 244, 217, 159, 186, 22, 92, 101, 43, 43, 65, 0, 47, 116, 179, 58, 254, 194,
+            92, 78, 189, 159, 241, 5, 102, 2, 51, 237, 200, 135, 94, 33, 242, 127, 34, 129, 50, 77,
+            96, 119, 44, 90, 94, 56, 198, 152, 56, 77, 52, 13, 117, 91, 95, 8, 176, 98, 223, 58,
+            211, 137, 150, 22, 226, 43, 105, 5, 194, 35, 60, 252, 22, 177, 18, 219, 74, 157, 92,
+            247, 69, 162, 226, 3, 33, 94, 250, 32, 131, 6, 24, 25, 81, 139, 184, 54, 238, 70, 188,
+            225, 93, 248, 15, 47, 45, 102, 185, 143, 243, 12, 157, 128, 140, 161, 146, 250, 170,
+            46, 231, 118, 34, 102, 106, 68, 78, 89, 74, 193, 117, 240, 84, 169, 167, 63, 148, 25,
+            180, 36, 203, 238, 65, 89, 193, 96, 191, 15, 247, 19, 154, 44, 245, 39, 143, 245, 60,
+            170, 196, 169, 52, 76, 2, 7, 242, 37, 139, 246, 111, 117, 174, 16, 230, 71, 68, 29,
+            107, 125, 106, 164, 19, 107, 144, 31, 114, 54, 226, 26, 194, 39, 235, 64, 56, 101, 230,
+            216, 12, 138, 96, 26, 50, 198, 166, 86, 164, 64, 106, 122, 178, 151, 3, 184, 189, 89,
+            136, 225, 189, 239, 29, 88, 109, 45, 212, 240, 201, 99, 49, 214, 128, 57, 49, 34, 26,
+            124, 21, 242, 134, 38, 161, 85, 126, 46, 87, 241, 44, 45, 25, 157, 1, 54, 183, 121,
+            139, 191, 30, 128, 31, 160, 208, 3, 14, 46, 32, 253, 153, 133, 68, 68, 33, 144, 181,
+            182, 199, 15, 13, 76, 56, 99, 235, 96, 95, 151, 14, 165, 147, 107, 138, 163, 20, 155,
+            98, 201, 123, 168, 73, 112, 186, 208, 191, 216, 128, 97, 115, 32, 233, 42, 84, 69, 141,
+            193, 26, 36, 141, 7, 5, 238, 36, 68, 24, 254, 240, 70, 173, 172, 9, 64, 48, 225, 19,
+            195, 186, 28, 82, 38, 38, 57, 146, 111, 20, 30, 35, 180, 18, 231, 212, 132, 12, 224, 1,
+            237, 157, 13, 179, 152, 112, 183, 179, 163, 203, 27, 154, 73, 37, 186, 46, 177, 24,
+            178, 16, 120, 206, 137, 126, 85, 216, 188, 122, 207, 91, 125, 160, 21, 95, 38, 143,
+            129, 42, 48, 105, 32, 51, 96, 90, 2, 183, 221, 251, 213, 97, 162, 72, 55, 128, 62, 138,
+            69, 139, 10, 143, 169, 17, 155, 75, 138, 237, 109, 69, 45, 99, 141, 232, 74, 25, 238,
+            130, 136, 234, 83, 26, 72, 29, 42, 193, 44, 225, 244, 98, 40, 13, 144, 136, 172, 187,
+            101, 141, 128, 179, 10, 165, 215, 152, 225, 218, 134, 202, 134, 105, 33, 178, 154, 48,
+            172, 41, 167, 94, 98, 206, 170, 214, 78, 29, 52, 56, 157, 176, 244, 133, 22, 116, 250,
+            203, 71, 53, 142, 137, 139, 133, 223, 152, 166, 174, 65, 137, 214, 138, 227, 47, 106,
+            202, 115, 1, 78, 215, 1, 14, 125, 19, 16, 83, 80, 37, 232, 34, 115, 204, 5, 130, 165,
+            133, 241, 169, 101, 163, 167, 62, 91, 1, 6, 81, 172, 178, 120, 24, 61, 107, 236, 79,
+            114, 155, 37, 231, 74, 109, 48, 246, 189, 108, 154, 46, 212, 248, 103, 56, 73, 65, 187,
+            128, 49, 34, 51, 231, 217, 129, 192, 205, 59, 179, 136, 248, 158, 70, 30, 229, 124,
+            211, 136, 157, 221, 82, 181, 230, 215, 7, 102, 44, 29, 224, 105, 131, 250, 71, 90, 158,
+            231, 16, 216, 112, 147, 141, 228, 167, 134, 142, 52,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 55785621);
+    }
+
+    #[test]
+    pub fn nist_test_verify_5() {
+        let nonce_msg = [
+            33, 40, 149, 45, 26, 44, 156, 99, 85, 132, 255, 148, 27, 194, 54, 59, 37, 146, 199,
+            248, 143, 240, 67, 108, 134, 220, 34, 195, 159, 128, 180, 50, 114, 224, 48, 130, 252,
+            71, 201, 102, 219, 229, 182, 194, 153, 180, 79, 141, 96, 250, 151, 42, 51, 109, 247,
+            137, 239, 69, 52, 236, 155, 169, 13, 249, 42, 212, 1, 209, 144, 121, 81, 235, 98, 133,
+            237, 168, 241, 52, 39, 122, 176, 161, 20, 80, 1, 195, 78, 57, 33, 135, 18, 37, 6, 170,
+            45, 187, 134, 23, 215, 148, 58, 18, 158, 181, 192, 125, 241, 51, 215, 204, 222, 148,
+            167, 203, 127, 23, 149, 198, 36, 147, 237, 55, 83, 83, 209, 240, 68, 37, 125, 167, 153,
+            247, 209, 18, 193, 116, 251, 195, 86, 135, 226, 248, 127, 239, 190, 45, 131, 210, 157,
+            115, 20, 179, 10, 116, 159, 228, 27, 27, 129, 9, 86, 56, 241, 18, 188, 69, 99, 66, 10,
+            242, 53, 40, 14, 70, 111, 251, 231, 5, 12, 73, 55, 198, 15, 193, 141, 26, 96, 37, 188,
+            189, 72, 159, 12, 83, 142, 8, 142, 144, 106, 190, 133, 151, 226, 200, 235, 182, 79, 1,
+            210, 37, 200, 71, 170, 228, 183, 123, 174, 110, 186, 146, 105, 150, 44, 75, 148, 169,
+            115, 44, 234, 162, 203, 64, 147, 212, 66, 255, 188, 221,
+        ];
+        let sig = [
+            35, 249, 95, 43, 148, 87, 95, 13, 72, 125, 111, 164, 14, 0, 152, 186, 14, 252, 131,
+            145, 154, 27, 7, 90, 218, 193, 205, 230, 166, 85, 166, 137, 23, 118, 130, 70, 37, 236,
+            209, 197, 46, 28, 115, 72, 95, 21, 236, 227, 69, 245, 110, 160, 220, 140, 217, 161,
+            126, 227, 76, 225, 98, 119, 86, 4, 126, 114, 137, 233, 83, 10, 228, 14, 123, 104, 221,
+            108, 181, 153, 199, 14, 213, 150, 19, 248, 68, 229, 188, 141, 52, 250, 3, 147, 104,
+            225, 153, 196, 99, 45, 108, 238, 229, 78, 26, 243, 28, 165, 64, 146, 104, 195, 102,
+            195, 144, 198, 241, 231, 89, 46, 157, 137, 217, 103, 117, 92, 75, 77, 30, 209, 142,
+            106, 39, 217, 232, 233, 138, 126, 79, 141, 21, 240, 215, 189, 153, 116, 96, 197, 156,
+            189, 184, 211, 61, 212, 56, 89, 48, 115, 105, 222, 212, 130, 190, 99, 78, 35, 111, 225,
+            114, 242, 45, 107, 238, 190, 22, 136, 14, 88, 173, 214, 51, 24, 244, 106, 50, 143, 164,
+            11, 239, 78, 6, 147, 176, 75, 143, 130, 228, 201, 34, 204, 255, 95, 129, 187, 254, 188,
+            179, 41, 193, 161, 252, 200, 101, 210, 201, 42, 23, 107, 114, 218, 15, 138, 176, 123,
+            139, 108, 54, 76, 241, 244, 62, 22, 122, 187, 119, 21, 66, 214, 156, 32, 151, 32, 154,
+            106, 26, 136, 170, 155, 252, 183, 163, 84, 107, 29, 198, 169, 165, 97, 25, 211, 238,
+            93, 211, 167, 223, 24, 79, 36, 89, 221, 106, 61, 4, 98, 249, 84, 87, 237, 31, 67, 102,
+            40, 246, 21, 201, 148, 33, 18, 174, 78, 151, 77, 130, 119, 200, 67, 214, 196, 216, 96,
+            188, 29, 119, 42, 201, 224, 87, 130, 54, 43, 206, 131, 209, 212, 81, 87, 124, 75, 39,
+            105, 88, 85, 221, 99, 77, 204, 193, 201, 50, 167, 61, 123, 105, 159, 10, 10, 88, 119,
+            135, 138, 210, 250, 62, 137, 26, 116, 216, 98, 202, 187, 106, 216, 89, 102, 26, 219,
+            92, 109, 190, 205, 221, 241, 222, 195, 37, 127, 205, 204, 217, 140, 1, 40, 224, 180,
+            187, 190, 138, 108, 75, 113, 86, 173, 194, 86, 249, 205, 32, 45, 105, 118, 90, 98, 106,
+            13, 2, 35, 167, 134, 25, 75, 10, 105, 7, 186, 61, 247, 220, 28, 157, 186, 162, 242, 51,
+            140, 158, 48, 183, 158, 20, 87, 196, 243, 192, 244, 235, 204, 230, 85, 61, 85, 226,
+            178, 31, 243, 102, 163, 211, 108, 209, 238, 191, 199, 213, 161, 227, 49, 77, 150, 156,
+            254, 149, 1, 198, 146, 196, 190, 217, 102, 149, 150, 26, 167, 154, 57, 128, 56, 236,
+            162, 100, 236, 206, 37, 185, 180, 103, 188, 200, 70, 240, 114, 124, 194, 59, 217, 73,
+            165, 117, 154, 51, 158, 153, 97, 127, 146, 47, 140, 221, 206, 43, 240, 211, 55, 146,
+            134, 176, 125, 122, 211, 132, 218, 69, 176, 228, 86, 35, 189, 196, 151, 203, 218, 43,
+            35, 143, 40, 251, 101, 151, 187, 166, 231, 197, 88, 239, 31, 110, 52, 216, 241, 89, 61,
+            197, 103, 151, 133, 55, 21, 105, 154, 165, 56, 237, 167, 30, 132, 212, 194, 18, 107,
+            109, 251, 128, 239, 120, 94, 253, 147, 80, 87, 24, 133, 52, 234, 225, 149, 47, 166,
+            139, 240, 153, 93, 241, 237, 157, 162, 147, 88, 224, 110, 191, 108, 230, 236, 224, 224,
+            101, 51, 54, 156, 223, 52, 82, 75, 36, 61, 162, 114, 50, 194, 84, 217, 111, 225, 172,
+            211, 114, 214, 168, 240, 36, 53, 252, 129, 239, 181, 77, 209, 14, 120, 25, 115, 6, 146,
+            11, 22, 205, 68, 66, 160, 167, 147, 224, 116, 245, 76, 115, 189, 83, 58, 103, 110, 236,
+            195, 54, 111, 22, 175, 89, 95, 225, 230, 22, 58, 62, 238, 187, 3, 156, 223, 59, 187,
+            52, 10, 97, 11, 50, 106, 202, 39, 182, 103, 93, 5, 188, 107, 28, 220, 243, 128, 186,
+            123, 56, 93, 58, 164, 37, 180, 53, 87, 216, 222, 120, 153, 48, 49, 222, 39, 51, 40, 32,
+            133, 29, 37, 98, 52, 44, 138, 219, 10, 243, 184, 106, 131, 199, 63, 147, 64, 153, 82,
+            73, 109, 217, 212, 27, 184, 249, 133, 83, 166, 188, 25, 181, 30, 239, 2, 150, 76, 150,
+            237, 47, 31, 175, 109, 124, 162, 93, 103, 22, 44, 175, 214, 22, 45, 244, 1, 194, 52,
+            148, 125, 127, 135, 168, 146, 60, 178, 115, 55, 50, 114, 105, 20, 226, 58, 135, 35,
+            137, 175, 10, 164, 175, 104, 148, 40, 82, 33, 150, 252, 150, 56, 220, 241, 98, 201, 39,
+            209, 70, 213, 135, 223, 160, 51, 157, 191, 167, 99, 124, 235, 48, 243, 146, 67, 217,
+            122, 43, 126, 142, 244, 92, 142, 16, 252, 156, 118, 177, 186, 83, 221, 218, 20, 78, 28,
+            223, 243, 36, 90, 182, 53, 104, 144, 183, 88, 245, 207, 78, 193, 241, 111, 252, 66,
+            192, 179, 158, 13, 139, 23, 183, 174, 213, 241, 77, 43, 152, 70, 55, 131, 8, 194, 194,
+            27, 52, 67, 8, 200, 239, 211, 9, 214, 249, 18, 169, 149, 70, 39, 95, 209, 132, 196, 8,
+            178, 35, 17, 238, 242, 148, 109, 182, 56, 157, 18, 56, 34, 156, 216, 17, 223, 76, 153,
+            76, 85, 149, 253, 14, 197, 6, 236, 177, 141, 92, 55, 219, 95, 150, 101, 8, 44, 21, 141,
+            32, 216, 52, 247, 58, 237, 213, 13, 100, 213, 61, 132, 213, 178, 170, 105, 152, 82,
+            207, 98, 172, 183, 80, 82, 111, 12, 141, 224, 156, 124, 233, 69, 70, 160, 136, 41, 104,
+            61, 125, 203, 156, 33, 205, 78, 130, 238, 69, 15, 87, 105, 224, 188, 245, 137, 221, 73,
+            161, 157, 39, 29, 108, 84, 167, 4, 124, 228, 114, 39, 121, 42, 159, 211, 59, 221, 6,
+            143, 20, 168, 161, 28, 200, 107, 106, 204, 165, 179, 212, 250, 237, 138, 190, 116, 22,
+            200, 87, 231, 157, 102, 198, 169, 123, 28, 109, 63, 205, 1, 214, 232, 44, 175, 233, 60,
+            100, 76, 49, 65, 84, 223, 152, 27, 235, 8, 82, 14, 170, 2, 199, 201, 203, 92, 119, 156,
+            109, 34, 156, 140, 212, 142, 223, 173, 10, 79, 13, 185, 195, 51, 161, 157, 90, 253,
+            182, 118, 178, 207, 75, 76, 13, 10, 14, 236, 80, 95, 177, 160, 228, 69, 93, 149, 61, 6,
+            76, 86, 27, 159, 254, 117, 145, 87, 176, 117, 247, 144, 176, 33, 231, 87, 208, 69, 196,
+            29, 25, 136, 195, 220, 136, 130, 231, 25, 179, 73, 92, 238, 27, 209, 187, 70, 152, 226,
+            93, 59, 162, 73, 243, 238, 51, 5, 163, 131, 234, 227, 123, 106, 126, 249, 54, 131, 83,
+            107, 169, 123, 219, 165, 194, 179, 252, 34, 107, 255, 99, 235, 108, 79, 159, 48, 228,
+            38, 59, 156, 135, 37, 121, 203, 180, 221, 167, 166, 18, 199, 40, 149, 186, 132, 60,
+            211, 146, 53, 170, 69, 129, 135, 24, 88, 15, 235, 29, 47, 142, 96, 136, 79, 90, 49,
+            129, 112, 116, 232, 91, 132, 83, 110, 74, 186, 47, 234, 232, 64,
+        ];
+        let pk = [
+            10, 69, 38, 146, 150, 148, 70, 185, 138, 132, 229, 208, 243, 64, 111, 106, 216, 11, 87,
+            66, 217, 235, 60, 190, 196, 177, 111, 192, 64, 156, 173, 16, 32, 142, 172, 155, 41,
+            172, 63, 186, 124, 90, 167, 93, 170, 71, 0, 69, 214, 222, 19, 145, 31, 231, 211, 71,
+            206, 86, 162, 159, 160, 36, 236, 124, 36, 74, 77, 101, 203, 15, 90, 171, 36, 23, 114,
+            101, 151, 225, 95, 108, 94, 229, 152, 146, 71, 9, 117, 155, 20, 101, 31, 92, 86, 146,
+            60, 0, 126, 208, 160, 73, 118, 124, 154, 163, 29, 242, 93, 158, 48, 137, 182, 230, 151,
+            34, 12, 239, 130, 44, 93, 35, 70, 218, 161, 200, 37, 132, 103, 102, 97, 179, 242, 177,
+            150, 238, 184, 64, 0, 131, 132, 175, 123, 39, 172, 5, 164, 76, 203, 138, 162, 205, 71,
+            100, 60, 128, 144, 103, 8, 101, 19, 22, 97, 224, 33, 176, 206, 75, 76, 36, 247, 119,
+            10, 70, 164, 162, 202, 35, 112, 58, 139, 198, 119, 94, 69, 190, 176, 0, 137, 161, 201,
+            150, 21, 28, 57, 214, 149, 101, 33, 107, 52, 251, 83, 14, 157, 240, 16, 209, 232, 17,
+            131, 217, 138, 54, 144, 114, 160, 160, 206, 164, 179, 190, 137, 165, 3, 163, 51, 115,
+            228, 248, 41, 224, 36, 11, 90, 157, 232, 208, 81, 23, 50, 33, 176, 91, 7, 255, 79, 92,
+            52, 106, 156, 166, 175, 27, 93, 116, 161, 6, 130, 224, 22, 61, 157, 149, 28, 64, 12,
+            162, 79, 78, 132, 184, 57, 144, 80, 187, 191, 149, 229, 40, 237, 229, 215, 127, 178,
+            220, 19, 7, 168, 238, 0, 84, 111, 138, 239, 64, 65, 145, 125, 182, 250, 193, 100, 225,
+            48, 126, 161, 129, 77, 23, 118, 174, 221, 168, 211, 88, 111, 147, 77, 241, 3, 234, 242,
+            9, 176, 66, 128, 112, 218, 241, 86, 34, 115, 177, 83, 247, 184, 26, 157, 110, 30, 114,
+            89, 104, 143, 11, 95, 90, 113, 74, 100, 81, 83, 172, 24, 22, 17, 46, 18, 138, 126, 244,
+            98, 227, 134, 201, 157, 47, 218, 224, 56, 49, 221, 86, 130, 214, 18, 192, 57, 4, 117,
+            73, 150, 157, 89, 119, 135, 87, 169, 160, 125, 24, 215, 218, 101, 147, 56, 127, 134,
+            84, 39, 200, 102, 169, 110, 120, 255, 168, 66, 146, 90, 247, 227, 100, 173, 48, 167,
+            237, 42, 102, 22, 34, 173, 50, 78, 228, 148, 35, 133, 176, 17, 142, 162, 211, 67, 170,
+            72, 2, 64, 8, 53, 105, 73, 149, 61, 168, 189, 53, 250, 36, 228, 53, 49, 7, 103, 6, 85,
+            241, 148, 84, 141, 194, 255, 215, 178, 24, 129, 229, 178, 245, 66, 141, 90, 189, 111,
+            167, 13, 228, 174, 140, 38, 93, 73, 220, 137, 47, 23, 220, 143, 227, 155, 143, 218, 69,
+            150, 11, 20, 48, 166, 22, 114, 234, 72, 103, 106, 169, 205, 148, 113, 205, 247, 49,
+            107, 187, 37, 202, 197, 195, 23, 69, 108, 44, 164, 249, 130, 189, 99, 140, 101, 40,
+            198, 53, 198, 145, 139, 51, 37, 57, 213, 235, 11, 158, 154, 21, 40, 16, 88, 8, 241,
+            115, 53, 170, 102, 172, 17, 179, 6, 156, 102, 5, 232, 140, 99, 124, 214, 7, 38, 209,
+            109, 3, 90, 21, 13, 154, 142, 133, 135, 62, 78, 35, 8, 63, 39, 8, 100, 141, 209, 179,
+            135, 201, 201, 41, 42, 99, 22, 113, 147, 243, 147, 97, 251, 248, 167, 213, 213, 41,
+            148, 50, 209, 183, 101, 46, 33, 226, 142, 167, 8, 131, 29, 9, 138, 93, 154, 160, 201,
+            39, 2, 49, 154, 55, 43, 69, 196, 100, 2, 235, 180, 28, 218, 203, 179, 52, 7, 65, 174,
+            92, 103, 43, 130, 106, 11, 109, 100, 101, 8, 10, 28, 96, 240, 28, 198, 188, 217, 97,
+            115, 187, 33, 28, 184, 137, 83, 225, 57, 93, 66, 116, 188, 129, 229, 10, 202, 253, 29,
+            166, 221, 201, 189, 98, 248, 187, 54, 175, 25, 181, 204, 102, 81, 162, 224, 249, 3,
+            171, 224, 45, 77, 212, 59, 55, 172, 69, 105, 113, 130, 16, 162, 196, 19, 29, 66, 205,
+            234, 189, 206, 224, 104, 5, 118, 154, 120, 40, 210, 87, 118, 96, 89, 64, 16, 186, 143,
+            6, 97, 128, 175, 87, 242, 167, 84, 129, 23, 166, 129, 11, 21, 228, 42, 72, 253, 45, 86,
+            5, 209, 33, 249, 222, 111, 247, 173, 253, 242, 99, 163, 44, 191, 45, 88, 235, 203, 29,
+            23, 243, 125, 4, 177, 121, 65, 71, 207, 87, 216, 198, 107, 85, 156, 60, 180, 165, 93,
+            193, 131, 230, 7, 86, 109, 113, 40, 122, 227, 33, 170, 182, 104, 119, 112, 169, 150,
+            160, 26, 52, 137, 53, 227, 145, 139, 236, 71, 246, 55, 103, 249, 33, 96, 36, 242, 235,
+            132, 15, 191, 84, 228, 67, 62, 47, 239, 146, 217, 222, 69, 89, 77, 198, 108, 14, 248,
+            217, 87, 81, 234, 171, 64, 208, 58, 116, 163, 18, 103, 164, 51, 88, 135, 144, 106, 89,
+            58, 187, 66, 102, 34, 28, 138, 130, 230, 146, 147, 81, 93, 51, 100, 109, 149, 160, 18,
+            130, 140, 238, 126, 35, 230, 84, 182, 87, 114, 78, 2, 70, 239, 98, 126, 73, 241, 16,
+            177, 14, 209, 115, 186, 173, 89, 160, 82, 104, 57, 217, 11, 149, 113, 33, 92, 118, 180,
+            235, 167, 118, 143, 186, 115, 222, 67, 24, 24, 88, 55, 118, 181, 216, 154, 4, 12, 57,
+            53, 153, 138, 211, 64, 74, 82, 114, 153, 13, 242, 81, 168, 84, 14, 118, 212, 138, 134,
+            226, 131, 20, 94, 146, 118, 63, 100, 68, 68, 56, 137, 166, 240, 87, 196, 169, 164, 17,
+            197, 223, 93, 162, 73, 197, 147, 57, 59, 208, 68, 131, 205, 175, 90, 211, 158, 251,
+            143, 245, 208, 55, 23, 107, 96, 61, 62, 188, 131, 167, 173, 216, 173, 177, 126, 231,
+            245, 128, 255, 147, 13, 106, 193, 152, 212, 141, 9, 164, 195, 27, 201, 135, 11, 67,
+            186, 173, 152, 3, 27, 126, 20, 153, 24, 2, 5, 164, 57, 107, 221, 242, 135, 75, 228, 32,
+            113, 205, 226, 82, 240, 14, 81, 122, 20, 40, 16, 227, 32, 206, 94, 121, 86, 43, 201,
+            195, 8, 191, 38, 124, 230, 159, 130, 246, 4, 148, 93, 35, 221, 15, 44, 114, 225, 39,
+            160, 114, 43, 135, 6, 210, 170, 70, 35, 169, 172, 176, 244, 170, 120, 9, 213, 108, 51,
+            133, 169, 101, 204, 65, 117, 116, 140, 79, 99, 47, 172, 125, 97, 200, 209, 73, 129,
+            215, 41, 74, 138, 94, 138, 106, 23, 148, 5, 208, 184, 67, 77, 236, 235, 101, 1, 27, 68,
+            135, 162, 234, 58, 156, 65, 56, 10, 221, 137, 111, 253, 219, 66, 214, 164, 235, 133,
+            168, 79, 151, 176, 194, 234, 33, 125, 3, 233, 163, 220, 148, 123, 180, 228, 104, 81,
+            12, 109, 173, 133, 168, 65, 177, 232, 80, 118, 114, 22, 102, 105, 40, 156, 152, 181,
+            127, 16, 175, 150, 35, 46, 237, 240, 166, 238, 26, 229, 154, 198, 239, 38, 79, 131,
+            109, 50, 77, 209, 179, 181, 31, 215, 67, 208, 62, 178, 67, 4, 18, 53, 57, 134, 203,
+            165, 83, 164, 93, 94, 98, 229, 23, 20, 195, 105, 246, 34, 69, 202, 45, 169, 143, 41,
+            131, 249, 51, 74, 246, 190, 89, 198, 4, 111, 167, 120, 36, 114, 228, 1, 215, 200, 158,
+            79, 229, 222, 84, 213, 231, 63, 147, 177, 163, 181, 192, 24, 138, 105, 234, 115, 58,
+            246, 168, 164, 30, 164, 168, 17, 48, 9, 108, 92, 214, 142, 23, 170, 153, 26, 69, 205,
+            133, 243, 220, 91, 34, 125, 32, 253, 134, 253, 39, 134, 51, 166, 185, 83, 227, 195, 45,
+            61, 49, 245, 154, 215, 140, 169, 156, 113, 42, 7, 200, 170, 52, 142, 8, 204, 71, 23, 2,
+            128, 61, 117, 80, 155, 109, 209, 139, 79, 180, 47, 226, 238, 64, 115, 34, 61, 67, 232,
+            120, 94, 237, 121, 205, 254, 98, 141, 221, 173, 62, 94, 159, 37, 71, 171, 45, 64, 181,
+            193, 217, 235, 27, 140, 19, 210, 82, 33, 37, 103, 218, 126, 81, 212, 115, 196, 145, 50,
+            157, 74, 168, 17, 86, 151, 156, 165, 21, 70, 65, 183, 108, 187, 54, 9, 227, 33, 72, 77,
+            201, 21, 25, 22, 32, 225, 160, 180, 89, 30, 48, 1, 240, 65, 160, 177, 5, 100, 3, 48,
+            168, 103, 119, 245, 153, 164, 251, 234, 222, 94, 149, 79, 180, 90, 21, 36, 53, 253,
+            229, 183, 129, 6, 24, 67, 65, 117, 88, 195, 83, 52, 84, 88, 210, 167, 158, 97, 195,
+            174, 168, 193, 219, 182, 28, 106, 6, 80, 250, 153, 250, 25, 214, 147, 53, 242, 81, 86,
+            98, 27, 59, 128, 37, 104, 226, 159, 178, 98, 108, 30, 6, 83, 171, 127, 54, 114, 96, 54,
+            245, 159, 57, 190, 125, 84, 57, 80, 9, 182, 142, 173, 124, 10, 111, 132, 156, 112, 192,
+            56, 99, 35, 142, 25, 160, 30, 230, 233, 163, 35, 124, 97, 78, 239, 218, 20, 167, 16,
+            75, 136, 243, 32, 76, 74, 154, 44, 106, 96, 98, 70, 130, 253, 3, 160, 245, 250, 18,
+            222, 201, 72, 42, 109, 7, 122, 220, 163, 44, 120, 186, 32, 28, 98, 200, 129, 192, 225,
+            97, 145, 1, 109, 145, 226, 3, 67, 188, 33, 34, 8, 181, 247, 183, 22, 200, 32, 172, 150,
+            167, 106, 44, 46, 162, 108, 22, 60, 50, 89, 234, 133, 34, 217, 182, 118, 66, 160, 100,
+            129, 148, 94, 107, 255, 100, 161, 137, 100, 42, 243, 238, 158, 25, 136, 173, 128, 147,
+            48, 171, 91, 72, 49, 116, 153, 30, 162, 219, 136, 212, 223, 0, 135, 15, 87, 179, 37,
+            77, 178, 75, 159, 126, 67, 40, 155, 186, 218, 44, 123, 64, 214, 25, 122, 40, 167, 30,
+            66, 90, 227, 74, 214, 215, 43, 24, 37, 45, 224, 131, 91, 33, 93, 8, 46, 163, 58, 26,
+            66, 131, 238, 208, 233, 93, 35, 248, 55, 176, 48, 71, 114, 108, 104, 22, 193, 38, 37,
+            148, 22, 117, 75, 197, 6, 122, 217, 3, 47, 43, 120, 191, 85, 226, 143, 184, 134, 165,
+            143, 104, 74, 37, 248, 52, 21, 163, 22, 224, 142, 32, 144, 22, 242, 48, 73, 40, 17,
+            132, 108, 14, 210, 146, 228, 255, 93, 168, 165, 82, 30, 35, 210,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 59086715);
+    }
+
+    #[test]
+    pub fn nist_test_verify_6() {
+        let nonce_msg = [
+            4, 123, 200, 254, 174, 106, 17, 75, 35, 187, 193, 203, 22, 224, 216, 74, 115, 106, 186,
+            38, 208, 121, 221, 101, 35, 40, 138, 61, 242, 213, 77, 38, 222, 101, 139, 176, 6, 117,
+            163, 1, 0, 115, 190, 233, 127, 201, 124, 15, 188, 117, 13, 71, 74, 235, 147, 24, 159,
+            6, 30, 26, 92, 246, 96, 12, 4, 251, 4, 100, 51, 142, 199, 232, 82, 82, 249, 79, 203,
+            199, 178, 189, 0, 228, 56, 72, 13, 154, 243, 173, 217, 42, 146, 227, 226, 232, 172,
+            181, 80, 119, 195, 39, 143, 199, 80, 57, 136, 167, 110, 155, 96, 98, 153, 107, 32, 136,
+            154, 165, 91, 52, 61, 90, 0, 60, 138, 136, 82, 215, 56, 249, 85, 121, 159, 163, 66,
+            107, 229, 204, 211, 170, 107, 110, 218, 4, 212, 136, 73, 65, 255, 192, 182, 156, 90,
+            207, 18, 179, 71, 167, 77, 5, 128, 204, 51, 53, 186, 129, 98, 0, 248, 118, 116, 164,
+            193, 217, 128, 151, 199, 15, 47, 39, 199, 78, 148, 166, 97, 133, 6, 16, 236, 244, 132,
+            122, 181, 181, 131, 68, 249, 88, 197, 113, 158, 6, 186, 57, 98, 37, 187, 226, 26, 203,
+            15, 220, 81, 43, 136, 93, 57, 30, 17, 176, 192, 237, 92, 230, 181, 221, 143, 175, 249,
+            31, 80, 2, 92, 105, 212, 48, 114, 247, 112, 109, 128, 217, 253, 120, 110, 17, 4, 18,
+            93, 121, 165, 244, 181, 253, 131, 136, 21, 212, 79, 200, 177, 171, 103, 128, 120, 204,
+            23, 77, 222, 151, 13, 68, 139,
+        ];
+        let sig = [
+            182, 72, 13, 114, 191, 209, 79, 82, 179, 178, 194, 185, 45, 158, 7, 54, 111, 210, 45,
+            1, 198, 30, 61, 252, 94, 207, 0, 206, 91, 36, 101, 211, 155, 144, 67, 94, 115, 118, 50,
+            234, 143, 161, 63, 203, 38, 243, 76, 3, 163, 239, 133, 254, 19, 185, 77, 6, 32, 202,
+            184, 195, 105, 63, 185, 176, 183, 22, 49, 168, 206, 229, 37, 46, 130, 43, 245, 205,
+            192, 72, 194, 11, 0, 117, 34, 218, 105, 86, 50, 149, 146, 75, 167, 243, 178, 50, 185,
+            215, 188, 152, 54, 223, 4, 247, 247, 230, 139, 254, 170, 79, 63, 161, 232, 158, 105,
+            174, 58, 55, 128, 16, 138, 50, 71, 192, 182, 239, 21, 93, 214, 21, 33, 211, 109, 57,
+            44, 20, 65, 175, 98, 251, 156, 79, 66, 190, 208, 118, 224, 44, 82, 148, 193, 243, 120,
+            188, 95, 21, 163, 156, 82, 161, 154, 149, 31, 148, 157, 221, 82, 197, 118, 17, 172, 88,
+            171, 44, 246, 112, 129, 145, 35, 70, 134, 53, 205, 131, 36, 108, 52, 72, 152, 209, 36,
+            180, 191, 190, 32, 131, 192, 203, 47, 234, 83, 35, 72, 250, 147, 73, 87, 211, 174, 115,
+            222, 113, 250, 98, 213, 217, 50, 245, 144, 90, 231, 198, 66, 13, 102, 126, 161, 239,
+            29, 102, 217, 223, 173, 101, 103, 61, 166, 149, 170, 71, 217, 249, 145, 18, 218, 34,
+            169, 196, 202, 150, 78, 252, 73, 93, 181, 223, 167, 112, 239, 182, 213, 227, 64, 189,
+            89, 230, 173, 84, 201, 59, 202, 165, 133, 90, 65, 167, 93, 124, 85, 86, 114, 105, 178,
+            147, 177, 77, 91, 233, 199, 151, 145, 54, 41, 97, 178, 114, 80, 126, 171, 62, 191, 93,
+            229, 173, 49, 15, 138, 141, 126, 203, 179, 233, 239, 249, 179, 58, 68, 230, 108, 176,
+            226, 168, 179, 111, 220, 149, 0, 244, 193, 142, 44, 27, 203, 46, 170, 12, 218, 193,
+            154, 126, 119, 21, 29, 245, 19, 75, 176, 99, 120, 171, 143, 142, 188, 177, 65, 247, 76,
+            153, 229, 191, 49, 108, 104, 177, 123, 25, 28, 12, 6, 176, 182, 72, 224, 153, 2, 210,
+            129, 201, 83, 75, 73, 118, 169, 173, 95, 210, 45, 116, 18, 237, 19, 241, 101, 206, 200,
+            41, 22, 102, 162, 123, 47, 73, 174, 54, 218, 210, 90, 229, 218, 104, 54, 244, 222, 135,
+            247, 134, 136, 177, 227, 206, 30, 77, 17, 83, 161, 99, 120, 7, 247, 172, 9, 66, 61,
+            147, 119, 31, 85, 136, 239, 237, 60, 19, 44, 237, 215, 151, 63, 213, 225, 30, 235, 254,
+            197, 33, 130, 197, 81, 66, 63, 164, 110, 142, 195, 27, 152, 135, 166, 186, 114, 216,
+            213, 13, 139, 221, 194, 96, 232, 74, 70, 138, 126, 145, 96, 178, 207, 100, 74, 169, 11,
+            126, 38, 152, 56, 76, 163, 167, 217, 158, 198, 29, 152, 129, 57, 148, 186, 234, 200,
+            243, 247, 125, 8, 169, 0, 97, 227, 102, 65, 54, 65, 52, 247, 26, 122, 198, 179, 230,
+            18, 237, 166, 5, 38, 94, 26, 53, 11, 110, 120, 52, 140, 146, 237, 120, 155, 37, 74, 22,
+            101, 134, 209, 19, 195, 154, 77, 13, 217, 89, 212, 50, 116, 229, 118, 115, 28, 121,
+            173, 222, 11, 70, 13, 6, 53, 52, 158, 85, 117, 3, 154, 37, 249, 108, 210, 45, 134, 185,
+            170, 55, 220, 19, 96, 148, 247, 42, 42, 86, 149, 71, 182, 165, 100, 32, 162, 145, 100,
+            58, 70, 108, 106, 245, 8, 110, 99, 72, 130, 165, 233, 219, 114, 103, 17, 181, 127, 27,
+            15, 34, 67, 153, 223, 78, 224, 112, 135, 122, 38, 105, 167, 238, 102, 179, 213, 57, 37,
+            242, 142, 138, 125, 214, 143, 252, 45, 240, 105, 4, 63, 172, 110, 217, 38, 94, 31, 59,
+            123, 212, 123, 34, 74, 206, 56, 158, 175, 137, 134, 42, 172, 77, 213, 210, 161, 160,
+            48, 66, 218, 141, 151, 30, 186, 128, 120, 110, 184, 18, 37, 76, 101, 87, 114, 39, 201,
+            73, 43, 189, 67, 34, 190, 168, 217, 127, 43, 53, 235, 107, 172, 189, 120, 162, 40, 213,
+            180, 81, 234, 215, 132, 70, 164, 221, 139, 249, 249, 162, 232, 116, 200, 31, 150, 21,
+            17, 108, 27, 69, 118, 6, 73, 232, 55, 194, 16, 205, 93, 245, 74, 86, 99, 154, 251, 247,
+            21, 68, 129, 168, 184, 117, 34, 123, 124, 26, 173, 143, 36, 41, 243, 97, 41, 60, 152,
+            76, 203, 231, 214, 100, 228, 241, 59, 21, 9, 115, 223, 37, 52, 44, 244, 99, 136, 184,
+            40, 178, 102, 44, 193, 102, 203, 85, 182, 173, 12, 120, 114, 45, 15, 32, 245, 196, 82,
+            32, 161, 237, 16, 50, 89, 209, 231, 73, 84, 41, 3, 69, 112, 216, 26, 114, 163, 45, 217,
+            63, 180, 254, 163, 40, 134, 168, 84, 61, 197, 71, 202, 192, 8, 218, 23, 65, 41, 213,
+            127, 66, 19, 138, 139, 161, 211, 62, 93, 35, 233, 84, 162, 17, 229, 7, 126, 163, 70,
+            251, 44, 66, 16, 48, 208, 77, 187, 133, 209, 38, 255, 184, 162, 38, 130, 243, 114, 90,
+            251, 171, 177, 221, 248, 89, 168, 198, 189, 175, 171, 207, 98, 218, 105, 201, 125, 59,
+            167, 161, 99, 135, 35, 124, 68, 129, 97, 174, 146, 90, 127, 44, 162, 80, 17, 140, 129,
+            41, 100, 161, 204, 84, 24, 134, 98, 149, 52, 45, 7, 77, 177, 19, 78, 29, 182, 217, 46,
+            9, 141, 67, 199, 160, 240, 254, 21, 123, 19, 15, 242, 126, 188, 26, 158, 35, 175, 81,
+            46, 9, 160, 80, 112, 179, 92, 249, 82, 158, 247, 96, 238, 14, 78, 148, 43, 149, 240,
+            229, 83, 19, 58, 101, 145, 26, 8, 6, 116, 191, 192, 111, 207, 204, 47, 164, 214, 221,
+            13, 126, 26, 1, 248, 151, 152, 111, 175, 192, 95, 184, 232, 3, 47, 122, 65, 217, 12,
+            67, 65, 171, 204, 145, 191, 179, 88, 81, 73, 238, 45, 76, 210, 54, 246, 22, 82, 211,
+            138, 91, 94, 215, 203, 112, 135, 67, 208, 164, 105, 67, 59, 252, 123, 117, 87, 127, 65,
+            58, 76, 19, 179, 122, 73, 255, 230, 187, 32, 247, 105, 213, 13, 214, 251, 226, 220,
+            229, 158, 53, 91, 95, 251, 166, 86, 218, 186, 205, 145, 67, 123, 16, 21, 165, 27, 213,
+            66, 59, 206, 251, 25, 123, 107, 212, 70, 61, 18, 134, 249, 116, 197, 242, 188, 247,
+            122, 113, 170, 60, 102, 12, 143, 216, 42, 100, 162, 119, 199, 58, 119, 21, 191, 116,
+            41, 54, 246, 115, 69, 224, 144, 242, 32, 46, 254, 7, 205, 119, 146, 125, 218, 14, 204,
+            205, 109, 107, 244, 39, 78, 57, 249, 115, 105, 176, 5, 22, 245, 157, 220, 123, 41, 203,
+            98, 47, 73, 200, 239, 57, 20, 126, 242, 95, 152, 69, 47, 81, 121, 35, 80, 67, 85, 138,
+            252, 241, 27, 70, 236, 173, 50, 211, 14, 195, 185, 155, 51, 5, 42, 65, 180, 94, 6, 29,
+            184, 165, 197, 157, 38, 13, 167, 161, 181, 247, 170, 119, 142, 199, 255, 231, 249, 78,
+            116, 50,
+        ];
+        let pk = [
+            10, 114, 240, 199, 97, 25, 145, 199, 189, 38, 42, 154, 76, 9, 221, 108, 158, 54, 69,
+            10, 237, 100, 171, 208, 237, 81, 239, 102, 26, 114, 94, 177, 152, 187, 78, 153, 178,
+            164, 163, 81, 73, 168, 255, 94, 45, 206, 39, 7, 94, 71, 142, 198, 212, 200, 3, 213,
+            143, 96, 197, 61, 1, 43, 151, 214, 133, 197, 21, 96, 90, 172, 250, 98, 153, 180, 232,
+            31, 161, 33, 97, 185, 21, 169, 193, 196, 153, 94, 180, 122, 133, 151, 81, 14, 64, 146,
+            125, 22, 113, 4, 169, 12, 216, 189, 168, 229, 86, 55, 150, 5, 84, 132, 80, 220, 93,
+            188, 50, 102, 178, 96, 194, 226, 124, 168, 109, 64, 188, 104, 187, 127, 66, 23, 71,
+            240, 192, 26, 153, 234, 211, 87, 59, 76, 246, 67, 58, 241, 74, 251, 232, 242, 30, 64,
+            97, 35, 26, 92, 129, 59, 48, 126, 208, 154, 36, 178, 59, 12, 255, 198, 160, 33, 117,
+            61, 126, 135, 194, 158, 203, 83, 16, 209, 14, 196, 166, 5, 73, 141, 105, 26, 37, 7, 79,
+            102, 70, 90, 155, 148, 89, 85, 106, 134, 28, 166, 98, 107, 6, 63, 7, 69, 115, 20, 141,
+            169, 59, 147, 30, 209, 178, 246, 212, 17, 124, 88, 230, 20, 165, 7, 140, 121, 176, 82,
+            195, 88, 73, 159, 123, 186, 194, 208, 182, 131, 38, 79, 80, 213, 5, 110, 70, 132, 39,
+            2, 53, 80, 150, 221, 109, 22, 161, 82, 118, 175, 159, 46, 21, 214, 99, 134, 185, 237,
+            255, 14, 14, 79, 97, 126, 76, 163, 154, 246, 148, 40, 199, 87, 1, 44, 198, 193, 146,
+            69, 222, 37, 173, 102, 25, 68, 237, 44, 115, 74, 160, 182, 72, 223, 30, 222, 171, 125,
+            155, 242, 155, 72, 58, 23, 254, 162, 181, 14, 218, 171, 90, 94, 15, 193, 110, 86, 86,
+            30, 66, 204, 16, 162, 94, 33, 49, 110, 161, 32, 112, 215, 58, 176, 237, 158, 186, 216,
+            70, 12, 150, 253, 232, 112, 55, 41, 135, 48, 14, 28, 198, 45, 76, 220, 27, 138, 4, 194,
+            98, 236, 215, 90, 22, 80, 135, 56, 89, 243, 33, 156, 220, 251, 72, 66, 97, 95, 245,
+            131, 248, 220, 168, 139, 50, 132, 54, 49, 6, 224, 220, 51, 185, 107, 22, 43, 199, 238,
+            16, 217, 54, 73, 221, 195, 218, 33, 73, 214, 144, 148, 193, 61, 102, 104, 31, 163, 145,
+            198, 141, 187, 218, 58, 195, 16, 83, 48, 184, 133, 63, 178, 4, 43, 162, 52, 25, 156,
+            249, 237, 21, 70, 122, 33, 1, 187, 147, 92, 197, 188, 146, 50, 104, 183, 215, 250, 152,
+            45, 248, 230, 66, 232, 42, 115, 120, 159, 52, 107, 86, 171, 34, 161, 76, 18, 181, 138,
+            26, 143, 54, 226, 214, 162, 80, 35, 88, 5, 45, 41, 0, 195, 62, 64, 62, 163, 166, 188,
+            44, 29, 69, 164, 158, 246, 40, 22, 17, 167, 241, 220, 136, 116, 233, 71, 116, 57, 185,
+            100, 0, 101, 200, 175, 117, 121, 69, 176, 65, 144, 88, 174, 152, 195, 244, 110, 122,
+            129, 101, 213, 74, 74, 4, 206, 132, 97, 215, 235, 167, 95, 84, 7, 148, 104, 69, 35, 69,
+            15, 93, 65, 159, 20, 35, 212, 20, 36, 232, 233, 215, 143, 236, 40, 41, 156, 200, 35,
+            85, 217, 66, 16, 244, 50, 64, 131, 230, 75, 125, 98, 38, 151, 238, 11, 250, 175, 100,
+            17, 50, 199, 80, 215, 11, 234, 11, 53, 51, 161, 227, 114, 56, 23, 27, 79, 204, 5, 147,
+            241, 79, 18, 136, 136, 27, 158, 234, 242, 114, 108, 137, 16, 24, 177, 246, 68, 110,
+            134, 103, 2, 58, 32, 145, 123, 154, 5, 20, 20, 186, 245, 76, 234, 246, 115, 246, 179,
+            163, 26, 79, 63, 45, 213, 201, 69, 205, 72, 226, 86, 218, 121, 171, 74, 216, 19, 135,
+            20, 60, 245, 103, 93, 135, 90, 240, 86, 249, 13, 12, 223, 170, 212, 155, 202, 19, 218,
+            126, 6, 48, 127, 180, 25, 239, 49, 181, 188, 201, 35, 216, 152, 137, 104, 182, 214, 52,
+            4, 47, 181, 116, 217, 115, 229, 18, 16, 166, 32, 240, 248, 208, 73, 34, 224, 1, 122,
+            16, 209, 173, 110, 190, 24, 132, 22, 8, 10, 161, 9, 166, 45, 55, 232, 129, 95, 62, 71,
+            188, 32, 112, 96, 70, 96, 186, 186, 202, 23, 236, 204, 246, 8, 232, 42, 183, 191, 153,
+            7, 120, 93, 142, 37, 193, 33, 74, 179, 36, 80, 211, 232, 26, 149, 15, 64, 65, 137, 243,
+            140, 25, 101, 70, 98, 25, 58, 206, 222, 134, 237, 232, 87, 197, 145, 1, 45, 81, 31,
+            182, 109, 14, 26, 15, 249, 127, 155, 172, 224, 86, 148, 137, 153, 203, 225, 218, 208,
+            144, 238, 240, 240, 103, 22, 56, 30, 237, 86, 5, 84, 219, 239, 154, 93, 217, 6, 157,
+            157, 255, 176, 24, 149, 184, 169, 164, 77, 105, 246, 37, 88, 78, 77, 137, 22, 145, 215,
+            115, 3, 203, 247, 63, 109, 230, 66, 215, 95, 170, 142, 12, 43, 52, 122, 170, 31, 102,
+            157, 74, 59, 59, 2, 172, 32, 32, 15, 214, 86, 97, 166, 96, 54, 11, 114, 173, 162, 174,
+            26, 141, 199, 180, 49, 84, 31, 30, 141, 34, 248, 7, 92, 196, 142, 233, 126, 106, 75,
+            43, 102, 151, 208, 216, 160, 48, 204, 56, 42, 90, 25, 49, 236, 103, 200, 104, 94, 194,
+            169, 54, 83, 166, 129, 237, 185, 219, 218, 205, 234, 94, 117, 172, 36, 205, 199, 5, 26,
+            86, 235, 122, 18, 219, 233, 155, 165, 88, 33, 2, 29, 48, 182, 122, 136, 178, 171, 136,
+            218, 24, 214, 118, 40, 225, 29, 220, 143, 202, 246, 147, 23, 74, 53, 174, 32, 172, 116,
+            208, 94, 17, 27, 124, 79, 233, 251, 75, 141, 10, 172, 127, 183, 70, 142, 243, 58, 240,
+            251, 242, 88, 94, 220, 24, 157, 44, 230, 81, 65, 16, 71, 70, 142, 153, 151, 87, 71, 65,
+            10, 182, 71, 236, 91, 190, 4, 157, 166, 212, 102, 86, 204, 74, 113, 4, 75, 96, 192, 70,
+            85, 49, 223, 171, 168, 139, 212, 145, 182, 250, 224, 240, 169, 193, 94, 244, 133, 107,
+            43, 217, 192, 110, 225, 139, 227, 225, 75, 190, 73, 64, 152, 179, 32, 17, 184, 148,
+            188, 109, 83, 14, 13, 108, 85, 14, 82, 117, 157, 21, 170, 7, 201, 156, 167, 30, 204,
+            16, 137, 237, 140, 131, 129, 13, 91, 154, 2, 8, 100, 129, 143, 65, 119, 68, 78, 39, 87,
+            87, 231, 132, 102, 205, 112, 155, 40, 131, 131, 234, 203, 131, 189, 133, 179, 122, 108,
+            162, 250, 117, 206, 36, 119, 33, 68, 21, 21, 229, 86, 145, 252, 61, 34, 113, 74, 19,
+            37, 162, 163, 36, 18, 193, 168, 85, 110, 144, 161, 15, 146, 246, 6, 48, 238, 26, 240,
+            171, 36, 20, 77, 22, 74, 184, 231, 119, 43, 29, 114, 99, 138, 213, 1, 50, 245, 187,
+            184, 116, 220, 124, 60, 81, 98, 25, 141, 106, 188, 191, 138, 176, 249, 15, 237, 5, 26,
+            64, 113, 187, 253, 166, 243, 38, 18, 66, 105, 192, 165, 129, 19, 168, 197, 133, 80, 29,
+            8, 133, 100, 27, 163, 130, 216, 167, 140, 246, 7, 215, 23, 45, 231, 45, 53, 0, 218,
+            186, 159, 241, 178, 222, 212, 5, 33, 47, 121, 91, 82, 84, 152, 82, 3, 20, 190, 158, 58,
+            234, 134, 85, 201, 128, 20, 222, 11, 73, 105, 226, 27, 253, 70, 103, 98, 35, 173, 141,
+            38, 134, 203, 6, 89, 134, 134, 218, 22, 53, 66, 164, 132, 7, 242, 31, 209, 122, 106,
+            62, 75, 190, 244, 240, 136, 32, 20, 11, 148, 18, 32, 239, 69, 170, 136, 17, 5, 227, 51,
+            208, 95, 5, 196, 123, 228, 76, 145, 29, 65, 41, 106, 131, 94, 7, 234, 163, 177, 238,
+            48, 220, 11, 136, 138, 154, 96, 152, 161, 89, 46, 35, 153, 50, 137, 184, 128, 53, 88,
+            248, 215, 187, 65, 7, 244, 72, 65, 106, 216, 138, 64, 205, 64, 204, 209, 88, 163, 197,
+            183, 171, 165, 73, 219, 192, 150, 35, 135, 140, 250, 5, 226, 196, 3, 81, 45, 195, 149,
+            85, 30, 69, 149, 4, 89, 19, 217, 76, 3, 19, 22, 34, 120, 52, 15, 208, 121, 226, 81,
+            169, 2, 204, 129, 143, 8, 30, 40, 45, 42, 115, 58, 53, 148, 123, 90, 77, 237, 23, 4, 3,
+            181, 101, 166, 21, 162, 16, 35, 10, 36, 87, 227, 75, 241, 112, 214, 90, 81, 209, 131,
+            66, 135, 116, 192, 15, 238, 125, 250, 243, 105, 215, 134, 95, 53, 42, 37, 89, 138, 210,
+            9, 81, 96, 138, 228, 35, 214, 205, 64, 34, 234, 245, 110, 136, 58, 124, 44, 211, 116,
+            250, 157, 25, 55, 22, 128, 139, 194, 160, 70, 75, 17, 15, 49, 52, 214, 18, 40, 178,
+            173, 43, 15, 199, 70, 8, 173, 190, 144, 73, 138, 99, 40, 53, 103, 242, 49, 27, 82, 97,
+            36, 50, 210, 2, 228, 125, 92, 126, 60, 9, 45, 23, 49, 55, 168, 91, 119, 231, 22, 170,
+            118, 32, 216, 129, 239, 95, 95, 89, 224, 248, 131, 143, 215, 2, 174, 250, 108, 153,
+            251, 232, 67, 47, 37, 164, 226, 5, 163, 8, 163, 20, 54, 133, 80, 96, 65, 116, 169, 34,
+            55, 98, 132, 142, 62, 209, 54, 243, 188, 136, 30, 145, 149, 206, 10, 149, 30, 53, 32,
+            194, 52, 129, 18, 6, 92, 124, 34, 81, 185, 233, 86, 121, 161, 149, 166, 18, 150, 170,
+            186, 82, 18, 39, 165, 255, 137, 63, 157, 2, 87, 242, 241, 76, 87, 179, 14, 215, 102,
+            66, 104, 42, 74, 49, 246, 40, 136, 110, 51, 166, 44, 163, 36, 69, 218, 60, 77, 20, 162,
+            51, 247, 144, 216, 178, 85, 126, 203, 58, 40, 150, 67, 122, 17, 215, 188, 129, 206, 1,
+            26, 232, 215, 228, 70, 101, 7, 36, 126, 121, 232, 173, 128, 80, 217, 202, 225, 87, 144,
+            229, 57, 32, 186, 224, 249, 47, 140, 6, 8, 197, 1, 170, 1, 30, 182, 17, 122, 179, 84,
+            158, 55, 42, 85, 130, 3, 59, 0, 18, 141, 157, 150, 10, 176, 9, 164, 125, 241, 42, 84,
+            11, 147, 23, 58, 242, 108, 180, 185, 237, 56, 82, 170, 1, 72, 190, 41, 139, 179, 101,
+            51, 196, 24, 47, 121, 128, 13, 99, 145, 245, 103, 58,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 60287177);
+    }
+
+    #[test]
+    pub fn nist_test_verify_7() {
+        let nonce_msg = [
+            32, 61, 101, 38, 123, 220, 208, 69, 204, 128, 238, 3, 16, 8, 10, 109, 186, 91, 48, 123,
+            84, 255, 252, 73, 179, 110, 210, 219, 205, 117, 232, 130, 63, 219, 136, 134, 134, 231,
+            15, 238, 161, 88, 98, 69, 216, 31, 150, 189, 142, 232, 26, 163, 15, 16, 192, 173, 179,
+            67, 215, 76, 247, 44, 77, 255, 113, 85, 12, 18, 135, 58, 248, 159, 161, 135, 77, 71,
+            49, 201, 150, 36, 60, 55, 73, 175, 63, 97, 136, 255, 233, 250, 69, 67, 5, 73, 4, 81,
+            52, 235, 41, 239, 60, 236, 55, 231, 41, 4, 170, 8, 43, 28, 97, 97, 230, 181, 35, 97,
+            228, 154, 244, 147, 58, 141, 140, 7, 52, 242, 28, 175, 215, 70, 123, 12, 2, 135, 111,
+            67, 33, 29, 97, 34, 227, 231, 53, 254, 54, 6, 77, 247, 160, 201, 20, 73, 35, 124, 43,
+            199, 195, 167, 138, 199, 187, 15, 149, 103, 242, 87, 111, 5, 128, 44, 135, 42, 223, 24,
+            58, 135, 170, 59, 130, 23, 24, 143, 47, 53, 53, 248, 119, 114, 79, 53, 178, 158, 84,
+            93, 228, 188, 242, 88, 241, 59, 188, 126, 221, 140, 101, 135, 247, 51, 201, 105, 31,
+            116, 180, 21, 28, 248, 192, 96, 195, 174, 158, 141, 73, 254, 124, 119, 191, 71, 125,
+            201, 242, 63, 208, 240, 182, 115, 32, 39, 85, 41, 3, 75, 132, 249, 65, 118, 115, 9, 35,
+            192, 58, 165, 15, 149, 132, 217, 194, 214, 11, 141, 204, 248, 90, 19, 242, 67, 243, 10,
+            81, 171, 239, 187, 242, 205, 166, 2, 191, 61, 117, 232, 73, 235, 146, 66, 43, 128, 132,
+            22, 199, 229, 107, 4, 108, 227, 142, 70, 119, 173, 36, 210, 61, 114, 55, 169,
+        ];
+        let sig = [
+            113, 121, 81, 197, 173, 234, 124, 223, 25, 117, 6, 35, 181, 141, 170, 147, 24, 172,
+            226, 182, 238, 187, 249, 206, 238, 74, 37, 100, 81, 107, 172, 33, 147, 90, 101, 152,
+            88, 148, 33, 78, 75, 118, 82, 174, 61, 110, 44, 69, 220, 155, 252, 90, 208, 168, 163,
+            244, 75, 160, 155, 252, 43, 19, 213, 165, 7, 207, 75, 239, 123, 138, 76, 25, 12, 133,
+            74, 209, 63, 133, 60, 187, 238, 117, 46, 44, 239, 77, 248, 240, 106, 173, 107, 180,
+            184, 73, 42, 232, 102, 131, 65, 155, 248, 163, 85, 99, 170, 217, 254, 204, 205, 208,
+            132, 234, 166, 111, 44, 250, 231, 68, 73, 16, 245, 186, 127, 59, 182, 88, 215, 201,
+            193, 143, 80, 120, 246, 18, 106, 223, 60, 151, 230, 184, 96, 229, 73, 242, 245, 102,
+            234, 254, 222, 255, 162, 213, 253, 137, 231, 57, 46, 68, 186, 36, 252, 157, 52, 251,
+            93, 27, 211, 25, 24, 70, 103, 245, 1, 108, 97, 92, 39, 227, 57, 5, 38, 60, 212, 209,
+            122, 137, 42, 44, 234, 21, 41, 210, 177, 77, 22, 6, 182, 156, 33, 253, 132, 41, 144,
+            129, 210, 220, 170, 3, 181, 171, 239, 48, 204, 178, 47, 96, 112, 226, 155, 6, 104, 196,
+            44, 173, 196, 41, 36, 126, 251, 154, 54, 14, 80, 66, 230, 248, 46, 179, 52, 67, 158,
+            172, 33, 193, 115, 108, 187, 205, 227, 47, 221, 214, 19, 36, 87, 175, 109, 95, 28, 99,
+            77, 164, 20, 166, 150, 106, 244, 205, 234, 179, 150, 105, 195, 201, 27, 220, 239, 237,
+            241, 174, 57, 83, 244, 243, 48, 143, 60, 13, 242, 247, 92, 130, 177, 87, 28, 67, 43,
+            231, 136, 48, 227, 20, 105, 16, 30, 252, 151, 23, 3, 41, 75, 244, 199, 95, 129, 199,
+            49, 13, 98, 77, 2, 171, 208, 162, 207, 18, 87, 32, 247, 22, 70, 93, 168, 66, 43, 58,
+            204, 192, 134, 38, 14, 174, 135, 180, 75, 184, 174, 102, 155, 34, 87, 77, 164, 119,
+            101, 226, 107, 155, 230, 53, 243, 228, 154, 239, 14, 45, 187, 162, 157, 67, 142, 124,
+            162, 35, 235, 195, 226, 190, 170, 62, 140, 119, 108, 142, 98, 213, 32, 125, 18, 14, 69,
+            19, 81, 198, 169, 248, 217, 38, 190, 193, 251, 189, 124, 44, 112, 34, 18, 172, 111, 43,
+            179, 62, 127, 47, 18, 80, 229, 26, 119, 87, 18, 223, 35, 78, 25, 40, 164, 33, 230, 138,
+            12, 185, 16, 109, 139, 27, 48, 187, 208, 234, 88, 5, 199, 238, 160, 197, 74, 82, 15,
+            185, 108, 95, 46, 43, 159, 149, 110, 106, 116, 34, 65, 198, 195, 103, 144, 250, 147,
+            171, 30, 112, 78, 165, 189, 219, 245, 239, 75, 69, 9, 232, 198, 222, 109, 109, 16, 183,
+            147, 233, 209, 41, 205, 187, 21, 227, 153, 19, 164, 210, 125, 245, 248, 26, 1, 186,
+            138, 33, 146, 57, 73, 111, 220, 125, 50, 199, 6, 159, 92, 244, 17, 22, 69, 73, 129,
+            198, 222, 188, 228, 203, 145, 95, 42, 49, 200, 115, 195, 19, 166, 93, 24, 237, 116,
+            111, 202, 231, 109, 142, 79, 202, 59, 37, 50, 242, 251, 17, 30, 86, 244, 80, 140, 78,
+            125, 185, 41, 71, 98, 161, 156, 149, 168, 92, 40, 198, 112, 166, 145, 8, 57, 133, 154,
+            56, 100, 7, 209, 227, 177, 40, 61, 68, 205, 138, 118, 21, 91, 161, 193, 153, 39, 215,
+            130, 171, 178, 245, 37, 4, 26, 49, 143, 149, 64, 113, 7, 254, 62, 91, 226, 67, 69, 65,
+            222, 219, 184, 109, 234, 117, 36, 199, 220, 190, 28, 46, 132, 229, 212, 107, 8, 226,
+            151, 180, 34, 36, 119, 33, 148, 58, 179, 88, 139, 163, 215, 247, 23, 75, 188, 32, 254,
+            207, 185, 153, 174, 245, 162, 68, 108, 94, 60, 229, 41, 27, 123, 153, 139, 186, 13, 57,
+            205, 201, 81, 91, 94, 17, 202, 172, 50, 9, 106, 159, 107, 116, 209, 223, 45, 38, 5, 82,
+            141, 66, 83, 166, 135, 123, 57, 159, 23, 222, 54, 65, 11, 226, 205, 183, 12, 97, 112,
+            199, 38, 94, 60, 41, 196, 213, 147, 189, 180, 130, 93, 71, 219, 179, 43, 36, 69, 53,
+            72, 154, 235, 233, 138, 120, 211, 186, 183, 122, 16, 49, 123, 11, 94, 81, 123, 224,
+            229, 27, 170, 159, 92, 206, 216, 226, 123, 216, 98, 130, 233, 254, 50, 233, 185, 158,
+            219, 238, 181, 210, 53, 119, 6, 149, 70, 9, 220, 24, 237, 97, 19, 93, 109, 175, 227,
+            26, 100, 82, 148, 76, 168, 166, 63, 45, 247, 225, 172, 76, 47, 142, 137, 201, 103, 88,
+            21, 24, 212, 199, 240, 183, 79, 139, 92, 198, 89, 126, 203, 63, 57, 130, 55, 68, 195,
+            67, 135, 91, 88, 102, 245, 162, 67, 85, 94, 145, 173, 219, 88, 136, 21, 28, 115, 215,
+            102, 234, 147, 129, 8, 98, 14, 121, 225, 134, 125, 51, 250, 232, 190, 110, 74, 49, 131,
+            166, 104, 170, 51, 166, 141, 227, 77, 16, 120, 84, 226, 159, 127, 87, 141, 126, 110,
+            239, 8, 111, 97, 8, 48, 195, 245, 177, 220, 228, 41, 189, 122, 88, 77, 10, 150, 202,
+            20, 106, 108, 158, 216, 250, 49, 172, 197, 83, 142, 219, 106, 25, 167, 143, 84, 114,
+            93, 166, 180, 228, 182, 152, 61, 253, 241, 173, 202, 84, 243, 143, 218, 40, 109, 171,
+            10, 108, 233, 61, 23, 123, 139, 81, 171, 159, 86, 182, 237, 17, 215, 32, 9, 167, 105,
+            148, 193, 60, 104, 4, 191, 104, 136, 175, 103, 20, 167, 175, 169, 131, 201, 206, 207,
+            114, 49, 201, 78, 175, 13, 254, 196, 200, 125, 220, 11, 172, 251, 199, 58, 210, 244,
+            68, 57, 251, 142, 83, 147, 198, 107, 187, 55, 122, 55, 237, 179, 149, 183, 163, 167,
+            12, 34, 253, 198, 100, 153, 169, 87, 59, 208, 212, 140, 142, 67, 149, 25, 69, 8, 197,
+            251, 226, 146, 166, 69, 125, 181, 255, 160, 89, 199, 223, 20, 242, 237, 103, 206, 19,
+            11, 183, 172, 79, 236, 146, 37, 207, 120, 220, 83, 172, 183, 29, 2, 69, 235, 156, 97,
+            121, 72, 27, 144, 195, 35, 73, 151, 91, 21, 64, 129, 53, 186, 19, 19, 240, 62, 86, 38,
+            195, 139, 130, 100, 254, 57, 52, 142, 32, 113, 232, 200, 92, 139, 206, 208, 156, 139,
+            139, 88, 208, 120, 216, 236, 204, 207, 23, 14, 79, 58, 200, 113, 129, 105, 15, 47, 94,
+            106, 204, 186, 86, 166, 60, 137, 30, 12, 50, 192, 224, 101, 42, 53, 79, 59, 2, 225, 24,
+            199, 171, 235, 223, 117, 59, 26, 140, 221, 159, 173, 24, 71, 17, 42, 203, 121, 180,
+            183, 61, 185, 116, 19, 145, 176, 138, 179, 202, 198, 56, 249, 217, 181, 239, 227, 162,
+            69, 29, 146, 206, 132, 249, 223, 27, 171, 181, 121, 238, 240, 52, 229, 128, 195, 236,
+            18, 44, 252, 21, 191, 156, 161, 197, 217, 145, 67, 179, 45, 102, 10, 49, 98, 72, 84,
+            110, 29, 226, 48, 207, 153, 20, 100, 160, 97, 77, 184, 156, 32,
+        ];
+        let pk = [
+            10, 106, 245, 159, 74, 87, 0, 255, 115, 81, 94, 51, 154, 100, 80, 112, 18, 21, 74, 140,
+            213, 193, 159, 6, 107, 41, 185, 141, 25, 152, 141, 77, 201, 168, 175, 40, 15, 182, 119,
+            212, 51, 92, 94, 104, 24, 251, 96, 99, 170, 10, 111, 106, 23, 32, 237, 136, 95, 1, 153,
+            53, 134, 118, 23, 137, 88, 41, 161, 199, 54, 87, 140, 73, 161, 61, 151, 185, 150, 138,
+            23, 144, 44, 170, 244, 168, 220, 124, 37, 128, 34, 135, 77, 231, 12, 177, 203, 178,
+            114, 69, 95, 87, 226, 252, 195, 148, 66, 214, 79, 89, 125, 99, 46, 154, 26, 40, 98, 73,
+            33, 132, 237, 92, 123, 174, 186, 27, 254, 67, 168, 60, 205, 10, 2, 50, 230, 215, 58,
+            89, 5, 21, 188, 29, 28, 97, 56, 255, 160, 217, 82, 140, 96, 120, 103, 182, 222, 31,
+            235, 113, 254, 227, 133, 221, 236, 243, 165, 74, 197, 139, 158, 33, 137, 144, 2, 110,
+            0, 106, 14, 160, 191, 96, 123, 17, 60, 236, 81, 122, 80, 24, 180, 212, 73, 204, 96,
+            133, 135, 130, 227, 174, 200, 6, 74, 87, 152, 114, 26, 124, 183, 166, 115, 137, 124,
+            202, 73, 3, 173, 198, 117, 2, 157, 63, 156, 156, 26, 59, 187, 85, 195, 95, 98, 123,
+            183, 244, 141, 138, 70, 250, 73, 229, 120, 130, 97, 100, 18, 26, 104, 30, 102, 192,
+            154, 193, 115, 54, 21, 95, 87, 64, 29, 226, 200, 112, 106, 226, 177, 128, 118, 161,
+            240, 9, 227, 151, 218, 217, 37, 160, 101, 239, 100, 140, 173, 16, 51, 201, 124, 6, 28,
+            251, 97, 121, 142, 78, 90, 249, 67, 234, 106, 34, 99, 120, 153, 35, 84, 163, 77, 143,
+            60, 49, 108, 22, 247, 192, 170, 183, 210, 223, 66, 131, 41, 8, 73, 109, 242, 25, 85,
+            137, 92, 185, 13, 17, 213, 113, 147, 112, 1, 12, 75, 165, 174, 64, 125, 169, 4, 97,
+            240, 1, 12, 107, 72, 241, 235, 249, 117, 110, 198, 133, 10, 215, 34, 134, 192, 195, 3,
+            154, 20, 228, 73, 171, 234, 7, 98, 126, 170, 18, 215, 163, 4, 56, 12, 234, 161, 197,
+            101, 166, 205, 120, 87, 20, 202, 59, 24, 170, 146, 73, 81, 83, 90, 123, 122, 101, 21,
+            121, 92, 135, 62, 253, 100, 71, 188, 19, 63, 147, 25, 118, 181, 20, 13, 39, 46, 62,
+            138, 41, 41, 104, 208, 153, 29, 186, 107, 206, 27, 2, 117, 188, 49, 65, 91, 83, 17,
+            133, 122, 189, 89, 121, 205, 227, 159, 92, 220, 147, 246, 200, 224, 49, 130, 81, 7,
+            221, 0, 1, 104, 226, 129, 22, 142, 175, 137, 83, 137, 164, 168, 177, 25, 78, 35, 26,
+            96, 21, 32, 197, 243, 190, 42, 76, 112, 127, 78, 104, 51, 185, 200, 55, 40, 87, 56, 79,
+            106, 254, 36, 223, 223, 89, 105, 20, 199, 75, 4, 221, 175, 59, 125, 250, 7, 10, 132,
+            16, 132, 206, 132, 219, 171, 175, 167, 26, 133, 192, 91, 59, 9, 52, 64, 45, 12, 114,
+            44, 46, 252, 110, 108, 243, 249, 56, 216, 16, 165, 230, 59, 243, 93, 215, 94, 177, 92,
+            226, 249, 23, 161, 147, 163, 1, 123, 116, 132, 97, 67, 121, 4, 88, 195, 32, 132, 39,
+            150, 137, 89, 112, 125, 223, 224, 94, 6, 97, 118, 234, 79, 61, 21, 237, 253, 147, 216,
+            205, 29, 145, 110, 18, 100, 1, 39, 224, 80, 32, 4, 179, 94, 96, 130, 112, 50, 44, 24,
+            132, 237, 91, 160, 142, 86, 43, 242, 210, 39, 93, 205, 230, 69, 207, 169, 97, 59, 17,
+            76, 155, 143, 14, 183, 16, 25, 54, 176, 100, 100, 166, 4, 20, 81, 96, 238, 66, 128, 22,
+            186, 135, 246, 33, 202, 71, 180, 81, 160, 121, 57, 145, 168, 190, 194, 44, 200, 34,
+            157, 147, 132, 153, 110, 132, 174, 214, 138, 105, 178, 31, 91, 128, 76, 197, 39, 66,
+            56, 154, 177, 70, 113, 175, 48, 142, 216, 244, 152, 194, 130, 32, 82, 130, 190, 164, 4,
+            156, 182, 151, 1, 144, 79, 142, 175, 66, 209, 89, 251, 10, 230, 191, 242, 74, 7, 244,
+            66, 147, 1, 177, 194, 212, 155, 146, 225, 24, 26, 203, 245, 238, 34, 191, 159, 120,
+            152, 99, 216, 173, 111, 158, 204, 112, 100, 239, 213, 73, 157, 162, 74, 8, 46, 231, 84,
+            116, 192, 196, 194, 158, 37, 71, 109, 129, 153, 51, 164, 237, 193, 50, 242, 27, 119,
+            31, 9, 43, 150, 221, 99, 146, 235, 96, 164, 173, 121, 60, 101, 166, 162, 57, 122, 17,
+            63, 180, 2, 192, 169, 132, 14, 20, 66, 70, 98, 2, 77, 72, 111, 52, 100, 229, 217, 125,
+            17, 245, 105, 21, 217, 82, 53, 124, 176, 83, 60, 239, 129, 169, 120, 173, 216, 129,
+            147, 164, 64, 0, 91, 72, 246, 147, 131, 169, 69, 144, 129, 243, 204, 40, 74, 222, 189,
+            122, 58, 130, 47, 110, 224, 139, 101, 171, 19, 149, 190, 105, 81, 169, 6, 161, 200,
+            175, 166, 217, 118, 254, 7, 44, 6, 100, 178, 129, 160, 152, 25, 146, 176, 149, 245, 53,
+            221, 117, 126, 241, 159, 193, 159, 151, 160, 75, 68, 3, 231, 220, 142, 150, 172, 141,
+            227, 241, 146, 7, 176, 133, 70, 62, 225, 140, 106, 95, 70, 169, 159, 2, 26, 85, 37,
+            163, 110, 234, 99, 18, 1, 29, 85, 65, 183, 133, 243, 31, 44, 185, 224, 252, 100, 3,
+            171, 220, 5, 46, 212, 179, 140, 33, 156, 158, 104, 71, 139, 150, 32, 194, 82, 141, 3,
+            57, 206, 168, 58, 153, 248, 99, 53, 221, 94, 19, 96, 22, 234, 84, 187, 230, 204, 118,
+            150, 144, 246, 123, 12, 122, 190, 217, 97, 227, 77, 69, 237, 139, 208, 22, 83, 134, 42,
+            109, 62, 193, 98, 250, 101, 198, 249, 71, 180, 13, 34, 81, 129, 242, 140, 52, 166, 226,
+            180, 210, 165, 37, 24, 132, 50, 57, 0, 228, 61, 165, 55, 58, 81, 10, 142, 190, 234, 22,
+            235, 117, 64, 234, 16, 228, 162, 17, 243, 85, 151, 147, 137, 127, 187, 223, 7, 61, 10,
+            222, 35, 58, 57, 18, 23, 138, 221, 20, 182, 24, 5, 6, 95, 225, 17, 27, 241, 75, 112,
+            127, 146, 136, 228, 16, 97, 227, 109, 170, 161, 198, 123, 99, 159, 92, 8, 241, 65, 147,
+            143, 150, 55, 124, 91, 24, 181, 72, 241, 14, 94, 80, 21, 155, 1, 223, 102, 241, 117,
+            65, 45, 139, 7, 92, 248, 6, 233, 236, 43, 228, 186, 198, 32, 133, 100, 108, 201, 179,
+            17, 33, 99, 95, 100, 83, 24, 26, 174, 153, 211, 151, 34, 188, 165, 49, 198, 128, 141,
+            166, 184, 88, 198, 48, 219, 47, 101, 179, 97, 52, 80, 74, 230, 167, 15, 144, 127, 133,
+            25, 42, 235, 168, 136, 136, 106, 102, 128, 151, 70, 181, 97, 218, 171, 213, 36, 89,
+            201, 37, 5, 130, 40, 35, 190, 36, 162, 160, 124, 235, 58, 41, 136, 143, 209, 146, 230,
+            141, 103, 78, 178, 49, 12, 140, 243, 58, 224, 94, 90, 208, 163, 53, 56, 56, 217, 230,
+            31, 28, 191, 147, 237, 249, 242, 223, 204, 170, 146, 225, 59, 55, 57, 38, 140, 176,
+            130, 168, 41, 86, 212, 207, 32, 154, 40, 99, 44, 169, 31, 8, 82, 117, 230, 164, 69, 91,
+            65, 17, 117, 50, 41, 161, 31, 16, 114, 34, 169, 8, 132, 180, 129, 164, 3, 178, 135,
+            155, 46, 103, 197, 206, 10, 251, 111, 45, 27, 209, 88, 70, 49, 173, 46, 115, 177, 59,
+            116, 43, 219, 236, 35, 249, 180, 119, 10, 73, 161, 105, 150, 198, 69, 146, 218, 226,
+            98, 161, 174, 118, 173, 164, 2, 55, 9, 77, 16, 90, 109, 41, 165, 224, 84, 219, 189,
+            195, 30, 80, 110, 245, 25, 4, 151, 196, 101, 177, 101, 97, 13, 5, 103, 45, 221, 67,
+            128, 69, 163, 21, 95, 41, 149, 43, 245, 145, 173, 56, 26, 25, 116, 109, 173, 74, 126,
+            74, 123, 250, 123, 12, 55, 3, 153, 173, 230, 115, 3, 58, 25, 137, 4, 133, 87, 207, 221,
+            17, 88, 88, 91, 178, 143, 93, 153, 193, 249, 163, 81, 71, 253, 169, 164, 144, 245, 165,
+            197, 179, 23, 68, 63, 85, 156, 216, 199, 130, 30, 174, 69, 115, 204, 78, 71, 178, 197,
+            231, 103, 234, 1, 129, 32, 162, 32, 145, 145, 151, 190, 13, 182, 57, 101, 78, 200, 182,
+            217, 156, 185, 148, 153, 206, 53, 112, 184, 40, 34, 173, 15, 15, 189, 156, 2, 99, 192,
+            244, 42, 230, 68, 251, 161, 216, 88, 69, 200, 44, 212, 231, 46, 207, 72, 212, 184, 243,
+            40, 145, 162, 160, 82, 190, 0, 140, 35, 21, 63, 78, 6, 187, 239, 214, 228, 52, 214,
+            139, 240, 29, 171, 7, 27, 178, 123, 148, 124, 215, 100, 138, 104, 47, 18, 26, 22, 65,
+            97, 128, 222, 192, 186, 20, 140, 23, 162, 206, 4, 117, 92, 255, 125, 214, 109, 103, 84,
+            82, 135, 65, 198, 125, 73, 120, 217, 169, 76, 42, 159, 216, 211, 89, 11, 179, 133, 113,
+            196, 9, 20, 214, 177, 226, 130, 90, 28, 203, 228, 171, 58, 160, 135, 136, 237, 15, 63,
+            138, 22, 48, 180, 155, 158, 178, 24, 59, 217, 189, 192, 34, 28, 122, 251, 183, 133, 16,
+            237, 145, 53, 229, 161, 173, 142, 68, 135, 145, 158, 39, 107, 3, 72, 7, 80, 25, 211,
+            236, 195, 252, 175, 61, 217, 187, 193, 218, 95, 90, 108, 115, 213, 97, 137, 173, 150,
+            109, 185, 34, 105, 78, 12, 120, 82, 99, 20, 41, 108, 207, 43, 116, 1, 59, 80, 140, 217,
+            70, 205, 49, 209, 179, 155, 138, 104, 168, 106, 183, 203, 239, 250, 93, 13, 93, 166,
+            218, 134, 200, 88, 80, 175, 208, 28, 161, 60, 44, 149, 199, 154, 60, 217, 81, 14, 78,
+            253, 201, 230, 165, 41, 163, 189, 240, 66, 23, 138, 88, 110, 60, 210, 50, 12, 41, 175,
+            163, 121, 243, 0, 70, 27, 179, 113, 85, 201, 38, 229, 132, 249, 115, 104, 230, 133, 4,
+            18, 250, 73, 141, 23, 105, 27, 193, 129, 21, 157, 30, 241, 98, 238, 253, 178, 84, 45,
+            64, 35, 66, 130, 53, 88, 18, 129, 134, 101, 134, 119, 160, 87, 227, 132, 210, 205, 164,
+            170, 189, 66, 58, 39, 111, 172, 148, 124, 195, 5, 209, 174,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 56665478);
+    }
+
+    #[test]
+    pub fn nist_test_verify_8() {
+        let nonce_msg = [
+            103, 203, 221, 140, 198, 150, 72, 89, 42, 139, 63, 13, 216, 111, 101, 243, 230, 222,
+            58, 53, 222, 118, 7, 197, 114, 39, 37, 156, 126, 42, 221, 68, 158, 106, 90, 142, 14,
+            146, 189, 124, 147, 102, 237, 123, 59, 98, 60, 65, 20, 72, 182, 52, 68, 111, 26, 63,
+            170, 189, 209, 99, 166, 204, 30, 43, 202, 228, 169, 135, 3, 205, 140, 238, 68, 20, 5,
+            137, 47, 186, 5, 27, 226, 165, 134, 166, 149, 10, 94, 247, 58, 37, 94, 95, 134, 176,
+            215, 33, 46, 12, 81, 195, 188, 121, 190, 75, 136, 231, 110, 214, 240, 67, 254, 243, 32,
+            79, 175, 4, 75, 251, 30, 215, 34, 214, 30, 181, 208, 183, 76, 102, 162, 87, 232, 172,
+            58, 34, 6, 39, 60, 128, 210, 236, 33, 35, 164, 219, 183, 21, 214, 1, 24, 217, 158, 215,
+            50, 46, 56, 241, 86, 47, 130, 55, 145, 56, 218, 61, 219, 139, 170, 124, 230, 26, 183,
+            41, 175, 195, 116, 140, 1, 52, 99, 60, 244, 90, 153, 115, 192, 92, 117, 208, 78, 130,
+            246, 49, 132, 84, 39, 98, 107, 87, 153, 220, 7, 221, 248, 48, 186, 1, 232, 188, 98, 54,
+            187, 109, 3, 179, 125, 148, 157, 187, 41, 238, 199, 223, 230, 15, 188, 23, 234, 89, 9,
+            86, 210, 81, 83, 151, 146, 1, 110, 42, 139, 1, 231, 4, 118, 150, 27, 201, 173, 164, 60,
+            218, 104, 45, 12, 170, 79, 204, 88, 129, 11, 186, 26, 103, 62, 248, 246, 188, 144, 186,
+            238, 112, 30, 142, 79, 124, 4, 163, 70, 202, 86, 199, 178, 134, 47, 245, 119, 86, 206,
+            108, 209, 238, 34, 214, 119, 188, 218, 168, 150, 234, 233, 111, 135, 135, 14, 3, 44,
+            24, 182, 198, 160, 193, 161, 145, 250, 226, 237, 72, 124, 229, 82, 150, 204, 75, 99,
+            57, 234, 201, 232, 167, 66, 189, 10, 68, 195, 82, 92, 199, 80,
+        ];
+        let sig = [
+            186, 106, 165, 200, 29, 117, 153, 189, 122, 236, 179, 187, 62, 89, 31, 37, 105, 111,
+            65, 242, 49, 105, 164, 224, 147, 58, 115, 88, 77, 66, 63, 238, 164, 186, 113, 92, 180,
+            36, 205, 65, 31, 154, 251, 123, 130, 245, 149, 40, 177, 149, 150, 165, 11, 155, 211,
+            231, 95, 248, 171, 31, 114, 138, 129, 40, 104, 93, 21, 220, 149, 120, 152, 252, 241,
+            22, 166, 52, 66, 84, 34, 230, 41, 248, 50, 76, 35, 87, 55, 22, 180, 47, 175, 231, 100,
+            155, 35, 94, 70, 5, 255, 206, 115, 142, 52, 82, 174, 108, 242, 134, 125, 34, 216, 90,
+            58, 167, 146, 5, 131, 215, 154, 141, 185, 142, 210, 218, 68, 218, 234, 248, 98, 39,
+            101, 91, 224, 214, 241, 63, 120, 189, 197, 28, 242, 90, 177, 107, 67, 89, 28, 57, 38,
+            206, 140, 145, 72, 171, 118, 251, 81, 217, 148, 71, 62, 165, 109, 226, 134, 197, 173,
+            57, 120, 148, 123, 173, 79, 130, 171, 112, 209, 88, 133, 136, 222, 97, 72, 149, 162,
+            24, 214, 133, 100, 88, 18, 161, 82, 95, 235, 21, 238, 39, 242, 59, 72, 140, 68, 54,
+            237, 83, 227, 145, 233, 220, 227, 5, 163, 180, 152, 31, 221, 201, 4, 42, 79, 228, 102,
+            253, 129, 173, 124, 105, 59, 63, 245, 55, 185, 46, 75, 91, 248, 213, 124, 99, 5, 45, 6,
+            230, 31, 37, 18, 40, 242, 70, 120, 153, 1, 139, 33, 74, 90, 166, 66, 72, 163, 81, 111,
+            193, 146, 210, 241, 222, 81, 96, 236, 186, 50, 145, 33, 206, 250, 18, 50, 242, 229,
+            102, 51, 131, 80, 190, 207, 116, 47, 137, 156, 27, 126, 39, 31, 28, 239, 182, 8, 157,
+            75, 150, 150, 225, 91, 108, 46, 91, 101, 98, 142, 176, 145, 123, 177, 19, 106, 36, 37,
+            93, 81, 103, 220, 68, 202, 140, 219, 97, 20, 166, 24, 115, 165, 31, 140, 86, 31, 117,
+            152, 44, 239, 150, 115, 9, 7, 198, 125, 142, 85, 226, 187, 71, 196, 78, 246, 148, 120,
+            157, 126, 156, 222, 151, 72, 255, 235, 64, 117, 145, 183, 18, 83, 217, 198, 159, 215,
+            161, 76, 206, 137, 197, 147, 216, 178, 251, 152, 228, 78, 44, 197, 217, 219, 229, 141,
+            17, 79, 25, 158, 77, 177, 3, 193, 59, 247, 93, 77, 223, 155, 46, 45, 153, 53, 116, 103,
+            28, 115, 75, 36, 120, 63, 117, 249, 191, 57, 93, 91, 226, 104, 249, 42, 80, 29, 185,
+            23, 90, 58, 66, 93, 203, 79, 85, 220, 106, 169, 4, 237, 197, 162, 117, 107, 61, 222,
+            212, 217, 154, 38, 178, 250, 81, 88, 168, 248, 119, 148, 71, 186, 246, 142, 68, 47,
+            218, 171, 119, 187, 16, 220, 46, 53, 232, 76, 94, 47, 73, 134, 236, 97, 11, 99, 3, 201,
+            108, 222, 127, 203, 228, 204, 172, 209, 244, 58, 184, 98, 81, 53, 165, 86, 224, 231,
+            36, 81, 122, 68, 33, 37, 49, 36, 171, 57, 224, 175, 93, 45, 48, 86, 105, 98, 109, 17,
+            108, 202, 114, 189, 205, 85, 153, 20, 96, 249, 80, 241, 231, 202, 2, 129, 183, 83, 252,
+            29, 171, 137, 28, 157, 249, 112, 80, 186, 162, 165, 162, 250, 45, 42, 196, 90, 137,
+            177, 156, 76, 79, 3, 95, 37, 230, 215, 254, 7, 202, 153, 199, 119, 95, 168, 84, 73,
+            149, 157, 99, 39, 45, 4, 16, 183, 160, 133, 234, 252, 59, 133, 119, 249, 76, 170, 116,
+            130, 223, 223, 168, 36, 127, 122, 60, 148, 61, 56, 254, 154, 131, 187, 89, 123, 49,
+            236, 40, 178, 185, 74, 207, 57, 181, 142, 100, 16, 220, 73, 238, 180, 167, 102, 59, 70,
+            105, 96, 236, 60, 205, 24, 120, 226, 91, 23, 51, 145, 184, 32, 239, 3, 174, 157, 69,
+            90, 25, 229, 57, 23, 149, 195, 156, 54, 70, 41, 187, 201, 146, 216, 101, 71, 141, 161,
+            221, 185, 19, 153, 75, 82, 240, 243, 218, 137, 59, 13, 125, 147, 48, 178, 94, 204, 59,
+            104, 129, 10, 18, 97, 92, 157, 120, 222, 59, 220, 65, 60, 91, 226, 179, 86, 108, 200,
+            227, 224, 125, 103, 77, 201, 253, 97, 27, 92, 253, 146, 154, 183, 190, 120, 233, 230,
+            229, 43, 155, 30, 200, 172, 235, 81, 247, 158, 205, 113, 77, 90, 62, 101, 125, 187,
+            247, 78, 47, 54, 102, 156, 66, 191, 63, 133, 21, 149, 17, 147, 162, 34, 196, 161, 21,
+            86, 201, 160, 208, 105, 28, 4, 99, 158, 131, 72, 112, 238, 4, 206, 201, 151, 90, 102,
+            120, 255, 122, 60, 131, 54, 40, 86, 53, 50, 246, 239, 163, 105, 45, 117, 131, 207, 90,
+            165, 165, 136, 211, 166, 182, 137, 77, 154, 67, 53, 105, 104, 137, 131, 237, 136, 81,
+            171, 203, 215, 31, 68, 172, 122, 165, 252, 223, 130, 195, 189, 73, 81, 207, 67, 172,
+            189, 183, 238, 79, 197, 49, 211, 76, 216, 186, 213, 39, 2, 144, 108, 112, 70, 81, 28,
+            211, 77, 76, 220, 49, 242, 70, 52, 15, 9, 143, 67, 210, 27, 127, 38, 146, 133, 162,
+            166, 7, 87, 93, 252, 232, 85, 74, 155, 23, 138, 101, 14, 90, 6, 223, 150, 237, 22, 219,
+            149, 170, 57, 20, 200, 86, 107, 178, 208, 178, 205, 172, 153, 6, 185, 121, 251, 251,
+            26, 230, 17, 120, 177, 234, 167, 176, 189, 62, 41, 172, 145, 161, 15, 157, 178, 171,
+            223, 205, 177, 188, 5, 70, 46, 81, 251, 148, 118, 141, 26, 218, 18, 104, 4, 249, 151,
+            133, 12, 124, 227, 173, 41, 67, 209, 95, 106, 118, 251, 152, 189, 95, 54, 110, 87, 37,
+            206, 167, 160, 224, 67, 125, 134, 105, 229, 144, 193, 97, 12, 116, 104, 144, 233, 113,
+            172, 185, 218, 114, 96, 252, 236, 205, 239, 186, 106, 34, 247, 137, 67, 195, 7, 79,
+            116, 45, 154, 186, 202, 170, 186, 227, 10, 86, 161, 21, 51, 51, 154, 209, 108, 103, 41,
+            200, 147, 254, 103, 255, 135, 137, 241, 159, 86, 165, 176, 129, 0, 135, 142, 219, 160,
+            147, 170, 17, 109, 116, 69, 70, 250, 37, 20, 153, 187, 170, 188, 104, 17, 235, 42, 21,
+            13, 64, 94, 35, 157, 29, 136, 202, 116, 182, 133, 181, 220, 117, 33, 18, 135, 193, 32,
+            107, 43, 202, 139, 114, 230, 246, 246, 92, 179, 146, 188, 32, 249, 22, 139, 233, 107,
+            82, 190, 19, 134, 199, 69, 6, 206, 59, 109, 90, 151, 167, 64, 118, 138, 238, 47, 41,
+            51, 195, 178, 23, 201, 203, 167, 121, 228, 53, 39, 162, 165, 92, 203, 36, 229, 211,
+            236, 227, 164, 142, 117, 186, 129, 74, 146, 164, 185, 140, 131, 106, 208, 21, 75, 185,
+            65, 124, 112, 252, 8, 185, 3, 39, 117, 230, 28, 128, 148, 242, 214, 155, 236, 105, 42,
+            188, 134, 39, 141, 175, 109, 60, 208, 93, 252, 235, 183, 0, 35, 236, 140, 5, 31, 99,
+            147, 239, 175, 108, 175, 71, 231, 186, 115, 233, 253, 166, 55, 203, 165, 91, 10, 178,
+            63, 4, 119, 95, 115, 153, 244, 21, 167, 83, 218, 198, 192,
+        ];
+        let pk = [
+            10, 107, 214, 18, 137, 1, 170, 103, 85, 249, 74, 181, 47, 143, 32, 107, 225, 190, 53,
+            13, 8, 179, 94, 72, 239, 153, 145, 170, 89, 81, 68, 166, 134, 114, 43, 30, 158, 114,
+            11, 134, 80, 68, 139, 47, 77, 172, 208, 185, 92, 98, 157, 52, 169, 105, 65, 19, 34, 33,
+            117, 112, 91, 51, 104, 163, 58, 193, 82, 231, 192, 69, 202, 79, 136, 213, 80, 161, 168,
+            173, 119, 20, 146, 128, 165, 149, 253, 83, 34, 107, 129, 121, 6, 200, 148, 61, 165,
+            198, 147, 16, 211, 142, 68, 6, 211, 52, 35, 19, 27, 106, 39, 248, 191, 211, 208, 139,
+            226, 88, 153, 204, 93, 84, 119, 70, 139, 138, 56, 170, 40, 146, 109, 230, 4, 19, 193,
+            125, 106, 116, 147, 27, 140, 148, 195, 6, 76, 177, 228, 227, 105, 19, 58, 29, 93, 35,
+            255, 128, 35, 138, 185, 88, 146, 47, 88, 61, 156, 214, 244, 51, 195, 129, 162, 141,
+            154, 255, 0, 21, 78, 171, 18, 88, 128, 115, 176, 198, 65, 30, 45, 255, 244, 182, 10,
+            19, 10, 146, 136, 39, 130, 203, 171, 60, 189, 12, 226, 128, 174, 118, 56, 164, 183, 34,
+            131, 99, 46, 123, 92, 237, 91, 136, 224, 170, 33, 182, 204, 40, 11, 71, 166, 35, 188,
+            118, 248, 22, 149, 12, 72, 46, 221, 118, 252, 87, 161, 46, 20, 198, 144, 255, 196, 62,
+            60, 86, 189, 217, 64, 11, 249, 130, 21, 137, 1, 173, 29, 55, 160, 25, 58, 25, 21, 94,
+            219, 104, 29, 51, 150, 132, 157, 144, 38, 114, 48, 178, 124, 5, 189, 37, 136, 34, 67,
+            167, 129, 64, 184, 214, 70, 112, 138, 129, 132, 98, 125, 130, 224, 174, 209, 140, 123,
+            86, 244, 114, 48, 144, 202, 52, 145, 218, 193, 32, 155, 253, 129, 60, 205, 164, 192,
+            152, 165, 58, 192, 57, 163, 215, 85, 88, 33, 21, 159, 226, 102, 159, 85, 179, 98, 154,
+            225, 149, 204, 138, 24, 248, 193, 9, 164, 129, 253, 183, 97, 248, 176, 244, 222, 100,
+            118, 8, 226, 225, 65, 47, 95, 119, 160, 153, 227, 215, 66, 55, 141, 234, 254, 26, 187,
+            146, 94, 135, 142, 153, 41, 96, 7, 91, 113, 185, 38, 86, 211, 227, 7, 133, 45, 236, 91,
+            67, 202, 249, 101, 242, 188, 52, 236, 227, 70, 135, 136, 192, 165, 64, 30, 7, 164, 73,
+            151, 70, 73, 132, 204, 135, 172, 75, 11, 205, 104, 222, 181, 213, 74, 213, 60, 104, 53,
+            173, 233, 1, 248, 200, 67, 229, 28, 194, 233, 96, 137, 38, 217, 4, 10, 191, 246, 226,
+            105, 182, 187, 97, 98, 0, 32, 153, 177, 165, 38, 164, 240, 236, 238, 150, 99, 252, 218,
+            224, 44, 65, 99, 130, 244, 78, 139, 20, 206, 227, 122, 154, 35, 139, 134, 93, 75, 99,
+            213, 126, 194, 243, 131, 229, 163, 160, 116, 240, 93, 209, 98, 148, 48, 175, 128, 219,
+            163, 95, 56, 210, 155, 101, 62, 26, 141, 145, 64, 91, 57, 130, 152, 19, 75, 192, 178,
+            226, 62, 67, 248, 30, 6, 87, 121, 198, 169, 146, 0, 154, 249, 120, 203, 40, 79, 112,
+            48, 69, 162, 45, 224, 193, 181, 204, 210, 101, 69, 223, 240, 189, 158, 102, 75, 90,
+            161, 246, 15, 69, 89, 185, 255, 164, 16, 61, 32, 179, 235, 158, 144, 193, 115, 42, 91,
+            241, 102, 19, 110, 173, 200, 63, 246, 231, 225, 175, 108, 78, 135, 32, 214, 95, 41, 78,
+            92, 37, 119, 237, 83, 57, 36, 66, 70, 17, 95, 198, 247, 141, 108, 132, 71, 70, 33, 143,
+            143, 108, 71, 137, 43, 110, 131, 32, 61, 136, 180, 31, 233, 192, 171, 29, 199, 51, 175,
+            137, 48, 24, 149, 230, 102, 137, 128, 150, 139, 144, 160, 152, 236, 16, 0, 48, 222,
+            125, 196, 246, 29, 242, 41, 229, 133, 73, 126, 172, 196, 12, 236, 129, 2, 33, 67, 21,
+            178, 1, 87, 106, 104, 36, 182, 137, 160, 47, 155, 14, 21, 66, 67, 193, 216, 121, 121,
+            24, 199, 172, 128, 251, 16, 120, 107, 40, 175, 253, 29, 71, 143, 153, 182, 23, 182,
+            173, 52, 25, 204, 88, 171, 133, 59, 70, 132, 103, 30, 92, 77, 150, 18, 239, 199, 17,
+            18, 225, 163, 149, 161, 161, 90, 135, 132, 4, 57, 70, 66, 120, 4, 146, 200, 43, 44,
+            197, 121, 147, 126, 152, 224, 185, 172, 112, 181, 2, 50, 70, 185, 232, 133, 174, 153,
+            168, 225, 110, 64, 244, 141, 172, 28, 149, 125, 225, 33, 62, 36, 102, 0, 65, 9, 31,
+            159, 96, 252, 116, 186, 67, 203, 9, 129, 111, 218, 153, 19, 4, 29, 193, 195, 249, 119,
+            225, 157, 181, 68, 129, 86, 255, 11, 126, 135, 134, 143, 212, 200, 152, 187, 106, 214,
+            146, 70, 181, 102, 139, 119, 194, 42, 42, 39, 148, 227, 4, 134, 100, 164, 115, 230,
+            132, 185, 221, 76, 226, 1, 109, 114, 106, 201, 233, 212, 80, 46, 23, 162, 57, 97, 214,
+            156, 156, 188, 46, 12, 115, 40, 214, 163, 242, 172, 66, 227, 71, 106, 14, 10, 159, 234,
+            196, 233, 0, 74, 88, 71, 34, 22, 122, 53, 37, 52, 135, 174, 186, 209, 121, 66, 183,
+            185, 0, 117, 251, 38, 3, 66, 71, 21, 40, 119, 85, 222, 214, 189, 144, 248, 197, 79, 92,
+            110, 59, 188, 179, 11, 44, 158, 96, 95, 182, 28, 11, 110, 95, 219, 90, 113, 82, 230,
+            120, 67, 124, 10, 97, 226, 242, 148, 197, 144, 46, 245, 209, 196, 4, 70, 200, 29, 242,
+            174, 212, 162, 232, 204, 104, 54, 125, 245, 117, 99, 158, 61, 226, 42, 178, 63, 200,
+            45, 122, 130, 125, 56, 246, 64, 75, 156, 41, 255, 23, 193, 105, 168, 59, 84, 114, 57,
+            209, 156, 174, 22, 28, 198, 82, 118, 64, 56, 49, 209, 203, 39, 160, 236, 110, 52, 198,
+            196, 74, 66, 144, 39, 77, 165, 69, 240, 45, 75, 170, 99, 110, 111, 244, 145, 143, 63,
+            175, 32, 126, 183, 33, 194, 132, 135, 254, 14, 99, 196, 107, 185, 76, 102, 206, 122,
+            39, 215, 17, 6, 201, 120, 68, 4, 16, 236, 93, 41, 85, 144, 82, 40, 226, 101, 138, 254,
+            148, 56, 129, 18, 80, 201, 71, 10, 0, 163, 178, 183, 37, 44, 53, 164, 87, 182, 40, 98,
+            252, 36, 205, 103, 130, 5, 198, 170, 250, 197, 219, 140, 44, 89, 17, 86, 10, 18, 154,
+            100, 244, 100, 195, 218, 153, 94, 137, 90, 114, 39, 11, 220, 69, 132, 234, 199, 114,
+            30, 203, 83, 133, 133, 195, 182, 66, 3, 129, 111, 17, 195, 36, 121, 173, 183, 123, 149,
+            106, 74, 147, 192, 227, 73, 125, 240, 151, 8, 84, 73, 162, 20, 115, 232, 30, 133, 95,
+            173, 172, 230, 56, 163, 99, 101, 178, 57, 136, 184, 204, 150, 141, 69, 182, 144, 68,
+            180, 45, 66, 124, 80, 210, 91, 241, 151, 42, 144, 140, 56, 80, 83, 77, 37, 147, 25, 41,
+            99, 107, 39, 11, 64, 188, 221, 203, 245, 170, 217, 29, 38, 168, 87, 22, 30, 121, 185,
+            241, 171, 53, 135, 197, 67, 78, 65, 93, 211, 250, 145, 1, 164, 24, 205, 85, 36, 30,
+            252, 23, 100, 17, 104, 232, 193, 217, 190, 197, 14, 36, 202, 147, 11, 58, 180, 17, 209,
+            59, 203, 197, 161, 157, 111, 115, 197, 193, 124, 64, 129, 5, 150, 147, 30, 119, 139,
+            56, 179, 231, 32, 156, 194, 82, 102, 177, 182, 5, 110, 4, 5, 130, 254, 104, 2, 19, 227,
+            21, 122, 87, 26, 60, 226, 239, 125, 118, 187, 1, 240, 32, 102, 60, 24, 128, 179, 22,
+            218, 22, 101, 169, 90, 99, 190, 70, 71, 104, 36, 89, 184, 25, 21, 182, 123, 48, 240,
+            35, 27, 1, 74, 91, 136, 226, 200, 136, 173, 235, 136, 24, 95, 69, 238, 22, 198, 140,
+            225, 55, 103, 159, 166, 222, 55, 117, 249, 132, 197, 110, 254, 74, 216, 40, 201, 252,
+            161, 203, 48, 21, 247, 23, 62, 169, 119, 36, 145, 137, 36, 121, 140, 198, 39, 226, 122,
+            247, 168, 15, 46, 70, 108, 218, 137, 82, 11, 146, 72, 101, 114, 120, 111, 158, 227, 2,
+            157, 186, 11, 222, 219, 94, 42, 2, 167, 211, 26, 238, 226, 82, 224, 192, 35, 14, 156,
+            10, 30, 250, 236, 160, 14, 161, 123, 77, 85, 229, 176, 17, 234, 174, 136, 16, 99, 118,
+            106, 166, 236, 57, 246, 20, 74, 170, 235, 134, 116, 244, 191, 17, 68, 80, 191, 155, 76,
+            115, 234, 243, 207, 231, 174, 249, 104, 121, 57, 94, 34, 16, 56, 32, 250, 29, 91, 148,
+            126, 134, 52, 100, 235, 83, 6, 146, 148, 228, 24, 218, 98, 169, 17, 6, 239, 136, 181,
+            237, 72, 122, 217, 63, 185, 158, 93, 147, 65, 141, 120, 67, 175, 14, 24, 52, 109, 123,
+            16, 246, 101, 168, 128, 212, 111, 195, 2, 10, 14, 161, 102, 9, 1, 158, 75, 199, 84, 28,
+            25, 138, 243, 162, 137, 171, 57, 19, 38, 43, 76, 81, 120, 241, 24, 211, 221, 204, 133,
+            153, 164, 179, 162, 139, 28, 6, 116, 150, 228, 0, 193, 34, 161, 1, 8, 130, 147, 247,
+            107, 182, 21, 65, 228, 193, 147, 145, 252, 188, 124, 170, 88, 110, 103, 221, 112, 33,
+            0, 96, 16, 216, 154, 112, 237, 199, 54, 84, 204, 202, 63, 100, 37, 59, 171, 5, 24, 170,
+            148, 169, 48, 105, 213, 221, 133, 158, 227, 246, 64, 219, 124, 185, 68, 192, 198, 103,
+            168, 162, 158, 22, 39, 160, 162, 160, 231, 184, 209, 217, 137, 128, 74, 253, 32, 101,
+            194, 27, 248, 70, 92, 5, 46, 209, 52, 5, 68, 159, 40, 62, 240, 58, 191, 102, 194, 27,
+            145, 243, 251, 162, 129, 145, 59, 249, 47, 153, 188, 213, 116, 69, 192, 144, 203, 199,
+            35, 14, 53, 74, 9, 53, 124, 14, 197, 119, 222, 251, 229, 232, 146, 117, 82, 224, 213,
+            214, 251, 0, 150, 13, 84, 92, 71, 148, 38, 144, 57, 72, 224, 149, 225, 31, 146, 36,
+            196, 31, 85, 100, 33, 42, 26, 120, 170, 233, 167, 16, 249, 36, 238, 83, 212, 112, 84,
+            202, 144, 238, 47, 148, 186, 221, 114, 36, 233, 72, 133, 40, 5, 221, 135, 202, 246,
+            186, 88, 9, 16, 166, 100, 194, 40, 174, 146, 94, 100, 201, 3, 113, 86, 197, 35,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 58433791);
+    }
+
+    #[test]
+    pub fn nist_test_verify_9() {
+        let nonce_msg = [
+            106, 134, 71, 17, 16, 171, 251, 109, 160, 39, 197, 248, 122, 177, 242, 127, 139, 182,
+            180, 159, 63, 46, 145, 230, 180, 107, 227, 45, 142, 19, 13, 199, 162, 231, 65, 98, 140,
+            178, 188, 3, 9, 152, 17, 76, 132, 248, 64, 128, 231, 238, 187, 71, 210, 72, 152, 15,
+            172, 157, 40, 241, 171, 182, 219, 171, 61, 213, 154, 92, 253, 44, 124, 255, 127, 48,
+            131, 114, 135, 77, 213, 68, 124, 123, 2, 227, 1, 101, 80, 28, 12, 103, 49, 40, 228,
+            197, 67, 164, 20, 34, 43, 223, 71, 231, 244, 232, 220, 167, 87, 176, 244, 163, 40, 28,
+            13, 16, 196, 240, 42, 181, 42, 175, 91, 154, 113, 94, 1, 38, 7, 186, 49, 9, 71, 166,
+            10, 95, 98, 214, 184, 207, 169, 99, 134, 210, 124, 250, 112, 145, 137, 32, 36, 33, 192,
+            120, 147, 74, 162, 217, 85, 70, 142, 85, 10, 212, 208, 212, 172, 221, 152, 177, 104,
+            169, 86, 142, 35, 33, 146, 233, 39, 137, 131, 3, 23, 251, 201, 89, 8, 127, 255, 227,
+            83, 182, 193, 104, 243, 239, 190, 113, 100, 68, 79, 29, 108, 186, 82, 70, 227, 22, 88,
+            198, 84, 64, 168, 65, 219, 167, 130, 87, 231, 133, 2, 132, 62, 193, 166, 233, 113, 2,
+            41, 200, 238, 184, 93, 108, 221, 199, 213, 67, 40, 86, 36, 170, 31, 117, 106, 93, 212,
+            241, 165, 212, 250, 82, 219, 140, 92, 52, 136, 14, 212, 72, 251, 182, 210, 84, 80, 159,
+            190, 234, 15, 160, 34, 242, 118, 182, 166, 107, 239, 122, 191, 234, 96, 73, 255, 116,
+            41, 27, 171, 231, 129, 247, 24, 104, 51, 151, 7, 123, 41, 250, 158, 43, 70, 188, 107,
+            9, 37, 30, 88, 124, 197, 177, 130, 25, 93, 212, 6, 12, 196, 163, 25, 191, 190, 37, 26,
+            91, 102, 10, 115, 157, 254, 93, 14, 91, 147, 243, 203, 126, 68, 1, 148, 241, 200, 189,
+            169, 34, 203, 26, 62, 227, 210, 126, 223, 214, 28, 29, 49, 167, 244, 83, 78, 132, 136,
+            158, 200, 59, 81, 241, 100, 24, 146, 118, 100, 52,
+        ];
+        let sig = [
+            6, 52, 188, 126, 46, 76, 98, 175, 233, 103, 74, 33, 54, 98, 43, 169, 156, 135, 107,
+            186, 236, 67, 68, 115, 117, 169, 212, 251, 98, 9, 86, 255, 119, 164, 130, 35, 126, 94,
+            69, 111, 103, 125, 249, 199, 235, 26, 143, 36, 23, 91, 195, 222, 32, 159, 116, 94, 84,
+            87, 83, 56, 61, 230, 126, 135, 192, 25, 31, 251, 96, 226, 96, 138, 117, 3, 16, 243,
+            245, 88, 164, 95, 59, 203, 171, 210, 151, 28, 221, 91, 159, 74, 96, 246, 17, 10, 118,
+            123, 71, 172, 24, 1, 68, 209, 200, 40, 208, 141, 51, 31, 209, 33, 141, 194, 51, 172,
+            157, 200, 232, 37, 214, 7, 171, 152, 65, 216, 232, 144, 248, 51, 156, 210, 127, 163,
+            215, 238, 120, 62, 83, 3, 50, 105, 25, 217, 194, 197, 49, 246, 40, 182, 152, 146, 135,
+            89, 36, 85, 230, 128, 163, 201, 137, 104, 143, 19, 63, 98, 10, 123, 161, 214, 12, 2,
+            226, 217, 146, 238, 35, 59, 30, 142, 166, 74, 86, 74, 14, 198, 214, 224, 197, 17, 17,
+            55, 216, 255, 67, 157, 22, 46, 249, 213, 53, 46, 159, 193, 106, 69, 114, 229, 175, 172,
+            229, 240, 172, 157, 155, 45, 119, 99, 77, 3, 179, 17, 61, 61, 22, 191, 215, 136, 73,
+            94, 5, 163, 60, 189, 91, 88, 39, 94, 61, 54, 240, 204, 75, 205, 49, 186, 150, 105, 170,
+            237, 59, 102, 84, 156, 36, 213, 139, 121, 10, 159, 23, 249, 64, 245, 98, 116, 105, 131,
+            118, 130, 42, 111, 205, 166, 159, 89, 120, 12, 187, 134, 192, 35, 212, 211, 211, 13,
+            143, 41, 8, 212, 2, 83, 148, 53, 92, 178, 41, 99, 185, 71, 188, 86, 53, 54, 157, 195,
+            196, 111, 161, 120, 74, 59, 104, 70, 28, 139, 99, 14, 221, 228, 106, 90, 60, 110, 206,
+            91, 166, 65, 224, 218, 172, 50, 83, 72, 75, 52, 156, 20, 205, 133, 108, 95, 149, 105,
+            41, 33, 107, 227, 58, 142, 124, 92, 153, 23, 188, 198, 34, 196, 210, 25, 108, 224, 69,
+            152, 179, 152, 35, 100, 27, 41, 134, 93, 103, 152, 158, 9, 178, 131, 63, 185, 88, 204,
+            71, 23, 237, 178, 88, 77, 177, 65, 230, 153, 158, 1, 103, 170, 43, 94, 140, 222, 103,
+            107, 188, 211, 109, 144, 215, 127, 99, 111, 213, 245, 171, 48, 21, 186, 147, 201, 104,
+            221, 9, 189, 213, 145, 193, 148, 249, 213, 98, 13, 75, 107, 5, 147, 74, 58, 255, 237,
+            196, 187, 2, 35, 37, 82, 95, 232, 114, 53, 107, 35, 40, 98, 169, 208, 4, 155, 83, 190,
+            231, 150, 28, 170, 38, 253, 119, 104, 18, 149, 173, 1, 200, 126, 228, 114, 61, 182, 99,
+            80, 193, 118, 99, 211, 140, 159, 149, 221, 102, 24, 98, 17, 166, 126, 100, 237, 34,
+            154, 173, 240, 160, 245, 168, 46, 57, 119, 161, 188, 153, 147, 182, 176, 201, 89, 151,
+            162, 125, 64, 26, 168, 154, 162, 155, 233, 184, 201, 127, 230, 141, 105, 130, 79, 171,
+            239, 153, 177, 161, 141, 21, 33, 116, 107, 29, 161, 106, 159, 56, 23, 42, 132, 253,
+            112, 231, 172, 133, 173, 54, 243, 229, 15, 75, 42, 255, 184, 112, 75, 61, 95, 41, 21,
+            71, 81, 197, 67, 109, 60, 68, 217, 241, 61, 65, 141, 23, 188, 148, 147, 251, 214, 49,
+            66, 72, 204, 178, 4, 49, 254, 236, 244, 1, 24, 53, 244, 44, 51, 82, 93, 8, 64, 174,
+            149, 244, 251, 255, 140, 51, 178, 138, 127, 101, 191, 51, 19, 72, 93, 109, 188, 37, 73,
+            35, 34, 234, 232, 116, 15, 224, 201, 68, 27, 85, 89, 92, 232, 36, 75, 30, 52, 206, 80,
+            4, 129, 236, 243, 201, 105, 109, 203, 64, 205, 32, 236, 60, 45, 106, 58, 50, 252, 12,
+            118, 173, 205, 101, 138, 182, 111, 58, 84, 214, 214, 46, 106, 77, 173, 212, 84, 137,
+            241, 110, 211, 189, 55, 21, 106, 135, 19, 233, 219, 84, 217, 34, 208, 223, 4, 112, 216,
+            238, 81, 183, 40, 111, 230, 204, 46, 210, 51, 143, 159, 164, 113, 241, 90, 227, 50,
+            229, 97, 49, 168, 202, 73, 115, 210, 103, 146, 24, 138, 185, 23, 82, 149, 100, 135,
+            100, 192, 146, 161, 223, 173, 113, 57, 51, 205, 253, 227, 229, 106, 161, 42, 68, 63,
+            162, 10, 237, 105, 131, 50, 28, 124, 27, 103, 89, 91, 47, 28, 239, 5, 48, 192, 176,
+            209, 254, 86, 236, 249, 245, 168, 44, 19, 99, 77, 229, 183, 112, 236, 142, 73, 216,
+            205, 232, 113, 168, 164, 25, 91, 70, 144, 7, 197, 55, 71, 94, 61, 111, 21, 208, 202,
+            69, 123, 44, 183, 89, 158, 252, 248, 143, 27, 137, 139, 77, 120, 190, 234, 84, 71, 89,
+            152, 149, 220, 132, 137, 51, 250, 100, 255, 216, 104, 129, 70, 86, 160, 233, 35, 138,
+            201, 67, 121, 151, 88, 44, 199, 4, 139, 181, 248, 253, 134, 227, 166, 89, 126, 58, 170,
+            85, 206, 3, 139, 67, 73, 85, 197, 140, 220, 154, 3, 43, 221, 70, 83, 248, 14, 119, 191,
+            191, 207, 40, 174, 5, 174, 30, 138, 96, 9, 180, 205, 177, 35, 115, 66, 54, 172, 194,
+            229, 45, 225, 48, 249, 82, 109, 144, 213, 159, 61, 107, 185, 51, 8, 79, 43, 246, 173,
+            10, 187, 248, 51, 81, 72, 177, 115, 159, 231, 127, 57, 227, 95, 40, 141, 77, 209, 204,
+            74, 153, 9, 158, 62, 213, 134, 168, 123, 125, 45, 173, 148, 222, 204, 50, 80, 213, 198,
+            29, 171, 57, 142, 108, 55, 139, 226, 126, 42, 242, 73, 30, 254, 4, 76, 81, 8, 162, 183,
+            142, 164, 202, 138, 91, 224, 183, 47, 31, 225, 92, 121, 12, 188, 197, 110, 159, 19,
+            156, 133, 142, 193, 155, 148, 234, 90, 173, 142, 237, 173, 137, 37, 29, 75, 86, 34, 99,
+            9, 214, 168, 7, 162, 96, 206, 202, 14, 82, 22, 115, 160, 207, 106, 211, 235, 32, 77,
+            79, 106, 149, 60, 167, 179, 91, 248, 211, 111, 109, 184, 250, 217, 12, 9, 176, 90, 240,
+            56, 92, 58, 112, 180, 114, 147, 77, 76, 109, 5, 147, 186, 152, 115, 52, 46, 211, 8,
+            100, 137, 86, 244, 80, 252, 116, 230, 26, 247, 113, 97, 202, 226, 38, 74, 154, 94, 199,
+            19, 43, 2, 80, 228, 117, 113, 130, 97, 244, 8, 147, 68, 199, 246, 222, 220, 147, 85,
+            93, 159, 101, 81, 65, 45, 185, 108, 219, 195, 88, 70, 211, 50, 39, 130, 121, 242, 179,
+            133, 21, 203, 139, 46, 47, 20, 217, 183, 162, 228, 16, 132, 16, 250, 168, 101, 223,
+            146, 108, 252, 16, 97, 180, 133, 119, 76, 214, 149, 30, 91, 196, 5, 41, 124, 112, 236,
+            166, 191, 33, 3, 211, 96, 92, 198, 150, 189, 118, 233, 109, 224, 98, 34, 164, 224, 95,
+            230, 229, 239, 113, 176, 205, 18, 155, 5, 74, 93, 250, 55, 23, 255, 194, 159, 83, 185,
+            234, 102, 95, 130, 207, 19, 127, 83, 188, 191, 59, 36, 241, 202, 202, 188, 31, 233, 14,
+            231, 38, 102, 16,
+        ];
+        let pk = [
+            10, 72, 172, 137, 154, 87, 130, 95, 66, 116, 94, 102, 217, 143, 199, 22, 118, 162, 3,
+            151, 84, 126, 153, 180, 133, 198, 74, 25, 135, 83, 80, 86, 210, 130, 220, 65, 140, 56,
+            217, 56, 116, 143, 100, 11, 136, 240, 54, 18, 213, 22, 32, 110, 102, 145, 36, 192, 184,
+            60, 180, 219, 4, 250, 107, 202, 183, 112, 84, 0, 44, 238, 98, 84, 5, 245, 123, 99, 132,
+            30, 110, 40, 92, 104, 204, 12, 221, 84, 137, 155, 228, 218, 94, 122, 56, 132, 79, 161,
+            75, 23, 97, 190, 146, 28, 149, 119, 95, 223, 187, 16, 138, 245, 132, 166, 5, 150, 218,
+            192, 24, 105, 196, 253, 80, 220, 59, 137, 40, 75, 98, 124, 152, 241, 99, 207, 41, 247,
+            166, 148, 61, 128, 244, 218, 128, 190, 125, 174, 90, 24, 1, 28, 73, 32, 195, 81, 255,
+            46, 85, 97, 65, 46, 219, 124, 85, 49, 148, 77, 135, 180, 67, 131, 97, 21, 222, 30, 70,
+            115, 143, 194, 18, 89, 220, 38, 15, 25, 137, 98, 105, 3, 251, 167, 93, 238, 99, 193,
+            10, 231, 174, 135, 245, 37, 161, 202, 247, 219, 91, 213, 95, 46, 212, 118, 36, 238,
+            184, 81, 109, 30, 233, 96, 229, 84, 75, 86, 3, 194, 143, 166, 122, 101, 130, 192, 246,
+            219, 219, 152, 180, 54, 253, 146, 118, 87, 38, 68, 130, 203, 43, 116, 154, 23, 32, 60,
+            180, 40, 147, 170, 117, 59, 232, 230, 50, 85, 102, 234, 64, 193, 143, 50, 239, 111,
+            231, 122, 32, 80, 153, 121, 82, 142, 153, 28, 83, 24, 17, 154, 250, 85, 69, 0, 199,
+            180, 2, 186, 32, 186, 234, 184, 78, 210, 98, 79, 24, 1, 134, 120, 87, 210, 46, 176,
+            241, 80, 210, 78, 41, 189, 42, 27, 70, 58, 8, 83, 32, 148, 248, 145, 60, 67, 224, 15,
+            217, 52, 211, 166, 17, 182, 190, 172, 27, 193, 201, 159, 43, 51, 234, 2, 91, 130, 82,
+            68, 173, 1, 195, 8, 54, 71, 132, 49, 90, 204, 103, 102, 32, 214, 24, 8, 110, 201, 251,
+            167, 88, 52, 124, 0, 251, 56, 39, 46, 138, 141, 74, 244, 189, 131, 109, 104, 184, 233,
+            165, 166, 83, 188, 151, 88, 30, 168, 56, 166, 62, 183, 94, 22, 59, 11, 171, 149, 127,
+            44, 195, 208, 73, 236, 39, 42, 58, 25, 123, 216, 210, 145, 129, 54, 0, 154, 109, 99,
+            140, 37, 94, 83, 212, 192, 92, 126, 23, 249, 83, 17, 107, 232, 141, 165, 105, 104, 213,
+            230, 110, 7, 86, 78, 8, 178, 11, 130, 6, 88, 254, 182, 195, 172, 5, 174, 16, 180, 189,
+            194, 223, 228, 164, 4, 112, 66, 42, 99, 86, 187, 94, 70, 37, 1, 240, 18, 14, 17, 246,
+            132, 103, 143, 239, 31, 127, 205, 220, 227, 74, 11, 169, 22, 238, 67, 195, 25, 88, 17,
+            62, 42, 81, 81, 178, 131, 95, 138, 21, 33, 128, 164, 172, 116, 132, 182, 179, 152, 24,
+            220, 36, 63, 217, 182, 56, 40, 203, 229, 59, 118, 207, 192, 195, 238, 16, 45, 30, 230,
+            1, 174, 133, 129, 135, 188, 147, 16, 168, 2, 152, 119, 212, 184, 73, 4, 233, 87, 121,
+            66, 138, 34, 119, 73, 15, 58, 137, 30, 123, 161, 205, 111, 157, 245, 139, 198, 4, 1,
+            187, 155, 132, 140, 132, 90, 10, 36, 139, 176, 224, 187, 232, 170, 104, 51, 26, 23, 73,
+            250, 89, 123, 35, 8, 155, 197, 87, 223, 109, 88, 78, 74, 181, 187, 28, 1, 90, 142, 2,
+            226, 44, 218, 163, 135, 213, 77, 32, 208, 11, 136, 104, 174, 3, 18, 157, 228, 166, 137,
+            130, 2, 161, 189, 172, 154, 124, 242, 105, 33, 22, 158, 143, 116, 42, 10, 232, 70, 27,
+            46, 17, 118, 213, 228, 66, 45, 39, 135, 222, 31, 41, 118, 232, 212, 18, 206, 68, 209,
+            175, 195, 137, 58, 121, 204, 232, 38, 232, 18, 12, 149, 203, 88, 181, 35, 180, 157,
+            108, 165, 182, 231, 23, 255, 39, 4, 102, 137, 90, 162, 215, 190, 73, 102, 163, 33, 173,
+            169, 51, 21, 213, 3, 249, 204, 12, 6, 216, 165, 151, 220, 18, 156, 150, 130, 88, 247,
+            15, 196, 108, 35, 28, 178, 86, 159, 14, 184, 163, 181, 173, 53, 75, 140, 18, 11, 142,
+            55, 171, 19, 223, 215, 176, 49, 250, 149, 174, 22, 7, 139, 22, 124, 170, 251, 18, 140,
+            124, 241, 134, 233, 243, 232, 49, 30, 238, 228, 67, 78, 236, 231, 165, 224, 191, 101,
+            1, 164, 113, 27, 188, 0, 101, 223, 227, 96, 89, 80, 13, 200, 218, 208, 235, 18, 146,
+            208, 37, 114, 2, 123, 129, 192, 102, 4, 109, 69, 112, 179, 69, 161, 137, 228, 8, 253,
+            129, 85, 55, 164, 103, 196, 159, 82, 5, 203, 118, 189, 160, 142, 43, 16, 34, 98, 113,
+            78, 110, 140, 81, 176, 103, 246, 192, 204, 95, 190, 231, 169, 194, 171, 225, 56, 102,
+            215, 171, 185, 145, 58, 114, 225, 214, 103, 80, 196, 37, 180, 60, 173, 219, 114, 172,
+            173, 2, 140, 210, 119, 202, 223, 7, 63, 54, 208, 216, 4, 46, 154, 77, 189, 97, 208, 45,
+            83, 92, 84, 197, 249, 113, 41, 69, 142, 56, 205, 120, 216, 36, 89, 64, 78, 121, 55, 43,
+            223, 144, 155, 18, 136, 115, 135, 47, 140, 138, 6, 116, 74, 11, 137, 3, 193, 186, 40,
+            60, 99, 167, 9, 142, 148, 30, 133, 169, 241, 164, 250, 59, 253, 204, 162, 105, 23, 159,
+            154, 220, 248, 167, 193, 226, 116, 170, 173, 235, 35, 4, 38, 239, 140, 85, 5, 228, 158,
+            204, 53, 186, 137, 180, 193, 60, 192, 187, 116, 29, 65, 129, 62, 98, 202, 92, 210, 63,
+            32, 76, 72, 135, 65, 126, 245, 72, 164, 209, 72, 163, 89, 10, 209, 117, 206, 35, 74,
+            105, 57, 34, 9, 85, 74, 97, 153, 84, 195, 95, 66, 227, 25, 245, 114, 231, 48, 230, 172,
+            33, 61, 25, 41, 149, 66, 73, 110, 162, 141, 242, 98, 78, 96, 87, 149, 101, 171, 168,
+            136, 62, 138, 132, 108, 182, 115, 220, 133, 155, 5, 171, 195, 237, 138, 138, 103, 228,
+            6, 117, 2, 88, 167, 88, 41, 65, 184, 143, 66, 228, 48, 202, 244, 42, 176, 38, 39, 77,
+            114, 35, 82, 188, 32, 177, 50, 82, 76, 168, 86, 96, 122, 28, 234, 21, 18, 90, 214, 155,
+            8, 169, 22, 186, 125, 197, 216, 57, 58, 95, 38, 105, 27, 182, 73, 72, 167, 50, 237,
+            141, 31, 108, 245, 200, 89, 242, 235, 234, 89, 144, 42, 114, 181, 227, 27, 41, 86, 1,
+            82, 212, 70, 231, 110, 254, 87, 218, 159, 156, 214, 165, 248, 220, 43, 149, 100, 30, 7,
+            73, 58, 186, 4, 133, 227, 31, 76, 19, 97, 252, 147, 156, 92, 134, 47, 20, 219, 90, 148,
+            134, 26, 213, 69, 212, 97, 110, 68, 120, 152, 195, 71, 129, 25, 76, 209, 148, 219, 166,
+            129, 34, 161, 82, 158, 194, 124, 39, 144, 78, 25, 35, 121, 162, 106, 139, 73, 74, 118,
+            128, 46, 94, 80, 123, 248, 120, 106, 65, 25, 128, 73, 204, 162, 116, 17, 99, 169, 54,
+            78, 73, 130, 86, 220, 171, 178, 190, 121, 169, 254, 159, 164, 37, 128, 33, 97, 73, 227,
+            175, 39, 54, 129, 160, 185, 229, 50, 64, 144, 177, 131, 93, 15, 100, 20, 144, 228, 54,
+            47, 100, 167, 185, 242, 16, 23, 187, 5, 179, 76, 8, 244, 170, 153, 225, 233, 112, 148,
+            187, 54, 173, 221, 15, 162, 180, 158, 0, 190, 173, 178, 15, 130, 57, 36, 229, 36, 253,
+            51, 209, 200, 250, 230, 43, 94, 103, 177, 123, 112, 55, 99, 238, 122, 166, 25, 182,
+            205, 234, 77, 143, 196, 72, 81, 68, 95, 150, 101, 72, 116, 150, 192, 199, 156, 150,
+            244, 139, 198, 159, 231, 73, 172, 182, 47, 182, 81, 2, 168, 35, 97, 3, 26, 34, 85, 251,
+            178, 153, 175, 213, 73, 148, 251, 70, 21, 254, 131, 60, 161, 0, 99, 160, 42, 137, 177,
+            217, 226, 15, 152, 10, 176, 141, 220, 218, 176, 244, 189, 113, 134, 44, 153, 176, 177,
+            181, 185, 32, 195, 228, 155, 0, 74, 169, 107, 202, 14, 73, 144, 123, 224, 116, 16, 83,
+            165, 25, 246, 165, 74, 30, 137, 111, 114, 144, 137, 10, 12, 20, 14, 220, 58, 16, 163,
+            218, 106, 60, 253, 118, 106, 99, 132, 10, 162, 18, 82, 183, 68, 226, 30, 120, 46, 110,
+            176, 85, 107, 108, 125, 149, 19, 164, 35, 28, 24, 155, 236, 18, 90, 231, 47, 46, 69,
+            236, 84, 68, 152, 111, 119, 188, 182, 66, 18, 9, 132, 168, 86, 69, 80, 104, 24, 38, 81,
+            30, 28, 0, 73, 237, 34, 86, 185, 0, 30, 203, 220, 207, 5, 138, 158, 226, 32, 69, 164,
+            229, 146, 82, 8, 90, 79, 9, 2, 181, 65, 35, 4, 105, 96, 138, 176, 178, 4, 136, 10, 38,
+            0, 113, 69, 149, 23, 167, 4, 151, 71, 196, 45, 106, 76, 143, 69, 12, 237, 74, 72, 48,
+            10, 43, 18, 193, 166, 136, 220, 43, 175, 157, 244, 91, 81, 149, 42, 166, 97, 237, 93,
+            58, 132, 132, 111, 83, 41, 46, 152, 205, 95, 49, 39, 90, 169, 105, 121, 236, 186, 112,
+            169, 189, 48, 224, 228, 74, 14, 238, 31, 231, 202, 19, 122, 71, 122, 82, 217, 15, 230,
+            181, 187, 16, 226, 68, 0, 22, 157, 167, 29, 170, 100, 89, 67, 205, 65, 24, 228, 177,
+            206, 238, 129, 135, 73, 46, 185, 232, 139, 173, 144, 220, 69, 209, 174, 159, 190, 41,
+            48, 128, 3, 198, 212, 87, 189, 189, 148, 73, 94, 236, 53, 108, 68, 53, 203, 61, 17,
+            139, 143, 13, 20, 196, 170, 204, 205, 53, 73, 161, 119, 31, 68, 58, 102, 117, 197, 234,
+            159, 228, 142, 181, 36, 211, 8, 108, 15, 64, 1, 1, 112, 33, 184, 33, 10, 13, 146, 11,
+            118, 184, 16, 51, 55, 153, 46, 196, 140, 102, 103, 100, 90, 180, 122, 28, 234, 70, 177,
+            49, 151, 80, 2, 37, 96, 147, 76, 41, 176, 19, 1, 224, 34, 12, 140, 234, 32, 202, 80,
+            21, 113, 213, 20, 78, 170, 89, 27, 66, 234, 197, 69, 88, 118, 108, 218, 15, 203, 120,
+            153, 224, 164, 8, 243, 130, 180, 97, 186, 161, 164, 57, 152, 247, 20, 175,
+        ];
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
+
+        let res = verify_distance_1024(&nonce_msg, &sig, &pk_ntt_fmt);
+        assert_eq!(res, 56737426);
+    }
+
+    #[test]
+    pub fn falcon512_header_rejected() {
+        let mut pk = [0u8; 1793];
+
+        pk[0] = 0x09;
+        assert_eq!(pk_to_ntt_fmt(&pk), Err(Error::BadHeader));
+    }
+}
@@ -40,7 +40,7 @@ pub mod tests {
                 !verify(
                     &msg,
                     &mutated,
-                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
                 ),
                 "Randomized sig should not verify"
             );
@@ -50,7 +50,7 @@ pub mod tests {
     #[test]
     fn fuzz_flip_sig_bits() {
         let (msg, pk, sig) = get_valid_test_vector();
-        let pk_ntt = pk_to_ntt_fmt(pk.as_slice().try_into().unwrap());
+        let pk_ntt = pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap();
 
         for n in [1, 3, 8, 16, 32, 64, 128, 256, 512] {
             let mut mutated = sig.clone();
@@ -65,7 +65,7 @@ pub mod tests {
     #[test]
     fn fuzz_swap_sig_bytes() {
         let (msg, pk, sig) = get_valid_test_vector();
-        let pk_ntt = pk_to_ntt_fmt(pk.as_slice().try_into().unwrap());
+        let pk_ntt = pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap();
 
         for n in [1, 4, 10, 16, 32, 64, 128, 256, 512] {
             let mut mutated = sig.clone();
@@ -88,7 +88,7 @@ pub mod tests {
             !verify(
                 &msg,
                 &mutated,
-                &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
             ),
             "Zero sig should not verify"
         );
@@ -105,7 +105,7 @@ pub mod tests {
             !verify(
                 &msg,
                 &mutated,
-                &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
             ),
             "All-0xFF sig should not verify"
         );
@@ -124,7 +124,7 @@ pub mod tests {
                     !verify(
                         &msg,
                         &mutated,
-                        &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                        &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
                     ),
                     "Truncated sig ({} bytes) should not verify",
                     n
@@ -145,7 +145,7 @@ pub mod tests {
                 !verify(
                     &msg,
                     &mutated,
-                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
                 ),
                 "Randomly extended sig ({} bytes) should not verify",
                 n
@@ -165,7 +165,7 @@ pub mod tests {
                 !verify(
                     &msg,
                     &mutated,
-                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
                 ),
                 "Zero extended sig ({} bytes) should not verify",
                 n
@@ -186,7 +186,7 @@ pub mod tests {
                 !verify(
                     &mutated,
                     &sig,
-                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
                 ),
                 "Randomized nonce should not verify"
             );
@@ -204,7 +204,7 @@ pub mod tests {
             !verify(
                 &mutated,
                 &sig,
-                &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
             ),
             "Zero nonce should not verify"
         );
@@ -221,7 +221,7 @@ pub mod tests {
             !verify(
                 &mutated,
                 &sig,
-                &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
             ),
             "0xFF nonce should not verify"
         );
@@ -240,7 +240,7 @@ pub mod tests {
                 !verify(
                     &mutated,
                     &sig,
-                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap())
+                    &pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap()
                 ),
                 "Swapped nonce bytes ({}x) should not verify",
                 n
@@ -95,7 +95,7 @@ pub mod tests {
             247, 203, 76, 155, 133, 9, 146, 249, 110, 32, 82, 83, 48, 89, 154, 182, 1, 212, 84,
             104, 142, 41, 76, 140, 62,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 28308410);
@@ -196,7 +196,7 @@ pub mod tests {
             156, 131, 23, 193, 252, 55, 81, 139, 22, 99, 120, 64, 168, 102, 39, 17, 62, 56, 9, 167,
             0, 204, 27,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 28553921);
@@ -299,7 +299,7 @@ pub mod tests {
             130, 187, 68, 215, 206, 55, 0, 6, 193, 133, 70, 172, 103, 10, 227, 139, 243, 194, 130,
             12, 228, 121, 149, 157, 205, 120,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 29548490);
@@ -404,7 +404,7 @@ pub mod tests {
             213, 32, 17, 37, 173, 153, 203, 20, 8, 167, 174, 158, 245, 29, 235, 104, 22, 253, 65,
             139, 221, 235, 152, 143, 120, 189, 9, 30, 236, 35,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 27490493);
@@ -510,7 +510,7 @@ pub mod tests {
             50, 31, 210, 15, 106, 240, 109, 126, 24, 188, 127, 215, 29, 138, 140, 33, 142, 127,
             176, 207, 14, 46,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 26659207);
@@ -618,7 +618,7 @@ pub mod tests {
             129, 84, 22, 254, 165, 70, 34, 11, 167, 205, 220, 179, 149, 71, 207, 93, 12, 254, 192,
             170, 162, 55, 144, 220, 166, 22, 94, 225, 98,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 28850571);
@@ -728,7 +728,7 @@ pub mod tests {
             57, 78, 41, 173, 184, 7, 69, 198, 234, 13, 50, 70, 69, 33, 145, 201, 235, 59, 181, 43,
             202, 236, 148, 9,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 26430443);
@@ -838,7 +838,7 @@ pub mod tests {
             169, 77, 213, 229, 13, 212, 116, 45, 85, 131, 170, 223, 253, 61, 232, 209, 226, 213,
             31, 93, 43, 181, 175, 166, 12, 83, 10, 64, 244, 152, 50, 11, 207, 130,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 26901708);
@@ -951,7 +951,7 @@ pub mod tests {
             121, 67, 61, 66, 192, 105, 185, 69, 3, 105, 92, 196, 246, 178, 254, 161, 101, 179, 113,
             74, 4, 212, 69, 133,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 27953404);
@@ -1066,7 +1066,7 @@ pub mod tests {
             55, 128, 138, 125, 39, 142, 102, 236, 187, 1, 67, 128, 163, 40, 8, 29, 88, 228, 32,
             187, 28, 249,
         ];
-        let pk_ntt_fmt = pk_to_ntt_fmt(&pk);
+        let pk_ntt_fmt = pk_to_ntt_fmt(&pk).unwrap();
 
         let res = verify_distance(&nonce_msg, &sig, &pk_ntt_fmt);
         assert_eq!(res, 30017223);
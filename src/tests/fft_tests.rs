@@ -0,0 +1,65 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        fft::{fft, ifft, poly_mul_fft},
+        shake256::Shake256Prng,
+    };
+
+    fn random_poly(prng: &mut Shake256Prng, n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|_| (prng.next_u8() as i32 - 128) as f64)
+            .collect()
+    }
+
+    #[test]
+    fn fft_ifft_round_trip() {
+        let mut prng = Shake256Prng::from_seed(b"fft round trip");
+
+        for logn in 1..=9 {
+            let n = 1 << logn;
+            let a = random_poly(&mut prng, n);
+            let mut b = a.clone();
+
+            fft(&mut b, logn);
+            ifft(&mut b, logn);
+
+            for (x, y) in a.iter().zip(&b) {
+                assert!((x - y).abs() < 1e-9, "logn = {}: {} != {}", logn, x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn fft_mul_matches_negacyclic_schoolbook() {
+        let mut prng = Shake256Prng::from_seed(b"fft mul");
+
+        for logn in 1..=9 {
+            let n = 1 << logn;
+            let a = random_poly(&mut prng, n);
+            let b = random_poly(&mut prng, n);
+            let mut expected = vec![0.0; n];
+
+            for i in 0..n {
+                for j in 0..n {
+                    if i + j < n {
+                        expected[i + j] += a[i] * b[j];
+                    } else {
+                        expected[i + j - n] -= a[i] * b[j];
+                    }
+                }
+            }
+
+            let mut fa = a.clone();
+            let mut fb = b.clone();
+
+            fft(&mut fa, logn);
+            fft(&mut fb, logn);
+            poly_mul_fft(&mut fa, &fb, logn);
+            ifft(&mut fa, logn);
+
+            for (x, y) in expected.iter().zip(&fa) {
+                assert!((x - y).abs() < 1e-6, "logn = {}: {} != {}", logn, x, y);
+            }
+        }
+    }
+}
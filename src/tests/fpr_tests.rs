@@ -0,0 +1,103 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        falcon512::{keygen, pk_to_ntt_fmt, sign_with, verify},
+        fpr::{Fpr, FprEmu},
+        shake256::Shake256Prng,
+    };
+
+    /// Random normal value with an exponent within 2^-60..2^60, either sign.
+    fn random_f64(prng: &mut Shake256Prng) -> f64 {
+        let r = prng.next_u64();
+        let e = 1023 - 60 + (r >> 52) % 121;
+
+        f64::from_bits((r & ((1 << 52) - 1)) | (e << 52) | (prng.next_u8() as u64 & 1) << 63)
+    }
+
+    fn emu(x: f64) -> FprEmu {
+        FprEmu(x.to_bits())
+    }
+
+    /// Same bits, or both zero (the emulation may return +0 where the FPU returns -0).
+    fn same(a: FprEmu, b: f64) -> bool {
+        a.0 == b.to_bits() || (a.0 << 1 == 0 && b == 0.0)
+    }
+
+    #[test]
+    fn fpr_emu_arithmetic_matches_native() {
+        let mut prng = Shake256Prng::from_seed(b"fpr arithmetic");
+
+        for _ in 0..20000 {
+            let x = random_f64(&mut prng);
+            let mut y = random_f64(&mut prng);
+
+            // close exponents too, where additions cancel
+            if prng.next_u8() & 1 == 0 {
+                y = x * (1.0 + y / 2f64.powi(61));
+            }
+
+            for (a, b) in [(x, y), (x, -x), (x, x), (x, 0.0), (0.0, y)] {
+                assert!(same(emu(a) + emu(b), a + b), "{} + {}", a, b);
+                assert!(same(emu(a) - emu(b), a - b), "{} - {}", a, b);
+                assert!(same(emu(a) * emu(b), a * b), "{} * {}", a, b);
+
+                if b != 0.0 {
+                    assert!(same(emu(a) / emu(b), a / b), "{} / {}", a, b);
+                }
+            }
+
+            assert!(same(emu(x.abs()).sqrt(), x.abs().sqrt()), "sqrt {}", x);
+            assert!(same(emu(x).half(), x * 0.5), "half {}", x);
+        }
+
+        assert_eq!(emu(0.0).sqrt().0, 0.0f64.to_bits());
+    }
+
+    #[test]
+    fn fpr_emu_conversions_match_native() {
+        let mut prng = Shake256Prng::from_seed(b"fpr conversions");
+
+        for _ in 0..20000 {
+            let x = random_f64(&mut prng);
+            // exact halves, to exercise ties in rint
+            let h = (prng.next_u64() as i32 >> 8) as f64 + 0.5;
+
+            for v in [x, h, -h] {
+                if v.abs() < 9.0e18 {
+                    assert_eq!(emu(v).rint(), v.rint(), "rint {}", v);
+                    assert_eq!(emu(v).floor(), Fpr::floor(v), "floor {}", v);
+                    assert_eq!(emu(v).trunc(), Fpr::trunc(v), "trunc {}", v);
+                }
+            }
+
+            let i = prng.next_u64() as i64 >> (prng.next_u8() & 63);
+
+            assert_eq!(FprEmu::of(i).0, (i as f64).to_bits(), "of {}", i);
+        }
+
+        assert_eq!(FprEmu::of(0).0, 0.0f64.to_bits());
+        assert_eq!(emu(0.0).rint(), 0);
+        assert_eq!(emu(0.0).trunc(), 0);
+        assert_eq!(emu(0.0).floor(), 0);
+    }
+
+    #[test]
+    fn sign_emu_matches_native() {
+        let (sk, pk) = keygen(&mut Shake256Prng::from_seed(b"fpr sign keygen"));
+        let pk_ntt = pk_to_ntt_fmt(&pk).unwrap();
+
+        for seed in 0u8..8 {
+            let msg = [seed; 33];
+            let native = sign_with::<f64>(&sk, &msg, &mut Shake256Prng::from_seed(&[seed]));
+            let emulated = sign_with::<FprEmu>(&sk, &msg, &mut Shake256Prng::from_seed(&[seed]));
+
+            assert_eq!(native.0, emulated.0);
+            assert_eq!(native.1[..native.2], emulated.1[..emulated.2]);
+            assert!(verify(
+                &[&emulated.0[..], &msg].concat(),
+                &emulated.1[..emulated.2],
+                &pk_ntt
+            ));
+        }
+    }
+}
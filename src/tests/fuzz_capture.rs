@@ -15,7 +15,7 @@ mod tests {
     #[ignore = "heavy (~minutes); run on demand"]
     fn capture_accepts() {
         let (msg, pk, sig) = get_valid_test_vector();
-        let pk_ntt = pk_to_ntt_fmt(pk.as_slice().try_into().unwrap());
+        let pk_ntt = pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap();
         let (orig_dec, _) = comp_decode(&sig);
         let mut rng = rand::rng();
 
@@ -53,7 +53,7 @@ mod tests {
     #[test]
     fn production_verify_accepts_valid_vector() {
         let (msg, pk, sig) = get_valid_test_vector();
-        let pk_ntt = crate::falcon512::pk_to_ntt_fmt(pk.as_slice().try_into().unwrap());
+        let pk_ntt = crate::falcon512::pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap();
         assert!(
             verify(&msg, &sig, &pk_ntt),
             "valid NIST vector must verify through the vartime production path"
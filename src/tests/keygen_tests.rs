@@ -0,0 +1,111 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::{N, Q},
+        error::Error,
+        falcon512::{keygen, mq_decode, mq_encode, pk_from_ntt_fmt, pk_to_ntt_fmt},
+        ntru::check_ntru_equation,
+        shake256::Shake256Prng,
+        tests::test_utils::get_valid_test_vector,
+    };
+
+    #[test]
+    fn keygen_satisfies_ntru_equation() {
+        let mut prng = Shake256Prng::from_seed(b"keygen ntru equation");
+        let (sk, _) = keygen(&mut prng);
+        let widen = |x: [i8; N]| x.map(|c| c as i32);
+
+        assert!(check_ntru_equation(
+            &widen(sk.f),
+            &widen(sk.g),
+            &widen(sk.big_f),
+            &widen(sk.big_g)
+        ));
+    }
+
+    #[test]
+    fn keygen_public_key_is_g_over_f() {
+        let mut prng = Shake256Prng::from_seed(b"keygen public key");
+        let (sk, pk) = keygen(&mut prng);
+
+        assert_eq!(pk[0], 0x09);
+
+        let mut h = [0u16; N];
+
+        assert_eq!(mq_decode(&mut h, &pk, 1), Ok(pk.len() - 1));
+
+        // h * f = g mod (q, X^N + 1)
+        for k in 0..N {
+            let mut acc: i64 = 0;
+
+            for i in 0..N {
+                let j = (k + N - i) % N;
+                let t = h[j] as i64 * sk.f[i] as i64;
+
+                acc += if i <= k { t } else { -t };
+            }
+
+            assert_eq!(
+                acc.rem_euclid(Q as i64),
+                (sk.g[k] as i64).rem_euclid(Q as i64)
+            );
+        }
+
+        // also accepted by the verifier's key parser
+        pk_to_ntt_fmt(&pk).unwrap();
+    }
+
+    #[test]
+    fn keygen_is_deterministic() {
+        let (sk0, pk0) = keygen(&mut Shake256Prng::from_seed(b"keygen seed"));
+        let (sk1, pk1) = keygen(&mut Shake256Prng::from_seed(b"keygen seed"));
+        let (_, pk2) = keygen(&mut Shake256Prng::from_seed(b"another keygen seed"));
+
+        assert_eq!(pk0, pk1);
+        assert_eq!(sk0.big_f, sk1.big_f);
+        assert_ne!(pk0, pk2);
+    }
+
+    #[test]
+    fn public_key_round_trips_through_ntt_format() {
+        let mut prng = Shake256Prng::from_seed(b"keygen pk export");
+
+        for _ in 0..3 {
+            let (_, pk) = keygen(&mut prng);
+
+            assert_eq!(pk_from_ntt_fmt(&pk_to_ntt_fmt(&pk).unwrap()).unwrap(), pk);
+        }
+    }
+
+    #[test]
+    fn nist_public_key_round_trips_through_ntt_format() {
+        let (_, pk, _) = get_valid_test_vector();
+        let pk: [u8; 897] = pk.try_into().unwrap();
+
+        assert_eq!(pk_from_ntt_fmt(&pk_to_ntt_fmt(&pk).unwrap()).unwrap(), pk);
+    }
+
+    #[test]
+    fn mq_encode_round_trip() {
+        let x: [u16; N] = core::array::from_fn(|i| (i * 4099 % Q as usize) as u16);
+        let mut buf = [0u8; 897];
+        let mut y = [0u16; N];
+
+        assert_eq!(mq_encode(&x, &mut buf, 1), 896);
+        assert_eq!(mq_decode(&mut y, &buf, 1), Ok(896));
+        assert_eq!(x, y);
+
+        let mut z = x;
+
+        z[7] = Q;
+        assert_eq!(mq_encode(&z, &mut buf, 1), 0);
+    }
+
+    #[test]
+    fn pk_from_ntt_fmt_rejects_unreduced() {
+        let mut pk_ntt = [0u16; N];
+
+        pk_ntt[0] = Q;
+        assert_eq!(pk_from_ntt_fmt(&pk_ntt), Err(Error::CoefficientOutOfRange));
+    }
+}
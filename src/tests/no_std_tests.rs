@@ -0,0 +1,47 @@
+//! Builds `tests/no_std`, a `#![no_std]` static library with its own panic handler calling the
+//! verification path (and signing on the emulated floating point) with the default features, so
+//! that a dependency on std or alloc creeping into it fails the test suite - including std linked
+//! without being used, which clashes with the panic handler.
+#[cfg(test)]
+pub mod tests {
+    use std::process::Command;
+
+    const BARE_METAL_TARGET: &str = "thumbv7em-none-eabihf";
+
+    fn build_no_std_crate(target: Option<&str>) {
+        let root = env!("CARGO_MANIFEST_DIR");
+        let mut build = Command::new(env!("CARGO"));
+
+        build
+            .args(["build", "--offline", "--quiet", "--manifest-path"])
+            .arg(format!("{}/tests/no_std/Cargo.toml", root))
+            .arg("--target-dir")
+            .arg(format!("{}/target/no_std", root));
+
+        if let Some(target) = target {
+            build.args(["--target", target]);
+        }
+
+        let output = build.output().expect("cargo not found");
+
+        assert!(
+            output.status.success(),
+            "no_std build failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn verify_path_builds_without_std() {
+        build_no_std_crate(None);
+    }
+
+    // the host build catches std and alloc; a target without std also catches code that only
+    // compiles on hosted platforms
+    #[test]
+    #[ignore = "needs the thumbv7em-none-eabihf target: `rustup target add thumbv7em-none-eabihf`, \
+                then `cargo test no_std -- --ignored`"]
+    fn verify_path_builds_for_bare_metal() {
+        build_no_std_crate(Some(BARE_METAL_TARGET));
+    }
+}
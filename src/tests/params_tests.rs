@@ -0,0 +1,269 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::Q,
+        error::Error,
+        falcon512::{
+            comp_decode_with, hash_to_point_vartime_with, is_short_with, mq_intt_with, mq_ntt_with,
+            mq_poly_montymul_ntt, mq_poly_montymul_ntt_n, mq_poly_tomonty_n, pk_from_ntt_fmt_with,
+            pk_to_ntt_fmt_with, verify_with,
+        },
+        params::{Falcon1024, Falcon512, FalconParams, SqueezeBuffer, Toy},
+        shake256::{shake_flip, shake_inject, Shake256Prng},
+    };
+
+    fn random_poly<const N: usize>(prng: &mut Shake256Prng) -> [u16; N] {
+        core::array::from_fn(|_| (prng.next_u64() % Q as u64) as u16)
+    }
+
+    // a * b mod (X^N + 1, q)
+    fn schoolbook<const N: usize>(a: &[u16; N], b: &[u16; N]) -> [u16; N] {
+        let mut r = [0u64; N];
+
+        for i in 0..N {
+            for j in 0..N {
+                let p = a[i] as u64 * b[j] as u64 % Q as u64;
+
+                if i + j < N {
+                    r[i + j] += p;
+                } else {
+                    r[i + j - N] += Q as u64 - p;
+                }
+            }
+        }
+
+        r.map(|x| (x % Q as u64) as u16)
+    }
+
+    fn check_ntt<P: FalconParams<N>, const N: usize>(prng: &mut Shake256Prng) {
+        let a = random_poly::<N>(prng);
+        let b = random_poly::<N>(prng);
+
+        // round trip (the forward output is lazily reduced, the inverse input must be < q)
+        let mut x = a;
+
+        mq_ntt_with::<P, N>(&mut x);
+        x.iter_mut().for_each(|w| *w %= Q);
+        mq_intt_with::<P, N>(&mut x);
+
+        assert_eq!(x, a, "N = {}: NTT round trip", N);
+
+        // negacyclic product
+        let mut fa = a;
+        let mut fb = b;
+
+        mq_ntt_with::<P, N>(&mut fa);
+        mq_ntt_with::<P, N>(&mut fb);
+        mq_poly_tomonty_n(&mut fb);
+        mq_poly_montymul_ntt_n(&mut fa, &fb);
+        mq_intt_with::<P, N>(&mut fa);
+
+        assert_eq!(fa, schoolbook(&a, &b), "N = {}: NTT product", N);
+    }
+
+    #[test]
+    fn ntt_all_degrees() {
+        let mut prng = Shake256Prng::from_seed(b"ntt all degrees");
+
+        check_ntt::<Toy<2>, 2>(&mut prng);
+        check_ntt::<Toy<4>, 4>(&mut prng);
+        check_ntt::<Toy<8>, 8>(&mut prng);
+        check_ntt::<Toy<16>, 16>(&mut prng);
+        check_ntt::<Toy<32>, 32>(&mut prng);
+        check_ntt::<Toy<64>, 64>(&mut prng);
+        check_ntt::<Toy<128>, 128>(&mut prng);
+        check_ntt::<Toy<256>, 256>(&mut prng);
+        check_ntt::<Falcon512, 512>(&mut prng);
+        check_ntt::<Falcon1024, 1024>(&mut prng);
+    }
+
+    #[test]
+    fn falcon512_ntt_product() {
+        let mut prng = Shake256Prng::from_seed(b"falcon512 ntt product");
+        let a = random_poly::<512>(&mut prng);
+        let b = random_poly::<512>(&mut prng);
+        let mut fa = a;
+        let mut fb = b;
+
+        crate::falcon512::mq_ntt(&mut fa);
+        crate::falcon512::to_ntt_monty(&mut fb);
+        mq_poly_montymul_ntt(&mut fa, &fb);
+        crate::falcon512::mq_intt(&mut fa);
+
+        assert_eq!(fa, schoolbook(&a, &b));
+    }
+
+    #[test]
+    fn toy_sizes() {
+        assert_eq!(<Toy<2> as FalconParams<2>>::LOGN, 1);
+        assert_eq!(<Toy<2> as FalconParams<2>>::PK_SIZE, 5);
+        assert_eq!(<Toy<2> as FalconParams<2>>::SIG_COMP_MAXSIZE, 3);
+        assert_eq!(<Toy<2> as FalconParams<2>>::SIG_L2_BOUND, 101498);
+        assert_eq!(<Toy<256> as FalconParams<256>>::LOGN, 8);
+        assert_eq!(<Toy<256> as FalconParams<256>>::PK_SIZE, 449);
+        assert_eq!(<Toy<256> as FalconParams<256>>::SIG_COMP_MAXSIZE, 356);
+        assert_eq!(<Toy<256> as FalconParams<256>>::SIG_L2_BOUND, 16468416);
+        assert_eq!(<Toy<256> as FalconParams<256>>::SIG_PADDED_SIZE, 356);
+        assert_eq!(<Toy<256> as FalconParams<256>>::SIG_CT_SIZE, 425);
+        assert_eq!(<Toy<2> as FalconParams<2>>::SIG_CT_SIZE, 44);
+    }
+
+    // 14-bit packed public key with header `logn`
+    fn pk_encode<P: FalconParams<N>, const N: usize>(h: &[u16; N]) -> Vec<u8> {
+        let mut out = vec![P::LOGN];
+        let mut acc = 0u32;
+        let mut acc_len = 0;
+
+        for &w in h {
+            acc = (acc << 14) | w as u32;
+            acc_len += 14;
+
+            while acc_len >= 8 {
+                acc_len -= 8;
+                out.push((acc >> acc_len) as u8);
+            }
+        }
+        if acc_len > 0 {
+            out.push((acc << (8 - acc_len)) as u8);
+        }
+
+        out
+    }
+
+    // compressed encoding of non-negative coefficients < 128: sign 0, 7 low bits, then a '1'
+    fn comp_encode_small(x: &[u16]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut acc = 0u32;
+        let mut acc_len = 0;
+
+        for &w in x {
+            acc = (acc << 9) | ((w as u32) << 1) | 1;
+            acc_len += 9;
+
+            while acc_len >= 8 {
+                acc_len -= 8;
+                out.push((acc >> acc_len) as u8);
+            }
+        }
+        if acc_len > 0 {
+            out.push((acc << (8 - acc_len)) as u8);
+        }
+
+        out
+    }
+
+    fn hash_to_point<P: FalconParams<N>, const N: usize>(nonce_msg: &[u8]) -> [u16; N] {
+        let mut shake_ctx = [0u64; 26];
+
+        shake_inject(&mut shake_ctx, nonce_msg);
+        shake_flip(&mut shake_ctx);
+
+        let extracted = P::Squeeze::squeeze(&mut shake_ctx);
+        let mut c = [0u16; N];
+
+        hash_to_point_vartime_with::<P, N>(extracted.as_ref(), &mut c);
+
+        c
+    }
+
+    // A toy key pair with s2 = 1: the public key is h = c - s1 for a small s1, so that
+    // s2 * h - c = -s1 is short.
+    fn check_toy_verify<const N: usize>()
+    where
+        Toy<N>: FalconParams<N>,
+    {
+        let nonce_msg = [0x5au8; 48];
+        let c = hash_to_point::<Toy<N>, N>(&nonce_msg);
+        let s1: [u16; N] = core::array::from_fn(|i| (i % 5) as u16);
+        let h: [u16; N] = core::array::from_fn(|i| (c[i] + Q - s1[i]) % Q);
+        let s2: [u16; N] = core::array::from_fn(|i| (i == 0) as u16);
+
+        let pk = pk_encode::<Toy<N>, N>(&h);
+        let pk_ntt_fmt = pk_to_ntt_fmt_with::<Toy<N>, N>(&pk).unwrap();
+        let mut pk_back = vec![0u8; pk.len()];
+
+        assert_eq!(
+            pk_from_ntt_fmt_with::<Toy<N>, N>(&pk_ntt_fmt, &mut pk_back),
+            Ok(pk.len())
+        );
+        assert_eq!(pk_back, pk);
+
+        let sig = comp_encode_small(&s2);
+
+        assert_eq!(comp_decode_with::<Toy<N>, N>(&sig), (s2, sig.len()));
+        assert!(
+            verify_with::<Toy<N>, N>(&nonce_msg, &sig, &pk_ntt_fmt),
+            "N = {}",
+            N
+        );
+
+        let mut tampered = nonce_msg;
+
+        tampered[47] ^= 1;
+
+        assert!(
+            !verify_with::<Toy<N>, N>(&tampered, &sig, &pk_ntt_fmt),
+            "N = {}",
+            N
+        );
+    }
+
+    #[test]
+    fn toy_verify() {
+        check_toy_verify::<2>();
+        check_toy_verify::<4>();
+        check_toy_verify::<8>();
+        check_toy_verify::<16>();
+        check_toy_verify::<32>();
+        check_toy_verify::<64>();
+        check_toy_verify::<128>();
+        check_toy_verify::<256>();
+    }
+
+    #[test]
+    fn toy_is_short_bound() {
+        // 318^2 + 32^2 = 102148 > 101498 >= 318^2 + 10^2
+        assert!(is_short_with::<Toy<2>, 2>(&[318, 0], &[10, 0]));
+        assert!(!is_short_with::<Toy<2>, 2>(&[318, 0], &[32, 0]));
+        // negative coefficients are sign-extended
+        assert!(is_short_with::<Toy<2>, 2>(
+            &[0u16.wrapping_sub(318), 0],
+            &[10, 0]
+        ));
+    }
+
+    #[test]
+    fn toy_pk_wrong_length() {
+        let pk = [3u8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        assert_eq!(pk_to_ntt_fmt_with::<Toy<8>, 8>(&pk), Err(Error::BadLength));
+    }
+
+    #[test]
+    fn toy_pk_checked() {
+        // N = 2: 28 bits of coefficients, 4 padding bits
+        let pk = pk_encode::<Toy<2>, 2>(&[1, Q - 1]);
+
+        assert_eq!(pk_to_ntt_fmt_with::<Toy<2>, 2>(&pk).map(|_| ()), Ok(()));
+
+        let mut bad = pk.clone();
+
+        bad[4] |= 1;
+        assert_eq!(
+            pk_to_ntt_fmt_with::<Toy<2>, 2>(&bad),
+            Err(Error::TrailingBits)
+        );
+
+        let mut bad = pk.clone();
+
+        bad[0] = 2;
+        assert_eq!(pk_to_ntt_fmt_with::<Toy<2>, 2>(&bad), Err(Error::BadHeader));
+
+        let bad = pk_encode::<Toy<2>, 2>(&[1, Q]);
+
+        assert_eq!(
+            pk_to_ntt_fmt_with::<Toy<2>, 2>(&bad),
+            Err(Error::CoefficientOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! The Python extension module: its Rust logic, then the module itself (`python3`, or `$PYTHON`,
+//! must be installed), built as a `cdylib` with the `python` feature and driven by
+//! `tests/python/test_falcon512.py` on the embedded NIST KATs, under pytest if it is installed.
+#[cfg(all(test, feature = "python"))]
+pub mod tests {
+    use std::{path::Path, process::Command};
+
+    use crate::{
+        constants::SIG_COMP_MAXSIZE, error::Error, falcon512::comp_encode, python::decode_s2,
+        tests::test_utils::get_valid_test_vector,
+    };
+
+    const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+    #[test]
+    fn decode_s2_round_trip() {
+        let (_, _, s2) = get_valid_test_vector();
+        let coeffs = decode_s2(&s2).unwrap();
+        let mut padded = s2.clone();
+
+        padded.extend_from_slice(&[0; 10]);
+
+        let mut encoded = [0u8; SIG_COMP_MAXSIZE as usize];
+        let len = comp_encode(
+            &coeffs
+                .iter()
+                .map(|&c| c as u16)
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            &mut encoded,
+        );
+
+        assert_eq!(&encoded[..len], &s2[..]);
+        assert_eq!(decode_s2(&padded).unwrap(), coeffs);
+
+        padded.push(1);
+
+        assert_eq!(decode_s2(&padded).unwrap_err(), Error::TrailingBits);
+        assert_eq!(decode_s2(&s2[..100]).unwrap_err(), Error::DecodeFailed);
+        assert_eq!(decode_s2(&[]).unwrap_err(), Error::DecodeFailed);
+    }
+
+    #[test]
+    fn python_module() {
+        let python = std::env::var("PYTHON").unwrap_or_else(|_| "python3".into());
+
+        assert!(
+            Command::new(&python).arg("--version").output().is_ok(),
+            "{} not found (set PYTHON to the interpreter to test against)",
+            python
+        );
+
+        let target = format!("{}/target/python", ROOT);
+        let output = Command::new(env!("CARGO"))
+            .args(["rustc", "--offline", "--quiet", "--lib"])
+            .args(["--crate-type", "cdylib", "--features", "python"])
+            .args(["--manifest-path", &format!("{}/Cargo.toml", ROOT)])
+            .args(["--target-dir", &target])
+            .env("PYO3_PYTHON", &python)
+            .output()
+            .expect("cargo not found");
+
+        assert!(
+            output.status.success(),
+            "extension module build failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        // Python imports extension modules as `<name>.so` (`.pyd` on Windows)
+        let lib = ["libfalcon512_rs.so", "libfalcon512_rs.dylib"]
+            .iter()
+            .map(|name| format!("{}/debug/{}", target, name))
+            .find(|path| Path::new(path).exists())
+            .expect("cdylib not found");
+        let module_dir = format!("{}/module", target);
+
+        std::fs::create_dir_all(&module_dir).unwrap();
+        std::fs::copy(&lib, format!("{}/falcon512_rs.so", module_dir)).unwrap();
+
+        let test_file = format!("{}/tests/python/test_falcon512.py", ROOT);
+        let has_pytest = Command::new(&python)
+            .args(["-c", "import pytest"])
+            .output()
+            .is_ok_and(|output| output.status.success());
+        let mut run = Command::new(&python);
+
+        if has_pytest {
+            run.args(["-m", "pytest", "-q", "-p", "no:cacheprovider"]);
+        }
+
+        let run = run
+            .arg(&test_file)
+            .env("PYTHONPATH", &module_dir)
+            .output()
+            .unwrap();
+
+        assert!(
+            run.status.success(),
+            "test_falcon512.py failed:\n{}{}",
+            String::from_utf8_lossy(&run.stdout),
+            String::from_utf8_lossy(&run.stderr)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(all(test, feature = "signature"))]
+pub mod tests {
+    use signature::{
+        rand_core::{CryptoRng, RngCore},
+        RandomizedSigner, SignatureEncoding, Verifier,
+    };
+
+    use crate::{
+        falcon512::{expand_secret_key, keygen, PublicKey, SigFormat, Signature},
+        shake256::Shake256Prng,
+    };
+
+    // a rand_core 0.6 RNG over the crate's PRNG
+    struct TestRng(Shake256Prng);
+
+    impl RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            self.0.fill_bytes(dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), signature::rand_core::Error> {
+            self.0.fill_bytes(dest);
+
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for TestRng {}
+
+    // code written against the traits only
+    fn check<V: Verifier<S>, S: SignatureEncoding>(vk: &V, msg: &[u8], sig: &S) -> bool {
+        let bytes = sig.to_bytes();
+        let Ok(decoded) = S::try_from(bytes.as_ref()) else {
+            return false;
+        };
+
+        vk.verify(msg, &decoded).is_ok()
+    }
+
+    #[test]
+    fn sign_and_verify_through_traits() {
+        let mut rng = TestRng(Shake256Prng::from_seed(b"rustcrypto traits"));
+        let (sk, pk_bytes) = keygen(&mut rng.0);
+        let pk = PublicKey::try_from(&pk_bytes[..]).unwrap();
+        let prepared = pk.prepare();
+
+        let sig: Signature = sk.sign_with_rng(&mut rng, b"msg");
+
+        assert_eq!(sig.format(), SigFormat::Compressed);
+        assert_eq!(sig.encoded_len(), sig.as_bytes().len());
+        assert_eq!(sig.to_bytes(), sig);
+        assert!(check(&pk, b"msg", &sig));
+        assert!(check(&prepared, b"msg", &sig));
+        assert!(!check(&pk, b"msh", &sig));
+        assert!(Verifier::verify(&prepared, b"msh", &sig).is_err());
+
+        let esk = expand_secret_key(&sk);
+        let sig: Signature = esk.try_sign_with_rng(&mut rng, b"tree").unwrap();
+
+        assert!(check(&prepared, b"tree", &sig));
+    }
+}
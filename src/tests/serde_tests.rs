@@ -0,0 +1,121 @@
+#[cfg(all(test, feature = "serde"))]
+pub mod tests {
+    use crate::{
+        falcon512::{keygen, sign, PreparedPublicKey, PublicKey, Signature},
+        shake256::Shake256Prng,
+        tests::test_utils::hex_encode,
+    };
+
+    fn signed(msg: &[u8]) -> (PublicKey, Signature) {
+        let mut rng = Shake256Prng::from_seed(b"serde");
+        let (sk, pk) = keygen(&mut rng);
+        let (nonce, sig, len) = sign(&sk, msg, &mut rng);
+
+        (
+            PublicKey::try_from(&pk[..]).unwrap(),
+            Signature::from_compressed(&nonce, &sig[..len]).unwrap(),
+        )
+    }
+
+    fn bincode_encode<T: serde::Serialize + ?Sized>(value: &T) -> Vec<u8> {
+        bincode::serde::encode_to_vec(value, bincode::config::standard()).unwrap()
+    }
+
+    fn bincode_decode<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+        bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+            .map(|(value, _)| value)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn json_hex_round_trip() {
+        let (pk, sig) = signed(b"json");
+        let prepared = pk.prepare();
+
+        let pk_json = serde_json::to_string(&pk).unwrap();
+        let sig_json = serde_json::to_string(&sig).unwrap();
+
+        assert_eq!(pk_json, format!("\"{}\"", hex_encode(pk.as_bytes())));
+        assert_eq!(sig_json, format!("\"{}\"", hex_encode(sig.as_bytes())));
+        assert_eq!(serde_json::to_string(&prepared).unwrap(), pk_json);
+
+        assert_eq!(serde_json::from_str::<PublicKey>(&pk_json).unwrap(), pk);
+        assert_eq!(
+            serde_json::from_str::<PreparedPublicKey>(&pk_json).unwrap(),
+            prepared
+        );
+        assert_eq!(serde_json::from_str::<Signature>(&sig_json).unwrap(), sig);
+
+        // hex digits are accepted in either case
+        let upper = pk_json.to_uppercase();
+
+        assert_eq!(serde_json::from_str::<PublicKey>(&upper).unwrap(), pk);
+    }
+
+    #[test]
+    fn bincode_raw_round_trip() {
+        let (pk, sig) = signed(b"bincode");
+        let prepared = pk.prepare();
+
+        let pk_bin = bincode_encode(&pk);
+        let sig_bin = bincode_encode(&sig);
+
+        // varint length prefix followed by the raw bytes
+        assert!(pk_bin.ends_with(pk.as_bytes()));
+        assert!(pk_bin.len() < pk.as_bytes().len() + 4);
+        assert!(sig_bin.ends_with(sig.as_bytes()));
+        assert_eq!(bincode_encode(&prepared), pk_bin);
+
+        assert_eq!(bincode_decode::<PublicKey>(&pk_bin), Ok(pk));
+        assert_eq!(bincode_decode::<PreparedPublicKey>(&pk_bin), Ok(prepared));
+        assert_eq!(bincode_decode::<Signature>(&sig_bin), Ok(sig));
+    }
+
+    #[test]
+    fn json_array_rejected() {
+        let (pk, _) = signed(b"array");
+        let array = serde_json::to_string(&pk.as_bytes()[..]).unwrap();
+
+        // human-readable formats take the hex string only
+        assert!(serde_json::from_str::<PublicKey>(&array).is_err());
+    }
+
+    #[test]
+    fn malformed_input_rejected() {
+        let (pk, sig) = signed(b"malformed");
+        let pk_hex = hex_encode(pk.as_bytes());
+        let sig_hex = hex_encode(sig.as_bytes());
+
+        let json = |s: &str| format!("\"{}\"", s);
+
+        // odd number of digits, non-hex digit
+        assert!(serde_json::from_str::<PublicKey>(&json(&pk_hex[1..])).is_err());
+        assert!(serde_json::from_str::<PublicKey>(&json(&pk_hex.replacen('0', "g", 1))).is_err());
+
+        // short and overlong keys
+        assert!(serde_json::from_str::<PublicKey>(&json(&pk_hex[2..])).is_err());
+        assert!(serde_json::from_str::<PublicKey>(&json(&format!("{}00", pk_hex))).is_err());
+
+        // bad header, coefficient >= q
+        let err = serde_json::from_str::<PublicKey>(&json(&format!("0a{}", &pk_hex[2..])));
+
+        assert!(err.unwrap_err().to_string().contains("header"));
+        assert!(
+            serde_json::from_str::<PreparedPublicKey>(&json(&format!("09ff{}", &pk_hex[4..])))
+                .is_err()
+        );
+
+        // signature with a bad header
+        let err = serde_json::from_str::<Signature>(&json(&format!("00{}", &sig_hex[2..])));
+
+        assert!(err.is_err());
+
+        // bincode: truncated key
+        let mut pk_bin = bincode_encode(&pk.as_bytes()[..896]);
+
+        assert!(bincode_decode::<PublicKey>(&pk_bin).is_err());
+
+        pk_bin = bincode_encode(&[0u8; 0][..]);
+        assert!(bincode_decode::<Signature>(&pk_bin).is_err());
+    }
+}
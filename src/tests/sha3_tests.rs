@@ -0,0 +1,174 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        sha3::{Sha3, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake, Shake128, Shake256},
+        tests::test_utils::hex_decode,
+    };
+
+    // messages of the NIST examples: empty, "abc", 448 bits, and 1600 bits of 0xa3
+    const MSG_448: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+    const MSG_1600: [u8; 200] = [0xa3; 200];
+
+    fn messages() -> [&'static [u8]; 4] {
+        [b"", b"abc", MSG_448, &MSG_1600]
+    }
+
+    #[test]
+    fn sha3_224_vectors() {
+        let expected = [
+            "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7",
+            "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf",
+            "8a24108b154ada21c9fd5574494479ba5c7e7ab76ef264ead0fcce33",
+            "9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0",
+        ];
+
+        for (msg, digest) in messages().iter().zip(expected) {
+            assert_eq!(Sha3_224::digest(msg)[..], hex_decode(digest));
+        }
+    }
+
+    #[test]
+    fn sha3_256_vectors() {
+        let expected = [
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+            "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376",
+            "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787",
+        ];
+
+        for (msg, digest) in messages().iter().zip(expected) {
+            assert_eq!(Sha3_256::digest(msg)[..], hex_decode(digest));
+        }
+    }
+
+    #[test]
+    fn sha3_384_vectors() {
+        let expected = [
+            "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2a\
+             c3713831264adb47fb6bd1e058d5f004",
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b2\
+             98d88cea927ac7f539f1edf228376d25",
+            "991c665755eb3a4b6bbdfb75c78a492e8c56a22c5c4d7e429bfdbc32b9d4ad5a\
+             a04a1f076e62fea19eef51acd0657c22",
+            "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd\
+             76197a31fd55ee989f2d7050dd473e8f",
+        ];
+
+        for (msg, digest) in messages().iter().zip(expected) {
+            assert_eq!(Sha3_384::digest(msg)[..], hex_decode(digest));
+        }
+    }
+
+    #[test]
+    fn sha3_512_vectors() {
+        let expected = [
+            "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a6\
+             15b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26",
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+             10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+            "04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636d\
+             ee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e",
+            "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca8\
+             1b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00",
+        ];
+
+        for (msg, digest) in messages().iter().zip(expected) {
+            assert_eq!(Sha3_512::digest(msg)[..], hex_decode(digest));
+        }
+    }
+
+    fn shake<const RATE: usize>(msg: &[u8], out_len: usize) -> Vec<u8> {
+        let mut ctx = Shake::<RATE>::new();
+        let mut out = vec![0u8; out_len];
+
+        ctx.update(msg);
+        ctx.finalize().read(&mut out);
+
+        out
+    }
+
+    #[test]
+    fn shake128_vectors() {
+        let expected = [
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26",
+            "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8",
+            "1a96182b50fb8c7e74e0a707788f55e98209b8d91fade8f32f8dd5cff7bf21f5",
+            "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037",
+        ];
+
+        for (msg, out) in messages().iter().zip(expected) {
+            assert_eq!(shake::<168>(msg, 32), hex_decode(out));
+        }
+
+        // 4096-bit outputs of the NIST examples: last 32 bytes
+        assert_eq!(
+            shake::<168>(b"", 512)[480..],
+            hex_decode("43e41b45a653f2a5c4492c1add544512dda2529833462b71a41a45be97290b6f")
+        );
+        assert_eq!(
+            shake::<168>(&MSG_1600, 512)[480..],
+            hex_decode("44c9fb359fd56ac0a9a75a743cff6862f17d7259ab075216c0699511643b6439")
+        );
+    }
+
+    #[test]
+    fn chunking_does_not_matter() {
+        let msg: Vec<u8> = (0..700u32).map(|i| (i * 31 + 5) as u8).collect();
+        let sha3_224 = Sha3_224::digest(&msg);
+        let sha3_512 = Sha3_512::digest(&msg);
+        let shake128 = shake::<168>(&msg, 400);
+        let shake256 = shake::<136>(&msg, 400);
+
+        // around the rates 72, 104, 136, 144 and 168
+        for chunk in [
+            1, 71, 72, 73, 104, 135, 136, 137, 143, 144, 167, 168, 169, 699,
+        ] {
+            let mut a = Sha3_224::new();
+            let mut b = Sha3_512::new();
+            let mut c = Shake128::new();
+            let mut d = Shake256::new();
+            let mut out128 = [0u8; 400];
+            let mut out256 = [0u8; 400];
+
+            for part in msg.chunks(chunk) {
+                a.update(part);
+                b.update(part);
+                c.update(part);
+                d.update(part);
+            }
+
+            // empty updates are no-ops
+            d.update(&[]);
+
+            let (mut c, mut d) = (c.finalize(), d.finalize());
+
+            for (part128, part256) in out128.chunks_mut(chunk).zip(out256.chunks_mut(chunk)) {
+                c.read(part128);
+                d.read(part256);
+            }
+
+            assert_eq!(a.finalize(), sha3_224, "chunk size {}", chunk);
+            assert_eq!(b.finalize(), sha3_512, "chunk size {}", chunk);
+            assert_eq!(out128[..], shake128[..], "chunk size {}", chunk);
+            assert_eq!(out256[..], shake256[..], "chunk size {}", chunk);
+        }
+    }
+
+    #[test]
+    fn general_rates() {
+        // a digest longer than the rate is squeezed over several permutations, and a SHA-3
+        // digest is a prefix of the longer digests of the same rate
+        let long = Sha3::<72, 200>::digest(b"abc");
+
+        assert_eq!(long[..64], Sha3_512::digest(b"abc"));
+        assert_eq!(
+            Sha3::<136, 100>::digest(b"abc")[..32],
+            Sha3_256::digest(b"abc")
+        );
+
+        // the rate sets the capacity: other rates give other functions
+        assert_ne!(shake::<160>(b"abc", 32), shake::<168>(b"abc", 32));
+        assert_ne!(shake::<8>(b"abc", 32), shake::<168>(b"abc", 32));
+        assert_eq!(shake::<8>(&MSG_1600, 300).len(), 300);
+    }
+}
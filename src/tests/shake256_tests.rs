@@ -0,0 +1,88 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::{M, SHAKE256_RATE},
+        shake256::{shake_extract, shake_flip, shake_inject, Shake256, Shake256Prng},
+        tests::test_utils::hex_decode,
+    };
+
+    fn shake256(input: &[u8], out_len: usize) -> Vec<u8> {
+        let mut ctx = Shake256::new();
+        let mut out = vec![0u8; out_len];
+
+        ctx.update(input);
+        ctx.finalize().read(&mut out);
+
+        out
+    }
+
+    // 1600-bit message of the NIST SHAKE256 example (0xa3 repeated), a multiple of no rate
+    const MSG_1600: [u8; 200] = [0xa3; 200];
+
+    #[test]
+    fn fips202_vectors() {
+        assert_eq!(
+            shake256(b"", 64),
+            hex_decode(
+                "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f\
+                 d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+            )
+        );
+        assert_eq!(
+            shake256(b"abc", 64),
+            hex_decode(
+                "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739\
+                 d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4"
+            )
+        );
+
+        // 4096-bit outputs of the NIST examples: first and last 32 bytes
+        let empty = shake256(b"", 512);
+        let msg_1600 = shake256(&MSG_1600, 512);
+
+        assert_eq!(
+            empty[480..],
+            hex_decode("ab0bae316339894304e35877b0c28a9b1fd166c796b9cc258a064a8f57e27f2a")
+        );
+        assert_eq!(
+            msg_1600[..32],
+            hex_decode("cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d")
+        );
+        assert_eq!(
+            msg_1600[480..],
+            hex_decode("6a1a9d7846436e4dca5728b6f760eef0ca92bf0be5615e96959d767197a0beeb")
+        );
+    }
+
+    #[test]
+    fn matches_falcon_helpers() {
+        // one-shot `shake_inject` / `shake_extract`, as used by `hash_to_point`
+        for len in [
+            0,
+            73,
+            SHAKE256_RATE as usize,
+            3 * SHAKE256_RATE as usize + 5,
+        ] {
+            let msg: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let mut shake_ctx = [0u64; 26];
+
+            shake_inject(&mut shake_ctx, &msg);
+            shake_flip(&mut shake_ctx);
+
+            let words = shake_extract(&mut shake_ctx);
+            let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+            let out = shake256(&msg, 2 * M as usize);
+
+            // `shake_extract` squeezes `2 * M` (1434) bytes into its words
+            assert_eq!(out[..], bytes[..2 * M as usize], "length {}", len);
+        }
+
+        // `Shake256Prng` is SHAKE256 of its seed
+        let mut prng = Shake256Prng::from_seed(b"seed");
+        let mut stream = [0u8; 500];
+
+        prng.fill_bytes(&mut stream);
+
+        assert_eq!(stream[..], shake256(b"seed", 500)[..]);
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::{N, SIG_COMP_MAXSIZE, SIG_CT_SIZE, SIG_PADDED_SIZE},
+        error::Error,
+        falcon512::{
+            comp_decode, comp_encode, keygen, pk_to_ntt_fmt, sig_decode, sign, trim_i16_decode,
+            trim_i16_encode, verify_sig, SigFormat, Signature,
+        },
+        shake256::Shake256Prng,
+    };
+
+    // a key pair and the three encodings of one signature on `msg`
+    fn signed(msg: &[u8]) -> ([u16; 512], Vec<u8>, Vec<u8>, Vec<u8>) {
+        let mut rng = Shake256Prng::from_seed(b"sig formats");
+        let (sk, pk) = keygen(&mut rng);
+
+        // the padded format only fits signatures of at most 625 bytes (almost all of them)
+        let (nonce, sig, len) = loop {
+            let (nonce, sig, len) = sign(&sk, msg, &mut rng);
+
+            if 41 + len <= SIG_PADDED_SIZE {
+                break (nonce, sig, len);
+            }
+        };
+
+        let comp = [&[0x39][..], &nonce, &sig[..len]].concat();
+        let mut padded = comp.clone();
+
+        padded.resize(SIG_PADDED_SIZE, 0);
+
+        let (s2, _) = comp_decode(&sig[..len]);
+        let mut ct = [&[0x59][..], &nonce].concat();
+
+        ct.resize(SIG_CT_SIZE, 0);
+        assert_eq!(trim_i16_encode(&s2, 12, &mut ct, 41), SIG_CT_SIZE - 41);
+
+        (pk_to_ntt_fmt(&pk).unwrap(), comp, padded, ct)
+    }
+
+    #[test]
+    fn all_formats_verify() {
+        let msg = b"envelope";
+        let (pk_ntt, comp, padded, ct) = signed(msg);
+
+        for (sig, format) in [
+            (&comp, SigFormat::Compressed),
+            (&padded, SigFormat::Padded),
+            (&ct, SigFormat::Ct),
+        ] {
+            let decoded = sig_decode(sig).unwrap();
+
+            assert_eq!(decoded.format, format);
+            assert_eq!(&decoded.nonce[..], &comp[1..41]);
+
+            let typed = Signature::try_from(&sig[..]).unwrap();
+
+            assert_eq!(typed.format(), format);
+            assert_eq!(typed.nonce(), decoded.nonce);
+            assert_eq!(typed.decode().s2, decoded.s2);
+            assert_eq!(verify_sig(msg, sig, &pk_ntt), Ok(()), "{:?}", format);
+            assert_eq!(
+                verify_sig(b"envelopf", sig, &pk_ntt),
+                Err(Error::NormTooLarge),
+                "{:?}",
+                format
+            );
+        }
+
+        assert_eq!(sig_decode(&comp).unwrap().s2, sig_decode(&ct).unwrap().s2);
+    }
+
+    #[test]
+    fn header_checked() {
+        let msg = b"header";
+        let (pk_ntt, comp, padded, ct) = signed(msg);
+
+        for sig in [comp, padded, ct] {
+            // logn nibble (Falcon-1024), then an unknown format
+            for header in [sig[0] + 1, (sig[0] & 0x0f) | 0x20, (sig[0] & 0x0f) | 0x40] {
+                let mut bad = sig.clone();
+
+                bad[0] = header;
+                assert_eq!(sig_decode(&bad), Err(Error::BadHeader));
+                assert_eq!(verify_sig(msg, &bad, &pk_ntt), Err(Error::BadHeader));
+            }
+        }
+    }
+
+    #[test]
+    fn lengths_and_padding_checked() {
+        let msg = b"lengths";
+        let (pk_ntt, comp, padded, ct) = signed(msg);
+
+        // header and nonce only
+        assert_eq!(sig_decode(&comp[..41]), Err(Error::BadLength));
+
+        // compressed body cut short
+        assert_eq!(
+            sig_decode(&comp[..comp.len() - 1]),
+            Err(Error::DecodeFailed)
+        );
+
+        // zero padding, but not to the padded size
+        let mut extra = comp.clone();
+
+        extra.push(0);
+        if extra.len() != SIG_PADDED_SIZE {
+            assert_eq!(sig_decode(&extra), Err(Error::BadLength));
+        }
+
+        // non-zero padding
+        let mut bad = padded.clone();
+
+        *bad.last_mut().unwrap() = 1;
+        assert_eq!(sig_decode(&bad), Err(Error::TrailingBits));
+        assert_eq!(verify_sig(msg, &bad, &pk_ntt), Err(Error::TrailingBits));
+
+        // constant-time: exact size only
+        assert_eq!(sig_decode(&ct[..SIG_CT_SIZE - 1]), Err(Error::BadLength));
+
+        let mut long = ct.clone();
+
+        long.push(0);
+        assert_eq!(sig_decode(&long), Err(Error::BadLength));
+    }
+
+    #[test]
+    fn trim_i16_round_trip() {
+        let x: [u16; 512] = core::array::from_fn(|i| ((i as i16 * 37) % 2047 - 1023) as u16);
+        let mut buf = [0u8; 768];
+        let mut y = [0u16; 512];
+
+        assert_eq!(trim_i16_encode(&x, 12, &mut buf, 0), 768);
+        assert_eq!(trim_i16_decode(&mut y, 12, &buf, 0), 768);
+        assert_eq!(x, y);
+
+        // -2^11 is not encodable, and rejected on decode
+        let mut z = x;
+
+        z[3] = 0u16.wrapping_sub(2048);
+        assert_eq!(trim_i16_encode(&z, 12, &mut buf, 0), 0);
+
+        buf[0] = 0x80;
+        buf[1] &= 0x0f;
+        assert_eq!(trim_i16_decode(&mut y, 12, &buf, 0), 0);
+    }
+
+    #[test]
+    fn comp_encode_inverts_comp_decode() {
+        let mut rng = Shake256Prng::from_seed(b"comp encode");
+        let (sk, _) = keygen(&mut rng);
+
+        for _ in 0..4 {
+            let (_, sig, len) = sign(&sk, b"re-encode", &mut rng);
+            let (s2, read) = comp_decode(&sig[..len]);
+            let mut out = [0u8; SIG_COMP_MAXSIZE as usize];
+
+            assert_eq!(read, len);
+            assert_eq!(comp_encode(&s2, &mut out), len);
+            assert_eq!(out[..len], sig[..len]);
+        }
+    }
+
+    #[test]
+    fn comp_encode_limits() {
+        let mut out = [0u8; SIG_COMP_MAXSIZE as usize];
+        let mut x = [0u16; N];
+
+        // +-2047 (8 + 15 + 1 bits) round-trip, zero never gets the sign bit
+        x[0] = 2047;
+        x[1] = 0u16.wrapping_sub(2047);
+        x[2] = 0u16.wrapping_sub(1);
+
+        let len = comp_encode(&x, &mut out);
+
+        // 2 x 24 + 510 x 9 bits
+        assert_eq!(len, 580);
+        assert_eq!(comp_decode(&out[..len]), (x, len));
+
+        // magnitude limit
+        x[0] = 2048;
+        assert_eq!(comp_encode(&x, &mut out), 0);
+        x[0] = 0u16.wrapping_sub(2048);
+        assert_eq!(comp_encode(&x, &mut out), 0);
+
+        // too large for SIG_COMP_MAXSIZE (or the buffer), whatever the buffer size
+        let big = [1000u16; N];
+        let mut large = [0u8; 4 * SIG_COMP_MAXSIZE as usize];
+
+        assert_eq!(comp_encode(&big, &mut large), 0);
+        assert_eq!(comp_encode(&[0u16; N], &mut out[..575]), 0);
+        assert_eq!(comp_encode(&[0u16; N], &mut out[..576]), 576);
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        falcon512::{comp_decode, keygen, pk_to_ntt_fmt, sign, verify},
+        shake256::Shake256Prng,
+    };
+
+    fn nonce_msg(nonce: &[u8], msg: &[u8]) -> Vec<u8> {
+        [nonce, msg].concat()
+    }
+
+    #[test]
+    fn sign_then_verify() {
+        let mut rng = Shake256Prng::from_seed(b"sign then verify");
+        let (sk, pk) = keygen(&mut rng);
+        let pk_ntt = pk_to_ntt_fmt(&pk).unwrap();
+
+        // short (single block) and long (multi-block) absorption of nonce || msg
+        for msg in [b"hello falcon".to_vec(), vec![0xa5; 96], vec![0x5a; 1000]] {
+            let (nonce, sig, len) = sign(&sk, &msg, &mut rng);
+
+            assert!(verify(&nonce_msg(&nonce, &msg), &sig[..len], &pk_ntt));
+        }
+    }
+
+    #[test]
+    fn sign_rejected_on_other_message_or_key() {
+        let mut rng = Shake256Prng::from_seed(b"sign reject");
+        let (sk, pk) = keygen(&mut rng);
+        let (_, other_pk) = keygen(&mut rng);
+        let (nonce, sig, len) = sign(&sk, b"message", &mut rng);
+
+        assert!(verify(
+            &nonce_msg(&nonce, b"message"),
+            &sig[..len],
+            &pk_to_ntt_fmt(&pk).unwrap()
+        ));
+        assert!(!verify(
+            &nonce_msg(&nonce, b"massage"),
+            &sig[..len],
+            &pk_to_ntt_fmt(&pk).unwrap()
+        ));
+        assert!(!verify(
+            &nonce_msg(&nonce, b"message"),
+            &sig[..len],
+            &pk_to_ntt_fmt(&other_pk).unwrap()
+        ));
+    }
+
+    #[test]
+    fn sign_output_decodes_to_short_s2() {
+        let mut rng = Shake256Prng::from_seed(b"sign decode");
+        let (sk, _) = keygen(&mut rng);
+        let (_, sig, len) = sign(&sk, b"decode me", &mut rng);
+        let (s2, read) = comp_decode(&sig[..len]);
+
+        assert_eq!(read, len);
+        assert!(s2.iter().all(|&w| (w as i16).unsigned_abs() < 2048));
+    }
+
+    #[test]
+    fn sign_is_deterministic_per_seed() {
+        let (sk, _) = keygen(&mut Shake256Prng::from_seed(b"sign seed"));
+        let a = sign(&sk, b"msg", &mut Shake256Prng::from_seed(b"rng"));
+        let b = sign(&sk, b"msg", &mut Shake256Prng::from_seed(b"rng"));
+
+        assert_eq!(a.0, b.0);
+        assert_eq!(a.2, b.2);
+        assert_eq!(a.1[..a.2], b.1[..b.2]);
+    }
+}
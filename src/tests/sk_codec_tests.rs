@@ -0,0 +1,116 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::{FALCON_SK_SIZE, N},
+        error::Error,
+        falcon512::{keygen, sk_decode, sk_encode, trim_i8_decode, trim_i8_encode},
+        shake256::Shake256Prng,
+    };
+
+    fn encoded_key(seed: &[u8]) -> [u8; FALCON_SK_SIZE] {
+        sk_encode(&keygen(&mut Shake256Prng::from_seed(seed)).0)
+    }
+
+    #[test]
+    fn sk_round_trip_recomputes_big_g() {
+        for seed in [&b"sk codec 0"[..], b"sk codec 1", b"sk codec 2"] {
+            let (sk, _) = keygen(&mut Shake256Prng::from_seed(seed));
+            let bytes = sk_encode(&sk);
+            let decoded = sk_decode(&bytes).unwrap();
+
+            assert_eq!(bytes[0], 0x59);
+            assert_eq!(decoded.f, sk.f);
+            assert_eq!(decoded.g, sk.g);
+            assert_eq!(decoded.big_f, sk.big_f);
+            assert_eq!(decoded.big_g, sk.big_g);
+            assert_eq!(sk_encode(&decoded), bytes);
+        }
+    }
+
+    #[test]
+    fn trim_i8_bit_layout() {
+        let mut x = [0i8; N];
+
+        x[0] = 1;
+        x[1] = -1;
+        x[2] = 31;
+        x[3] = -31;
+
+        let mut out = [0u8; 384];
+
+        // 000001 111111 011111 100001
+        assert_eq!(trim_i8_encode(&x, 6, &mut out, 0), 384);
+        assert_eq!(out[..3], [0x07, 0xf7, 0xe1]);
+        assert!(out[3..].iter().all(|&b| b == 0));
+
+        let mut y = [0i8; N];
+
+        assert_eq!(trim_i8_decode(&mut y, 6, &out, 0), 384);
+        assert_eq!(x, y);
+
+        // out of range, or not enough room
+        x[4] = 32;
+        assert_eq!(trim_i8_encode(&x, 6, &mut out, 0), 0);
+        x[4] = -32;
+        assert_eq!(trim_i8_encode(&x, 6, &mut out, 0), 0);
+        x[4] = 0;
+        assert_eq!(trim_i8_encode(&x, 6, &mut out, 1), 0);
+        assert_eq!(trim_i8_decode(&mut y, 6, &out[..383], 0), 0);
+    }
+
+    #[test]
+    fn trim_i8_rejects_forbidden_value() {
+        // 100000 = -32 at 6 bits
+        let mut input = [0u8; 384];
+
+        input[0] = 0x80;
+
+        assert_eq!(trim_i8_decode(&mut [0i8; N], 6, &input, 0), 0);
+
+        // 10000000 = -128 at 8 bits
+        let mut input = [0u8; 512];
+
+        input[100] = 0x80;
+
+        assert_eq!(trim_i8_decode(&mut [0i8; N], 8, &input, 0), 0);
+    }
+
+    #[test]
+    fn sk_decode_rejects_bad_header() {
+        let mut bytes = encoded_key(b"sk header");
+
+        bytes[0] = 0x5a;
+
+        assert_eq!(sk_decode(&bytes).err(), Some(Error::InvalidSecretKey));
+    }
+
+    #[test]
+    fn sk_decode_rejects_forbidden_coefficient() {
+        let mut bytes = encoded_key(b"sk forbidden");
+
+        // first 6-bit coefficient of g set to 100000
+        bytes[1 + 384] = (bytes[1 + 384] & 0x03) | 0x80;
+
+        assert_eq!(sk_decode(&bytes).err(), Some(Error::InvalidSecretKey));
+    }
+
+    #[test]
+    fn sk_decode_rejects_non_invertible_f() {
+        let mut bytes = encoded_key(b"sk non invertible");
+
+        // f = 0
+        bytes[1..385].fill(0);
+
+        assert_eq!(sk_decode(&bytes).err(), Some(Error::InvalidSecretKey));
+    }
+
+    #[test]
+    fn sk_decode_rejects_inconsistent_big_f() {
+        let mut bytes = encoded_key(b"sk inconsistent");
+
+        // G = g * F / f mod q is no longer small
+        bytes[1 + 768] ^= 0x01;
+
+        assert_eq!(sk_decode(&bytes).err(), Some(Error::InvalidSecretKey));
+    }
+}
@@ -0,0 +1,345 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        sha3::Shake,
+        sp800_185::{CShake, CShake128, Kmac, Kmac128, Kmac256, TupleHash, TupleHash128},
+        tests::test_utils::hex_decode,
+    };
+
+    // data, key, customization strings and tuples of the NIST samples
+    const DATA_4: [u8; 4] = [0x00, 0x01, 0x02, 0x03];
+
+    fn data_200() -> Vec<u8> {
+        (0..200).collect()
+    }
+
+    fn key() -> Vec<u8> {
+        (0x40..0x60).collect()
+    }
+
+    const EMAIL: &[u8] = b"Email Signature";
+    const TAGGED: &[u8] = b"My Tagged Application";
+    const TUPLE_APP: &[u8] = b"My Tuple App";
+
+    fn tuple(len: usize) -> Vec<Vec<u8>> {
+        [
+            vec![0x00, 0x01, 0x02],
+            (0x10..0x16).collect(),
+            (0x20..0x29).collect(),
+        ][..len]
+            .to_vec()
+    }
+
+    fn cshake<const RATE: usize>(data: &[u8], customization: &[u8], out_len: usize) -> Vec<u8> {
+        let mut ctx = CShake::<RATE>::new(b"", customization);
+        let mut out = vec![0u8; out_len];
+
+        ctx.update(data);
+        ctx.finalize().read(&mut out);
+
+        out
+    }
+
+    fn kmac<const RATE: usize>(data: &[u8], customization: &[u8], out_len: usize) -> Vec<u8> {
+        let mut ctx = Kmac::<RATE>::new(&key(), customization);
+        let mut out = vec![0u8; out_len];
+
+        ctx.update(data);
+        ctx.finalize(&mut out);
+
+        out
+    }
+
+    fn kmac_xof<const RATE: usize>(data: &[u8], customization: &[u8], out_len: usize) -> Vec<u8> {
+        let mut ctx = Kmac::<RATE>::new(&key(), customization);
+        let mut out = vec![0u8; out_len];
+
+        ctx.update(data);
+        ctx.finalize_xof().read(&mut out);
+
+        out
+    }
+
+    fn tuple_hash<const RATE: usize>(
+        elements: &[Vec<u8>],
+        customization: &[u8],
+        out_len: usize,
+        xof: bool,
+    ) -> Vec<u8> {
+        let mut ctx = TupleHash::<RATE>::new(customization);
+        let mut out = vec![0u8; out_len];
+
+        for element in elements {
+            ctx.update(element);
+        }
+
+        if xof {
+            ctx.finalize_xof().read(&mut out);
+        } else {
+            ctx.finalize(&mut out);
+        }
+
+        out
+    }
+
+    #[test]
+    fn cshake_samples() {
+        assert_eq!(
+            cshake::<168>(&DATA_4, EMAIL, 32),
+            hex_decode("c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5")
+        );
+        assert_eq!(
+            cshake::<168>(&data_200(), EMAIL, 32),
+            hex_decode("c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b")
+        );
+        assert_eq!(
+            cshake::<136>(&DATA_4, EMAIL, 64),
+            hex_decode(
+                "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1\
+                 64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+            )
+        );
+        assert_eq!(
+            cshake::<136>(&data_200(), EMAIL, 64),
+            hex_decode(
+                "07dc27b11e51fbac75bc7b3c1d983e8b4b85fb1defaf218912ac864302730917\
+                 27f42b17ed1df63e8ec118f04b23633c1dfb1574c8fb55cb45da8e25afb092bb"
+            )
+        );
+    }
+
+    #[test]
+    fn cshake_without_names_is_shake() {
+        let data = data_200();
+
+        for len in [0, 4, 200] {
+            let mut shake = Shake::<168>::new();
+            let mut expected = [0u8; 300];
+
+            shake.update(&data[..len]);
+            shake.finalize().read(&mut expected);
+
+            assert_eq!(cshake::<168>(&data[..len], b"", 300), expected);
+        }
+
+        // the function name alone separates domains
+        let mut named = CShake128::new(b"KMAC", b"");
+        let mut out = [0u8; 32];
+
+        named.update(&DATA_4);
+        named.finalize().read(&mut out);
+
+        assert_ne!(out[..], cshake::<168>(&DATA_4, b"", 32));
+        assert_ne!(out[..], cshake::<168>(&DATA_4, b"KMAC", 32));
+    }
+
+    #[test]
+    fn kmac_samples() {
+        assert_eq!(
+            kmac::<168>(&DATA_4, b"", 32),
+            hex_decode("e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e")
+        );
+        assert_eq!(
+            kmac::<168>(&DATA_4, TAGGED, 32),
+            hex_decode("3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5")
+        );
+        assert_eq!(
+            kmac::<168>(&data_200(), TAGGED, 32),
+            hex_decode("1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230")
+        );
+        assert_eq!(
+            kmac::<136>(&DATA_4, TAGGED, 64),
+            hex_decode(
+                "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+                 f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+            )
+        );
+        assert_eq!(
+            kmac::<136>(&data_200(), b"", 64),
+            hex_decode(
+                "75358cf39e41494e949707927cee0af20a3ff553904c86b08f21cc414bcfd691\
+                 589d27cf5e15369cbbff8b9a4c2eb17800855d0235ff635da82533ec6b759b69"
+            )
+        );
+        assert_eq!(
+            kmac::<136>(&data_200(), TAGGED, 64),
+            hex_decode(
+                "b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d9\
+                 70fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965"
+            )
+        );
+    }
+
+    #[test]
+    fn kmac_xof_samples() {
+        assert_eq!(
+            kmac_xof::<168>(&DATA_4, b"", 32),
+            hex_decode("cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35")
+        );
+        assert_eq!(
+            kmac_xof::<168>(&DATA_4, TAGGED, 32),
+            hex_decode("31a44527b4ed9f5c6101d11de6d26f0620aa5c341def41299657fe9df1a3b16c")
+        );
+        assert_eq!(
+            kmac_xof::<168>(&data_200(), TAGGED, 32),
+            hex_decode("47026c7cd793084aa0283c253ef658490c0db61438b8326fe9bddf281b83ae0f")
+        );
+        assert_eq!(
+            kmac_xof::<136>(&DATA_4, TAGGED, 64),
+            hex_decode(
+                "1755133f1534752aad0748f2c706fb5c784512cab835cd15676b16c0c6647fa9\
+                 6faa7af634a0bf8ff6df39374fa00fad9a39e322a7c92065a64eb1fb0801eb2b"
+            )
+        );
+        assert_eq!(
+            kmac_xof::<136>(&data_200(), b"", 64),
+            hex_decode(
+                "ff7b171f1e8a2b24683eed37830ee797538ba8dc563f6da1e667391a75edc02c\
+                 a633079f81ce12a25f45615ec89972031d18337331d24ceb8f8ca8e6a19fd98b"
+            )
+        );
+        assert_eq!(
+            kmac_xof::<136>(&data_200(), TAGGED, 64),
+            hex_decode(
+                "d5be731c954ed7732846bb59dbe3a8e30f83e77a4bff4459f2f1c2b4ecebb8ce\
+                 67ba01c62e8ab8578d2d499bd1bb276768781190020a306a97de281dcc30305d"
+            )
+        );
+    }
+
+    #[test]
+    fn kmac_output_length_is_bound() {
+        let data = data_200();
+
+        // KMAC: a shorter tag is not a prefix of a longer one; KMACXOF: it is
+        assert_ne!(
+            kmac::<168>(&data, TAGGED, 16)[..],
+            kmac::<168>(&data, TAGGED, 32)[..16]
+        );
+        assert_eq!(
+            kmac_xof::<168>(&data, TAGGED, 16)[..],
+            kmac_xof::<168>(&data, TAGGED, 32)[..16]
+        );
+
+        // another key gives another tag
+        let mut other_key = Kmac128::new(&key()[1..], TAGGED);
+        let mut out = [0u8; 32];
+
+        other_key.update(&data);
+        other_key.finalize(&mut out);
+
+        assert_ne!(out[..], kmac::<168>(&data, TAGGED, 32));
+
+        // any chunking gives the same tag
+        for chunk in [1, 7, 135, 136, 137, 168, 169] {
+            let mut ctx = Kmac256::new(&key(), TAGGED);
+            let mut out = [0u8; 64];
+
+            for part in data.chunks(chunk) {
+                ctx.update(part);
+            }
+
+            ctx.finalize(&mut out);
+
+            assert_eq!(
+                out[..],
+                kmac::<136>(&data, TAGGED, 64),
+                "chunk size {}",
+                chunk
+            );
+        }
+    }
+
+    #[test]
+    fn tuple_hash_samples() {
+        assert_eq!(
+            tuple_hash::<168>(&tuple(2), b"", 32, false),
+            hex_decode("c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1")
+        );
+        assert_eq!(
+            tuple_hash::<168>(&tuple(2), TUPLE_APP, 32, false),
+            hex_decode("75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb")
+        );
+        assert_eq!(
+            tuple_hash::<168>(&tuple(3), TUPLE_APP, 32, false),
+            hex_decode("e60f202c89a2631eda8d4c588ca5fd07f39e5151998deccf973adb3804bb6e84")
+        );
+        assert_eq!(
+            tuple_hash::<136>(&tuple(2), b"", 64, false),
+            hex_decode(
+                "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec6073\
+                 11ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194"
+            )
+        );
+        assert_eq!(
+            tuple_hash::<136>(&tuple(2), TUPLE_APP, 64, false),
+            hex_decode(
+                "147c2191d5ed7efd98dbd96d7ab5a11692576f5fe2a5065f3e33de6bba9f3aa1\
+                 c4e9a068a289c61c95aab30aee1e410b0b607de3620e24a4e3bf9852a1d4367e"
+            )
+        );
+        assert_eq!(
+            tuple_hash::<136>(&tuple(3), TUPLE_APP, 64, false),
+            hex_decode(
+                "45000be63f9b6bfd89f54717670f69a9bc763591a4f05c50d68891a744bcc6e7\
+                 d6d5b5e82c018da999ed35b0bb49c9678e526abd8e85c13ed254021db9e790ce"
+            )
+        );
+    }
+
+    #[test]
+    fn tuple_hash_xof_samples() {
+        assert_eq!(
+            tuple_hash::<168>(&tuple(2), b"", 32, true),
+            hex_decode("2f103cd7c32320353495c68de1a8129245c6325f6f2a3d608d92179c96e68488")
+        );
+        assert_eq!(
+            tuple_hash::<168>(&tuple(2), TUPLE_APP, 32, true),
+            hex_decode("3fc8ad69453128292859a18b6c67d7ad85f01b32815e22ce839c49ec374e9b9a")
+        );
+        assert_eq!(
+            tuple_hash::<168>(&tuple(3), TUPLE_APP, 32, true),
+            hex_decode("900fe16cad098d28e74d632ed852f99daab7f7df4d99e775657885b4bf76d6f8")
+        );
+        assert_eq!(
+            tuple_hash::<136>(&tuple(2), b"", 64, true),
+            hex_decode(
+                "03ded4610ed6450a1e3f8bc44951d14fbc384ab0efe57b000df6b6df5aae7cd5\
+                 68e77377daf13f37ec75cf5fc598b6841d51dd207c991cd45d210ba60ac52eb9"
+            )
+        );
+        assert_eq!(
+            tuple_hash::<136>(&tuple(2), TUPLE_APP, 64, true),
+            hex_decode(
+                "6483cb3c9952eb20e830af4785851fc597ee3bf93bb7602c0ef6a65d741aeca7\
+                 e63c3b128981aa05c6d27438c79d2754bb1b7191f125d6620fca12ce658b2442"
+            )
+        );
+        assert_eq!(
+            tuple_hash::<136>(&tuple(3), TUPLE_APP, 64, true),
+            hex_decode(
+                "0c59b11464f2336c34663ed51b2b950bec743610856f36c28d1d088d8a244628\
+                 4dd09830a6a178dc752376199fae935d86cfdee5913d4922dfd369b66a53c897"
+            )
+        );
+    }
+
+    #[test]
+    fn tuple_elements_are_separated() {
+        let split = |elements: &[&[u8]]| {
+            let mut ctx = TupleHash128::new(b"");
+            let mut out = [0u8; 32];
+
+            for element in elements {
+                ctx.update(element);
+            }
+
+            ctx.finalize(&mut out);
+            out
+        };
+
+        assert_ne!(split(&[b"ab", b"c"]), split(&[b"a", b"bc"]));
+        assert_ne!(split(&[b"abc"]), split(&[b"abc", b""]));
+        assert_ne!(split(&[]), split(&[b""]));
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+pub mod tests {
+    use std::io::{self, Cursor, Read};
+
+    use crate::{
+        constants::NONCE_LEN,
+        error::Error,
+        falcon512::{keygen, sign, verify_detailed, PreparedPublicKey, PublicKey, Signature},
+        shake256::Shake256Prng,
+        stream::{verify_reader, StreamVerifier},
+        tests::test_utils::get_valid_test_vector,
+    };
+
+    // (prepared key, nonce, message, compressed s2) of the first KAT
+    fn kat() -> (PreparedPublicKey, [u8; 40], Vec<u8>, Vec<u8>) {
+        let (nonce_msg, pk, s2) = get_valid_test_vector();
+        let (nonce, msg) = nonce_msg.split_at(NONCE_LEN as usize);
+
+        (
+            PreparedPublicKey::try_from(&pk[..]).unwrap(),
+            nonce.try_into().unwrap(),
+            msg.to_vec(),
+            s2,
+        )
+    }
+
+    fn stream(
+        pk: &PreparedPublicKey,
+        s2: &[u8],
+        nonce: &[u8; 40],
+        msg: &[u8],
+        chunk: usize,
+    ) -> Result<(), Error> {
+        let mut verifier = StreamVerifier::new(pk, s2, nonce)?;
+
+        for part in msg.chunks(chunk) {
+            verifier.update(part);
+        }
+
+        verifier.finish()
+    }
+
+    #[test]
+    fn kat_verifies_in_any_chunking() {
+        let (pk, nonce, msg, s2) = kat();
+
+        for chunk in [1, 3, 32, 33, msg.len()] {
+            assert_eq!(
+                stream(&pk, &s2, &nonce, &msg, chunk),
+                Ok(()),
+                "chunk size {}",
+                chunk
+            );
+        }
+
+        let mut bad_msg = msg.clone();
+
+        bad_msg[10] ^= 1;
+
+        assert_eq!(
+            stream(&pk, &s2, &nonce, &bad_msg, 5),
+            Err(Error::NormTooLarge)
+        );
+        assert_eq!(
+            stream(&pk, &s2, &nonce, &msg[1..], 5),
+            Err(Error::NormTooLarge)
+        );
+
+        // same norm as the one-shot report
+        let mut verifier = StreamVerifier::new(&pk, &s2, &nonce).unwrap();
+
+        verifier.update(&msg);
+
+        let mut nonce_msg = nonce.to_vec();
+
+        nonce_msg.extend_from_slice(&msg);
+
+        assert_eq!(
+            verifier.finish_detailed(),
+            verify_detailed(&nonce_msg, &s2, pk.as_ntt_fmt())
+        );
+    }
+
+    #[test]
+    fn malformed_signature_rejected() {
+        let (pk, nonce, _, s2) = kat();
+
+        assert_eq!(
+            StreamVerifier::new(&pk, &[], &nonce).err(),
+            Some(Error::BadLength)
+        );
+        assert_eq!(
+            StreamVerifier::new(&pk, &[0; 800], &nonce).err(),
+            Some(Error::BadLength)
+        );
+        assert_eq!(
+            StreamVerifier::new(&pk, &s2[..100], &nonce).err(),
+            Some(Error::DecodeFailed)
+        );
+
+        let mut padded = s2.clone();
+
+        padded.push(0);
+
+        assert_eq!(
+            StreamVerifier::new(&pk, &padded, &nonce).err(),
+            Some(Error::DecodeFailed)
+        );
+    }
+
+    // a reader yielding at most 1000 bytes per call
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(1000).min(self.0.len());
+
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+
+            Ok(n)
+        }
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+
+    #[test]
+    fn large_message_from_reader() {
+        let mut rng = Shake256Prng::from_seed(b"stream");
+        let (sk, pk) = keygen(&mut rng);
+        let pk = PublicKey::try_from(&pk[..]).unwrap().prepare();
+        let msg: Vec<u8> = (0..1 << 20).map(|i: u32| (i ^ (i >> 11)) as u8).collect();
+        let (nonce, s2, len) = sign(&sk, &msg, &mut rng);
+        let sig = Signature::from_compressed(&nonce, &s2[..len]).unwrap();
+
+        assert_eq!(pk.verify(&msg, &sig), Ok(()));
+        assert!(verify_reader(&pk, &sig, Cursor::new(&msg)).is_ok());
+        assert!(verify_reader(&pk, &sig, Trickle(&msg)).is_ok());
+
+        let mut verifier = StreamVerifier::from_signature(&pk, &sig);
+
+        for part in msg.chunks(4096) {
+            verifier.update(part);
+        }
+
+        assert_eq!(verifier.finish(), Ok(()));
+
+        // a verification failure is `InvalidData`, wrapping the error
+        let err = verify_reader(&pk, &sig, Cursor::new(&msg[1..])).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.get_ref().unwrap().downcast_ref::<Error>(),
+            Some(&Error::NormTooLarge)
+        );
+
+        // read errors are passed through
+        let err = verify_reader(&pk, &sig, Cursor::new(&msg[..10]).chain(Failing)).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+    }
+}
@@ -1,9 +1,12 @@
+use core::fmt::Write;
+
 use crate::{
     constants::{N, NONCE_LEN, Q, SIG_COMP_MAXSIZE},
     falcon512::{
-        comp_decode, distance, hash_to_point_vartime, mq_intt, mq_ntt, mq_poly_montymul_ntt,
-        mq_poly_sub,
+        comp_decode, comp_decode_with, distance, hash_to_point_vartime, hash_to_point_vartime_with,
+        mq_intt, mq_ntt, mq_poly_montymul_ntt, mq_poly_sub, verify_raw_norm,
     },
+    params::{Falcon1024, FalconParams, SqueezeBuffer},
     shake256::{shake_extract_vartime, shake_flip, shake_inject},
 };
 
@@ -83,6 +86,45 @@ pub fn verify_distance(nonce_msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; N]) -> u
     verify_distance_raw(&mut hash_nonce_msg, &decoded_sig, pk_ntt_fmt, &mut s1)
 }
 
+// Falcon-1024 counterpart of `verify_distance`: the squared norm of (s1, s2), 0 on a malformed
+// signature
+pub fn verify_distance_1024(nonce_msg: &[u8], sig: &[u8], pk_ntt_fmt: &[u16; 1024]) -> u32 {
+    verify_distance_with::<Falcon1024, 1024>(nonce_msg, sig, pk_ntt_fmt)
+}
+
+// `verify_distance` for any parameter set
+pub fn verify_distance_with<P: FalconParams<D>, const D: usize>(
+    nonce_msg: &[u8],
+    sig: &[u8],
+    pk_ntt_fmt: &[u16; D],
+) -> u32 {
+    let sig_len = sig.len();
+
+    if sig_len < 1 || sig_len > P::SIG_COMP_MAXSIZE {
+        return 0;
+    }
+
+    let (decoded_sig, sz2) = comp_decode_with::<P, D>(sig);
+
+    if sz2 != sig_len {
+        return 0;
+    }
+
+    let mut shake_ctx = [0u64; 26];
+
+    shake_inject(&mut shake_ctx, nonce_msg);
+    shake_flip(&mut shake_ctx);
+
+    let extracted = P::Squeeze::squeeze(&mut shake_ctx);
+    let mut hash_nonce_msg = [0u16; D];
+
+    hash_to_point_vartime_with::<P, D>(extracted.as_ref(), &mut hash_nonce_msg);
+
+    let mut s1 = [0u16; D];
+
+    verify_raw_norm::<P, D>(&mut hash_nonce_msg, &decoded_sig, pk_ntt_fmt, &mut s1)
+}
+
 pub fn get_valid_test_vector() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
     let msg = vec![
         51, 179, 192, 117, 7, 228, 32, 23, 72, 73, 77, 131, 43, 110, 226, 166, 201, 59, 255, 155,
@@ -176,6 +218,22 @@ pub fn get_valid_test_vector() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
     (msg, pk, sig)
 }
 
+// bytes of a hex string (test vectors)
+pub fn hex_decode(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// lowercase hex string of bytes
+pub fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        write!(s, "{:02x}", b).unwrap();
+        s
+    })
+}
+
 #[cfg(test)]
 pub mod mutation_utils {
     use rand::prelude::*;
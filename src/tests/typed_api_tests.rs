@@ -0,0 +1,95 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::{N, Q},
+        error::Error,
+        falcon512::{
+            keygen, pk_to_ntt_fmt, sign, PreparedPublicKey, PublicKey, SigFormat, Signature,
+        },
+        shake256::Shake256Prng,
+    };
+
+    fn signed(msg: &[u8]) -> ([u8; 897], Signature) {
+        let mut rng = Shake256Prng::from_seed(b"typed api");
+        let (sk, pk) = keygen(&mut rng);
+        let (nonce, sig, len) = sign(&sk, msg, &mut rng);
+
+        (pk, Signature::from_compressed(&nonce, &sig[..len]).unwrap())
+    }
+
+    #[test]
+    fn public_key_verify() {
+        let (pk_bytes, sig) = signed(b"typed");
+        let pk = PublicKey::try_from(&pk_bytes[..]).unwrap();
+        let prepared = pk.prepare();
+
+        assert_eq!(pk.as_bytes(), &pk_bytes);
+        assert_eq!(prepared.as_ntt_fmt(), &pk_to_ntt_fmt(&pk_bytes).unwrap());
+        assert_eq!(PublicKey::from(&prepared), pk);
+        assert_eq!(PreparedPublicKey::try_from(&pk_bytes[..]), Ok(prepared));
+
+        assert_eq!(pk.verify(b"typed", &sig), Ok(()));
+        assert_eq!(prepared.verify(b"typed", &sig), Ok(()));
+        assert_eq!(pk.verify(b"typeD", &sig), Err(Error::NormTooLarge));
+    }
+
+    #[test]
+    fn public_key_checked() {
+        let (pk_bytes, _) = signed(b"checked");
+
+        assert_eq!(PublicKey::try_from(&pk_bytes[..896]), Err(Error::BadLength));
+
+        let mut bad = pk_bytes;
+
+        bad[0] = 0x0a;
+        assert_eq!(PublicKey::try_from(&bad[..]), Err(Error::BadHeader));
+
+        // first coefficient = 0x3fff >= q
+        let mut bad = pk_bytes;
+
+        bad[1] = 0xff;
+        bad[2] |= 0xfc;
+        assert_eq!(
+            PublicKey::try_from(&bad[..]),
+            Err(Error::CoefficientOutOfRange)
+        );
+        assert_eq!(
+            PreparedPublicKey::try_from(&bad[..]),
+            Err(Error::CoefficientOutOfRange)
+        );
+
+        let mut ntt = [0u16; N];
+
+        assert!(PreparedPublicKey::from_ntt_fmt(&ntt).is_ok());
+        ntt[N - 1] = Q;
+        assert_eq!(
+            PreparedPublicKey::from_ntt_fmt(&ntt),
+            Err(Error::CoefficientOutOfRange)
+        );
+    }
+
+    #[test]
+    fn signature_checked() {
+        let (_, sig) = signed(b"sig");
+        let bytes = sig.as_bytes();
+
+        assert_eq!(bytes[0], 0x39);
+        assert_eq!(sig.format(), SigFormat::Compressed);
+        assert_eq!(&sig.nonce()[..], &bytes[1..41]);
+        assert_eq!(Signature::try_from(bytes), Ok(sig.clone()));
+
+        assert_eq!(
+            Signature::try_from(&bytes[..bytes.len() - 1]),
+            Err(Error::DecodeFailed)
+        );
+
+        let mut bad = bytes.to_vec();
+
+        bad[0] = 0x3a;
+        assert_eq!(Signature::try_from(&bad[..]), Err(Error::BadHeader));
+        assert_eq!(
+            Signature::from_compressed(&[0; 40], &[0xff; 712]),
+            Err(Error::BadLength)
+        );
+    }
+}
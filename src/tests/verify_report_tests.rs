@@ -0,0 +1,104 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        constants::{SIG_COMP_MAXSIZE, SIG_L2_BOUND},
+        error::Error,
+        falcon512::{keygen, pk_to_ntt_fmt, sign, verify, verify_detailed, verify_sig_detailed},
+        shake256::Shake256Prng,
+        tests::test_utils::{get_valid_test_vector, verify_distance},
+    };
+
+    #[test]
+    fn valid_signature_reports_norm() {
+        let (nonce_msg, pk, sig) = get_valid_test_vector();
+        let pk_ntt = pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap();
+        let report = verify_detailed(&nonce_msg, &sig, &pk_ntt);
+
+        assert!(report.is_valid());
+        assert_eq!(
+            report.norm,
+            Some(verify_distance(&nonce_msg, &sig, &pk_ntt))
+        );
+        assert_eq!(report.bound, 34034726);
+        assert!(report.norm.unwrap() <= report.bound);
+    }
+
+    #[test]
+    fn rejecting_stage_reported() {
+        let (nonce_msg, pk, sig) = get_valid_test_vector();
+        let pk_ntt = pk_to_ntt_fmt(pk.as_slice().try_into().unwrap()).unwrap();
+
+        // length: empty, too long, nonce without a message
+        for (nonce_msg, sig) in [
+            (&nonce_msg[..], &[][..]),
+            (&nonce_msg[..], &[0u8; SIG_COMP_MAXSIZE as usize + 1][..]),
+            (&nonce_msg[..40], &sig[..]),
+        ] {
+            let report = verify_detailed(nonce_msg, sig, &pk_ntt);
+
+            assert_eq!(report.result, Err(Error::BadLength));
+            assert_eq!(report.norm, None);
+            assert_eq!(report.bound, SIG_L2_BOUND);
+        }
+
+        // compressed encoding: cut short, then trailing bytes
+        let report = verify_detailed(&nonce_msg, &sig[..sig.len() - 1], &pk_ntt);
+
+        assert_eq!(report.result, Err(Error::DecodeFailed));
+        assert_eq!(report.norm, None);
+
+        let long = [&sig[..], &[0]].concat();
+
+        assert_eq!(
+            verify_detailed(&nonce_msg, &long, &pk_ntt).result,
+            Err(Error::DecodeFailed)
+        );
+
+        // norm: another message hashes to an unrelated challenge
+        let mut other = nonce_msg.clone();
+
+        *other.last_mut().unwrap() ^= 1;
+
+        let report = verify_detailed(&other, &sig, &pk_ntt);
+
+        assert_eq!(report.result, Err(Error::NormTooLarge));
+        assert!(report.norm.unwrap() > SIG_L2_BOUND);
+        assert_eq!(report.norm, Some(verify_distance(&other, &sig, &pk_ntt)));
+        assert!(!verify(&other, &sig, &pk_ntt));
+    }
+
+    #[test]
+    fn complete_signature_report() {
+        let mut rng = Shake256Prng::from_seed(b"verify report");
+        let (sk, pk) = keygen(&mut rng);
+        let pk_ntt = pk_to_ntt_fmt(&pk).unwrap();
+        let (nonce, sig, len) = sign(&sk, b"report", &mut rng);
+        let comp = [&[0x39][..], &nonce, &sig[..len]].concat();
+
+        let report = verify_sig_detailed(b"report", &comp, &pk_ntt);
+
+        assert!(report.is_valid());
+        assert_eq!(
+            report.norm,
+            Some(verify_distance(
+                &[&nonce[..], b"report"].concat(),
+                &sig[..len],
+                &pk_ntt
+            ))
+        );
+
+        let report = verify_sig_detailed(b"other", &comp, &pk_ntt);
+
+        assert_eq!(report.result, Err(Error::NormTooLarge));
+        assert!(report.norm.unwrap() > report.bound);
+
+        let mut bad = comp.clone();
+
+        bad[0] = 0x3a;
+
+        let report = verify_sig_detailed(b"report", &bad, &pk_ntt);
+
+        assert_eq!(report.result, Err(Error::BadHeader));
+        assert_eq!(report.norm, None);
+    }
+}
@@ -0,0 +1,157 @@
+//! The wasm bindings: their Rust logic on the host, then (ignored by default: it needs the
+//! `wasm32-unknown-unknown` target, the `wasm-bindgen` CLI and Node) the real module, built for
+//! wasm32, generated with `wasm-bindgen --target nodejs` and driven by `tests/wasm/verify.mjs`.
+#[cfg(all(test, feature = "wasm"))]
+pub mod tests {
+    use std::{path::Path, process::Command};
+
+    use crate::{
+        constants::{FALCON_PK_SIZE, NONCE_LEN},
+        error::Error,
+        falcon512::Signature,
+        tests::test_utils::{get_valid_test_vector, hex_encode},
+        wasm::{prepare, prepare_key, verify, verify_prepared, PreparedKey},
+    };
+
+    const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+    const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+    // (public key, message, complete signature) of the first KAT
+    fn vector() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let (nonce_msg, pk, comp_s2) = get_valid_test_vector();
+        let (nonce, msg) = nonce_msg.split_at(NONCE_LEN as usize);
+        let sig = Signature::from_compressed(nonce.try_into().unwrap(), &comp_s2).unwrap();
+
+        (pk, msg.to_vec(), sig.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn bindings_verify() {
+        let (pk, msg, sig) = vector();
+        let key = prepare_key(&pk).unwrap();
+
+        assert!(key.verify(&msg, &sig));
+        assert!(PreparedKey::new(&pk).unwrap().verify(&msg, &sig));
+        assert!(verify(&pk, &msg, &sig).unwrap());
+
+        let mut bad_msg = msg.clone();
+        let mut bad_sig = sig.clone();
+
+        bad_msg[0] ^= 1;
+        bad_sig[100] ^= 1;
+
+        assert!(!key.verify(&bad_msg, &sig));
+        assert!(!key.verify(&msg, &bad_sig));
+        assert!(!key.verify(&msg, &sig[..1 + NONCE_LEN as usize]));
+        assert!(!key.verify(&msg, &[]));
+        assert!(!verify(&pk, &bad_msg, &sig).unwrap());
+    }
+
+    #[test]
+    fn malformed_key_rejected() {
+        let (pk, msg, sig) = vector();
+
+        // what the bindings throw (a `JsError` cannot be built off wasm32)
+        assert_eq!(prepare(&pk[1..]).unwrap_err(), Error::BadLength);
+        assert_eq!(
+            prepare(&[0u8; FALCON_PK_SIZE]).unwrap_err(),
+            Error::BadHeader
+        );
+        assert!(verify_prepared(&prepare(&pk).unwrap(), &msg, &sig));
+    }
+
+    fn installed(program: &str) -> bool {
+        Command::new(program)
+            .arg("--version")
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    fn wasm_target_installed() -> bool {
+        Command::new("rustc")
+            .args(["--print", "target-libdir", "--target", WASM_TARGET])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .is_some_and(|output| {
+                Path::new(String::from_utf8_lossy(&output.stdout).trim()).exists()
+            })
+    }
+
+    #[test]
+    #[ignore = "needs the wasm32-unknown-unknown target (`rustup target add \
+                wasm32-unknown-unknown`), the wasm-bindgen CLI of the dependency's version \
+                (`cargo install wasm-bindgen-cli`) and Node; run with \
+                `cargo test --features wasm node_runner -- --ignored`"]
+    fn node_runner() {
+        assert!(
+            wasm_target_installed(),
+            "{} target not installed",
+            WASM_TARGET
+        );
+
+        for program in ["wasm-bindgen", "node"] {
+            assert!(installed(program), "{} not found", program);
+        }
+
+        let target = format!("{}/target/wasm", ROOT);
+        let output = Command::new(env!("CARGO"))
+            .args(["rustc", "--offline", "--quiet", "--release", "--lib"])
+            .args(["--crate-type", "cdylib", "--features", "wasm"])
+            .args(["--target", WASM_TARGET])
+            .args(["--manifest-path", &format!("{}/Cargo.toml", ROOT)])
+            .args(["--target-dir", &target])
+            .output()
+            .expect("cargo not found");
+
+        assert!(
+            output.status.success(),
+            "wasm build failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let pkg = format!("{}/pkg", target);
+        let bindgen = Command::new("wasm-bindgen")
+            .args(["--target", "nodejs", "--out-dir", &pkg])
+            .arg(format!(
+                "{}/{}/release/falcon512_rs.wasm",
+                target, WASM_TARGET
+            ))
+            .output()
+            .unwrap();
+
+        assert!(
+            bindgen.status.success(),
+            "wasm-bindgen failed:\n{}",
+            String::from_utf8_lossy(&bindgen.stderr)
+        );
+
+        let (pk, msg, sig) = vector();
+        let vector_path = format!("{}/vector.json", target);
+
+        std::fs::write(
+            &vector_path,
+            format!(
+                "{{\"pk\": \"{}\", \"msg\": \"{}\", \"sig\": \"{}\"}}\n",
+                hex_encode(&pk),
+                hex_encode(&msg),
+                hex_encode(&sig)
+            ),
+        )
+        .unwrap();
+
+        let run = Command::new("node")
+            .arg(format!("{}/tests/wasm/verify.mjs", ROOT))
+            .arg(format!("{}/falcon512_rs.js", pkg))
+            .arg(&vector_path)
+            .output()
+            .unwrap();
+
+        assert!(
+            run.status.success(),
+            "verify.mjs failed:\n{}{}",
+            String::from_utf8_lossy(&run.stdout),
+            String::from_utf8_lossy(&run.stderr)
+        );
+    }
+}
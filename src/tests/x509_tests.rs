@@ -0,0 +1,308 @@
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        error::Error,
+        falcon512::{keygen, sign, PublicKey, SecretKey, Signature},
+        pkix::{pk_to_spki_der, FALCON512_OID},
+        shake256::Shake256Prng,
+        x509::{verify_chain, Certificate, ChainError},
+    };
+
+    fn tlv(tag: u8, parts: &[&[u8]]) -> Vec<u8> {
+        let content = parts.concat();
+        let mut out = vec![tag];
+
+        match content.len() {
+            len @ 0..=0x7f => out.push(len as u8),
+            len @ 0x80..=0xff => out.extend_from_slice(&[0x81, len as u8]),
+            len => out.extend_from_slice(&[0x82, (len >> 8) as u8, len as u8]),
+        }
+
+        out.extend_from_slice(&content);
+        out
+    }
+
+    // Name with a single commonName
+    fn name(cn: &str) -> Vec<u8> {
+        let attr = tlv(
+            0x30,
+            &[
+                &tlv(0x06, &[&[0x55, 0x04, 0x03]]),
+                &tlv(0x0c, &[cn.as_bytes()]),
+            ],
+        );
+
+        tlv(0x30, &[&tlv(0x31, &[&attr])])
+    }
+
+    fn algorithm(oid: &[u8]) -> Vec<u8> {
+        tlv(0x30, &[&tlv(0x06, &[oid])])
+    }
+
+    struct Ca {
+        name: &'static str,
+        sk: SecretKey,
+        pk: PublicKey,
+    }
+
+    fn ca(name: &'static str) -> Ca {
+        let mut rng = Shake256Prng::from_seed(name.as_bytes());
+        let (sk, pk) = keygen(&mut rng);
+
+        Ca {
+            name,
+            sk,
+            pk: PublicKey::try_from(&pk[..]).unwrap(),
+        }
+    }
+
+    fn tbs(serial: u8, issuer: &str, subject: &str, pk: &PublicKey, alg: &[u8]) -> Vec<u8> {
+        let validity = tlv(
+            0x30,
+            &[
+                &tlv(0x17, &[b"260101000000Z"]),
+                &tlv(0x17, &[b"360101000000Z"]),
+            ],
+        );
+        // basicConstraints: cA = TRUE
+        let extensions = tlv(
+            0xa3,
+            &[&tlv(
+                0x30,
+                &[&tlv(
+                    0x30,
+                    &[
+                        &tlv(0x06, &[&[0x55, 0x1d, 0x13]]),
+                        &tlv(0x01, &[&[0xff]]),
+                        &tlv(0x04, &[&tlv(0x30, &[&tlv(0x01, &[&[0xff]])])]),
+                    ],
+                )],
+            )],
+        );
+
+        tlv(
+            0x30,
+            &[
+                &tlv(0xa0, &[&tlv(0x02, &[&[2]])]),
+                &tlv(0x02, &[&[serial]]),
+                &algorithm(alg),
+                &name(issuer),
+                &validity,
+                &name(subject),
+                &pk_to_spki_der(pk),
+                &extensions,
+            ],
+        )
+    }
+
+    fn certificate(tbs: &[u8], alg: &[u8], sig: &[u8]) -> Vec<u8> {
+        tlv(0x30, &[tbs, &algorithm(alg), &tlv(0x03, &[&[0], sig])])
+    }
+
+    fn issue(issuer: &Ca, subject: &str, pk: &PublicKey, serial: u8) -> Vec<u8> {
+        let tbs = tbs(serial, issuer.name, subject, pk, FALCON512_OID);
+        let mut rng = Shake256Prng::from_seed(&tbs);
+        let (nonce, sig, len) = sign(&issuer.sk, &tbs, &mut rng);
+        let sig = Signature::from_compressed(&nonce, &sig[..len]).unwrap();
+
+        certificate(&tbs, FALCON512_OID, sig.as_bytes())
+    }
+
+    struct Pki {
+        root: Vec<u8>,
+        intermediate: Vec<u8>,
+        leaf: Vec<u8>,
+    }
+
+    fn pki() -> Pki {
+        let root = ca("Root CA");
+        let intermediate = ca("Intermediate CA");
+        let leaf = ca("leaf.example");
+
+        Pki {
+            root: issue(&root, root.name, &root.pk, 1),
+            intermediate: issue(&root, intermediate.name, &intermediate.pk, 2),
+            leaf: issue(&intermediate, leaf.name, &leaf.pk, 3),
+        }
+    }
+
+    #[test]
+    fn certificate_fields() {
+        let pki = pki();
+        let leaf = Certificate::from_der(&pki.leaf).unwrap();
+        let intermediate = Certificate::from_der(&pki.intermediate).unwrap();
+
+        assert_eq!(leaf.as_der(), &pki.leaf[..]);
+        assert_eq!(leaf.signature_algorithm(), FALCON512_OID);
+        assert_eq!(leaf.issuer(), &name("Intermediate CA")[..]);
+        assert_eq!(leaf.subject(), &name("leaf.example")[..]);
+        assert_eq!(leaf.public_key(), Ok(ca("leaf.example").pk));
+        assert_eq!(
+            leaf.subject_public_key_info(),
+            &pk_to_spki_der(&ca("leaf.example").pk)[..]
+        );
+
+        // the TBSCertificate is the first element of the certificate, the signature its last
+        // bytes
+        assert_eq!(
+            &pki.leaf[4..4 + leaf.tbs_certificate().len()],
+            leaf.tbs_certificate()
+        );
+        assert!(pki.leaf.ends_with(leaf.signature()));
+        assert_eq!(leaf.signature()[0], 0x39);
+
+        assert_eq!(
+            leaf.verify_signature(&intermediate.public_key().unwrap()),
+            Ok(())
+        );
+        assert_eq!(leaf.verify_issued_by(&intermediate), Ok(()));
+        assert_eq!(
+            leaf.verify_signature(&ca("Root CA").pk),
+            Err(Error::NormTooLarge)
+        );
+    }
+
+    #[test]
+    fn chain_verification() {
+        let pki = pki();
+        let root = Certificate::from_der(&pki.root).unwrap();
+        let intermediate = Certificate::from_der(&pki.intermediate).unwrap();
+        let leaf = Certificate::from_der(&pki.leaf).unwrap();
+
+        assert_eq!(verify_chain(&[leaf, intermediate], &root), Ok(()));
+        assert_eq!(verify_chain(&[leaf, intermediate, root], &root), Ok(()));
+        assert_eq!(verify_chain(&[intermediate], &root), Ok(()));
+        assert_eq!(verify_chain(&[root], &root), Ok(()));
+
+        // any certificate can be the trust anchor
+        assert_eq!(verify_chain(&[leaf], &intermediate), Ok(()));
+
+        // missing intermediate, wrong order, untrusted root
+        assert_eq!(
+            verify_chain(&[leaf], &root),
+            Err(ChainError {
+                index: 0,
+                error: Error::IssuerMismatch
+            })
+        );
+        assert_eq!(
+            verify_chain(&[intermediate, leaf], &root),
+            Err(ChainError {
+                index: 0,
+                error: Error::IssuerMismatch
+            })
+        );
+        assert_eq!(
+            verify_chain(&[leaf, intermediate], &leaf),
+            Err(ChainError {
+                index: 1,
+                error: Error::IssuerMismatch
+            })
+        );
+        assert_eq!(
+            verify_chain(&[], &root),
+            Err(ChainError {
+                index: 0,
+                error: Error::BadLength
+            })
+        );
+
+        // a root with the same name and another key
+        let impostor = Ca {
+            name: "Root CA",
+            ..ca("impostor")
+        };
+        let fake_root = issue(&impostor, impostor.name, &impostor.pk, 1);
+        let fake_root = Certificate::from_der(&fake_root).unwrap();
+
+        assert_eq!(
+            verify_chain(&[leaf, intermediate], &fake_root),
+            Err(ChainError {
+                index: 1,
+                error: Error::NormTooLarge
+            })
+        );
+    }
+
+    #[test]
+    fn tampered_certificate() {
+        let pki = pki();
+        let intermediate = Certificate::from_der(&pki.intermediate).unwrap();
+        let root = Certificate::from_der(&pki.root).unwrap();
+
+        // change the subject name: still well-formed, no longer signed
+        let mut der = pki.leaf.clone();
+        let pos = der.windows(12).position(|w| w == b"leaf.example").unwrap();
+
+        der[pos] = b'L';
+
+        let leaf = Certificate::from_der(&der).unwrap();
+
+        assert_eq!(
+            verify_chain(&[leaf, intermediate], &root),
+            Err(ChainError {
+                index: 0,
+                error: Error::NormTooLarge
+            })
+        );
+    }
+
+    #[test]
+    fn foreign_algorithm() {
+        // sha256WithRSAEncryption
+        let rsa = [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+        let issuer = ca("Root CA");
+        let tbs = tbs(1, "Root CA", "rsa", &issuer.pk, &rsa);
+        let der = certificate(&tbs, &rsa, &[0x55; 256]);
+        let cert = Certificate::from_der(&der).unwrap();
+
+        assert_eq!(cert.signature_algorithm(), &rsa);
+        assert_eq!(
+            cert.verify_signature(&issuer.pk),
+            Err(Error::UnknownAlgorithm)
+        );
+
+        // TBSCertificate and certificate algorithms differ
+        let der = certificate(&tbs, FALCON512_OID, &[0x55; 256]);
+
+        assert_eq!(Certificate::from_der(&der), Err(Error::MalformedDer));
+    }
+
+    #[test]
+    fn malformed_certificate() {
+        let pki = pki();
+        let len = pki.leaf.len();
+
+        // truncated, trailing data, bad outer tag
+        assert_eq!(
+            Certificate::from_der(&pki.leaf[..len - 1]),
+            Err(Error::MalformedDer)
+        );
+
+        let mut long = pki.leaf.clone();
+
+        long.push(0);
+        assert_eq!(Certificate::from_der(&long), Err(Error::MalformedDer));
+
+        let mut bad = pki.leaf.clone();
+
+        bad[0] = 0x31;
+        assert_eq!(Certificate::from_der(&bad), Err(Error::MalformedDer));
+
+        // signature with unused bits
+        let leaf = Certificate::from_der(&pki.leaf).unwrap();
+        let mut bad = pki.leaf.clone();
+
+        bad[len - leaf.signature().len() - 1] = 1;
+        assert_eq!(Certificate::from_der(&bad), Err(Error::MalformedDer));
+
+        // the signature bits are checked as a Falcon signature
+        let der = certificate(leaf.tbs_certificate(), FALCON512_OID, &[0x39; 10]);
+        let cert = Certificate::from_der(&der).unwrap();
+
+        assert_eq!(
+            cert.verify_signature(&ca("Intermediate CA").pk),
+            Err(Error::BadLength)
+        );
+    }
+}
@@ -77,17 +77,6 @@ pub fn sign_extend_u16_to_u32(x: u16) -> u32 {
     (x as i16) as i32 as u32
 }
 
-/// Panics with a revert message.
-///
-/// # Parameters
-/// - `reason`: The reason for reverting (displayed in the panic message).
-///
-/// # Panics
-/// Always panics with the message `"Reverted: {reason}"`.
-pub fn revert(reason: &str) {
-    panic!("Reverted: {}", reason);
-}
-
 /// SWAR (SIMD-within-a-register) utilities for **exactly 7× u16** packed into a `u128`
 /// using 17-bit lanes (16 data bits + 1 reserved MSB per lane, 119 bits used).
 ///
@@ -0,0 +1,79 @@
+//! JavaScript bindings (`wasm` feature), for browsers and Node through `wasm-bindgen`: built with
+//! `cargo rustc --release --lib --crate-type cdylib --target wasm32-unknown-unknown --features
+//! wasm`, then `wasm-bindgen`.
+//!
+//! Keys, messages and signatures are `Uint8Array`s: the 897-byte public key and the complete
+//! signature (`header ‖ nonce ‖ s2`, any [`SigFormat`](crate::falcon512::SigFormat)), as for
+//! [`PublicKey`] and [`Signature`]. A malformed public key throws an `Error` with the
+//! [`Error`] description; a malformed or invalid signature makes `verify` return `false`.
+//!
+//! ```js
+//! import { prepareKey, verify } from "falcon512_rs";
+//!
+//! const key = prepareKey(pk); // reuse for many signatures
+//! key.verify(msg, sig);       // true / false
+//! verify(pk, msg, sig);       // one-shot
+//! ```
+//!
+//! The bindings only use the verification path, which does not need `std`: the feature does not
+//! enable it, so `--target wasm32-unknown-unknown --features wasm` builds no key generation, no
+//! `f64` signing and no test or benchmark code into the module (only the exception messages are
+//! allocated, on the module heap).
+
+extern crate alloc;
+
+use alloc::string::ToString;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    error::Error,
+    falcon512::{PreparedPublicKey, PublicKey, Signature},
+};
+
+/// A public key in NTT format, for repeated verifications (`prepareKey`).
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct PreparedKey(PreparedPublicKey);
+
+#[wasm_bindgen]
+impl PreparedKey {
+    /// Parses and prepares a serialized public key; throws if it is malformed.
+    #[wasm_bindgen(constructor)]
+    pub fn new(pk: &[u8]) -> Result<PreparedKey, JsError> {
+        prepare(pk).map(PreparedKey).map_err(js_error)
+    }
+
+    /// Verifies `sig` on `msg`.
+    pub fn verify(&self, msg: &[u8], sig: &[u8]) -> bool {
+        verify_prepared(&self.0, msg, sig)
+    }
+}
+
+/// Parses and prepares a serialized public key; throws if it is malformed.
+#[wasm_bindgen(js_name = prepareKey)]
+pub fn prepare_key(pk: &[u8]) -> Result<PreparedKey, JsError> {
+    PreparedKey::new(pk)
+}
+
+/// Verifies `sig` on `msg` with a serialized public key; throws if the key is malformed.
+#[wasm_bindgen]
+pub fn verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<bool, JsError> {
+    let pk = prepare(pk).map_err(js_error)?;
+
+    Ok(verify_prepared(&pk, msg, sig))
+}
+
+// `JsError` can only be built on wasm32 (it panics elsewhere): the conversions stay out of the
+// Rust logic below, which the host test suite calls directly.
+fn js_error(err: Error) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+pub(crate) fn prepare(pk: &[u8]) -> Result<PreparedPublicKey, Error> {
+    PublicKey::try_from(pk).map(|pk| pk.prepare())
+}
+
+pub(crate) fn verify_prepared(pk: &PreparedPublicKey, msg: &[u8], sig: &[u8]) -> bool {
+    Signature::try_from(sig).is_ok_and(|sig| pk.verify(msg, &sig).is_ok())
+}
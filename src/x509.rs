@@ -0,0 +1,230 @@
+//! Verification of Falcon-512 signed X.509 certificates (RFC 5280).
+//!
+//! [`Certificate::from_der`] parses a DER certificate far enough to locate the signed
+//! `TBSCertificate`, the signature algorithm and signature bits, the issuer and subject names and
+//! the subject public key, without copying. Signatures use the Falcon-512 OID of [`crate::pkix`]
+//! as signature algorithm and hold a complete Falcon signature (`header ‖ nonce ‖ s2`, as
+//! produced by oqs-provider), verified over the DER `TBSCertificate` with [`PublicKey::verify`].
+//!
+//! [`verify_chain`] checks signatures and issuer/subject names along a chain up to a trusted root.
+//! It does not implement the rest of the RFC 5280 path validation: validity periods, basic
+//! constraints, key usage, name constraints and revocation are left to the caller.
+
+use core::fmt;
+
+use crate::{
+    der::{Reader, TAG_BIT_STRING, TAG_INTEGER, TAG_SEQUENCE},
+    error::Error,
+    falcon512::{PublicKey, Signature},
+    pkix::{is_falcon512_oid, pk_from_spki_der},
+};
+
+// TBSCertificate fields with context-specific tags
+const TAG_VERSION: u8 = 0xa0;
+const TAG_ISSUER_UID: u8 = 0x81;
+const TAG_SUBJECT_UID: u8 = 0x82;
+const TAG_EXTENSIONS: u8 = 0xa3;
+
+/// A parsed X.509 certificate, borrowing from its DER encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Certificate<'a> {
+    der: &'a [u8],
+    tbs: &'a [u8],
+    issuer: &'a [u8],
+    subject: &'a [u8],
+    spki: &'a [u8],
+    signature_algorithm: &'a [u8],
+    signature: &'a [u8],
+}
+
+impl<'a> Certificate<'a> {
+    /// Parses a DER certificate.
+    ///
+    /// # Parameters
+    /// - `der`: The DER encoding (nothing may follow it).
+    ///
+    /// # Returns
+    /// The certificate, or [`Error::MalformedDer`] if `der` is not a DER X.509 certificate
+    /// (including a `TBSCertificate` signature algorithm other than the outer one). The
+    /// algorithms and the public key are not interpreted here.
+    pub fn from_der(der: &'a [u8]) -> Result<Self, Error> {
+        let mut outer = Reader::new(der);
+        let mut cert = Reader::new(outer.read(TAG_SEQUENCE)?);
+
+        outer.finish()?;
+
+        let tbs = cert.read_raw(TAG_SEQUENCE)?;
+        let signature_algorithm = cert.read_algorithm()?;
+        let signature = match cert.read(TAG_BIT_STRING)?.split_first() {
+            Some((0, signature)) => signature,
+            _ => return Err(Error::MalformedDer),
+        };
+
+        cert.finish()?;
+
+        let mut fields = Reader::new(Reader::new(tbs).read(TAG_SEQUENCE)?);
+
+        if let Some(version) = fields.read_optional(TAG_VERSION)? {
+            let mut version = Reader::new(version);
+
+            version.read(TAG_INTEGER)?;
+            version.finish()?;
+        }
+
+        fields.read(TAG_INTEGER)?;
+
+        if fields.read_algorithm()? != signature_algorithm {
+            return Err(Error::MalformedDer);
+        }
+
+        let issuer = fields.read_raw(TAG_SEQUENCE)?;
+
+        fields.read(TAG_SEQUENCE)?;
+
+        let subject = fields.read_raw(TAG_SEQUENCE)?;
+        let spki = fields.read_raw(TAG_SEQUENCE)?;
+
+        for tag in [TAG_ISSUER_UID, TAG_SUBJECT_UID, TAG_EXTENSIONS] {
+            fields.read_optional(tag)?;
+        }
+
+        fields.finish()?;
+
+        Ok(Certificate {
+            der,
+            tbs,
+            issuer,
+            subject,
+            spki,
+            signature_algorithm,
+            signature,
+        })
+    }
+
+    /// The DER encoding of the certificate.
+    pub fn as_der(&self) -> &'a [u8] {
+        self.der
+    }
+
+    /// The DER `TBSCertificate`: the signed bytes.
+    pub fn tbs_certificate(&self) -> &'a [u8] {
+        self.tbs
+    }
+
+    /// The signature algorithm OID (content octets).
+    pub fn signature_algorithm(&self) -> &'a [u8] {
+        self.signature_algorithm
+    }
+
+    /// The signature bits.
+    pub fn signature(&self) -> &'a [u8] {
+        self.signature
+    }
+
+    /// The DER issuer `Name`.
+    pub fn issuer(&self) -> &'a [u8] {
+        self.issuer
+    }
+
+    /// The DER subject `Name`.
+    pub fn subject(&self) -> &'a [u8] {
+        self.subject
+    }
+
+    /// The DER `SubjectPublicKeyInfo`.
+    pub fn subject_public_key_info(&self) -> &'a [u8] {
+        self.spki
+    }
+
+    /// The subject public key, if it is a Falcon-512 key (see [`pk_from_spki_der`]).
+    pub fn public_key(&self) -> Result<PublicKey, Error> {
+        pk_from_spki_der(self.spki)
+    }
+
+    /// Verifies the certificate signature with the issuer's public key.
+    ///
+    /// # Parameters
+    /// - `issuer_pk`: The issuer's public key.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid, or:
+    ///   - [`Error::UnknownAlgorithm`] if the signature algorithm is not Falcon-512.
+    ///   - The [`Signature::try_from`] or [`PublicKey::verify`] error otherwise.
+    pub fn verify_signature(&self, issuer_pk: &PublicKey) -> Result<(), Error> {
+        if !is_falcon512_oid(self.signature_algorithm) {
+            return Err(Error::UnknownAlgorithm);
+        }
+
+        issuer_pk.verify(self.tbs, &Signature::try_from(self.signature)?)
+    }
+
+    /// Checks that `issuer` issued the certificate: the issuer name matches the subject name of
+    /// `issuer` (byte-wise DER comparison), and the signature verifies with its public key.
+    ///
+    /// # Returns
+    /// `Ok(())`, [`Error::IssuerMismatch`] if the names differ, the [`Certificate::public_key`]
+    /// error of `issuer`, or the [`Certificate::verify_signature`] error.
+    pub fn verify_issued_by(&self, issuer: &Certificate) -> Result<(), Error> {
+        if self.issuer != issuer.subject {
+            return Err(Error::IssuerMismatch);
+        }
+
+        self.verify_signature(&issuer.public_key()?)
+    }
+}
+
+/// Why [`verify_chain`] rejected a chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChainError {
+    /// Position in the chain of the certificate that failed (0 = leaf).
+    pub index: usize,
+    /// Why it failed.
+    pub error: Error,
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "certificate {}: {}", self.index, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChainError {}
+
+/// Verifies a certificate chain up to a trusted root.
+///
+/// Every certificate must be issued ([`Certificate::verify_issued_by`]) by the next one, and the
+/// last one by `root`. The chain may end with `root` itself, which is then not checked: the root
+/// is trusted as configured, its self-signature is not verified (a chain of only `root` is
+/// valid).
+///
+/// # Parameters
+/// - `chain`: The certificates, leaf first.
+/// - `root`: The trusted root certificate.
+///
+/// # Returns
+/// `Ok(())` if the chain is valid, or the first failure with the position of the certificate:
+/// [`Error::BadLength`] at index 0 for an empty chain, otherwise the
+/// [`Certificate::verify_issued_by`] error.
+pub fn verify_chain(chain: &[Certificate], root: &Certificate) -> Result<(), ChainError> {
+    if chain.is_empty() {
+        return Err(ChainError {
+            index: 0,
+            error: Error::BadLength,
+        });
+    }
+
+    let chain = match chain.split_last() {
+        Some((last, rest)) if last.der == root.der => rest,
+        _ => chain,
+    };
+
+    for (index, cert) in chain.iter().enumerate() {
+        let issuer = chain.get(index + 1).unwrap_or(root);
+
+        cert.verify_issued_by(issuer)
+            .map_err(|error| ChainError { index, error })?;
+    }
+
+    Ok(())
+}
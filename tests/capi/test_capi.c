@@ -0,0 +1,313 @@
+/*
+ * Test program of the C API (include/falcon512_rs.h), compiled and run by
+ * src/tests/capi_tests.rs against the shared library built with the `capi` and `std` features.
+ *
+ * The known-answer vector is the first NIST KAT of src/tests/falcon512_tests_0.rs: nonce || message,
+ * the compressed s2 and the public key.
+ */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "falcon512_rs.h"
+
+#define NONCE_LEN 40
+
+static int failures = 0;
+
+#define CHECK(cond)                                                  \
+    do {                                                             \
+        if (!(cond)) {                                               \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+            failures++;                                              \
+        }                                                            \
+    } while (0)
+
+#define CHECK_CODE(call, code)                                                       \
+    do {                                                                             \
+        int32_t rc_ = (call);                                                        \
+        if (rc_ != (code)) {                                                         \
+            fprintf(stderr, "%s:%d: %s returned %d (%s), expected %d\n", __FILE__,   \
+                    __LINE__, #call, rc_, FALCON512_RS_error_message(rc_), (code));  \
+            failures++;                                                              \
+        }                                                                            \
+    } while (0)
+
+static const uint8_t KAT_NONCE_MSG[73] = {
+    0x33, 0xb3, 0xc0, 0x75, 0x07, 0xe4, 0x20, 0x17, 0x48, 0x49, 0x4d, 0x83,
+    0x2b, 0x6e, 0xe2, 0xa6, 0xc9, 0x3b, 0xff, 0x9b, 0x0e, 0xe3, 0x43, 0xb5,
+    0x50, 0xd1, 0xf8, 0x5a, 0x3d, 0x0d, 0xe0, 0xd7, 0x04, 0xc6, 0xd1, 0x78,
+    0x42, 0x95, 0x13, 0x09, 0xd8, 0x1c, 0x4d, 0x8d, 0x73, 0x4f, 0xcb, 0xfb,
+    0xea, 0xde, 0x3d, 0x3f, 0x8a, 0x03, 0x9f, 0xaa, 0x2a, 0x2c, 0x99, 0x57,
+    0xe8, 0x35, 0xad, 0x55, 0xb2, 0x2e, 0x75, 0xbf, 0x57, 0xbb, 0x55, 0x6a,
+    0xc8,
+};
+
+static const uint8_t KAT_COMP_S2[615] = {
+    0x07, 0x65, 0x84, 0x3d, 0x1e, 0x46, 0x0d, 0x17, 0xa5, 0x27, 0xd2, 0xbc,
+    0xa4, 0x05, 0xbd, 0x55, 0xbb, 0xc7, 0xda, 0x09, 0xa8, 0xc6, 0x20, 0xbe,
+    0x0a, 0xf4, 0xa7, 0x67, 0xd9, 0xdb, 0x96, 0xb8, 0x0f, 0x55, 0xe4, 0x66,
+    0x67, 0x67, 0x51, 0xea, 0xab, 0xa7, 0xb9, 0x3b, 0x86, 0xd7, 0x11, 0x32,
+    0xda, 0xa0, 0xeb, 0x37, 0x67, 0x82, 0xb9, 0xee, 0xe3, 0x75, 0x19, 0xce,
+    0x10, 0xfd, 0xd3, 0x3f, 0xe9, 0xf2, 0x93, 0x12, 0xc3, 0x1d, 0x87, 0x36,
+    0x20, 0x6d, 0x16, 0x5c, 0xf4, 0xc5, 0x28, 0xaa, 0x3d, 0xdc, 0x01, 0x78,
+    0x45, 0xe1, 0xf0, 0xdd, 0x5b, 0x0a, 0x44, 0xff, 0x96, 0x1c, 0x42, 0xd8,
+    0x74, 0xa9, 0x55, 0x33, 0xe5, 0xb4, 0x38, 0x98, 0x2f, 0x52, 0x4c, 0xa9,
+    0x54, 0xd8, 0x75, 0x33, 0xbf, 0xbe, 0x42, 0xc6, 0x3f, 0xf2, 0xab, 0xc7,
+    0x7a, 0x34, 0xc7, 0x9d, 0xb5, 0x5a, 0x99, 0x17, 0x1b, 0xbc, 0xb7, 0x2c,
+    0x84, 0x2a, 0x65, 0x30, 0xaf, 0x2f, 0x75, 0x3f, 0x0c, 0x34, 0xac, 0x63,
+    0x2f, 0x9f, 0x1e, 0x79, 0x49, 0xf0, 0xbf, 0x6c, 0x67, 0x66, 0x5b, 0x27,
+    0x72, 0x2a, 0x88, 0x57, 0xd6, 0x26, 0xb6, 0xff, 0x1a, 0x13, 0x6d, 0x92,
+    0x3a, 0x39, 0xf4, 0x06, 0x9b, 0x74, 0x77, 0xff, 0x94, 0x6e, 0x52, 0x47,
+    0xa6, 0x62, 0x77, 0x91, 0xd4, 0x9b, 0x59, 0xed, 0xc9, 0xe2, 0x52, 0x5a,
+    0x86, 0x0e, 0x6e, 0x98, 0x28, 0xd1, 0x8f, 0x64, 0xa9, 0xf1, 0x72, 0x22,
+    0xe8, 0x16, 0x6a, 0x02, 0x45, 0x38, 0x59, 0xbb, 0xda, 0x0b, 0x81, 0x86,
+    0xd8, 0xc9, 0x92, 0x8b, 0xb5, 0x71, 0xe4, 0x14, 0x64, 0x01, 0xd7, 0x43,
+    0x0e, 0x22, 0x59, 0x04, 0x67, 0x3a, 0xd2, 0x1c, 0xca, 0xc5, 0x4c, 0x14,
+    0x6c, 0x24, 0x8a, 0x1d, 0xd6, 0x9a, 0xb6, 0x49, 0x1e, 0x90, 0x1d, 0x6d,
+    0x71, 0xb1, 0x52, 0x15, 0x5b, 0xe9, 0x7d, 0xe0, 0x57, 0xf3, 0x91, 0x6a,
+    0x3f, 0x1b, 0x42, 0x73, 0x30, 0x8c, 0x29, 0xb2, 0xf4, 0xd9, 0x69, 0x71,
+    0x67, 0xb9, 0x06, 0x81, 0xb1, 0x58, 0x3e, 0xd9, 0x30, 0xa7, 0x1e, 0x99,
+    0x04, 0x67, 0xde, 0xa3, 0x68, 0x13, 0x4b, 0xec, 0xee, 0xbd, 0x59, 0x7f,
+    0x9b, 0xec, 0x92, 0x2e, 0x81, 0x6f, 0x1b, 0x05, 0x70, 0xd7, 0x28, 0xf4,
+    0xae, 0x04, 0x64, 0xc1, 0xf7, 0x97, 0x65, 0x7f, 0x87, 0xa4, 0xe5, 0x2d,
+    0xcd, 0xca, 0xeb, 0x92, 0x72, 0x66, 0x2e, 0xa6, 0x6d, 0x7c, 0x6c, 0xd8,
+    0x78, 0x1b, 0x31, 0xaf, 0x55, 0x5a, 0xd9, 0x3f, 0x5f, 0x65, 0xe7, 0x58,
+    0x16, 0xcb, 0x8d, 0xc3, 0x06, 0xbb, 0x67, 0xe5, 0x92, 0xb5, 0x26, 0x1b,
+    0xac, 0xa7, 0xc5, 0x09, 0x62, 0x9e, 0xa2, 0xaf, 0x8a, 0xbb, 0x80, 0xcb,
+    0xa8, 0x9e, 0xe5, 0x35, 0xb7, 0x6d, 0xfd, 0x9c, 0xcb, 0xbe, 0x3b, 0xf4,
+    0x8f, 0x2b, 0xc8, 0xaa, 0x34, 0xb2, 0x6e, 0x11, 0x03, 0x29, 0x10, 0x53,
+    0xf5, 0xcb, 0x8d, 0xe3, 0xa4, 0x5a, 0xfa, 0x5a, 0x76, 0xdf, 0x8b, 0x21,
+    0x22, 0xed, 0x2c, 0x82, 0xfb, 0xcf, 0x22, 0x59, 0x29, 0x0d, 0x41, 0xa1,
+    0x4f, 0x86, 0xb1, 0x2f, 0x35, 0xf5, 0xd4, 0x97, 0x62, 0xb3, 0x4c, 0xff,
+    0x13, 0xee, 0x7e, 0x42, 0xed, 0xec, 0x70, 0x20, 0x1d, 0x7f, 0x37, 0xc3,
+    0x33, 0x16, 0x28, 0x8f, 0xa3, 0x07, 0x8e, 0x36, 0xe5, 0x81, 0x08, 0x86,
+    0x5c, 0x3c, 0xfe, 0x26, 0x3d, 0x56, 0x36, 0x92, 0x04, 0x3d, 0xec, 0xc6,
+    0x2f, 0x34, 0x26, 0xf8, 0x60, 0x61, 0x28, 0x5b, 0x7b, 0x1b, 0x33, 0x6f,
+    0x56, 0xff, 0x41, 0xbb, 0x65, 0xe9, 0xcd, 0x6d, 0x9b, 0x92, 0xfd, 0x90,
+    0xf8, 0x64, 0xaa, 0x1c, 0x92, 0x3c, 0xb8, 0xc7, 0x55, 0xf5, 0xcd, 0xe1,
+    0x77, 0x0d, 0x86, 0x25, 0x95, 0x42, 0x71, 0x49, 0xd7, 0x72, 0x1a, 0xaa,
+    0xb5, 0xd1, 0x94, 0xae, 0xa9, 0xac, 0xde, 0xca, 0x15, 0xbe, 0x43, 0xcb,
+    0xa6, 0xa6, 0x2b, 0x5a, 0x33, 0x90, 0x9e, 0x9f, 0xc4, 0xda, 0x1c, 0x58,
+    0x14, 0xfb, 0xd7, 0xcd, 0x6a, 0x2f, 0xa5, 0x72, 0xe3, 0x18, 0xb4, 0x2c,
+    0x6c, 0x31, 0x91, 0x40, 0xb8, 0x6e, 0x66, 0x39, 0x25, 0x80, 0xa1, 0x1a,
+    0x2b, 0x43, 0x1f, 0x44, 0xc1, 0xf9, 0x27, 0x0e, 0x4f, 0x7b, 0x24, 0x90,
+    0xf3, 0xb3, 0x25, 0xa9, 0x97, 0x7a, 0x71, 0xa5, 0x75, 0x91, 0x56, 0x36,
+    0x63, 0x5b, 0x99, 0x69, 0xdb, 0xd6, 0xd2, 0x20, 0xb2, 0x4c, 0x3d, 0x99,
+    0xce, 0xbb, 0xbd, 0x83, 0x4b, 0x88, 0x22, 0x2b, 0xd0, 0x8c, 0x3a, 0xbe,
+    0x12, 0x4e, 0x80,
+};
+
+static const uint8_t KAT_PK[897] = {
+    0x09, 0x6b, 0xa8, 0x6c, 0xb6, 0x58, 0xa8, 0xf4, 0x45, 0xc9, 0xa5, 0xe4,
+    0xc2, 0x83, 0x74, 0xbe, 0xc8, 0x79, 0xc8, 0x65, 0x5f, 0x68, 0x52, 0x69,
+    0x23, 0x24, 0x09, 0x18, 0x07, 0x4d, 0x01, 0x47, 0xc0, 0x31, 0x62, 0xe4,
+    0xa4, 0x92, 0x00, 0x64, 0x8c, 0x65, 0x28, 0x03, 0xc6, 0xfd, 0x75, 0x09,
+    0xae, 0x9a, 0xa7, 0x99, 0xd6, 0x31, 0x0d, 0x0b, 0xd4, 0x27, 0x24, 0xe0,
+    0x63, 0x59, 0x20, 0x18, 0x62, 0x07, 0x00, 0x07, 0x67, 0xca, 0x5a, 0x85,
+    0x46, 0xb1, 0x75, 0x53, 0x08, 0xc3, 0x04, 0xb8, 0x4f, 0xc9, 0x3b, 0x06,
+    0x9e, 0x26, 0x59, 0x85, 0xb3, 0x98, 0xd6, 0xb8, 0x34, 0x69, 0x82, 0x87,
+    0xff, 0x82, 0x9a, 0xa8, 0x20, 0xf1, 0x7a, 0x7f, 0x42, 0x26, 0xab, 0x21,
+    0xf6, 0x01, 0xeb, 0xd7, 0x17, 0x52, 0x26, 0xba, 0xb2, 0x56, 0xd8, 0x88,
+    0x8f, 0x00, 0x90, 0x32, 0x56, 0x6d, 0x63, 0x83, 0xd6, 0x84, 0x57, 0xea,
+    0x15, 0x5a, 0x94, 0x30, 0x18, 0x70, 0xd5, 0x89, 0xc6, 0x78, 0xed, 0x30,
+    0x42, 0x59, 0xe9, 0xd3, 0x7b, 0x19, 0x3b, 0xc2, 0xa7, 0xcc, 0xbc, 0xbe,
+    0xc5, 0x1d, 0x69, 0x15, 0x8c, 0x44, 0x07, 0x3a, 0xec, 0x97, 0x92, 0x63,
+    0x02, 0x53, 0x31, 0x8b, 0xc9, 0x54, 0xdb, 0xf5, 0x0d, 0x15, 0x02, 0x82,
+    0x90, 0xdc, 0x2d, 0x30, 0x9c, 0x7b, 0x7b, 0x02, 0xa6, 0x82, 0x37, 0x44,
+    0xd4, 0x63, 0xda, 0x17, 0x74, 0x95, 0x95, 0xcb, 0x77, 0xe6, 0xd1, 0x6d,
+    0x20, 0xd1, 0xb4, 0xc3, 0xaa, 0xd8, 0x9d, 0x32, 0x0e, 0xbe, 0x5a, 0x67,
+    0x2b, 0xb9, 0x6d, 0x6c, 0xd5, 0xc1, 0xef, 0xec, 0x8b, 0x81, 0x12, 0x00,
+    0xcb, 0xb0, 0x62, 0xe4, 0x73, 0x35, 0x25, 0x40, 0xed, 0xde, 0xf8, 0xaf,
+    0x94, 0x99, 0xf8, 0xcd, 0xd1, 0xdc, 0x7c, 0x68, 0x73, 0xf0, 0xc7, 0xa6,
+    0xbc, 0xb7, 0x09, 0x75, 0x60, 0x27, 0x1f, 0x94, 0x68, 0x49, 0xb7, 0xf3,
+    0x73, 0x64, 0x0b, 0xb6, 0x9c, 0xa9, 0xb5, 0x18, 0xaa, 0x38, 0x0a, 0x6e,
+    0xb0, 0xa7, 0x27, 0x5e, 0xe8, 0x4e, 0x9c, 0x22, 0x1a, 0xed, 0x88, 0xf5,
+    0xbf, 0xba, 0xf4, 0x3a, 0x3e, 0xde, 0x8e, 0x6a, 0xa4, 0x25, 0x58, 0x10,
+    0x4f, 0xaf, 0x80, 0x0e, 0x01, 0x84, 0x41, 0x93, 0x03, 0x76, 0xc6, 0xf6,
+    0xe7, 0x51, 0x56, 0x99, 0x71, 0xf4, 0x7a, 0xdb, 0xca, 0x5c, 0xa0, 0x0c,
+    0x80, 0x19, 0x88, 0xf3, 0x17, 0xa1, 0x87, 0x22, 0xa2, 0x92, 0x98, 0x92,
+    0x5e, 0xa1, 0x54, 0xdb, 0xc9, 0x02, 0x4e, 0x12, 0x05, 0x24, 0xa2, 0xd4,
+    0x1d, 0xc0, 0xf1, 0x8f, 0xd8, 0xd9, 0x09, 0xf6, 0xc5, 0x09, 0x77, 0x40,
+    0x4e, 0x20, 0x17, 0x67, 0x07, 0x8b, 0xa9, 0xa1, 0xf9, 0xe4, 0x0a, 0x8b,
+    0x2b, 0xa9, 0xc0, 0x1b, 0x7d, 0xa3, 0xa0, 0xb7, 0x3a, 0x4c, 0x2a, 0x6b,
+    0x4f, 0x51, 0x8b, 0xbe, 0xe3, 0x45, 0x5d, 0x0a, 0xf2, 0x20, 0x4d, 0xdc,
+    0x03, 0x1c, 0x80, 0x5c, 0x72, 0xcc, 0xb6, 0x47, 0x94, 0x0b, 0x1e, 0x67,
+    0x94, 0xd8, 0x59, 0xaa, 0xeb, 0xce, 0xa0, 0xde, 0xb5, 0x81, 0xd6, 0x1b,
+    0x92, 0x48, 0xbd, 0x96, 0x97, 0xb5, 0xcb, 0x97, 0x4a, 0x81, 0x76, 0xe8,
+    0xf9, 0x10, 0x46, 0x9c, 0xae, 0x0a, 0xb4, 0xed, 0x92, 0xd2, 0xae, 0xe9,
+    0xf7, 0xeb, 0x50, 0x29, 0x6d, 0xaf, 0x80, 0x57, 0x47, 0x63, 0x05, 0xc1,
+    0x18, 0x9d, 0x1d, 0x98, 0x40, 0xa0, 0x94, 0x4f, 0x04, 0x47, 0xfb, 0x81,
+    0xe5, 0x11, 0x42, 0x0e, 0x67, 0x89, 0x1b, 0x98, 0xfa, 0x6c, 0x25, 0x70,
+    0x34, 0xd5, 0xa0, 0x63, 0x43, 0x7d, 0x37, 0x91, 0x77, 0xce, 0x8d, 0x3f,
+    0xa6, 0xea, 0xf1, 0x2e, 0x2d, 0xbb, 0x7e, 0xb8, 0xe4, 0x98, 0x48, 0x16,
+    0x12, 0xb1, 0x92, 0x96, 0x17, 0xda, 0x5f, 0xb4, 0x5e, 0x4c, 0xdf, 0x89,
+    0x39, 0x27, 0xd8, 0xba, 0x84, 0x2a, 0xa8, 0x61, 0xd9, 0xc5, 0x04, 0x71,
+    0xc6, 0xd0, 0xc6, 0xdf, 0x7e, 0x2b, 0xb2, 0x64, 0x65, 0xa0, 0xeb, 0x6a,
+    0x3a, 0x70, 0x9d, 0xe7, 0x92, 0xaa, 0xfa, 0xaf, 0x92, 0x2a, 0xa9, 0x5d,
+    0xd5, 0x92, 0x0b, 0x72, 0xb4, 0xb8, 0x85, 0x6c, 0x6e, 0x63, 0x28, 0x60,
+    0xb1, 0x0f, 0x5c, 0xc0, 0x84, 0x50, 0x00, 0x36, 0x71, 0xaf, 0x38, 0x89,
+    0x61, 0x87, 0x2b, 0x46, 0x64, 0x00, 0xad, 0xb8, 0x15, 0xba, 0x81, 0xea,
+    0x79, 0x49, 0x45, 0xd1, 0x9a, 0x10, 0x06, 0x22, 0xa6, 0xca, 0x0d, 0x41,
+    0xc4, 0xea, 0x62, 0x0c, 0x21, 0xdc, 0x12, 0x51, 0x19, 0xe3, 0x72, 0x41,
+    0x8f, 0x04, 0x40, 0x2d, 0x9f, 0xa7, 0x18, 0x0f, 0x7b, 0xc8, 0x9a, 0xfa,
+    0x54, 0xf8, 0x08, 0x22, 0x44, 0xa4, 0x2f, 0x46, 0xe5, 0xb5, 0xab, 0xce,
+    0x87, 0xb5, 0x0a, 0x7d, 0x6f, 0xeb, 0xe8, 0xd7, 0xbb, 0xba, 0xc9, 0x26,
+    0x57, 0xcb, 0xda, 0x1d, 0xb7, 0xc2, 0x55, 0x72, 0xa4, 0xc1, 0xd0, 0xba,
+    0xea, 0x30, 0x44, 0x7a, 0x86, 0x5a, 0x2b, 0x10, 0x36, 0xb8, 0x80, 0x03,
+    0x7e, 0x2f, 0x4d, 0x26, 0xd4, 0x53, 0xe9, 0xe9, 0x13, 0x25, 0x97, 0x79,
+    0xe9, 0x16, 0x9b, 0x28, 0xa6, 0x2e, 0xb8, 0x09, 0xa5, 0xc7, 0x44, 0xe0,
+    0x4e, 0x26, 0x0e, 0x1f, 0x2b, 0xbd, 0xa8, 0x74, 0xf1, 0xac, 0x67, 0x48,
+    0x39, 0xdd, 0xb4, 0x7b, 0x31, 0x48, 0xc5, 0x94, 0x6d, 0xe0, 0x18, 0x01,
+    0x48, 0xb7, 0x97, 0x3d, 0x63, 0xc5, 0x81, 0x93, 0xb1, 0x7c, 0xd0, 0x5d,
+    0x16, 0xe8, 0x0c, 0xd7, 0x92, 0x8c, 0x2a, 0x33, 0x83, 0x63, 0xa2, 0x3a,
+    0x81, 0xc0, 0x60, 0x8c, 0x87, 0x50, 0x55, 0x89, 0xb9, 0xda, 0x1c, 0x61,
+    0x7e, 0x7b, 0x70, 0x78, 0x6b, 0x67, 0x54, 0xfb, 0xb3, 0x0a, 0x58, 0x16,
+    0x81, 0x0b, 0x9e, 0x12, 0x6c, 0xfc, 0xc5, 0xaa, 0x49, 0x32, 0x6e, 0x9d,
+    0x84, 0x29, 0x73, 0x87, 0x4b, 0x63, 0x59, 0xb5, 0xdb, 0x75, 0x61, 0x0b,
+    0xa6, 0x8a, 0x98, 0xc7, 0xb5, 0xe8, 0x3f, 0x12, 0x5a, 0x82, 0x52, 0x2e,
+    0x13, 0xb8, 0x3f, 0xb8, 0xf8, 0x64, 0xe2, 0xa9, 0x7b, 0x73, 0xb5, 0xd5,
+    0x44, 0xa7, 0x41, 0x5b, 0x65, 0x04, 0xa1, 0x39, 0x39, 0xea, 0xb1, 0x59,
+    0x5d, 0x64, 0xfa, 0xf4, 0x1f, 0xab, 0x25, 0xa8, 0x64, 0xa5, 0x74, 0xde,
+    0x52, 0x44, 0x05, 0xe8, 0x78, 0x33, 0x98, 0x77, 0x88, 0x6d, 0x2f, 0xc0,
+    0x7f, 0xa0, 0x31, 0x15, 0x08, 0x25, 0x24, 0x13, 0xed, 0xfa, 0x11, 0x58,
+    0x46, 0x66, 0x67, 0xaf, 0xf7, 0x83, 0x86, 0xda, 0xf7, 0xcb, 0x4c, 0x9b,
+    0x85, 0x09, 0x92, 0xf9, 0x6e, 0x20, 0x52, 0x53, 0x30, 0x59, 0x9a, 0xb6,
+    0x01, 0xd4, 0x54, 0x68, 0x8e, 0x29, 0x4c, 0x8c, 0x3e,
+};
+
+static void test_error_messages(void) {
+    CHECK(strcmp(FALCON512_RS_error_message(FALCON512_RS_OK), "success") == 0);
+    CHECK(strcmp(FALCON512_RS_error_message(FALCON512_RS_ERR_NORM_TOO_LARGE),
+                 "signature vector too long") == 0);
+    CHECK(strcmp(FALCON512_RS_error_message(FALCON512_RS_ERR_NULL_POINTER), "null pointer") == 0);
+    CHECK(strcmp(FALCON512_RS_error_message(42), "unknown error") == 0);
+}
+
+static void test_known_answer(void) {
+    const size_t msg_len = sizeof KAT_NONCE_MSG - NONCE_LEN;
+    const uint8_t *msg = KAT_NONCE_MSG + NONCE_LEN;
+    uint8_t sig[FALCON512_RS_CRYPTO_BYTES];
+    uint8_t sm[sizeof KAT_NONCE_MSG + FALCON512_RS_CRYPTO_BYTES + 2];
+    uint8_t m[sizeof sm];
+    uint8_t pk[FALCON512_RS_CRYPTO_PUBLICKEYBYTES];
+    uint16_t pk_ntt[FALCON512_RS_PREPARED_PUBLICKEY_COEFFS];
+    size_t sig_len = 1 + NONCE_LEN + sizeof KAT_COMP_S2;
+    size_t sm_len, m_len = 0;
+
+    /* detached signature: header || nonce || compressed s2 */
+    sig[0] = 0x39;
+    memcpy(sig + 1, KAT_NONCE_MSG, NONCE_LEN);
+    memcpy(sig + 1 + NONCE_LEN, KAT_COMP_S2, sizeof KAT_COMP_S2);
+
+    CHECK_CODE(FALCON512_RS_crypto_sign_verify(sig, sig_len, msg, msg_len, KAT_PK),
+               FALCON512_RS_OK);
+    CHECK_CODE(FALCON512_RS_prepare_public_key(pk_ntt, KAT_PK), FALCON512_RS_OK);
+    CHECK_CODE(FALCON512_RS_crypto_sign_verify_prepared(sig, sig_len, msg, msg_len, pk_ntt),
+               FALCON512_RS_OK);
+
+    /* signed message: length of 0x29 || s2, nonce, message, 0x29 || s2 */
+    sm[0] = (uint8_t)((1 + sizeof KAT_COMP_S2) >> 8);
+    sm[1] = (uint8_t)(1 + sizeof KAT_COMP_S2);
+    memcpy(sm + 2, KAT_NONCE_MSG, sizeof KAT_NONCE_MSG);
+    sm[2 + sizeof KAT_NONCE_MSG] = 0x29;
+    memcpy(sm + 3 + sizeof KAT_NONCE_MSG, KAT_COMP_S2, sizeof KAT_COMP_S2);
+    sm_len = 3 + sizeof KAT_NONCE_MSG + sizeof KAT_COMP_S2;
+
+    CHECK_CODE(FALCON512_RS_crypto_sign_open(m, &m_len, sm, sm_len, KAT_PK), FALCON512_RS_OK);
+    CHECK(m_len == msg_len && memcmp(m, msg, msg_len) == 0);
+
+    /* tampered message, public key and signature */
+    sm[2 + NONCE_LEN] ^= 1;
+    CHECK_CODE(FALCON512_RS_crypto_sign_open(m, &m_len, sm, sm_len, KAT_PK),
+               FALCON512_RS_ERR_NORM_TOO_LARGE);
+    sm[2 + NONCE_LEN] ^= 1;
+    sm[0] = 0xff;
+    CHECK_CODE(FALCON512_RS_crypto_sign_open(m, &m_len, sm, sm_len, KAT_PK),
+               FALCON512_RS_ERR_BAD_LENGTH);
+
+    memcpy(pk, KAT_PK, sizeof pk);
+    pk[0] = 0x0a;
+    CHECK_CODE(FALCON512_RS_crypto_sign_verify(sig, sig_len, msg, msg_len, pk),
+               FALCON512_RS_ERR_BAD_HEADER);
+    CHECK_CODE(FALCON512_RS_prepare_public_key(pk_ntt, pk), FALCON512_RS_ERR_BAD_HEADER);
+
+    CHECK_CODE(FALCON512_RS_crypto_sign_verify(sig, sig_len - 1, msg, msg_len, KAT_PK),
+               FALCON512_RS_ERR_DECODE_FAILED);
+    CHECK_CODE(FALCON512_RS_crypto_sign_verify(sig, sig_len, msg, msg_len, NULL),
+               FALCON512_RS_ERR_NULL_POINTER);
+
+    pk_ntt[0] = 12289;
+    CHECK_CODE(FALCON512_RS_crypto_sign_verify_prepared(sig, sig_len, msg, msg_len, pk_ntt),
+               FALCON512_RS_ERR_COEFFICIENT_OUT_OF_RANGE);
+}
+
+static void test_sign_round_trip(void) {
+    /* one seed for the key pair and a fresh one for every signature */
+    static const uint8_t keygen_seed[] = "capi test keygen seed";
+    static const uint8_t sig_seed[] = "capi test signature seed";
+    static const uint8_t sm_seed[] = "capi test signed message seed";
+    static const uint8_t empty_seed[] = "capi test empty message seed";
+    static const char text[] = "message signed through the C API";
+    uint8_t pk[FALCON512_RS_CRYPTO_PUBLICKEYBYTES];
+    uint8_t sk[FALCON512_RS_CRYPTO_SECRETKEYBYTES];
+    uint8_t sig[FALCON512_RS_CRYPTO_BYTES];
+    uint8_t sm[sizeof text + FALCON512_RS_CRYPTO_BYTES + 2];
+    uint8_t m[sizeof sm];
+    size_t sig_len = 0, sm_len = 0, m_len = 0;
+
+    CHECK_CODE(FALCON512_RS_crypto_sign_keypair_seeded(pk, sk, keygen_seed, sizeof keygen_seed),
+               FALCON512_RS_OK);
+    CHECK(pk[0] == 0x09 && sk[0] == 0x59);
+
+    CHECK_CODE(FALCON512_RS_crypto_sign_signature_seeded(sig, &sig_len, (const uint8_t *)text,
+                                                         sizeof text, sk, sig_seed,
+                                                         sizeof sig_seed),
+               FALCON512_RS_OK);
+    CHECK(sig_len <= FALCON512_RS_CRYPTO_BYTES && sig[0] == 0x39);
+    CHECK_CODE(FALCON512_RS_crypto_sign_verify(sig, sig_len, (const uint8_t *)text, sizeof text,
+                                               pk),
+               FALCON512_RS_OK);
+    CHECK_CODE(FALCON512_RS_crypto_sign_verify(sig, sig_len, (const uint8_t *)text,
+                                               sizeof text - 1, pk),
+               FALCON512_RS_ERR_NORM_TOO_LARGE);
+
+    /* signed message, signed and opened in place */
+    memcpy(sm, text, sizeof text);
+    CHECK_CODE(FALCON512_RS_crypto_sign_seeded(sm, &sm_len, sm, sizeof text, sk, sm_seed,
+                                               sizeof sm_seed),
+               FALCON512_RS_OK);
+    CHECK(sm_len >= 2 + NONCE_LEN + sizeof text + 1 && sm_len <= sizeof sm);
+    CHECK_CODE(FALCON512_RS_crypto_sign_open(sm, &m_len, sm, sm_len, pk), FALCON512_RS_OK);
+    CHECK(m_len == sizeof text && memcmp(sm, text, sizeof text) == 0);
+
+    /* signed message with an empty message */
+    CHECK_CODE(FALCON512_RS_crypto_sign_seeded(sm, &sm_len, NULL, 0, sk, empty_seed,
+                                               sizeof empty_seed),
+               FALCON512_RS_OK);
+    CHECK(sm_len >= 2 + NONCE_LEN + 1 && sm_len <= 2 + FALCON512_RS_CRYPTO_BYTES);
+    m_len = 1;
+    CHECK_CODE(FALCON512_RS_crypto_sign_open(m, &m_len, sm, sm_len, pk), FALCON512_RS_OK);
+    CHECK(m_len == 0);
+
+    /* an invalid private key is reported, not a crash */
+    sk[0] = 0x5a;
+    CHECK_CODE(FALCON512_RS_crypto_sign_signature_seeded(sig, &sig_len, (const uint8_t *)text,
+                                                         sizeof text, sk, sig_seed,
+                                                         sizeof sig_seed),
+               FALCON512_RS_ERR_INVALID_SECRET_KEY);
+}
+
+int main(void) {
+    test_error_messages();
+    test_known_answer();
+    test_sign_round_trip();
+
+    if (failures != 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+
+    printf("C API tests passed\n");
+    return 0;
+}
@@ -0,0 +1,57 @@
+#![no_std]
+
+use core::panic::PanicInfo;
+
+use falcon512_rs::{
+    error::Error,
+    falcon1024,
+    falcon512::{
+        pk_to_ntt_fmt, sig_decode, sign_with, verify, verify_detailed, PublicKey, SecretKey,
+        Signature,
+    },
+    fpr::FprEmu,
+    pkix::{pk_from_spki_pem, sk_from_pkcs8_der},
+    shake256::Shake256Prng,
+    x509::{verify_chain, Certificate, ChainError},
+};
+
+pub fn verify_raw(pk: &[u8; 897], nonce_msg: &[u8], sig: &[u8]) -> Result<bool, Error> {
+    Ok(verify(nonce_msg, sig, &pk_to_ntt_fmt(pk)?))
+}
+
+pub fn verify_complete(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+    sig_decode(sig)?;
+
+    PublicKey::try_from(pk)?.verify(msg, &Signature::try_from(sig)?)
+}
+
+pub fn verify_norm(pk: &[u8; 897], nonce_msg: &[u8], sig: &[u8]) -> Option<u32> {
+    verify_detailed(nonce_msg, sig, &pk_to_ntt_fmt(pk).ok()?).norm
+}
+
+pub fn verify_1024(pk: &[u8; 1793], msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+    falcon1024::verify_sig(msg, sig, &falcon1024::pk_to_ntt_fmt(pk)?)
+}
+
+pub fn sign_emulated(sk: &SecretKey, msg: &[u8], seed: &[u8]) -> usize {
+    sign_with::<FprEmu>(sk, msg, &mut Shake256Prng::from_seed(seed)).2
+}
+
+pub fn verify_pem(pem: &str, msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+    pk_from_spki_pem(pem)?.verify(msg, &Signature::try_from(sig)?)
+}
+
+pub fn sign_pkcs8(der: &[u8], msg: &[u8], seed: &[u8]) -> Result<usize, Error> {
+    Ok(sign_emulated(&sk_from_pkcs8_der(der)?.0, msg, seed))
+}
+
+pub fn verify_cert(leaf: &[u8], root: &[u8]) -> Result<(), ChainError> {
+    let cert = |der| Certificate::from_der(der).map_err(|error| ChainError { index: 0, error });
+
+    verify_chain(&[cert(leaf)?], &cert(root)?)
+}
+
+#[panic_handler]
+fn panic(_: &PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,157 @@
+"""Tests of the Python extension module (src/python.rs), run by src/tests/python_tests.rs.
+
+The known-answer vectors are the 100 NIST KATs embedded in src/tests/falcon512_tests_*.rs
+(nonce || message, compressed s2, public key). Runs under pytest, or standalone when pytest is
+not installed: `PYTHONPATH=<dir of falcon512_rs.so> python3 tests/python/test_falcon512.py`.
+"""
+
+import pathlib
+import re
+
+import falcon512_rs
+
+NONCE_LEN = 40
+SIG_HEADER = 0x39
+ROOT = pathlib.Path(__file__).resolve().parents[2]
+ARRAY = r"let {} = \[([\d,\s]*)\];"
+
+
+def load_kats():
+    kats = []
+
+    for path in sorted(ROOT.glob("src/tests/falcon512_tests_*.rs")):
+        src = path.read_text()
+        arrays = {
+            name: [bytes(int(b) for b in body.split(",") if b.strip())
+                   for body in re.findall(ARRAY.format(name), src)]
+            for name in ("nonce_msg", "sig", "pk")
+        }
+
+        kats += zip(arrays["nonce_msg"], arrays["sig"], arrays["pk"])
+
+    return kats
+
+
+KATS = load_kats()
+
+
+def split(kat):
+    """(public key, message, complete signature) of a KAT."""
+    nonce_msg, s2, pk = kat
+
+    return pk, nonce_msg[NONCE_LEN:], bytes([SIG_HEADER]) + nonce_msg[:NONCE_LEN] + s2
+
+
+def reference_decode(s2):
+    """Straightforward decoder of the compressed format: sign bit, 7 low bits, unary high bits."""
+    bits = "".join(f"{b:08b}" for b in s2)
+    pos = 0
+    coeffs = []
+
+    for _ in range(512):
+        sign, low = bits[pos], int(bits[pos + 1:pos + 8], 2)
+        high = bits.index("1", pos + 8) - (pos + 8)
+        pos += 8 + high + 1
+        value = (high << 7) | low
+
+        coeffs.append(-value if sign == "1" else value)
+
+    assert "1" not in bits[pos:]
+
+    return coeffs
+
+
+def test_kats_embedded():
+    assert len(KATS) == 100
+
+
+def test_verify_kats():
+    for kat in KATS:
+        pk, msg, sig = split(kat)
+        key = falcon512_rs.prepare_public_key(pk)
+
+        falcon512_rs.verify(pk, msg, sig)
+        key.verify(msg, sig)
+        assert bytes(key) == pk
+
+
+def test_rejected_signature():
+    pk, msg, sig = split(KATS[0])
+    key = falcon512_rs.PreparedPublicKey(pk)
+
+    try:
+        key.verify(msg + b"!", sig)
+    except falcon512_rs.VerificationError as err:
+        assert isinstance(err, falcon512_rs.FalconError)
+        assert isinstance(err, ValueError)
+        assert err.kind == "NormTooLarge"
+        assert err.code == -6
+        assert err.bound == 34034726
+        assert err.norm > err.bound
+    else:
+        raise AssertionError("tampered message accepted")
+
+
+def test_malformed_signature():
+    pk, msg, sig = split(KATS[0])
+
+    for bad, kind in [(sig[:NONCE_LEN + 1], "BadLength"), (b"\x49" + sig[1:], "BadHeader")]:
+        try:
+            falcon512_rs.verify(pk, msg, bad)
+        except falcon512_rs.VerificationError as err:
+            assert err.kind == kind
+            assert err.norm is None
+        else:
+            raise AssertionError(f"{kind} accepted")
+
+
+def test_malformed_key():
+    pk = split(KATS[0])[0]
+
+    for bad, kind, code in [(pk[1:], "BadLength", -4), (b"\x0a" + pk[1:], "BadHeader", -1)]:
+        try:
+            falcon512_rs.prepare_public_key(bad)
+        except falcon512_rs.FalconError as err:
+            assert not isinstance(err, falcon512_rs.VerificationError)
+            assert (err.kind, err.code) == (kind, code)
+        else:
+            raise AssertionError(f"{kind} key accepted")
+
+
+def test_prepared_key():
+    pk = split(KATS[0])[0]
+    coeffs = falcon512_rs.prepare_public_key(pk).ntt_coefficients()
+
+    assert len(coeffs) == 512
+    assert all(0 <= c < 12289 for c in coeffs)
+
+
+def test_decode_compressed():
+    for _, s2, _ in KATS:
+        coeffs = falcon512_rs.decode_compressed(s2)
+
+        assert coeffs == reference_decode(s2)
+        assert all(-2047 <= c <= 2047 for c in coeffs)
+        assert falcon512_rs.decode_compressed(s2 + bytes(10)) == coeffs
+
+
+def test_decode_compressed_invalid():
+    s2 = KATS[0][1]
+
+    for bad, kind in [(s2[:100], "DecodeFailed"), (s2 + b"\x01", "TrailingBits")]:
+        try:
+            falcon512_rs.decode_compressed(bad)
+        except falcon512_rs.FalconError as err:
+            assert err.kind == kind
+        else:
+            raise AssertionError(f"{kind} encoding accepted")
+
+
+if __name__ == "__main__":
+    tests = [(name, f) for name, f in sorted(globals().items()) if name.startswith("test_")]
+
+    for name, test in tests:
+        test()
+        print(f"{name} passed")
+
+    print(f"{len(tests)} passed")
@@ -0,0 +1,48 @@
+// Test program of the wasm bindings (src/wasm.rs), run with Node by src/tests/wasm_tests.rs on
+// the `wasm-bindgen --target nodejs` output.
+//
+// usage: node verify.mjs <pkg/falcon512_rs.js> <vector.json>
+//
+// The vector holds a known-answer public key, message and complete signature as hex strings.
+
+import assert from "node:assert/strict";
+import { readFileSync } from "node:fs";
+import { createRequire } from "node:module";
+
+const [pkgPath, vectorPath] = process.argv.slice(2);
+const falcon = createRequire(import.meta.url)(pkgPath);
+const vector = JSON.parse(readFileSync(vectorPath, "utf8"));
+const hex = (s) => Uint8Array.from(Buffer.from(s, "hex"));
+
+const pk = hex(vector.pk);
+const msg = hex(vector.msg);
+const sig = hex(vector.sig);
+
+// one-shot and prepared verification of the vector
+assert.equal(falcon.verify(pk, msg, sig), true);
+
+const key = falcon.prepareKey(pk);
+
+assert.equal(key.verify(msg, sig), true);
+assert.equal(new falcon.PreparedKey(pk).verify(msg, sig), true);
+
+// tampered message, tampered signature, truncated signature
+const badMsg = msg.slice();
+badMsg[0] ^= 1;
+
+const badSig = sig.slice();
+badSig[100] ^= 1;
+
+assert.equal(key.verify(badMsg, sig), false);
+assert.equal(key.verify(msg, badSig), false);
+assert.equal(key.verify(msg, sig.subarray(0, 41)), false);
+assert.equal(falcon.verify(pk, badMsg, sig), false);
+
+// malformed public keys throw with the error description
+assert.throws(() => falcon.prepareKey(pk.subarray(1)), /length/);
+assert.throws(() => new falcon.PreparedKey(new Uint8Array(897)), /header/);
+assert.throws(() => falcon.verify(pk.subarray(1), msg, sig), /length/);
+
+key.free();
+
+console.log("wasm bindings: ok");